@@ -0,0 +1,7 @@
+import { RecipeField } from './generated';
+
+// Packs the field groups into the bitmask taken by `lockRecipeV1`.
+export function buildFields(fields: RecipeField[]) {
+  // eslint-disable-next-line no-bitwise
+  return fields.reduce((bits, field) => bits | (1 << field), 0);
+}
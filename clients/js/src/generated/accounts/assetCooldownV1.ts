@@ -0,0 +1,169 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type AssetCooldownV1 = Account<AssetCooldownV1AccountData>;
+
+export type AssetCooldownV1AccountData = {
+  discriminator: Array<number>;
+  asset: PublicKey;
+  lastCaptureSlot: bigint;
+  lastReleaseSlot: bigint;
+  bump: number;
+};
+
+export type AssetCooldownV1AccountDataArgs = {
+  asset: PublicKey;
+  lastCaptureSlot: number | bigint;
+  lastReleaseSlot: number | bigint;
+  bump: number;
+};
+
+export function getAssetCooldownV1AccountDataSerializer(): Serializer<
+  AssetCooldownV1AccountDataArgs,
+  AssetCooldownV1AccountData
+> {
+  return mapSerializer<
+    AssetCooldownV1AccountDataArgs,
+    any,
+    AssetCooldownV1AccountData
+  >(
+    struct<AssetCooldownV1AccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['asset', publicKeySerializer()],
+        ['lastCaptureSlot', u64()],
+        ['lastReleaseSlot', u64()],
+        ['bump', u8()],
+      ],
+      { description: 'AssetCooldownV1AccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [199, 212, 122, 145, 17, 174, 13, 66],
+    })
+  ) as Serializer<AssetCooldownV1AccountDataArgs, AssetCooldownV1AccountData>;
+}
+
+export function deserializeAssetCooldownV1(
+  rawAccount: RpcAccount
+): AssetCooldownV1 {
+  return deserializeAccount(
+    rawAccount,
+    getAssetCooldownV1AccountDataSerializer()
+  );
+}
+
+export async function fetchAssetCooldownV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<AssetCooldownV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'AssetCooldownV1');
+  return deserializeAssetCooldownV1(maybeAccount);
+}
+
+export async function safeFetchAssetCooldownV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<AssetCooldownV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeAssetCooldownV1(maybeAccount) : null;
+}
+
+export async function fetchAllAssetCooldownV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<AssetCooldownV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'AssetCooldownV1');
+    return deserializeAssetCooldownV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllAssetCooldownV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<AssetCooldownV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeAssetCooldownV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getAssetCooldownV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      asset: PublicKey;
+      lastCaptureSlot: number | bigint;
+      lastReleaseSlot: number | bigint;
+      bump: number;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      asset: [8, publicKeySerializer()],
+      lastCaptureSlot: [40, u64()],
+      lastReleaseSlot: [48, u64()],
+      bump: [56, u8()],
+    })
+    .deserializeUsing<AssetCooldownV1>((account) =>
+      deserializeAssetCooldownV1(account)
+    )
+    .whereField('discriminator', [199, 212, 122, 145, 17, 174, 13, 66]);
+}
+
+export function getAssetCooldownV1Size(): number {
+  return 57;
+}
@@ -0,0 +1,185 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  BasketEntry,
+  BasketEntryArgs,
+  getBasketEntrySerializer,
+} from '../types';
+
+export type BasketV1 = Account<BasketV1AccountData>;
+
+export type BasketV1AccountData = {
+  discriminator: Array<number>;
+  recipe: PublicKey;
+  entries: Array<BasketEntry>;
+  bump: number;
+};
+
+export type BasketV1AccountDataArgs = {
+  recipe: PublicKey;
+  entries: Array<BasketEntryArgs>;
+  bump: number;
+};
+
+export function getBasketV1AccountDataSerializer(): Serializer<
+  BasketV1AccountDataArgs,
+  BasketV1AccountData
+> {
+  return mapSerializer<BasketV1AccountDataArgs, any, BasketV1AccountData>(
+    struct<BasketV1AccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['recipe', publicKeySerializer()],
+        ['entries', array(getBasketEntrySerializer())],
+        ['bump', u8()],
+      ],
+      { description: 'BasketV1AccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [208, 20, 25, 108, 236, 252, 197, 98],
+    })
+  ) as Serializer<BasketV1AccountDataArgs, BasketV1AccountData>;
+}
+
+export function deserializeBasketV1(rawAccount: RpcAccount): BasketV1 {
+  return deserializeAccount(rawAccount, getBasketV1AccountDataSerializer());
+}
+
+export async function fetchBasketV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<BasketV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'BasketV1');
+  return deserializeBasketV1(maybeAccount);
+}
+
+export async function safeFetchBasketV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<BasketV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeBasketV1(maybeAccount) : null;
+}
+
+export async function fetchAllBasketV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<BasketV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'BasketV1');
+    return deserializeBasketV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllBasketV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<BasketV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeBasketV1(maybeAccount as RpcAccount));
+}
+
+export function getBasketV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      recipe: PublicKey;
+      entries: Array<BasketEntryArgs>;
+      bump: number;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      recipe: [8, publicKeySerializer()],
+      entries: [40, array(getBasketEntrySerializer())],
+      bump: [null, u8()],
+    })
+    .deserializeUsing<BasketV1>((account) => deserializeBasketV1(account))
+    .whereField('discriminator', [208, 20, 25, 108, 236, 252, 197, 98]);
+}
+
+export function findBasketV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the recipe */
+    recipe: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('basket'),
+    publicKeySerializer().serialize(seeds.recipe),
+  ]);
+}
+
+export async function fetchBasketV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findBasketV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<BasketV1> {
+  return fetchBasketV1(context, findBasketV1Pda(context, seeds), options);
+}
+
+export async function safeFetchBasketV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findBasketV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<BasketV1 | null> {
+  return safeFetchBasketV1(context, findBasketV1Pda(context, seeds), options);
+}
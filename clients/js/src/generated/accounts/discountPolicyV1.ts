@@ -0,0 +1,203 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  DiscountEntry,
+  DiscountEntryArgs,
+  getDiscountEntrySerializer,
+} from '../types';
+
+export type DiscountPolicyV1 = Account<DiscountPolicyV1AccountData>;
+
+export type DiscountPolicyV1AccountData = {
+  discriminator: Array<number>;
+  recipe: PublicKey;
+  entries: Array<DiscountEntry>;
+  bump: number;
+};
+
+export type DiscountPolicyV1AccountDataArgs = {
+  recipe: PublicKey;
+  entries: Array<DiscountEntryArgs>;
+  bump: number;
+};
+
+export function getDiscountPolicyV1AccountDataSerializer(): Serializer<
+  DiscountPolicyV1AccountDataArgs,
+  DiscountPolicyV1AccountData
+> {
+  return mapSerializer<
+    DiscountPolicyV1AccountDataArgs,
+    any,
+    DiscountPolicyV1AccountData
+  >(
+    struct<DiscountPolicyV1AccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['recipe', publicKeySerializer()],
+        ['entries', array(getDiscountEntrySerializer())],
+        ['bump', u8()],
+      ],
+      { description: 'DiscountPolicyV1AccountData' }
+    ),
+    (value) => ({ ...value, discriminator: [80, 53, 95, 59, 210, 46, 59, 195] })
+  ) as Serializer<DiscountPolicyV1AccountDataArgs, DiscountPolicyV1AccountData>;
+}
+
+export function deserializeDiscountPolicyV1(
+  rawAccount: RpcAccount
+): DiscountPolicyV1 {
+  return deserializeAccount(
+    rawAccount,
+    getDiscountPolicyV1AccountDataSerializer()
+  );
+}
+
+export async function fetchDiscountPolicyV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<DiscountPolicyV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'DiscountPolicyV1');
+  return deserializeDiscountPolicyV1(maybeAccount);
+}
+
+export async function safeFetchDiscountPolicyV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<DiscountPolicyV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeDiscountPolicyV1(maybeAccount) : null;
+}
+
+export async function fetchAllDiscountPolicyV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<DiscountPolicyV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'DiscountPolicyV1');
+    return deserializeDiscountPolicyV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllDiscountPolicyV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<DiscountPolicyV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeDiscountPolicyV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getDiscountPolicyV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      recipe: PublicKey;
+      entries: Array<DiscountEntryArgs>;
+      bump: number;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      recipe: [8, publicKeySerializer()],
+      entries: [40, array(getDiscountEntrySerializer())],
+      bump: [null, u8()],
+    })
+    .deserializeUsing<DiscountPolicyV1>((account) =>
+      deserializeDiscountPolicyV1(account)
+    )
+    .whereField('discriminator', [80, 53, 95, 59, 210, 46, 59, 195]);
+}
+
+export function findDiscountPolicyV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the recipe */
+    recipe: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('discount'),
+    publicKeySerializer().serialize(seeds.recipe),
+  ]);
+}
+
+export async function fetchDiscountPolicyV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findDiscountPolicyV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<DiscountPolicyV1> {
+  return fetchDiscountPolicyV1(
+    context,
+    findDiscountPolicyV1Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchDiscountPolicyV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findDiscountPolicyV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<DiscountPolicyV1 | null> {
+  return safeFetchDiscountPolicyV1(
+    context,
+    findDiscountPolicyV1Pda(context, seeds),
+    options
+  );
+}
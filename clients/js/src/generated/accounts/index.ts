@@ -6,7 +6,19 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+export * from './assetCooldownV1';
+export * from './basketV1';
+export * from './discountPolicyV1';
 export * from './escrowV1';
 export * from './escrowV2';
 export * from './nftDataV1';
+export * from './operatorsV1';
+export * from './paymentTableV1';
+export * from './pendingRecipeUpdateV1';
+export * from './protocolConfigV1';
+export * from './quoteNonceV1';
+export * from './recipeHistoryV1';
 export * from './recipeV1';
+export * from './recipeVaultV1';
+export * from './swapReceiptV1';
+export * from './walletActivityV1';
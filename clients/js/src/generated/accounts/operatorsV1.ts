@@ -0,0 +1,194 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  OperatorEntry,
+  OperatorEntryArgs,
+  getOperatorEntrySerializer,
+} from '../types';
+
+export type OperatorsV1 = Account<OperatorsV1AccountData>;
+
+export type OperatorsV1AccountData = {
+  discriminator: Array<number>;
+  recipe: PublicKey;
+  authority: PublicKey;
+  entries: Array<OperatorEntry>;
+  bump: number;
+};
+
+export type OperatorsV1AccountDataArgs = {
+  recipe: PublicKey;
+  authority: PublicKey;
+  entries: Array<OperatorEntryArgs>;
+  bump: number;
+};
+
+export function getOperatorsV1AccountDataSerializer(): Serializer<
+  OperatorsV1AccountDataArgs,
+  OperatorsV1AccountData
+> {
+  return mapSerializer<OperatorsV1AccountDataArgs, any, OperatorsV1AccountData>(
+    struct<OperatorsV1AccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['recipe', publicKeySerializer()],
+        ['authority', publicKeySerializer()],
+        ['entries', array(getOperatorEntrySerializer())],
+        ['bump', u8()],
+      ],
+      { description: 'OperatorsV1AccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [5, 219, 115, 79, 171, 81, 120, 241],
+    })
+  ) as Serializer<OperatorsV1AccountDataArgs, OperatorsV1AccountData>;
+}
+
+export function deserializeOperatorsV1(rawAccount: RpcAccount): OperatorsV1 {
+  return deserializeAccount(rawAccount, getOperatorsV1AccountDataSerializer());
+}
+
+export async function fetchOperatorsV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<OperatorsV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'OperatorsV1');
+  return deserializeOperatorsV1(maybeAccount);
+}
+
+export async function safeFetchOperatorsV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<OperatorsV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeOperatorsV1(maybeAccount) : null;
+}
+
+export async function fetchAllOperatorsV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<OperatorsV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'OperatorsV1');
+    return deserializeOperatorsV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllOperatorsV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<OperatorsV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeOperatorsV1(maybeAccount as RpcAccount));
+}
+
+export function getOperatorsV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      recipe: PublicKey;
+      authority: PublicKey;
+      entries: Array<OperatorEntryArgs>;
+      bump: number;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      recipe: [8, publicKeySerializer()],
+      authority: [40, publicKeySerializer()],
+      entries: [72, array(getOperatorEntrySerializer())],
+      bump: [null, u8()],
+    })
+    .deserializeUsing<OperatorsV1>((account) => deserializeOperatorsV1(account))
+    .whereField('discriminator', [5, 219, 115, 79, 171, 81, 120, 241]);
+}
+
+export function findOperatorsV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the recipe */
+    recipe: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('operators'),
+    publicKeySerializer().serialize(seeds.recipe),
+  ]);
+}
+
+export async function fetchOperatorsV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findOperatorsV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<OperatorsV1> {
+  return fetchOperatorsV1(context, findOperatorsV1Pda(context, seeds), options);
+}
+
+export async function safeFetchOperatorsV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findOperatorsV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<OperatorsV1 | null> {
+  return safeFetchOperatorsV1(
+    context,
+    findOperatorsV1Pda(context, seeds),
+    options
+  );
+}
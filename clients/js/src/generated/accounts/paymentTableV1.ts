@@ -0,0 +1,206 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  PaymentEntry,
+  PaymentEntryArgs,
+  getPaymentEntrySerializer,
+} from '../types';
+
+export type PaymentTableV1 = Account<PaymentTableV1AccountData>;
+
+export type PaymentTableV1AccountData = {
+  discriminator: Array<number>;
+  recipe: PublicKey;
+  entries: Array<PaymentEntry>;
+  bump: number;
+};
+
+export type PaymentTableV1AccountDataArgs = {
+  recipe: PublicKey;
+  entries: Array<PaymentEntryArgs>;
+  bump: number;
+};
+
+export function getPaymentTableV1AccountDataSerializer(): Serializer<
+  PaymentTableV1AccountDataArgs,
+  PaymentTableV1AccountData
+> {
+  return mapSerializer<
+    PaymentTableV1AccountDataArgs,
+    any,
+    PaymentTableV1AccountData
+  >(
+    struct<PaymentTableV1AccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['recipe', publicKeySerializer()],
+        ['entries', array(getPaymentEntrySerializer())],
+        ['bump', u8()],
+      ],
+      { description: 'PaymentTableV1AccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [107, 112, 248, 103, 20, 209, 192, 112],
+    })
+  ) as Serializer<PaymentTableV1AccountDataArgs, PaymentTableV1AccountData>;
+}
+
+export function deserializePaymentTableV1(
+  rawAccount: RpcAccount
+): PaymentTableV1 {
+  return deserializeAccount(
+    rawAccount,
+    getPaymentTableV1AccountDataSerializer()
+  );
+}
+
+export async function fetchPaymentTableV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<PaymentTableV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'PaymentTableV1');
+  return deserializePaymentTableV1(maybeAccount);
+}
+
+export async function safeFetchPaymentTableV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<PaymentTableV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializePaymentTableV1(maybeAccount) : null;
+}
+
+export async function fetchAllPaymentTableV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<PaymentTableV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'PaymentTableV1');
+    return deserializePaymentTableV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllPaymentTableV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<PaymentTableV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializePaymentTableV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getPaymentTableV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      recipe: PublicKey;
+      entries: Array<PaymentEntryArgs>;
+      bump: number;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      recipe: [8, publicKeySerializer()],
+      entries: [40, array(getPaymentEntrySerializer())],
+      bump: [null, u8()],
+    })
+    .deserializeUsing<PaymentTableV1>((account) =>
+      deserializePaymentTableV1(account)
+    )
+    .whereField('discriminator', [107, 112, 248, 103, 20, 209, 192, 112]);
+}
+
+export function findPaymentTableV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the recipe */
+    recipe: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('payments'),
+    publicKeySerializer().serialize(seeds.recipe),
+  ]);
+}
+
+export async function fetchPaymentTableV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findPaymentTableV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<PaymentTableV1> {
+  return fetchPaymentTableV1(
+    context,
+    findPaymentTableV1Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchPaymentTableV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findPaymentTableV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<PaymentTableV1 | null> {
+  return safeFetchPaymentTableV1(
+    context,
+    findPaymentTableV1Pda(context, seeds),
+    options
+  );
+}
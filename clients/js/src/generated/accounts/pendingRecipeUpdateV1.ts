@@ -0,0 +1,235 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  option,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  UpdateRecipeV1Ix,
+  UpdateRecipeV1IxArgs,
+  getUpdateRecipeV1IxSerializer,
+} from '../types';
+
+export type PendingRecipeUpdateV1 = Account<PendingRecipeUpdateV1AccountData>;
+
+export type PendingRecipeUpdateV1AccountData = {
+  discriminator: Array<number>;
+  recipe: PublicKey;
+  authority: PublicKey;
+  activationSlot: bigint;
+  token: Option<PublicKey>;
+  feeLocation: PublicKey;
+  bump: number;
+  update: UpdateRecipeV1Ix;
+};
+
+export type PendingRecipeUpdateV1AccountDataArgs = {
+  recipe: PublicKey;
+  authority: PublicKey;
+  activationSlot: number | bigint;
+  token: OptionOrNullable<PublicKey>;
+  feeLocation: PublicKey;
+  bump: number;
+  update: UpdateRecipeV1IxArgs;
+};
+
+export function getPendingRecipeUpdateV1AccountDataSerializer(): Serializer<
+  PendingRecipeUpdateV1AccountDataArgs,
+  PendingRecipeUpdateV1AccountData
+> {
+  return mapSerializer<
+    PendingRecipeUpdateV1AccountDataArgs,
+    any,
+    PendingRecipeUpdateV1AccountData
+  >(
+    struct<PendingRecipeUpdateV1AccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['recipe', publicKeySerializer()],
+        ['authority', publicKeySerializer()],
+        ['activationSlot', u64()],
+        ['token', option(publicKeySerializer())],
+        ['feeLocation', publicKeySerializer()],
+        ['bump', u8()],
+        ['update', getUpdateRecipeV1IxSerializer()],
+      ],
+      { description: 'PendingRecipeUpdateV1AccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [49, 183, 60, 152, 41, 172, 145, 24],
+    })
+  ) as Serializer<
+    PendingRecipeUpdateV1AccountDataArgs,
+    PendingRecipeUpdateV1AccountData
+  >;
+}
+
+export function deserializePendingRecipeUpdateV1(
+  rawAccount: RpcAccount
+): PendingRecipeUpdateV1 {
+  return deserializeAccount(
+    rawAccount,
+    getPendingRecipeUpdateV1AccountDataSerializer()
+  );
+}
+
+export async function fetchPendingRecipeUpdateV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<PendingRecipeUpdateV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'PendingRecipeUpdateV1');
+  return deserializePendingRecipeUpdateV1(maybeAccount);
+}
+
+export async function safeFetchPendingRecipeUpdateV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<PendingRecipeUpdateV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializePendingRecipeUpdateV1(maybeAccount)
+    : null;
+}
+
+export async function fetchAllPendingRecipeUpdateV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<PendingRecipeUpdateV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'PendingRecipeUpdateV1');
+    return deserializePendingRecipeUpdateV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllPendingRecipeUpdateV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<PendingRecipeUpdateV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializePendingRecipeUpdateV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getPendingRecipeUpdateV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      recipe: PublicKey;
+      authority: PublicKey;
+      activationSlot: number | bigint;
+      token: OptionOrNullable<PublicKey>;
+      feeLocation: PublicKey;
+      bump: number;
+      update: UpdateRecipeV1IxArgs;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      recipe: [8, publicKeySerializer()],
+      authority: [40, publicKeySerializer()],
+      activationSlot: [72, u64()],
+      token: [80, option(publicKeySerializer())],
+      feeLocation: [null, publicKeySerializer()],
+      bump: [null, u8()],
+      update: [null, getUpdateRecipeV1IxSerializer()],
+    })
+    .deserializeUsing<PendingRecipeUpdateV1>((account) =>
+      deserializePendingRecipeUpdateV1(account)
+    )
+    .whereField('discriminator', [49, 183, 60, 152, 41, 172, 145, 24]);
+}
+
+export function findPendingRecipeUpdateV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the recipe */
+    recipe: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('pending'),
+    publicKeySerializer().serialize(seeds.recipe),
+  ]);
+}
+
+export async function fetchPendingRecipeUpdateV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findPendingRecipeUpdateV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<PendingRecipeUpdateV1> {
+  return fetchPendingRecipeUpdateV1(
+    context,
+    findPendingRecipeUpdateV1Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchPendingRecipeUpdateV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findPendingRecipeUpdateV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<PendingRecipeUpdateV1 | null> {
+  return safeFetchPendingRecipeUpdateV1(
+    context,
+    findPendingRecipeUpdateV1Pda(context, seeds),
+    options
+  );
+}
@@ -0,0 +1,202 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u16,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type ProtocolConfigV1 = Account<ProtocolConfigV1AccountData>;
+
+export type ProtocolConfigV1AccountData = {
+  discriminator: Array<number>;
+  admin: PublicKey;
+  paused: boolean;
+  pauseReason: number;
+  bump: number;
+};
+
+export type ProtocolConfigV1AccountDataArgs = {
+  admin: PublicKey;
+  paused: boolean;
+  pauseReason: number;
+  bump: number;
+};
+
+export function getProtocolConfigV1AccountDataSerializer(): Serializer<
+  ProtocolConfigV1AccountDataArgs,
+  ProtocolConfigV1AccountData
+> {
+  return mapSerializer<
+    ProtocolConfigV1AccountDataArgs,
+    any,
+    ProtocolConfigV1AccountData
+  >(
+    struct<ProtocolConfigV1AccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['admin', publicKeySerializer()],
+        ['paused', bool()],
+        ['pauseReason', u16()],
+        ['bump', u8()],
+      ],
+      { description: 'ProtocolConfigV1AccountData' }
+    ),
+    (value) => ({ ...value, discriminator: [84, 23, 98, 54, 166, 135, 91, 74] })
+  ) as Serializer<ProtocolConfigV1AccountDataArgs, ProtocolConfigV1AccountData>;
+}
+
+export function deserializeProtocolConfigV1(
+  rawAccount: RpcAccount
+): ProtocolConfigV1 {
+  return deserializeAccount(
+    rawAccount,
+    getProtocolConfigV1AccountDataSerializer()
+  );
+}
+
+export async function fetchProtocolConfigV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ProtocolConfigV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'ProtocolConfigV1');
+  return deserializeProtocolConfigV1(maybeAccount);
+}
+
+export async function safeFetchProtocolConfigV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ProtocolConfigV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeProtocolConfigV1(maybeAccount) : null;
+}
+
+export async function fetchAllProtocolConfigV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ProtocolConfigV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'ProtocolConfigV1');
+    return deserializeProtocolConfigV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllProtocolConfigV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ProtocolConfigV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeProtocolConfigV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getProtocolConfigV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      admin: PublicKey;
+      paused: boolean;
+      pauseReason: number;
+      bump: number;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      admin: [8, publicKeySerializer()],
+      paused: [40, bool()],
+      pauseReason: [41, u16()],
+      bump: [43, u8()],
+    })
+    .deserializeUsing<ProtocolConfigV1>((account) =>
+      deserializeProtocolConfigV1(account)
+    )
+    .whereField('discriminator', [84, 23, 98, 54, 166, 135, 91, 74]);
+}
+
+export function getProtocolConfigV1Size(): number {
+  return 44;
+}
+
+export function findProtocolConfigV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('protocol'),
+  ]);
+}
+
+export async function fetchProtocolConfigV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  options?: RpcGetAccountOptions
+): Promise<ProtocolConfigV1> {
+  return fetchProtocolConfigV1(
+    context,
+    findProtocolConfigV1Pda(context),
+    options
+  );
+}
+
+export async function safeFetchProtocolConfigV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  options?: RpcGetAccountOptions
+): Promise<ProtocolConfigV1 | null> {
+  return safeFetchProtocolConfigV1(
+    context,
+    findProtocolConfigV1Pda(context),
+    options
+  );
+}
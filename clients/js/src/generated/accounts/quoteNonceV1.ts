@@ -0,0 +1,154 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type QuoteNonceV1 = Account<QuoteNonceV1AccountData>;
+
+export type QuoteNonceV1AccountData = {
+  discriminator: Array<number>;
+  recipe: PublicKey;
+  nonce: bigint;
+  bump: number;
+};
+
+export type QuoteNonceV1AccountDataArgs = {
+  recipe: PublicKey;
+  nonce: number | bigint;
+  bump: number;
+};
+
+export function getQuoteNonceV1AccountDataSerializer(): Serializer<
+  QuoteNonceV1AccountDataArgs,
+  QuoteNonceV1AccountData
+> {
+  return mapSerializer<
+    QuoteNonceV1AccountDataArgs,
+    any,
+    QuoteNonceV1AccountData
+  >(
+    struct<QuoteNonceV1AccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['recipe', publicKeySerializer()],
+        ['nonce', u64()],
+        ['bump', u8()],
+      ],
+      { description: 'QuoteNonceV1AccountData' }
+    ),
+    (value) => ({ ...value, discriminator: [210, 6, 167, 25, 8, 109, 232, 36] })
+  ) as Serializer<QuoteNonceV1AccountDataArgs, QuoteNonceV1AccountData>;
+}
+
+export function deserializeQuoteNonceV1(rawAccount: RpcAccount): QuoteNonceV1 {
+  return deserializeAccount(rawAccount, getQuoteNonceV1AccountDataSerializer());
+}
+
+export async function fetchQuoteNonceV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<QuoteNonceV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'QuoteNonceV1');
+  return deserializeQuoteNonceV1(maybeAccount);
+}
+
+export async function safeFetchQuoteNonceV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<QuoteNonceV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeQuoteNonceV1(maybeAccount) : null;
+}
+
+export async function fetchAllQuoteNonceV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<QuoteNonceV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'QuoteNonceV1');
+    return deserializeQuoteNonceV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllQuoteNonceV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<QuoteNonceV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeQuoteNonceV1(maybeAccount as RpcAccount));
+}
+
+export function getQuoteNonceV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      recipe: PublicKey;
+      nonce: number | bigint;
+      bump: number;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      recipe: [8, publicKeySerializer()],
+      nonce: [40, u64()],
+      bump: [48, u8()],
+    })
+    .deserializeUsing<QuoteNonceV1>((account) =>
+      deserializeQuoteNonceV1(account)
+    )
+    .whereField('discriminator', [210, 6, 167, 25, 8, 109, 232, 36]);
+}
+
+export function getQuoteNonceV1Size(): number {
+  return 49;
+}
@@ -0,0 +1,212 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  RecipeChange,
+  RecipeChangeArgs,
+  getRecipeChangeSerializer,
+} from '../types';
+
+export type RecipeHistoryV1 = Account<RecipeHistoryV1AccountData>;
+
+export type RecipeHistoryV1AccountData = {
+  discriminator: Array<number>;
+  recipe: PublicKey;
+  count: bigint;
+  bump: number;
+  entries: Array<RecipeChange>;
+};
+
+export type RecipeHistoryV1AccountDataArgs = {
+  recipe: PublicKey;
+  count: number | bigint;
+  bump: number;
+  entries: Array<RecipeChangeArgs>;
+};
+
+export function getRecipeHistoryV1AccountDataSerializer(): Serializer<
+  RecipeHistoryV1AccountDataArgs,
+  RecipeHistoryV1AccountData
+> {
+  return mapSerializer<
+    RecipeHistoryV1AccountDataArgs,
+    any,
+    RecipeHistoryV1AccountData
+  >(
+    struct<RecipeHistoryV1AccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['recipe', publicKeySerializer()],
+        ['count', u64()],
+        ['bump', u8()],
+        ['entries', array(getRecipeChangeSerializer())],
+      ],
+      { description: 'RecipeHistoryV1AccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [1, 152, 186, 189, 198, 90, 17, 108],
+    })
+  ) as Serializer<RecipeHistoryV1AccountDataArgs, RecipeHistoryV1AccountData>;
+}
+
+export function deserializeRecipeHistoryV1(
+  rawAccount: RpcAccount
+): RecipeHistoryV1 {
+  return deserializeAccount(
+    rawAccount,
+    getRecipeHistoryV1AccountDataSerializer()
+  );
+}
+
+export async function fetchRecipeHistoryV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<RecipeHistoryV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'RecipeHistoryV1');
+  return deserializeRecipeHistoryV1(maybeAccount);
+}
+
+export async function safeFetchRecipeHistoryV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<RecipeHistoryV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeRecipeHistoryV1(maybeAccount) : null;
+}
+
+export async function fetchAllRecipeHistoryV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<RecipeHistoryV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'RecipeHistoryV1');
+    return deserializeRecipeHistoryV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllRecipeHistoryV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<RecipeHistoryV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeRecipeHistoryV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getRecipeHistoryV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      recipe: PublicKey;
+      count: number | bigint;
+      bump: number;
+      entries: Array<RecipeChangeArgs>;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      recipe: [8, publicKeySerializer()],
+      count: [40, u64()],
+      bump: [48, u8()],
+      entries: [49, array(getRecipeChangeSerializer())],
+    })
+    .deserializeUsing<RecipeHistoryV1>((account) =>
+      deserializeRecipeHistoryV1(account)
+    )
+    .whereField('discriminator', [1, 152, 186, 189, 198, 90, 17, 108]);
+}
+
+export function findRecipeHistoryV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the recipe */
+    recipe: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('history'),
+    publicKeySerializer().serialize(seeds.recipe),
+  ]);
+}
+
+export async function fetchRecipeHistoryV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findRecipeHistoryV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<RecipeHistoryV1> {
+  return fetchRecipeHistoryV1(
+    context,
+    findRecipeHistoryV1Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchRecipeHistoryV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findRecipeHistoryV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<RecipeHistoryV1 | null> {
+  return safeFetchRecipeHistoryV1(
+    context,
+    findRecipeHistoryV1Pda(context, seeds),
+    options
+  );
+}
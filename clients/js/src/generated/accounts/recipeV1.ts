@@ -22,6 +22,8 @@ import {
 import {
   Serializer,
   array,
+  bool,
+  i64,
   mapSerializer,
   publicKey as publicKeySerializer,
   string,
@@ -30,6 +32,44 @@ import {
   u64,
   u8,
 } from '@metaplex-foundation/umi/serializers';
+import {
+  AssetCooldown,
+  AssetCooldownArgs,
+  CircuitBreaker,
+  CircuitBreakerArgs,
+  Currency,
+  CurrencyArgs,
+  FeeCaps,
+  FeeCapsArgs,
+  LoyaltyRate,
+  LoyaltyRateArgs,
+  OutflowWindow,
+  OutflowWindowArgs,
+  PricingCurve,
+  PricingCurveArgs,
+  RecipeGate,
+  RecipeGateArgs,
+  RecipeKind,
+  RecipeKindArgs,
+  RecipeStats,
+  RecipeStatsArgs,
+  SwapWindow,
+  SwapWindowArgs,
+  WalletLimits,
+  WalletLimitsArgs,
+  getAssetCooldownSerializer,
+  getCircuitBreakerSerializer,
+  getCurrencySerializer,
+  getFeeCapsSerializer,
+  getLoyaltyRateSerializer,
+  getOutflowWindowSerializer,
+  getPricingCurveSerializer,
+  getRecipeGateSerializer,
+  getRecipeKindSerializer,
+  getRecipeStatsSerializer,
+  getSwapWindowSerializer,
+  getWalletLimitsSerializer,
+} from '../types';
 
 export type RecipeV1 = Account<RecipeV1AccountData>;
 
@@ -43,7 +83,7 @@ export type RecipeV1AccountData = {
   uri: string;
   max: bigint;
   min: bigint;
-  amount: bigint;
+  captureAmount: bigint;
   feeAmountCapture: bigint;
   solFeeAmountCapture: bigint;
   feeAmountRelease: bigint;
@@ -51,6 +91,33 @@ export type RecipeV1AccountData = {
   count: bigint;
   path: number;
   bump: number;
+  releaseAmount: bigint;
+  pricing: PricingCurve;
+  inventory: bigint;
+  netSwaps: bigint;
+  currency: Currency;
+  quoteSigner: PublicKey;
+  captureWindow: SwapWindow;
+  releaseWindow: SwapWindow;
+  walletLimits: WalletLimits;
+  circuitBreaker: CircuitBreaker;
+  outflowWindow: OutflowWindow;
+  paused: boolean;
+  pauseReason: number;
+  assetCooldown: AssetCooldown;
+  stats: RecipeStats;
+  loyalty: LoyaltyRate;
+  pendingAuthority: PublicKey;
+  timelockSlots: bigint;
+  locked: number;
+  feeCaps: FeeCaps;
+  kind: RecipeKind;
+  assetRoot: Array<number>;
+  walletRoot: Array<number>;
+  gate: RecipeGate;
+  previousAuthority: PublicKey;
+  legacyAuthority: PublicKey;
+  legacyToken: PublicKey;
 };
 
 export type RecipeV1AccountDataArgs = {
@@ -62,7 +129,7 @@ export type RecipeV1AccountDataArgs = {
   uri: string;
   max: number | bigint;
   min: number | bigint;
-  amount: number | bigint;
+  captureAmount: number | bigint;
   feeAmountCapture: number | bigint;
   solFeeAmountCapture: number | bigint;
   feeAmountRelease: number | bigint;
@@ -70,6 +137,33 @@ export type RecipeV1AccountDataArgs = {
   count: number | bigint;
   path: number;
   bump: number;
+  releaseAmount: number | bigint;
+  pricing: PricingCurveArgs;
+  inventory: number | bigint;
+  netSwaps: number | bigint;
+  currency: CurrencyArgs;
+  quoteSigner: PublicKey;
+  captureWindow: SwapWindowArgs;
+  releaseWindow: SwapWindowArgs;
+  walletLimits: WalletLimitsArgs;
+  circuitBreaker: CircuitBreakerArgs;
+  outflowWindow: OutflowWindowArgs;
+  paused: boolean;
+  pauseReason: number;
+  assetCooldown: AssetCooldownArgs;
+  stats: RecipeStatsArgs;
+  loyalty: LoyaltyRateArgs;
+  pendingAuthority: PublicKey;
+  timelockSlots: number | bigint;
+  locked: number;
+  feeCaps: FeeCapsArgs;
+  kind: RecipeKindArgs;
+  assetRoot: Array<number>;
+  walletRoot: Array<number>;
+  gate: RecipeGateArgs;
+  previousAuthority: PublicKey;
+  legacyAuthority: PublicKey;
+  legacyToken: PublicKey;
 };
 
 export function getRecipeV1AccountDataSerializer(): Serializer<
@@ -88,7 +182,7 @@ export function getRecipeV1AccountDataSerializer(): Serializer<
         ['uri', string()],
         ['max', u64()],
         ['min', u64()],
-        ['amount', u64()],
+        ['captureAmount', u64()],
         ['feeAmountCapture', u64()],
         ['solFeeAmountCapture', u64()],
         ['feeAmountRelease', u64()],
@@ -96,6 +190,33 @@ export function getRecipeV1AccountDataSerializer(): Serializer<
         ['count', u64()],
         ['path', u16()],
         ['bump', u8()],
+        ['releaseAmount', u64()],
+        ['pricing', getPricingCurveSerializer()],
+        ['inventory', u64()],
+        ['netSwaps', i64()],
+        ['currency', getCurrencySerializer()],
+        ['quoteSigner', publicKeySerializer()],
+        ['captureWindow', getSwapWindowSerializer()],
+        ['releaseWindow', getSwapWindowSerializer()],
+        ['walletLimits', getWalletLimitsSerializer()],
+        ['circuitBreaker', getCircuitBreakerSerializer()],
+        ['outflowWindow', getOutflowWindowSerializer()],
+        ['paused', bool()],
+        ['pauseReason', u16()],
+        ['assetCooldown', getAssetCooldownSerializer()],
+        ['stats', getRecipeStatsSerializer()],
+        ['loyalty', getLoyaltyRateSerializer()],
+        ['pendingAuthority', publicKeySerializer()],
+        ['timelockSlots', u64()],
+        ['locked', u16()],
+        ['feeCaps', getFeeCapsSerializer()],
+        ['kind', getRecipeKindSerializer()],
+        ['assetRoot', array(u8(), { size: 32 })],
+        ['walletRoot', array(u8(), { size: 32 })],
+        ['gate', getRecipeGateSerializer()],
+        ['previousAuthority', publicKeySerializer()],
+        ['legacyAuthority', publicKeySerializer()],
+        ['legacyToken', publicKeySerializer()],
       ],
       { description: 'RecipeV1AccountData' }
     ),
@@ -182,7 +303,7 @@ export function getRecipeV1GpaBuilder(
       uri: string;
       max: number | bigint;
       min: number | bigint;
-      amount: number | bigint;
+      captureAmount: number | bigint;
       feeAmountCapture: number | bigint;
       solFeeAmountCapture: number | bigint;
       feeAmountRelease: number | bigint;
@@ -190,6 +311,33 @@ export function getRecipeV1GpaBuilder(
       count: number | bigint;
       path: number;
       bump: number;
+      releaseAmount: number | bigint;
+      pricing: PricingCurveArgs;
+      inventory: number | bigint;
+      netSwaps: number | bigint;
+      currency: CurrencyArgs;
+      quoteSigner: PublicKey;
+      captureWindow: SwapWindowArgs;
+      releaseWindow: SwapWindowArgs;
+      walletLimits: WalletLimitsArgs;
+      circuitBreaker: CircuitBreakerArgs;
+      outflowWindow: OutflowWindowArgs;
+      paused: boolean;
+      pauseReason: number;
+      assetCooldown: AssetCooldownArgs;
+      stats: RecipeStatsArgs;
+      loyalty: LoyaltyRateArgs;
+      pendingAuthority: PublicKey;
+      timelockSlots: number | bigint;
+      locked: number;
+      feeCaps: FeeCapsArgs;
+      kind: RecipeKindArgs;
+      assetRoot: Array<number>;
+      walletRoot: Array<number>;
+      gate: RecipeGateArgs;
+      previousAuthority: PublicKey;
+      legacyAuthority: PublicKey;
+      legacyToken: PublicKey;
     }>({
       discriminator: [0, array(u8(), { size: 8 })],
       collection: [8, publicKeySerializer()],
@@ -200,7 +348,7 @@ export function getRecipeV1GpaBuilder(
       uri: [null, string()],
       max: [null, u64()],
       min: [null, u64()],
-      amount: [null, u64()],
+      captureAmount: [null, u64()],
       feeAmountCapture: [null, u64()],
       solFeeAmountCapture: [null, u64()],
       feeAmountRelease: [null, u64()],
@@ -208,6 +356,33 @@ export function getRecipeV1GpaBuilder(
       count: [null, u64()],
       path: [null, u16()],
       bump: [null, u8()],
+      releaseAmount: [null, u64()],
+      pricing: [null, getPricingCurveSerializer()],
+      inventory: [null, u64()],
+      netSwaps: [null, i64()],
+      currency: [null, getCurrencySerializer()],
+      quoteSigner: [null, publicKeySerializer()],
+      captureWindow: [null, getSwapWindowSerializer()],
+      releaseWindow: [null, getSwapWindowSerializer()],
+      walletLimits: [null, getWalletLimitsSerializer()],
+      circuitBreaker: [null, getCircuitBreakerSerializer()],
+      outflowWindow: [null, getOutflowWindowSerializer()],
+      paused: [null, bool()],
+      pauseReason: [null, u16()],
+      assetCooldown: [null, getAssetCooldownSerializer()],
+      stats: [null, getRecipeStatsSerializer()],
+      loyalty: [null, getLoyaltyRateSerializer()],
+      pendingAuthority: [null, publicKeySerializer()],
+      timelockSlots: [null, u64()],
+      locked: [null, u16()],
+      feeCaps: [null, getFeeCapsSerializer()],
+      kind: [null, getRecipeKindSerializer()],
+      assetRoot: [null, array(u8(), { size: 32 })],
+      walletRoot: [null, array(u8(), { size: 32 })],
+      gate: [null, getRecipeGateSerializer()],
+      previousAuthority: [null, publicKeySerializer()],
+      legacyAuthority: [null, publicKeySerializer()],
+      legacyToken: [null, publicKeySerializer()],
     })
     .deserializeUsing<RecipeV1>((account) => deserializeRecipeV1(account))
     .whereField('discriminator', [137, 249, 37, 80, 19, 50, 78, 169]);
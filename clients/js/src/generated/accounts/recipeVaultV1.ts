@@ -0,0 +1,197 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type RecipeVaultV1 = Account<RecipeVaultV1AccountData>;
+
+export type RecipeVaultV1AccountData = {
+  discriminator: Array<number>;
+  recipe: PublicKey;
+  bump: number;
+};
+
+export type RecipeVaultV1AccountDataArgs = { recipe: PublicKey; bump: number };
+
+export function getRecipeVaultV1AccountDataSerializer(): Serializer<
+  RecipeVaultV1AccountDataArgs,
+  RecipeVaultV1AccountData
+> {
+  return mapSerializer<
+    RecipeVaultV1AccountDataArgs,
+    any,
+    RecipeVaultV1AccountData
+  >(
+    struct<RecipeVaultV1AccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['recipe', publicKeySerializer()],
+        ['bump', u8()],
+      ],
+      { description: 'RecipeVaultV1AccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [144, 202, 127, 63, 34, 189, 38, 19],
+    })
+  ) as Serializer<RecipeVaultV1AccountDataArgs, RecipeVaultV1AccountData>;
+}
+
+export function deserializeRecipeVaultV1(
+  rawAccount: RpcAccount
+): RecipeVaultV1 {
+  return deserializeAccount(
+    rawAccount,
+    getRecipeVaultV1AccountDataSerializer()
+  );
+}
+
+export async function fetchRecipeVaultV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<RecipeVaultV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'RecipeVaultV1');
+  return deserializeRecipeVaultV1(maybeAccount);
+}
+
+export async function safeFetchRecipeVaultV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<RecipeVaultV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeRecipeVaultV1(maybeAccount) : null;
+}
+
+export async function fetchAllRecipeVaultV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<RecipeVaultV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'RecipeVaultV1');
+    return deserializeRecipeVaultV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllRecipeVaultV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<RecipeVaultV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeRecipeVaultV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getRecipeVaultV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      recipe: PublicKey;
+      bump: number;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      recipe: [8, publicKeySerializer()],
+      bump: [40, u8()],
+    })
+    .deserializeUsing<RecipeVaultV1>((account) =>
+      deserializeRecipeVaultV1(account)
+    )
+    .whereField('discriminator', [144, 202, 127, 63, 34, 189, 38, 19]);
+}
+
+export function getRecipeVaultV1Size(): number {
+  return 41;
+}
+
+export function findRecipeVaultV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the recipe */
+    recipe: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('vault'),
+    publicKeySerializer().serialize(seeds.recipe),
+  ]);
+}
+
+export async function fetchRecipeVaultV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findRecipeVaultV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<RecipeVaultV1> {
+  return fetchRecipeVaultV1(
+    context,
+    findRecipeVaultV1Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchRecipeVaultV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findRecipeVaultV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<RecipeVaultV1 | null> {
+  return safeFetchRecipeVaultV1(
+    context,
+    findRecipeVaultV1Pda(context, seeds),
+    options
+  );
+}
@@ -0,0 +1,229 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type SwapReceiptV1 = Account<SwapReceiptV1AccountData>;
+
+export type SwapReceiptV1AccountData = {
+  discriminator: Array<number>;
+  recipe: PublicKey;
+  user: PublicKey;
+  captures: bigint;
+  releases: bigint;
+  lastSwapSlot: bigint;
+  loyaltyPoints: bigint;
+  bump: number;
+};
+
+export type SwapReceiptV1AccountDataArgs = {
+  recipe: PublicKey;
+  user: PublicKey;
+  captures: number | bigint;
+  releases: number | bigint;
+  lastSwapSlot: number | bigint;
+  loyaltyPoints: number | bigint;
+  bump: number;
+};
+
+export function getSwapReceiptV1AccountDataSerializer(): Serializer<
+  SwapReceiptV1AccountDataArgs,
+  SwapReceiptV1AccountData
+> {
+  return mapSerializer<
+    SwapReceiptV1AccountDataArgs,
+    any,
+    SwapReceiptV1AccountData
+  >(
+    struct<SwapReceiptV1AccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['recipe', publicKeySerializer()],
+        ['user', publicKeySerializer()],
+        ['captures', u64()],
+        ['releases', u64()],
+        ['lastSwapSlot', u64()],
+        ['loyaltyPoints', u64()],
+        ['bump', u8()],
+      ],
+      { description: 'SwapReceiptV1AccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [230, 238, 44, 28, 252, 27, 66, 222],
+    })
+  ) as Serializer<SwapReceiptV1AccountDataArgs, SwapReceiptV1AccountData>;
+}
+
+export function deserializeSwapReceiptV1(
+  rawAccount: RpcAccount
+): SwapReceiptV1 {
+  return deserializeAccount(
+    rawAccount,
+    getSwapReceiptV1AccountDataSerializer()
+  );
+}
+
+export async function fetchSwapReceiptV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<SwapReceiptV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'SwapReceiptV1');
+  return deserializeSwapReceiptV1(maybeAccount);
+}
+
+export async function safeFetchSwapReceiptV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<SwapReceiptV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeSwapReceiptV1(maybeAccount) : null;
+}
+
+export async function fetchAllSwapReceiptV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<SwapReceiptV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'SwapReceiptV1');
+    return deserializeSwapReceiptV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllSwapReceiptV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<SwapReceiptV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeSwapReceiptV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getSwapReceiptV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      recipe: PublicKey;
+      user: PublicKey;
+      captures: number | bigint;
+      releases: number | bigint;
+      lastSwapSlot: number | bigint;
+      loyaltyPoints: number | bigint;
+      bump: number;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      recipe: [8, publicKeySerializer()],
+      user: [40, publicKeySerializer()],
+      captures: [72, u64()],
+      releases: [80, u64()],
+      lastSwapSlot: [88, u64()],
+      loyaltyPoints: [96, u64()],
+      bump: [104, u8()],
+    })
+    .deserializeUsing<SwapReceiptV1>((account) =>
+      deserializeSwapReceiptV1(account)
+    )
+    .whereField('discriminator', [230, 238, 44, 28, 252, 27, 66, 222]);
+}
+
+export function getSwapReceiptV1Size(): number {
+  return 105;
+}
+
+export function findSwapReceiptV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the recipe */
+    recipe: PublicKey;
+    /** The address of the user */
+    user: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('receipt'),
+    publicKeySerializer().serialize(seeds.recipe),
+    publicKeySerializer().serialize(seeds.user),
+  ]);
+}
+
+export async function fetchSwapReceiptV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findSwapReceiptV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<SwapReceiptV1> {
+  return fetchSwapReceiptV1(
+    context,
+    findSwapReceiptV1Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchSwapReceiptV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findSwapReceiptV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<SwapReceiptV1 | null> {
+  return safeFetchSwapReceiptV1(
+    context,
+    findSwapReceiptV1Pda(context, seeds),
+    options
+  );
+}
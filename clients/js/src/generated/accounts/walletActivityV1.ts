@@ -0,0 +1,230 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type WalletActivityV1 = Account<WalletActivityV1AccountData>;
+
+export type WalletActivityV1AccountData = {
+  discriminator: Array<number>;
+  recipe: PublicKey;
+  user: PublicKey;
+  windowStart: bigint;
+  captures: number;
+  releases: number;
+  lastSwapSlot: bigint;
+  bump: number;
+};
+
+export type WalletActivityV1AccountDataArgs = {
+  recipe: PublicKey;
+  user: PublicKey;
+  windowStart: number | bigint;
+  captures: number;
+  releases: number;
+  lastSwapSlot: number | bigint;
+  bump: number;
+};
+
+export function getWalletActivityV1AccountDataSerializer(): Serializer<
+  WalletActivityV1AccountDataArgs,
+  WalletActivityV1AccountData
+> {
+  return mapSerializer<
+    WalletActivityV1AccountDataArgs,
+    any,
+    WalletActivityV1AccountData
+  >(
+    struct<WalletActivityV1AccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['recipe', publicKeySerializer()],
+        ['user', publicKeySerializer()],
+        ['windowStart', u64()],
+        ['captures', u32()],
+        ['releases', u32()],
+        ['lastSwapSlot', u64()],
+        ['bump', u8()],
+      ],
+      { description: 'WalletActivityV1AccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [154, 250, 127, 114, 1, 125, 229, 10],
+    })
+  ) as Serializer<WalletActivityV1AccountDataArgs, WalletActivityV1AccountData>;
+}
+
+export function deserializeWalletActivityV1(
+  rawAccount: RpcAccount
+): WalletActivityV1 {
+  return deserializeAccount(
+    rawAccount,
+    getWalletActivityV1AccountDataSerializer()
+  );
+}
+
+export async function fetchWalletActivityV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<WalletActivityV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'WalletActivityV1');
+  return deserializeWalletActivityV1(maybeAccount);
+}
+
+export async function safeFetchWalletActivityV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<WalletActivityV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeWalletActivityV1(maybeAccount) : null;
+}
+
+export async function fetchAllWalletActivityV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<WalletActivityV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'WalletActivityV1');
+    return deserializeWalletActivityV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllWalletActivityV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<WalletActivityV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeWalletActivityV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getWalletActivityV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      recipe: PublicKey;
+      user: PublicKey;
+      windowStart: number | bigint;
+      captures: number;
+      releases: number;
+      lastSwapSlot: number | bigint;
+      bump: number;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      recipe: [8, publicKeySerializer()],
+      user: [40, publicKeySerializer()],
+      windowStart: [72, u64()],
+      captures: [80, u32()],
+      releases: [84, u32()],
+      lastSwapSlot: [88, u64()],
+      bump: [96, u8()],
+    })
+    .deserializeUsing<WalletActivityV1>((account) =>
+      deserializeWalletActivityV1(account)
+    )
+    .whereField('discriminator', [154, 250, 127, 114, 1, 125, 229, 10]);
+}
+
+export function getWalletActivityV1Size(): number {
+  return 97;
+}
+
+export function findWalletActivityV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the recipe */
+    recipe: PublicKey;
+    /** The address of the user */
+    user: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('wallet'),
+    publicKeySerializer().serialize(seeds.recipe),
+    publicKeySerializer().serialize(seeds.user),
+  ]);
+}
+
+export async function fetchWalletActivityV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findWalletActivityV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<WalletActivityV1> {
+  return fetchWalletActivityV1(
+    context,
+    findWalletActivityV1Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchWalletActivityV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findWalletActivityV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<WalletActivityV1 | null> {
+  return safeFetchWalletActivityV1(
+    context,
+    findWalletActivityV1Pda(context, seeds),
+    options
+  );
+}
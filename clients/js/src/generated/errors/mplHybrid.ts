@@ -278,6 +278,649 @@ export class ReleaseBlockedError extends ProgramError {
 codeToErrorMap.set(0x1783, ReleaseBlockedError);
 nameToErrorMap.set('ReleaseBlocked', ReleaseBlockedError);
 
+/** InvalidDiscount: Discount exceeds 100% */
+export class InvalidDiscountError extends ProgramError {
+  override readonly name: string = 'InvalidDiscount';
+
+  readonly code: number = 0x1784; // 6020
+
+  constructor(program: Program, cause?: Error) {
+    super('Discount exceeds 100%', program, cause);
+  }
+}
+codeToErrorMap.set(0x1784, InvalidDiscountError);
+nameToErrorMap.set('InvalidDiscount', InvalidDiscountError);
+
+/** InvalidDiscountProof: Discount proof does not qualify for a discount */
+export class InvalidDiscountProofError extends ProgramError {
+  override readonly name: string = 'InvalidDiscountProof';
+
+  readonly code: number = 0x1785; // 6021
+
+  constructor(program: Program, cause?: Error) {
+    super('Discount proof does not qualify for a discount', program, cause);
+  }
+}
+codeToErrorMap.set(0x1785, InvalidDiscountProofError);
+nameToErrorMap.set('InvalidDiscountProof', InvalidDiscountProofError);
+
+/** InvalidPricingCurve: Pricing curve minimum exceeds its maximum */
+export class InvalidPricingCurveError extends ProgramError {
+  override readonly name: string = 'InvalidPricingCurve';
+
+  readonly code: number = 0x1786; // 6022
+
+  constructor(program: Program, cause?: Error) {
+    super('Pricing curve minimum exceeds its maximum', program, cause);
+  }
+}
+codeToErrorMap.set(0x1786, InvalidPricingCurveError);
+nameToErrorMap.set('InvalidPricingCurve', InvalidPricingCurveError);
+
+/** MissingTokenAccount: Token account is required for this recipe */
+export class MissingTokenAccountError extends ProgramError {
+  override readonly name: string = 'MissingTokenAccount';
+
+  readonly code: number = 0x1787; // 6023
+
+  constructor(program: Program, cause?: Error) {
+    super('Token account is required for this recipe', program, cause);
+  }
+}
+codeToErrorMap.set(0x1787, MissingTokenAccountError);
+nameToErrorMap.set('MissingTokenAccount', MissingTokenAccountError);
+
+/** InsufficientEscrowBalance: Escrow balance is too low */
+export class InsufficientEscrowBalanceError extends ProgramError {
+  override readonly name: string = 'InsufficientEscrowBalance';
+
+  readonly code: number = 0x1788; // 6024
+
+  constructor(program: Program, cause?: Error) {
+    super('Escrow balance is too low', program, cause);
+  }
+}
+codeToErrorMap.set(0x1788, InsufficientEscrowBalanceError);
+nameToErrorMap.set('InsufficientEscrowBalance', InsufficientEscrowBalanceError);
+
+/** InvalidPathForCurrency: Path is not supported for this currency */
+export class InvalidPathForCurrencyError extends ProgramError {
+  override readonly name: string = 'InvalidPathForCurrency';
+
+  readonly code: number = 0x1789; // 6025
+
+  constructor(program: Program, cause?: Error) {
+    super('Path is not supported for this currency', program, cause);
+  }
+}
+codeToErrorMap.set(0x1789, InvalidPathForCurrencyError);
+nameToErrorMap.set('InvalidPathForCurrency', InvalidPathForCurrencyError);
+
+/** InvalidBasket: Basket entries are invalid */
+export class InvalidBasketError extends ProgramError {
+  override readonly name: string = 'InvalidBasket';
+
+  readonly code: number = 0x178a; // 6026
+
+  constructor(program: Program, cause?: Error) {
+    super('Basket entries are invalid', program, cause);
+  }
+}
+codeToErrorMap.set(0x178a, InvalidBasketError);
+nameToErrorMap.set('InvalidBasket', InvalidBasketError);
+
+/** MissingBasketAccount: Basket account is required for this recipe */
+export class MissingBasketAccountError extends ProgramError {
+  override readonly name: string = 'MissingBasketAccount';
+
+  readonly code: number = 0x178b; // 6027
+
+  constructor(program: Program, cause?: Error) {
+    super('Basket account is required for this recipe', program, cause);
+  }
+}
+codeToErrorMap.set(0x178b, MissingBasketAccountError);
+nameToErrorMap.set('MissingBasketAccount', MissingBasketAccountError);
+
+/** InvalidPaymentTable: Payment table entries are invalid */
+export class InvalidPaymentTableError extends ProgramError {
+  override readonly name: string = 'InvalidPaymentTable';
+
+  readonly code: number = 0x178c; // 6028
+
+  constructor(program: Program, cause?: Error) {
+    super('Payment table entries are invalid', program, cause);
+  }
+}
+codeToErrorMap.set(0x178c, InvalidPaymentTableError);
+nameToErrorMap.set('InvalidPaymentTable', InvalidPaymentTableError);
+
+/** PaymentMintNotAccepted: Mint is not accepted by this recipe */
+export class PaymentMintNotAcceptedError extends ProgramError {
+  override readonly name: string = 'PaymentMintNotAccepted';
+
+  readonly code: number = 0x178d; // 6029
+
+  constructor(program: Program, cause?: Error) {
+    super('Mint is not accepted by this recipe', program, cause);
+  }
+}
+codeToErrorMap.set(0x178d, PaymentMintNotAcceptedError);
+nameToErrorMap.set('PaymentMintNotAccepted', PaymentMintNotAcceptedError);
+
+/** InvalidQuote: Price quote is invalid */
+export class InvalidQuoteError extends ProgramError {
+  override readonly name: string = 'InvalidQuote';
+
+  readonly code: number = 0x178e; // 6030
+
+  constructor(program: Program, cause?: Error) {
+    super('Price quote is invalid', program, cause);
+  }
+}
+codeToErrorMap.set(0x178e, InvalidQuoteError);
+nameToErrorMap.set('InvalidQuote', InvalidQuoteError);
+
+/** QuoteExpired: Price quote has expired */
+export class QuoteExpiredError extends ProgramError {
+  override readonly name: string = 'QuoteExpired';
+
+  readonly code: number = 0x178f; // 6031
+
+  constructor(program: Program, cause?: Error) {
+    super('Price quote has expired', program, cause);
+  }
+}
+codeToErrorMap.set(0x178f, QuoteExpiredError);
+nameToErrorMap.set('QuoteExpired', QuoteExpiredError);
+
+/** QuoteAlreadyUsed: Price quote has already been used */
+export class QuoteAlreadyUsedError extends ProgramError {
+  override readonly name: string = 'QuoteAlreadyUsed';
+
+  readonly code: number = 0x1790; // 6032
+
+  constructor(program: Program, cause?: Error) {
+    super('Price quote has already been used', program, cause);
+  }
+}
+codeToErrorMap.set(0x1790, QuoteAlreadyUsedError);
+nameToErrorMap.set('QuoteAlreadyUsed', QuoteAlreadyUsedError);
+
+/** CaptureNotOpen: Capture window has not opened yet */
+export class CaptureNotOpenError extends ProgramError {
+  override readonly name: string = 'CaptureNotOpen';
+
+  readonly code: number = 0x1791; // 6033
+
+  constructor(program: Program, cause?: Error) {
+    super('Capture window has not opened yet', program, cause);
+  }
+}
+codeToErrorMap.set(0x1791, CaptureNotOpenError);
+nameToErrorMap.set('CaptureNotOpen', CaptureNotOpenError);
+
+/** CaptureClosed: Capture window has closed */
+export class CaptureClosedError extends ProgramError {
+  override readonly name: string = 'CaptureClosed';
+
+  readonly code: number = 0x1792; // 6034
+
+  constructor(program: Program, cause?: Error) {
+    super('Capture window has closed', program, cause);
+  }
+}
+codeToErrorMap.set(0x1792, CaptureClosedError);
+nameToErrorMap.set('CaptureClosed', CaptureClosedError);
+
+/** ReleaseNotOpen: Release window has not opened yet */
+export class ReleaseNotOpenError extends ProgramError {
+  override readonly name: string = 'ReleaseNotOpen';
+
+  readonly code: number = 0x1793; // 6035
+
+  constructor(program: Program, cause?: Error) {
+    super('Release window has not opened yet', program, cause);
+  }
+}
+codeToErrorMap.set(0x1793, ReleaseNotOpenError);
+nameToErrorMap.set('ReleaseNotOpen', ReleaseNotOpenError);
+
+/** ReleaseClosed: Release window has closed */
+export class ReleaseClosedError extends ProgramError {
+  override readonly name: string = 'ReleaseClosed';
+
+  readonly code: number = 0x1794; // 6036
+
+  constructor(program: Program, cause?: Error) {
+    super('Release window has closed', program, cause);
+  }
+}
+codeToErrorMap.set(0x1794, ReleaseClosedError);
+nameToErrorMap.set('ReleaseClosed', ReleaseClosedError);
+
+/** InvalidSwapWindow: Swap window must start before it ends */
+export class InvalidSwapWindowError extends ProgramError {
+  override readonly name: string = 'InvalidSwapWindow';
+
+  readonly code: number = 0x1795; // 6037
+
+  constructor(program: Program, cause?: Error) {
+    super('Swap window must start before it ends', program, cause);
+  }
+}
+codeToErrorMap.set(0x1795, InvalidSwapWindowError);
+nameToErrorMap.set('InvalidSwapWindow', InvalidSwapWindowError);
+
+/** WalletCooldownActive: Wallet is still cooling down from its last swap */
+export class WalletCooldownActiveError extends ProgramError {
+  override readonly name: string = 'WalletCooldownActive';
+
+  readonly code: number = 0x1796; // 6038
+
+  constructor(program: Program, cause?: Error) {
+    super('Wallet is still cooling down from its last swap', program, cause);
+  }
+}
+codeToErrorMap.set(0x1796, WalletCooldownActiveError);
+nameToErrorMap.set('WalletCooldownActive', WalletCooldownActiveError);
+
+/** WalletCaptureLimitReached: Wallet has reached its capture limit */
+export class WalletCaptureLimitReachedError extends ProgramError {
+  override readonly name: string = 'WalletCaptureLimitReached';
+
+  readonly code: number = 0x1797; // 6039
+
+  constructor(program: Program, cause?: Error) {
+    super('Wallet has reached its capture limit', program, cause);
+  }
+}
+codeToErrorMap.set(0x1797, WalletCaptureLimitReachedError);
+nameToErrorMap.set('WalletCaptureLimitReached', WalletCaptureLimitReachedError);
+
+/** WalletReleaseLimitReached: Wallet has reached its release limit */
+export class WalletReleaseLimitReachedError extends ProgramError {
+  override readonly name: string = 'WalletReleaseLimitReached';
+
+  readonly code: number = 0x1798; // 6040
+
+  constructor(program: Program, cause?: Error) {
+    super('Wallet has reached its release limit', program, cause);
+  }
+}
+codeToErrorMap.set(0x1798, WalletReleaseLimitReachedError);
+nameToErrorMap.set('WalletReleaseLimitReached', WalletReleaseLimitReachedError);
+
+/** InvalidWalletActivity: Wallet activity account is invalid */
+export class InvalidWalletActivityError extends ProgramError {
+  override readonly name: string = 'InvalidWalletActivity';
+
+  readonly code: number = 0x1799; // 6041
+
+  constructor(program: Program, cause?: Error) {
+    super('Wallet activity account is invalid', program, cause);
+  }
+}
+codeToErrorMap.set(0x1799, InvalidWalletActivityError);
+nameToErrorMap.set('InvalidWalletActivity', InvalidWalletActivityError);
+
+/** WalletActivityNotExpired: Wallet activity window has not passed */
+export class WalletActivityNotExpiredError extends ProgramError {
+  override readonly name: string = 'WalletActivityNotExpired';
+
+  readonly code: number = 0x179a; // 6042
+
+  constructor(program: Program, cause?: Error) {
+    super('Wallet activity window has not passed', program, cause);
+  }
+}
+codeToErrorMap.set(0x179a, WalletActivityNotExpiredError);
+nameToErrorMap.set('WalletActivityNotExpired', WalletActivityNotExpiredError);
+
+/** CircuitBreakerTripped: Release limit of the recipe is reached for this window */
+export class CircuitBreakerTrippedError extends ProgramError {
+  override readonly name: string = 'CircuitBreakerTripped';
+
+  readonly code: number = 0x179b; // 6043
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Release limit of the recipe is reached for this window',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x179b, CircuitBreakerTrippedError);
+nameToErrorMap.set('CircuitBreakerTripped', CircuitBreakerTrippedError);
+
+/** RecipePaused: Recipe is paused */
+export class RecipePausedError extends ProgramError {
+  override readonly name: string = 'RecipePaused';
+
+  readonly code: number = 0x179c; // 6044
+
+  constructor(program: Program, cause?: Error) {
+    super('Recipe is paused', program, cause);
+  }
+}
+codeToErrorMap.set(0x179c, RecipePausedError);
+nameToErrorMap.set('RecipePaused', RecipePausedError);
+
+/** ProtocolPaused: Protocol is paused */
+export class ProtocolPausedError extends ProgramError {
+  override readonly name: string = 'ProtocolPaused';
+
+  readonly code: number = 0x179d; // 6045
+
+  constructor(program: Program, cause?: Error) {
+    super('Protocol is paused', program, cause);
+  }
+}
+codeToErrorMap.set(0x179d, ProtocolPausedError);
+nameToErrorMap.set('ProtocolPaused', ProtocolPausedError);
+
+/** InvalidProtocolAdmin: Signer is not the protocol admin */
+export class InvalidProtocolAdminError extends ProgramError {
+  override readonly name: string = 'InvalidProtocolAdmin';
+
+  readonly code: number = 0x179e; // 6046
+
+  constructor(program: Program, cause?: Error) {
+    super('Signer is not the protocol admin', program, cause);
+  }
+}
+codeToErrorMap.set(0x179e, InvalidProtocolAdminError);
+nameToErrorMap.set('InvalidProtocolAdmin', InvalidProtocolAdminError);
+
+/** MissingPauseAccount: Account to pause is missing */
+export class MissingPauseAccountError extends ProgramError {
+  override readonly name: string = 'MissingPauseAccount';
+
+  readonly code: number = 0x179f; // 6047
+
+  constructor(program: Program, cause?: Error) {
+    super('Account to pause is missing', program, cause);
+  }
+}
+codeToErrorMap.set(0x179f, MissingPauseAccountError);
+nameToErrorMap.set('MissingPauseAccount', MissingPauseAccountError);
+
+/** AssetCooldownActive: Asset is still cooling down from its last swap */
+export class AssetCooldownActiveError extends ProgramError {
+  override readonly name: string = 'AssetCooldownActive';
+
+  readonly code: number = 0x17a0; // 6048
+
+  constructor(program: Program, cause?: Error) {
+    super('Asset is still cooling down from its last swap', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a0, AssetCooldownActiveError);
+nameToErrorMap.set('AssetCooldownActive', AssetCooldownActiveError);
+
+/** InvalidAssetCooldown: Asset cooldown account is invalid */
+export class InvalidAssetCooldownError extends ProgramError {
+  override readonly name: string = 'InvalidAssetCooldown';
+
+  readonly code: number = 0x17a1; // 6049
+
+  constructor(program: Program, cause?: Error) {
+    super('Asset cooldown account is invalid', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a1, InvalidAssetCooldownError);
+nameToErrorMap.set('InvalidAssetCooldown', InvalidAssetCooldownError);
+
+/** InvalidSwapReceipt: Swap receipt account is invalid */
+export class InvalidSwapReceiptError extends ProgramError {
+  override readonly name: string = 'InvalidSwapReceipt';
+
+  readonly code: number = 0x17a2; // 6050
+
+  constructor(program: Program, cause?: Error) {
+    super('Swap receipt account is invalid', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a2, InvalidSwapReceiptError);
+nameToErrorMap.set('InvalidSwapReceipt', InvalidSwapReceiptError);
+
+/** InvalidPendingAuthority: Signer is not the pending authority */
+export class InvalidPendingAuthorityError extends ProgramError {
+  override readonly name: string = 'InvalidPendingAuthority';
+
+  readonly code: number = 0x17a3; // 6051
+
+  constructor(program: Program, cause?: Error) {
+    super('Signer is not the pending authority', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a3, InvalidPendingAuthorityError);
+nameToErrorMap.set('InvalidPendingAuthority', InvalidPendingAuthorityError);
+
+/** DuplicateOperator: Operator is listed more than once */
+export class DuplicateOperatorError extends ProgramError {
+  override readonly name: string = 'DuplicateOperator';
+
+  readonly code: number = 0x17a4; // 6052
+
+  constructor(program: Program, cause?: Error) {
+    super('Operator is listed more than once', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a4, DuplicateOperatorError);
+nameToErrorMap.set('DuplicateOperator', DuplicateOperatorError);
+
+/** TimelockActive: Change must be queued behind the recipe timelock */
+export class TimelockActiveError extends ProgramError {
+  override readonly name: string = 'TimelockActive';
+
+  readonly code: number = 0x17a5; // 6053
+
+  constructor(program: Program, cause?: Error) {
+    super('Change must be queued behind the recipe timelock', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a5, TimelockActiveError);
+nameToErrorMap.set('TimelockActive', TimelockActiveError);
+
+/** RecipeUpdateAlreadyQueued: A recipe update is already queued */
+export class RecipeUpdateAlreadyQueuedError extends ProgramError {
+  override readonly name: string = 'RecipeUpdateAlreadyQueued';
+
+  readonly code: number = 0x17a6; // 6054
+
+  constructor(program: Program, cause?: Error) {
+    super('A recipe update is already queued', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a6, RecipeUpdateAlreadyQueuedError);
+nameToErrorMap.set('RecipeUpdateAlreadyQueued', RecipeUpdateAlreadyQueuedError);
+
+/** TimelockNotElapsed: Queued recipe update is not active yet */
+export class TimelockNotElapsedError extends ProgramError {
+  override readonly name: string = 'TimelockNotElapsed';
+
+  readonly code: number = 0x17a7; // 6055
+
+  constructor(program: Program, cause?: Error) {
+    super('Queued recipe update is not active yet', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a7, TimelockNotElapsedError);
+nameToErrorMap.set('TimelockNotElapsed', TimelockNotElapsedError);
+
+/** RecipeFieldLocked: Recipe field is locked */
+export class RecipeFieldLockedError extends ProgramError {
+  override readonly name: string = 'RecipeFieldLocked';
+
+  readonly code: number = 0x17a8; // 6056
+
+  constructor(program: Program, cause?: Error) {
+    super('Recipe field is locked', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a8, RecipeFieldLockedError);
+nameToErrorMap.set('RecipeFieldLocked', RecipeFieldLockedError);
+
+/** FeeCapExceeded: Fee is above the recipe fee cap */
+export class FeeCapExceededError extends ProgramError {
+  override readonly name: string = 'FeeCapExceeded';
+
+  readonly code: number = 0x17a9; // 6057
+
+  constructor(program: Program, cause?: Error) {
+    super('Fee is above the recipe fee cap', program, cause);
+  }
+}
+codeToErrorMap.set(0x17a9, FeeCapExceededError);
+nameToErrorMap.set('FeeCapExceeded', FeeCapExceededError);
+
+/** InvalidFeeCap: Fee caps can only be lowered */
+export class InvalidFeeCapError extends ProgramError {
+  override readonly name: string = 'InvalidFeeCap';
+
+  readonly code: number = 0x17aa; // 6058
+
+  constructor(program: Program, cause?: Error) {
+    super('Fee caps can only be lowered', program, cause);
+  }
+}
+codeToErrorMap.set(0x17aa, InvalidFeeCapError);
+nameToErrorMap.set('InvalidFeeCap', InvalidFeeCapError);
+
+/** AssetNotAllowlisted: Asset is not in the recipe allowlist */
+export class AssetNotAllowlistedError extends ProgramError {
+  override readonly name: string = 'AssetNotAllowlisted';
+
+  readonly code: number = 0x17ab; // 6059
+
+  constructor(program: Program, cause?: Error) {
+    super('Asset is not in the recipe allowlist', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ab, AssetNotAllowlistedError);
+nameToErrorMap.set('AssetNotAllowlisted', AssetNotAllowlistedError);
+
+/** WalletNotAllowlisted: Wallet is not in the recipe allowlist */
+export class WalletNotAllowlistedError extends ProgramError {
+  override readonly name: string = 'WalletNotAllowlisted';
+
+  readonly code: number = 0x17ac; // 6060
+
+  constructor(program: Program, cause?: Error) {
+    super('Wallet is not in the recipe allowlist', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ac, WalletNotAllowlistedError);
+nameToErrorMap.set('WalletNotAllowlisted', WalletNotAllowlistedError);
+
+/** InvalidGateAsset: Gate asset is invalid */
+export class InvalidGateAssetError extends ProgramError {
+  override readonly name: string = 'InvalidGateAsset';
+
+  readonly code: number = 0x17ad; // 6061
+
+  constructor(program: Program, cause?: Error) {
+    super('Gate asset is invalid', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ad, InvalidGateAssetError);
+nameToErrorMap.set('InvalidGateAsset', InvalidGateAssetError);
+
+/** GateNotMet: Not enough gate collection assets held */
+export class GateNotMetError extends ProgramError {
+  override readonly name: string = 'GateNotMet';
+
+  readonly code: number = 0x17ae; // 6062
+
+  constructor(program: Program, cause?: Error) {
+    super('Not enough gate collection assets held', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ae, GateNotMetError);
+nameToErrorMap.set('GateNotMet', GateNotMetError);
+
+/** InventoryCannotBeSet: Inventory can only be set once before the first swap */
+export class InventoryCannotBeSetError extends ProgramError {
+  override readonly name: string = 'InventoryCannotBeSet';
+
+  readonly code: number = 0x17af; // 6063
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Inventory can only be set once before the first swap',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17af, InventoryCannotBeSetError);
+nameToErrorMap.set('InventoryCannotBeSet', InventoryCannotBeSetError);
+
+/** MissingVaultAccount: Native SOL recipes need their vault account */
+export class MissingVaultAccountError extends ProgramError {
+  override readonly name: string = 'MissingVaultAccount';
+
+  readonly code: number = 0x17b0; // 6064
+
+  constructor(program: Program, cause?: Error) {
+    super('Native SOL recipes need their vault account', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b0, MissingVaultAccountError);
+nameToErrorMap.set('MissingVaultAccount', MissingVaultAccountError);
+
+/** QuoteWithAlternativePayment: Quoted recipes can't be paid with an alternative payment */
+export class QuoteWithAlternativePaymentError extends ProgramError {
+  override readonly name: string = 'QuoteWithAlternativePayment';
+
+  readonly code: number = 0x17b1; // 6065
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Quoted recipes can\'t be paid with an alternative payment',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17b1, QuoteWithAlternativePaymentError);
+nameToErrorMap.set(
+  'QuoteWithAlternativePayment',
+  QuoteWithAlternativePaymentError
+);
+
+/** RecipeTimelocked: Recipe configuration can't change while the recipe is timelocked */
+export class RecipeTimelockedError extends ProgramError {
+  override readonly name: string = 'RecipeTimelocked';
+
+  readonly code: number = 0x17b2; // 6066
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Recipe configuration can\'t change while the recipe is timelocked',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17b2, RecipeTimelockedError);
+nameToErrorMap.set('RecipeTimelocked', RecipeTimelockedError);
+
+/** InvalidRecipeFields: Lock fields include undefined recipe fields */
+export class InvalidRecipeFieldsError extends ProgramError {
+  override readonly name: string = 'InvalidRecipeFields';
+
+  readonly code: number = 0x17b3; // 6067
+
+  constructor(program: Program, cause?: Error) {
+    super('Lock fields include undefined recipe fields', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b3, InvalidRecipeFieldsError);
+nameToErrorMap.set('InvalidRecipeFields', InvalidRecipeFieldsError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
@@ -0,0 +1,147 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type AcceptRecipeAuthorityV1InstructionAccounts = {
+  recipe: PublicKey | Pda;
+  authority?: Signer;
+  escrow: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type AcceptRecipeAuthorityV1InstructionData = {
+  discriminator: Array<number>;
+};
+
+export type AcceptRecipeAuthorityV1InstructionDataArgs = {};
+
+export function getAcceptRecipeAuthorityV1InstructionDataSerializer(): Serializer<
+  AcceptRecipeAuthorityV1InstructionDataArgs,
+  AcceptRecipeAuthorityV1InstructionData
+> {
+  return mapSerializer<
+    AcceptRecipeAuthorityV1InstructionDataArgs,
+    any,
+    AcceptRecipeAuthorityV1InstructionData
+  >(
+    struct<AcceptRecipeAuthorityV1InstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'AcceptRecipeAuthorityV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [140, 100, 181, 61, 180, 58, 175, 171],
+    })
+  ) as Serializer<
+    AcceptRecipeAuthorityV1InstructionDataArgs,
+    AcceptRecipeAuthorityV1InstructionData
+  >;
+}
+
+// Instruction.
+export function acceptRecipeAuthorityV1(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: AcceptRecipeAuthorityV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    recipe: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.recipe ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    escrow: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.escrow ?? null,
+    },
+    eventAuthority: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getAcceptRecipeAuthorityV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,171 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findPendingRecipeUpdateV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CancelRecipeUpdateV1InstructionAccounts = {
+  pendingUpdate?: PublicKey | Pda;
+  recipe: PublicKey | Pda;
+  authority?: Signer;
+  collection: PublicKey | Pda;
+  queuedBy: PublicKey | Pda;
+  operators?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type CancelRecipeUpdateV1InstructionData = {
+  discriminator: Array<number>;
+};
+
+export type CancelRecipeUpdateV1InstructionDataArgs = {};
+
+export function getCancelRecipeUpdateV1InstructionDataSerializer(): Serializer<
+  CancelRecipeUpdateV1InstructionDataArgs,
+  CancelRecipeUpdateV1InstructionData
+> {
+  return mapSerializer<
+    CancelRecipeUpdateV1InstructionDataArgs,
+    any,
+    CancelRecipeUpdateV1InstructionData
+  >(
+    struct<CancelRecipeUpdateV1InstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'CancelRecipeUpdateV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: [155, 96, 193, 171, 86, 38, 6, 81] })
+  ) as Serializer<
+    CancelRecipeUpdateV1InstructionDataArgs,
+    CancelRecipeUpdateV1InstructionData
+  >;
+}
+
+// Instruction.
+export function cancelRecipeUpdateV1(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: CancelRecipeUpdateV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    pendingUpdate: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.pendingUpdate ?? null,
+    },
+    recipe: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.recipe ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    collection: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.collection ?? null,
+    },
+    queuedBy: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.queuedBy ?? null,
+    },
+    operators: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.operators ?? null,
+    },
+    eventAuthority: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.pendingUpdate.value) {
+    resolvedAccounts.pendingUpdate.value = findPendingRecipeUpdateV1Pda(
+      context,
+      {
+        recipe: expectPublicKey(resolvedAccounts.recipe.value),
+      }
+    );
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCancelRecipeUpdateV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
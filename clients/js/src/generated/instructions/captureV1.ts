@@ -23,6 +23,7 @@ import {
   struct,
   u8,
 } from '@metaplex-foundation/umi/serializers';
+import { findProtocolConfigV1Pda } from '../accounts';
 import {
   ResolvedAccount,
   ResolvedAccountsWithIndices,
@@ -48,6 +49,9 @@ export type CaptureV1InstructionAccounts = {
   systemProgram?: PublicKey | Pda;
   tokenProgram?: PublicKey | Pda;
   associatedTokenProgram?: PublicKey | Pda;
+  protocolConfig?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
 };
 
 // Data.
@@ -168,6 +172,21 @@ export function captureV1(
       isWritable: false as boolean,
       value: input.associatedTokenProgram ?? null,
     },
+    protocolConfig: {
+      index: 16,
+      isWritable: false as boolean,
+      value: input.protocolConfig ?? null,
+    },
+    eventAuthority: {
+      index: 17,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 18,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Default values.
@@ -229,6 +248,19 @@ export function captureV1(
       'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
     );
   }
+  if (!resolvedAccounts.protocolConfig.value) {
+    resolvedAccounts.protocolConfig.value = findProtocolConfigV1Pda(context);
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
 
   // Accounts in order.
   const orderedAccounts: ResolvedAccount[] = Object.values(
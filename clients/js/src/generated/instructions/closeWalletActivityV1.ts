@@ -0,0 +1,152 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findWalletActivityV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CloseWalletActivityV1InstructionAccounts = {
+  walletActivity?: PublicKey | Pda;
+  recipe: PublicKey | Pda;
+  user: Signer;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type CloseWalletActivityV1InstructionData = {
+  discriminator: Array<number>;
+};
+
+export type CloseWalletActivityV1InstructionDataArgs = {};
+
+export function getCloseWalletActivityV1InstructionDataSerializer(): Serializer<
+  CloseWalletActivityV1InstructionDataArgs,
+  CloseWalletActivityV1InstructionData
+> {
+  return mapSerializer<
+    CloseWalletActivityV1InstructionDataArgs,
+    any,
+    CloseWalletActivityV1InstructionData
+  >(
+    struct<CloseWalletActivityV1InstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'CloseWalletActivityV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [228, 175, 55, 252, 118, 124, 241, 228],
+    })
+  ) as Serializer<
+    CloseWalletActivityV1InstructionDataArgs,
+    CloseWalletActivityV1InstructionData
+  >;
+}
+
+// Instruction.
+export function closeWalletActivityV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: CloseWalletActivityV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    walletActivity: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.walletActivity ?? null,
+    },
+    recipe: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.recipe ?? null,
+    },
+    user: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.user ?? null,
+    },
+    eventAuthority: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.walletActivity.value) {
+    resolvedAccounts.walletActivity.value = findWalletActivityV1Pda(context, {
+      recipe: expectPublicKey(resolvedAccounts.recipe.value),
+      user: expectPublicKey(resolvedAccounts.user.value),
+    });
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getCloseWalletActivityV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
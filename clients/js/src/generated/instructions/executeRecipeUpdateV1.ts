@@ -0,0 +1,199 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  findPendingRecipeUpdateV1Pda,
+  findRecipeHistoryV1Pda,
+} from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ExecuteRecipeUpdateV1InstructionAccounts = {
+  pendingUpdate?: PublicKey | Pda;
+  recipe: PublicKey | Pda;
+  authority?: PublicKey | Pda;
+  payer?: Signer;
+  systemProgram?: PublicKey | Pda;
+  operators?: PublicKey | Pda;
+  history?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type ExecuteRecipeUpdateV1InstructionData = {
+  discriminator: Array<number>;
+};
+
+export type ExecuteRecipeUpdateV1InstructionDataArgs = {};
+
+export function getExecuteRecipeUpdateV1InstructionDataSerializer(): Serializer<
+  ExecuteRecipeUpdateV1InstructionDataArgs,
+  ExecuteRecipeUpdateV1InstructionData
+> {
+  return mapSerializer<
+    ExecuteRecipeUpdateV1InstructionDataArgs,
+    any,
+    ExecuteRecipeUpdateV1InstructionData
+  >(
+    struct<ExecuteRecipeUpdateV1InstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'ExecuteRecipeUpdateV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [85, 222, 47, 110, 227, 98, 231, 0],
+    })
+  ) as Serializer<
+    ExecuteRecipeUpdateV1InstructionDataArgs,
+    ExecuteRecipeUpdateV1InstructionData
+  >;
+}
+
+// Instruction.
+export function executeRecipeUpdateV1(
+  context: Pick<Context, 'eddsa' | 'identity' | 'payer' | 'programs'>,
+  input: ExecuteRecipeUpdateV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    pendingUpdate: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.pendingUpdate ?? null,
+    },
+    recipe: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.recipe ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    operators: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.operators ?? null,
+    },
+    history: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.history ?? null,
+    },
+    eventAuthority: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.pendingUpdate.value) {
+    resolvedAccounts.pendingUpdate.value = findPendingRecipeUpdateV1Pda(
+      context,
+      {
+        recipe: expectPublicKey(resolvedAccounts.recipe.value),
+      }
+    );
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity.publicKey;
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.history.value) {
+    resolvedAccounts.history.value = findRecipeHistoryV1Pda(context, {
+      recipe: expectPublicKey(resolvedAccounts.recipe.value),
+    });
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getExecuteRecipeUpdateV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
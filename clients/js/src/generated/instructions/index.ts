@@ -6,16 +6,40 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+export * from './acceptRecipeAuthorityV1';
+export * from './cancelRecipeUpdateV1';
 export * from './captureV1';
 export * from './captureV2';
+export * from './closeWalletActivityV1';
+export * from './executeRecipeUpdateV1';
+export * from './initBasketV1';
+export * from './initDiscountPolicyV1';
 export * from './initEscrowV1';
 export * from './initEscrowV2';
 export * from './initNftDataV1';
+export * from './initOperatorsV1';
+export * from './initPaymentTableV1';
+export * from './initProtocolConfigV1';
 export * from './initRecipeV1';
+export * from './lockRecipeV1';
+export * from './migrateEscrowConfigV1';
 export * from './migrateNftV1';
+export * from './migrateRecipeV1';
 export * from './migrateTokensV1';
+export * from './proposeRecipeAuthorityV1';
+export * from './queueRecipeUpdateV1';
 export * from './releaseV1';
 export * from './releaseV2';
+export * from './resetCircuitBreakerV1';
+export * from './setPauseV1';
+export * from './setProtocolAdminV1';
+export * from './transferEscrowAssetV1';
+export * from './transferEscrowReserveV1';
+export * from './updateBasketV1';
+export * from './updateDiscountPolicyV1';
 export * from './updateEscrowV1';
 export * from './updateNewDataV1';
+export * from './updateOperatorsV1';
+export * from './updatePaymentTableV1';
 export * from './updateRecipeV1';
+export * from './withdrawEscrowV1';
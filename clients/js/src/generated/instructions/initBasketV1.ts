@@ -0,0 +1,194 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findBasketV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  BasketEntry,
+  BasketEntryArgs,
+  getBasketEntrySerializer,
+} from '../types';
+
+// Accounts.
+export type InitBasketV1InstructionAccounts = {
+  basket?: PublicKey | Pda;
+  recipe: PublicKey | Pda;
+  authority?: Signer;
+  collection: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  operators?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type InitBasketV1InstructionData = {
+  discriminator: Array<number>;
+  entries: Array<BasketEntry>;
+};
+
+export type InitBasketV1InstructionDataArgs = {
+  entries: Array<BasketEntryArgs>;
+};
+
+export function getInitBasketV1InstructionDataSerializer(): Serializer<
+  InitBasketV1InstructionDataArgs,
+  InitBasketV1InstructionData
+> {
+  return mapSerializer<
+    InitBasketV1InstructionDataArgs,
+    any,
+    InitBasketV1InstructionData
+  >(
+    struct<InitBasketV1InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['entries', array(getBasketEntrySerializer())],
+      ],
+      { description: 'InitBasketV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [240, 52, 171, 151, 35, 46, 192, 48],
+    })
+  ) as Serializer<InitBasketV1InstructionDataArgs, InitBasketV1InstructionData>;
+}
+
+// Args.
+export type InitBasketV1InstructionArgs = InitBasketV1InstructionDataArgs;
+
+// Instruction.
+export function initBasketV1(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: InitBasketV1InstructionAccounts & InitBasketV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    basket: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.basket ?? null,
+    },
+    recipe: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.recipe ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    collection: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.collection ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    operators: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.operators ?? null,
+    },
+    eventAuthority: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: InitBasketV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.basket.value) {
+    resolvedAccounts.basket.value = findBasketV1Pda(context, {
+      recipe: expectPublicKey(resolvedAccounts.recipe.value),
+    });
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getInitBasketV1InstructionDataSerializer().serialize(
+    resolvedArgs as InitBasketV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
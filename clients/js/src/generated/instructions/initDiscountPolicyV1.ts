@@ -0,0 +1,199 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findDiscountPolicyV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  DiscountEntry,
+  DiscountEntryArgs,
+  getDiscountEntrySerializer,
+} from '../types';
+
+// Accounts.
+export type InitDiscountPolicyV1InstructionAccounts = {
+  discountPolicy?: PublicKey | Pda;
+  recipe: PublicKey | Pda;
+  authority?: Signer;
+  collection: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  operators?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type InitDiscountPolicyV1InstructionData = {
+  discriminator: Array<number>;
+  entries: Array<DiscountEntry>;
+};
+
+export type InitDiscountPolicyV1InstructionDataArgs = {
+  entries: Array<DiscountEntryArgs>;
+};
+
+export function getInitDiscountPolicyV1InstructionDataSerializer(): Serializer<
+  InitDiscountPolicyV1InstructionDataArgs,
+  InitDiscountPolicyV1InstructionData
+> {
+  return mapSerializer<
+    InitDiscountPolicyV1InstructionDataArgs,
+    any,
+    InitDiscountPolicyV1InstructionData
+  >(
+    struct<InitDiscountPolicyV1InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['entries', array(getDiscountEntrySerializer())],
+      ],
+      { description: 'InitDiscountPolicyV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [214, 140, 46, 10, 237, 109, 54, 72],
+    })
+  ) as Serializer<
+    InitDiscountPolicyV1InstructionDataArgs,
+    InitDiscountPolicyV1InstructionData
+  >;
+}
+
+// Args.
+export type InitDiscountPolicyV1InstructionArgs =
+  InitDiscountPolicyV1InstructionDataArgs;
+
+// Instruction.
+export function initDiscountPolicyV1(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: InitDiscountPolicyV1InstructionAccounts &
+    InitDiscountPolicyV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    discountPolicy: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.discountPolicy ?? null,
+    },
+    recipe: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.recipe ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    collection: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.collection ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    operators: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.operators ?? null,
+    },
+    eventAuthority: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: InitDiscountPolicyV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.discountPolicy.value) {
+    resolvedAccounts.discountPolicy.value = findDiscountPolicyV1Pda(context, {
+      recipe: expectPublicKey(resolvedAccounts.recipe.value),
+    });
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getInitDiscountPolicyV1InstructionDataSerializer().serialize(
+    resolvedArgs as InitDiscountPolicyV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
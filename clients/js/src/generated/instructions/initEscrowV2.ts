@@ -12,6 +12,7 @@ import {
   PublicKey,
   Signer,
   TransactionBuilder,
+  publicKey,
   transactionBuilder,
 } from '@metaplex-foundation/umi';
 import {
@@ -34,6 +35,8 @@ export type InitEscrowV2InstructionAccounts = {
   escrow?: PublicKey | Pda;
   authority?: Signer;
   systemProgram?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
 };
 
 // Data.
@@ -89,6 +92,16 @@ export function initEscrowV2(
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
+    eventAuthority: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Default values.
@@ -107,6 +120,16 @@ export function initEscrowV2(
     );
     resolvedAccounts.systemProgram.isWritable = false;
   }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
 
   // Accounts in order.
   const orderedAccounts: ResolvedAccount[] = Object.values(
@@ -0,0 +1,188 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findOperatorsV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  OperatorEntry,
+  OperatorEntryArgs,
+  getOperatorEntrySerializer,
+} from '../types';
+
+// Accounts.
+export type InitOperatorsV1InstructionAccounts = {
+  operators?: PublicKey | Pda;
+  recipe: PublicKey | Pda;
+  authority?: Signer;
+  collection: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type InitOperatorsV1InstructionData = {
+  discriminator: Array<number>;
+  entries: Array<OperatorEntry>;
+};
+
+export type InitOperatorsV1InstructionDataArgs = {
+  entries: Array<OperatorEntryArgs>;
+};
+
+export function getInitOperatorsV1InstructionDataSerializer(): Serializer<
+  InitOperatorsV1InstructionDataArgs,
+  InitOperatorsV1InstructionData
+> {
+  return mapSerializer<
+    InitOperatorsV1InstructionDataArgs,
+    any,
+    InitOperatorsV1InstructionData
+  >(
+    struct<InitOperatorsV1InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['entries', array(getOperatorEntrySerializer())],
+      ],
+      { description: 'InitOperatorsV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: [187, 26, 6, 77, 88, 230, 55, 207] })
+  ) as Serializer<
+    InitOperatorsV1InstructionDataArgs,
+    InitOperatorsV1InstructionData
+  >;
+}
+
+// Args.
+export type InitOperatorsV1InstructionArgs = InitOperatorsV1InstructionDataArgs;
+
+// Instruction.
+export function initOperatorsV1(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: InitOperatorsV1InstructionAccounts & InitOperatorsV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    operators: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.operators ?? null,
+    },
+    recipe: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.recipe ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    collection: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.collection ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    eventAuthority: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: InitOperatorsV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.operators.value) {
+    resolvedAccounts.operators.value = findOperatorsV1Pda(context, {
+      recipe: expectPublicKey(resolvedAccounts.recipe.value),
+    });
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getInitOperatorsV1InstructionDataSerializer().serialize(
+    resolvedArgs as InitOperatorsV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,178 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findProtocolConfigV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type InitProtocolConfigV1InstructionAccounts = {
+  protocolConfig?: PublicKey | Pda;
+  authority?: Signer;
+  programData: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type InitProtocolConfigV1InstructionData = {
+  discriminator: Array<number>;
+  admin: PublicKey;
+};
+
+export type InitProtocolConfigV1InstructionDataArgs = { admin: PublicKey };
+
+export function getInitProtocolConfigV1InstructionDataSerializer(): Serializer<
+  InitProtocolConfigV1InstructionDataArgs,
+  InitProtocolConfigV1InstructionData
+> {
+  return mapSerializer<
+    InitProtocolConfigV1InstructionDataArgs,
+    any,
+    InitProtocolConfigV1InstructionData
+  >(
+    struct<InitProtocolConfigV1InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['admin', publicKeySerializer()],
+      ],
+      { description: 'InitProtocolConfigV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [215, 161, 121, 61, 141, 26, 4, 141],
+    })
+  ) as Serializer<
+    InitProtocolConfigV1InstructionDataArgs,
+    InitProtocolConfigV1InstructionData
+  >;
+}
+
+// Args.
+export type InitProtocolConfigV1InstructionArgs =
+  InitProtocolConfigV1InstructionDataArgs;
+
+// Instruction.
+export function initProtocolConfigV1(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: InitProtocolConfigV1InstructionAccounts &
+    InitProtocolConfigV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    protocolConfig: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.protocolConfig ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    programData: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.programData ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    eventAuthority: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: InitProtocolConfigV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.protocolConfig.value) {
+    resolvedAccounts.protocolConfig.value = findProtocolConfigV1Pda(context);
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getInitProtocolConfigV1InstructionDataSerializer().serialize(
+    resolvedArgs as InitProtocolConfigV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -9,6 +9,8 @@
 import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
 import {
   Context,
+  Option,
+  OptionOrNullable,
   Pda,
   PublicKey,
   Signer,
@@ -20,6 +22,8 @@ import {
   Serializer,
   array,
   mapSerializer,
+  option,
+  publicKey as publicKeySerializer,
   string,
   struct,
   u16,
@@ -33,19 +37,31 @@ import {
   expectPublicKey,
   getAccountMetasAndSigners,
 } from '../shared';
+import {
+  Currency,
+  CurrencyArgs,
+  RecipeKind,
+  RecipeKindArgs,
+  getCurrencySerializer,
+  getRecipeKindSerializer,
+} from '../types';
 
 // Accounts.
 export type InitRecipeV1InstructionAccounts = {
   recipe?: PublicKey | Pda;
   authority?: Signer;
   collection: PublicKey | Pda;
-  token: PublicKey | Pda;
+  token?: PublicKey | Pda;
   feeLocation: PublicKey | Pda;
   /** The ATA for token fees to be stored */
   feeAta?: PublicKey | Pda;
   systemProgram?: PublicKey | Pda;
   tokenProgram?: PublicKey | Pda;
   associatedTokenProgram?: PublicKey | Pda;
+  /** The lamports of native SOL recipes are kept here */
+  vault?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
 };
 
 // Data.
@@ -55,12 +71,16 @@ export type InitRecipeV1InstructionData = {
   uri: string;
   max: bigint;
   min: bigint;
-  amount: bigint;
+  captureAmount: bigint;
+  releaseAmount: bigint;
   feeAmountCapture: bigint;
   feeAmountRelease: bigint;
   solFeeAmountCapture: bigint;
   solFeeAmountRelease: bigint;
   path: number;
+  currency: Currency;
+  kind: RecipeKind;
+  quoteSigner: Option<PublicKey>;
 };
 
 export type InitRecipeV1InstructionDataArgs = {
@@ -68,12 +88,16 @@ export type InitRecipeV1InstructionDataArgs = {
   uri: string;
   max: number | bigint;
   min: number | bigint;
-  amount: number | bigint;
+  captureAmount: number | bigint;
+  releaseAmount: number | bigint;
   feeAmountCapture: number | bigint;
   feeAmountRelease: number | bigint;
   solFeeAmountCapture: number | bigint;
   solFeeAmountRelease: number | bigint;
   path: number;
+  currency: CurrencyArgs;
+  kind: RecipeKindArgs;
+  quoteSigner: OptionOrNullable<PublicKey>;
 };
 
 export function getInitRecipeV1InstructionDataSerializer(): Serializer<
@@ -92,12 +116,16 @@ export function getInitRecipeV1InstructionDataSerializer(): Serializer<
         ['uri', string()],
         ['max', u64()],
         ['min', u64()],
-        ['amount', u64()],
+        ['captureAmount', u64()],
+        ['releaseAmount', u64()],
         ['feeAmountCapture', u64()],
         ['feeAmountRelease', u64()],
         ['solFeeAmountCapture', u64()],
         ['solFeeAmountRelease', u64()],
         ['path', u16()],
+        ['currency', getCurrencySerializer()],
+        ['kind', getRecipeKindSerializer()],
+        ['quoteSigner', option(publicKeySerializer())],
       ],
       { description: 'InitRecipeV1InstructionData' }
     ),
@@ -169,6 +197,21 @@ export function initRecipeV1(
       isWritable: false as boolean,
       value: input.associatedTokenProgram ?? null,
     },
+    vault: {
+      index: 9,
+      isWritable: true as boolean,
+      value: input.vault ?? null,
+    },
+    eventAuthority: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 11,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
@@ -184,10 +227,12 @@ export function initRecipeV1(
     resolvedAccounts.authority.value = context.identity;
   }
   if (!resolvedAccounts.feeAta.value) {
-    resolvedAccounts.feeAta.value = findAssociatedTokenPda(context, {
-      mint: expectPublicKey(resolvedAccounts.token.value),
-      owner: expectPublicKey(resolvedAccounts.feeLocation.value),
-    });
+    if (resolvedAccounts.token.value) {
+      resolvedAccounts.feeAta.value = findAssociatedTokenPda(context, {
+        mint: expectPublicKey(resolvedAccounts.token.value),
+        owner: expectPublicKey(resolvedAccounts.feeLocation.value),
+      });
+    }
   }
   if (!resolvedAccounts.systemProgram.value) {
     resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
@@ -208,6 +253,16 @@ export function initRecipeV1(
       'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
     );
   }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
 
   // Accounts in order.
   const orderedAccounts: ResolvedAccount[] = Object.values(
@@ -0,0 +1,165 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  option,
+  struct,
+  u16,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import { FeeCaps, FeeCapsArgs, getFeeCapsSerializer } from '../types';
+
+// Accounts.
+export type LockRecipeV1InstructionAccounts = {
+  recipe: PublicKey | Pda;
+  authority?: Signer;
+  collection: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type LockRecipeV1InstructionData = {
+  discriminator: Array<number>;
+  fields: number;
+  feeCaps: Option<FeeCaps>;
+};
+
+export type LockRecipeV1InstructionDataArgs = {
+  fields: number;
+  feeCaps: OptionOrNullable<FeeCapsArgs>;
+};
+
+export function getLockRecipeV1InstructionDataSerializer(): Serializer<
+  LockRecipeV1InstructionDataArgs,
+  LockRecipeV1InstructionData
+> {
+  return mapSerializer<
+    LockRecipeV1InstructionDataArgs,
+    any,
+    LockRecipeV1InstructionData
+  >(
+    struct<LockRecipeV1InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['fields', u16()],
+        ['feeCaps', option(getFeeCapsSerializer())],
+      ],
+      { description: 'LockRecipeV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [96, 196, 39, 136, 53, 173, 21, 115],
+    })
+  ) as Serializer<LockRecipeV1InstructionDataArgs, LockRecipeV1InstructionData>;
+}
+
+// Args.
+export type LockRecipeV1InstructionArgs = LockRecipeV1InstructionDataArgs;
+
+// Instruction.
+export function lockRecipeV1(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: LockRecipeV1InstructionAccounts & LockRecipeV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    recipe: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.recipe ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    collection: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.collection ?? null,
+    },
+    eventAuthority: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: LockRecipeV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getLockRecipeV1InstructionDataSerializer().serialize(
+    resolvedArgs as LockRecipeV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
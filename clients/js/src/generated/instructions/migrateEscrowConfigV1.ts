@@ -0,0 +1,166 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type MigrateEscrowConfigV1InstructionAccounts = {
+  recipe: PublicKey | Pda;
+  escrowOld: PublicKey | Pda;
+  authority?: Signer;
+  collection: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type MigrateEscrowConfigV1InstructionData = {
+  discriminator: Array<number>;
+};
+
+export type MigrateEscrowConfigV1InstructionDataArgs = {};
+
+export function getMigrateEscrowConfigV1InstructionDataSerializer(): Serializer<
+  MigrateEscrowConfigV1InstructionDataArgs,
+  MigrateEscrowConfigV1InstructionData
+> {
+  return mapSerializer<
+    MigrateEscrowConfigV1InstructionDataArgs,
+    any,
+    MigrateEscrowConfigV1InstructionData
+  >(
+    struct<MigrateEscrowConfigV1InstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'MigrateEscrowConfigV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [109, 43, 231, 34, 235, 222, 216, 206],
+    })
+  ) as Serializer<
+    MigrateEscrowConfigV1InstructionDataArgs,
+    MigrateEscrowConfigV1InstructionData
+  >;
+}
+
+// Instruction.
+export function migrateEscrowConfigV1(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: MigrateEscrowConfigV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    recipe: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.recipe ?? null,
+    },
+    escrowOld: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.escrowOld ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    collection: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.collection ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    eventAuthority: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getMigrateEscrowConfigV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -12,6 +12,7 @@ import {
   PublicKey,
   Signer,
   TransactionBuilder,
+  publicKey,
   transactionBuilder,
 } from '@metaplex-foundation/umi';
 import {
@@ -21,9 +22,11 @@ import {
   struct,
   u8,
 } from '@metaplex-foundation/umi/serializers';
+import { findRecipeV1Pda } from '../accounts';
 import {
   ResolvedAccount,
   ResolvedAccountsWithIndices,
+  expectPublicKey,
   getAccountMetasAndSigners,
 } from '../shared';
 
@@ -36,6 +39,9 @@ export type MigrateNftV1InstructionAccounts = {
   collection: PublicKey | Pda;
   mplCore: PublicKey | Pda;
   systemProgram?: PublicKey | Pda;
+  recipe?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
 };
 
 // Data.
@@ -65,7 +71,7 @@ export function getMigrateNftV1InstructionDataSerializer(): Serializer<
 
 // Instruction.
 export function migrateNftV1(
-  context: Pick<Context, 'identity' | 'programs'>,
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
   input: MigrateNftV1InstructionAccounts
 ): TransactionBuilder {
   // Program ID.
@@ -111,6 +117,21 @@ export function migrateNftV1(
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
+    recipe: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.recipe ?? null,
+    },
+    eventAuthority: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Default values.
@@ -124,6 +145,21 @@ export function migrateNftV1(
     );
     resolvedAccounts.systemProgram.isWritable = false;
   }
+  if (!resolvedAccounts.recipe.value) {
+    resolvedAccounts.recipe.value = findRecipeV1Pda(context, {
+      collection: expectPublicKey(resolvedAccounts.collection.value),
+    });
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
 
   // Accounts in order.
   const orderedAccounts: ResolvedAccount[] = Object.values(
@@ -12,6 +12,7 @@ import {
   PublicKey,
   Signer,
   TransactionBuilder,
+  publicKey,
   transactionBuilder,
 } from '@metaplex-foundation/umi';
 import {
@@ -22,9 +23,11 @@ import {
   u64,
   u8,
 } from '@metaplex-foundation/umi/serializers';
+import { findRecipeV1Pda } from '../accounts';
 import {
   ResolvedAccount,
   ResolvedAccountsWithIndices,
+  expectPublicKey,
   getAccountMetasAndSigners,
 } from '../shared';
 
@@ -36,10 +39,14 @@ export type MigrateTokensV1InstructionAccounts = {
   collection: PublicKey | Pda;
   escrowNewTokenAccount: PublicKey | Pda;
   escrowOldTokenAccount: PublicKey | Pda;
+  /** The old escrow's token, the recipe token may have been repointed since. */
   token: PublicKey | Pda;
   systemProgram?: PublicKey | Pda;
   tokenProgram?: PublicKey | Pda;
   associatedTokenProgram: PublicKey | Pda;
+  recipe?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
 };
 
 // Data.
@@ -81,7 +88,7 @@ export type MigrateTokensV1InstructionArgs = MigrateTokensV1InstructionDataArgs;
 
 // Instruction.
 export function migrateTokensV1(
-  context: Pick<Context, 'identity' | 'programs'>,
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
   input: MigrateTokensV1InstructionAccounts & MigrateTokensV1InstructionArgs
 ): TransactionBuilder {
   // Program ID.
@@ -142,6 +149,21 @@ export function migrateTokensV1(
       isWritable: false as boolean,
       value: input.associatedTokenProgram ?? null,
     },
+    recipe: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.recipe ?? null,
+    },
+    eventAuthority: {
+      index: 11,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 12,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
@@ -165,6 +187,21 @@ export function migrateTokensV1(
     );
     resolvedAccounts.tokenProgram.isWritable = false;
   }
+  if (!resolvedAccounts.recipe.value) {
+    resolvedAccounts.recipe.value = findRecipeV1Pda(context, {
+      collection: expectPublicKey(resolvedAccounts.collection.value),
+    });
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
 
   // Accounts in order.
   const orderedAccounts: ResolvedAccount[] = Object.values(
@@ -0,0 +1,157 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ProposeRecipeAuthorityV1InstructionAccounts = {
+  recipe: PublicKey | Pda;
+  authority?: Signer;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type ProposeRecipeAuthorityV1InstructionData = {
+  discriminator: Array<number>;
+  newAuthority: PublicKey;
+};
+
+export type ProposeRecipeAuthorityV1InstructionDataArgs = {
+  newAuthority: PublicKey;
+};
+
+export function getProposeRecipeAuthorityV1InstructionDataSerializer(): Serializer<
+  ProposeRecipeAuthorityV1InstructionDataArgs,
+  ProposeRecipeAuthorityV1InstructionData
+> {
+  return mapSerializer<
+    ProposeRecipeAuthorityV1InstructionDataArgs,
+    any,
+    ProposeRecipeAuthorityV1InstructionData
+  >(
+    struct<ProposeRecipeAuthorityV1InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['newAuthority', publicKeySerializer()],
+      ],
+      { description: 'ProposeRecipeAuthorityV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [249, 9, 124, 231, 249, 49, 161, 24],
+    })
+  ) as Serializer<
+    ProposeRecipeAuthorityV1InstructionDataArgs,
+    ProposeRecipeAuthorityV1InstructionData
+  >;
+}
+
+// Args.
+export type ProposeRecipeAuthorityV1InstructionArgs =
+  ProposeRecipeAuthorityV1InstructionDataArgs;
+
+// Instruction.
+export function proposeRecipeAuthorityV1(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: ProposeRecipeAuthorityV1InstructionAccounts &
+    ProposeRecipeAuthorityV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    recipe: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.recipe ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    eventAuthority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: ProposeRecipeAuthorityV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getProposeRecipeAuthorityV1InstructionDataSerializer().serialize(
+    resolvedArgs as ProposeRecipeAuthorityV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
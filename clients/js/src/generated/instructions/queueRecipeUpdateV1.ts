@@ -0,0 +1,308 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  option,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u16,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findPendingRecipeUpdateV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  AssetCooldown,
+  AssetCooldownArgs,
+  CircuitBreaker,
+  CircuitBreakerArgs,
+  LoyaltyRate,
+  LoyaltyRateArgs,
+  PricingCurve,
+  PricingCurveArgs,
+  RecipeGate,
+  RecipeGateArgs,
+  SwapWindow,
+  SwapWindowArgs,
+  WalletLimits,
+  WalletLimitsArgs,
+  getAssetCooldownSerializer,
+  getCircuitBreakerSerializer,
+  getLoyaltyRateSerializer,
+  getPricingCurveSerializer,
+  getRecipeGateSerializer,
+  getSwapWindowSerializer,
+  getWalletLimitsSerializer,
+} from '../types';
+
+// Accounts.
+export type QueueRecipeUpdateV1InstructionAccounts = {
+  pendingUpdate?: PublicKey | Pda;
+  recipe: PublicKey | Pda;
+  authority?: Signer;
+  collection: PublicKey | Pda;
+  token?: PublicKey | Pda;
+  feeLocation: PublicKey | Pda;
+  operators?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type QueueRecipeUpdateV1InstructionData = {
+  discriminator: Array<number>;
+  name: Option<string>;
+  uri: Option<string>;
+  max: Option<bigint>;
+  min: Option<bigint>;
+  captureAmount: Option<bigint>;
+  releaseAmount: Option<bigint>;
+  feeAmountCapture: Option<bigint>;
+  feeAmountRelease: Option<bigint>;
+  solFeeAmountCapture: Option<bigint>;
+  solFeeAmountRelease: Option<bigint>;
+  path: Option<number>;
+  pricing: Option<PricingCurve>;
+  inventory: Option<bigint>;
+  quoteSigner: Option<PublicKey>;
+  captureWindow: Option<SwapWindow>;
+  releaseWindow: Option<SwapWindow>;
+  walletLimits: Option<WalletLimits>;
+  circuitBreaker: Option<CircuitBreaker>;
+  assetCooldown: Option<AssetCooldown>;
+  loyalty: Option<LoyaltyRate>;
+  timelockSlots: Option<bigint>;
+  assetRoot: Option<Array<number>>;
+  walletRoot: Option<Array<number>>;
+  gate: Option<RecipeGate>;
+};
+
+export type QueueRecipeUpdateV1InstructionDataArgs = {
+  name: OptionOrNullable<string>;
+  uri: OptionOrNullable<string>;
+  max: OptionOrNullable<number | bigint>;
+  min: OptionOrNullable<number | bigint>;
+  captureAmount: OptionOrNullable<number | bigint>;
+  releaseAmount: OptionOrNullable<number | bigint>;
+  feeAmountCapture: OptionOrNullable<number | bigint>;
+  feeAmountRelease: OptionOrNullable<number | bigint>;
+  solFeeAmountCapture: OptionOrNullable<number | bigint>;
+  solFeeAmountRelease: OptionOrNullable<number | bigint>;
+  path: OptionOrNullable<number>;
+  pricing: OptionOrNullable<PricingCurveArgs>;
+  inventory: OptionOrNullable<number | bigint>;
+  quoteSigner: OptionOrNullable<PublicKey>;
+  captureWindow: OptionOrNullable<SwapWindowArgs>;
+  releaseWindow: OptionOrNullable<SwapWindowArgs>;
+  walletLimits: OptionOrNullable<WalletLimitsArgs>;
+  circuitBreaker: OptionOrNullable<CircuitBreakerArgs>;
+  assetCooldown: OptionOrNullable<AssetCooldownArgs>;
+  loyalty: OptionOrNullable<LoyaltyRateArgs>;
+  timelockSlots: OptionOrNullable<number | bigint>;
+  assetRoot: OptionOrNullable<Array<number>>;
+  walletRoot: OptionOrNullable<Array<number>>;
+  gate: OptionOrNullable<RecipeGateArgs>;
+};
+
+export function getQueueRecipeUpdateV1InstructionDataSerializer(): Serializer<
+  QueueRecipeUpdateV1InstructionDataArgs,
+  QueueRecipeUpdateV1InstructionData
+> {
+  return mapSerializer<
+    QueueRecipeUpdateV1InstructionDataArgs,
+    any,
+    QueueRecipeUpdateV1InstructionData
+  >(
+    struct<QueueRecipeUpdateV1InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['name', option(string())],
+        ['uri', option(string())],
+        ['max', option(u64())],
+        ['min', option(u64())],
+        ['captureAmount', option(u64())],
+        ['releaseAmount', option(u64())],
+        ['feeAmountCapture', option(u64())],
+        ['feeAmountRelease', option(u64())],
+        ['solFeeAmountCapture', option(u64())],
+        ['solFeeAmountRelease', option(u64())],
+        ['path', option(u16())],
+        ['pricing', option(getPricingCurveSerializer())],
+        ['inventory', option(u64())],
+        ['quoteSigner', option(publicKeySerializer())],
+        ['captureWindow', option(getSwapWindowSerializer())],
+        ['releaseWindow', option(getSwapWindowSerializer())],
+        ['walletLimits', option(getWalletLimitsSerializer())],
+        ['circuitBreaker', option(getCircuitBreakerSerializer())],
+        ['assetCooldown', option(getAssetCooldownSerializer())],
+        ['loyalty', option(getLoyaltyRateSerializer())],
+        ['timelockSlots', option(u64())],
+        ['assetRoot', option(array(u8(), { size: 32 }))],
+        ['walletRoot', option(array(u8(), { size: 32 }))],
+        ['gate', option(getRecipeGateSerializer())],
+      ],
+      { description: 'QueueRecipeUpdateV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [24, 64, 139, 118, 46, 52, 131, 107],
+    })
+  ) as Serializer<
+    QueueRecipeUpdateV1InstructionDataArgs,
+    QueueRecipeUpdateV1InstructionData
+  >;
+}
+
+// Args.
+export type QueueRecipeUpdateV1InstructionArgs =
+  QueueRecipeUpdateV1InstructionDataArgs;
+
+// Instruction.
+export function queueRecipeUpdateV1(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: QueueRecipeUpdateV1InstructionAccounts &
+    QueueRecipeUpdateV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    pendingUpdate: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.pendingUpdate ?? null,
+    },
+    recipe: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.recipe ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    collection: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.collection ?? null,
+    },
+    token: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.token ?? null,
+    },
+    feeLocation: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.feeLocation ?? null,
+    },
+    operators: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.operators ?? null,
+    },
+    systemProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    eventAuthority: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: QueueRecipeUpdateV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.pendingUpdate.value) {
+    resolvedAccounts.pendingUpdate.value = findPendingRecipeUpdateV1Pda(
+      context,
+      {
+        recipe: expectPublicKey(resolvedAccounts.recipe.value),
+      }
+    );
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getQueueRecipeUpdateV1InstructionDataSerializer().serialize(
+    resolvedArgs as QueueRecipeUpdateV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -23,6 +23,7 @@ import {
   struct,
   u8,
 } from '@metaplex-foundation/umi/serializers';
+import { findProtocolConfigV1Pda } from '../accounts';
 import {
   ResolvedAccount,
   ResolvedAccountsWithIndices,
@@ -40,7 +41,8 @@ export type ReleaseV2InstructionAccounts = {
   collection: PublicKey | Pda;
   userTokenAccount?: PublicKey | Pda;
   escrowTokenAccount?: PublicKey | Pda;
-  token: PublicKey | Pda;
+  /** Checked against the recipe token and its payment table in the handler. */
+  token?: PublicKey | Pda;
   feeTokenAccount?: PublicKey | Pda;
   feeSolAccount?: PublicKey | Pda;
   feeProjectAccount: PublicKey | Pda;
@@ -49,12 +51,32 @@ export type ReleaseV2InstructionAccounts = {
   systemProgram?: PublicKey | Pda;
   tokenProgram?: PublicKey | Pda;
   associatedTokenProgram?: PublicKey | Pda;
+  discountPolicy?: PublicKey | Pda;
+  discountProof?: PublicKey | Pda;
+  basket?: PublicKey | Pda;
+  paymentTable?: PublicKey | Pda;
+  instructionsSysvar?: PublicKey | Pda;
+  quoteNonce?: PublicKey | Pda;
+  walletActivity?: PublicKey | Pda;
+  assetCooldown?: PublicKey | Pda;
+  swapReceipt?: PublicKey | Pda;
+  vault?: PublicKey | Pda;
+  protocolConfig?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
 };
 
 // Data.
-export type ReleaseV2InstructionData = { discriminator: Array<number> };
+export type ReleaseV2InstructionData = {
+  discriminator: Array<number>;
+  assetProof: Array<Array<number>>;
+  walletProof: Array<Array<number>>;
+};
 
-export type ReleaseV2InstructionDataArgs = {};
+export type ReleaseV2InstructionDataArgs = {
+  assetProof: Array<Array<number>>;
+  walletProof: Array<Array<number>>;
+};
 
 export function getReleaseV2InstructionDataSerializer(): Serializer<
   ReleaseV2InstructionDataArgs,
@@ -66,7 +88,11 @@ export function getReleaseV2InstructionDataSerializer(): Serializer<
     ReleaseV2InstructionData
   >(
     struct<ReleaseV2InstructionData>(
-      [['discriminator', array(u8(), { size: 8 })]],
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['assetProof', array(array(u8(), { size: 32 }))],
+        ['walletProof', array(array(u8(), { size: 32 }))],
+      ],
       { description: 'ReleaseV2InstructionData' }
     ),
     (value) => ({
@@ -76,10 +102,13 @@ export function getReleaseV2InstructionDataSerializer(): Serializer<
   ) as Serializer<ReleaseV2InstructionDataArgs, ReleaseV2InstructionData>;
 }
 
+// Args.
+export type ReleaseV2InstructionArgs = ReleaseV2InstructionDataArgs;
+
 // Instruction.
 export function releaseV2(
   context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
-  input: ReleaseV2InstructionAccounts
+  input: ReleaseV2InstructionAccounts & ReleaseV2InstructionArgs
 ): TransactionBuilder {
   // Program ID.
   const programId = context.programs.getPublicKey(
@@ -174,32 +203,109 @@ export function releaseV2(
       isWritable: false as boolean,
       value: input.associatedTokenProgram ?? null,
     },
+    discountPolicy: {
+      index: 17,
+      isWritable: false as boolean,
+      value: input.discountPolicy ?? null,
+    },
+    discountProof: {
+      index: 18,
+      isWritable: false as boolean,
+      value: input.discountProof ?? null,
+    },
+    basket: {
+      index: 19,
+      isWritable: false as boolean,
+      value: input.basket ?? null,
+    },
+    paymentTable: {
+      index: 20,
+      isWritable: false as boolean,
+      value: input.paymentTable ?? null,
+    },
+    instructionsSysvar: {
+      index: 21,
+      isWritable: false as boolean,
+      value: input.instructionsSysvar ?? null,
+    },
+    quoteNonce: {
+      index: 22,
+      isWritable: true as boolean,
+      value: input.quoteNonce ?? null,
+    },
+    walletActivity: {
+      index: 23,
+      isWritable: true as boolean,
+      value: input.walletActivity ?? null,
+    },
+    assetCooldown: {
+      index: 24,
+      isWritable: true as boolean,
+      value: input.assetCooldown ?? null,
+    },
+    swapReceipt: {
+      index: 25,
+      isWritable: true as boolean,
+      value: input.swapReceipt ?? null,
+    },
+    vault: {
+      index: 26,
+      isWritable: true as boolean,
+      value: input.vault ?? null,
+    },
+    protocolConfig: {
+      index: 27,
+      isWritable: false as boolean,
+      value: input.protocolConfig ?? null,
+    },
+    eventAuthority: {
+      index: 28,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 29,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
+  // Arguments.
+  const resolvedArgs: ReleaseV2InstructionArgs = { ...input };
+
   // Default values.
   if (!resolvedAccounts.authority.value) {
     resolvedAccounts.authority.value = context.identity;
   }
   if (!resolvedAccounts.userTokenAccount.value) {
-    resolvedAccounts.userTokenAccount.value = findAssociatedTokenPda(context, {
-      mint: expectPublicKey(resolvedAccounts.token.value),
-      owner: expectPublicKey(resolvedAccounts.owner.value),
-    });
+    if (resolvedAccounts.token.value) {
+      resolvedAccounts.userTokenAccount.value = findAssociatedTokenPda(
+        context,
+        {
+          mint: expectPublicKey(resolvedAccounts.token.value),
+          owner: expectPublicKey(resolvedAccounts.owner.value),
+        }
+      );
+    }
   }
   if (!resolvedAccounts.escrowTokenAccount.value) {
-    resolvedAccounts.escrowTokenAccount.value = findAssociatedTokenPda(
-      context,
-      {
-        mint: expectPublicKey(resolvedAccounts.token.value),
-        owner: expectPublicKey(resolvedAccounts.escrow.value),
-      }
-    );
+    if (resolvedAccounts.token.value) {
+      resolvedAccounts.escrowTokenAccount.value = findAssociatedTokenPda(
+        context,
+        {
+          mint: expectPublicKey(resolvedAccounts.token.value),
+          owner: expectPublicKey(resolvedAccounts.escrow.value),
+        }
+      );
+    }
   }
   if (!resolvedAccounts.feeTokenAccount.value) {
-    resolvedAccounts.feeTokenAccount.value = findAssociatedTokenPda(context, {
-      mint: expectPublicKey(resolvedAccounts.token.value),
-      owner: expectPublicKey(resolvedAccounts.feeProjectAccount.value),
-    });
+    if (resolvedAccounts.token.value) {
+      resolvedAccounts.feeTokenAccount.value = findAssociatedTokenPda(context, {
+        mint: expectPublicKey(resolvedAccounts.token.value),
+        owner: expectPublicKey(resolvedAccounts.feeProjectAccount.value),
+      });
+    }
   }
   if (!resolvedAccounts.feeSolAccount.value) {
     resolvedAccounts.feeSolAccount.value = publicKey(
@@ -235,6 +341,19 @@ export function releaseV2(
       'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
     );
   }
+  if (!resolvedAccounts.protocolConfig.value) {
+    resolvedAccounts.protocolConfig.value = findProtocolConfigV1Pda(context);
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
 
   // Accounts in order.
   const orderedAccounts: ResolvedAccount[] = Object.values(
@@ -249,7 +368,9 @@ export function releaseV2(
   );
 
   // Data.
-  const data = getReleaseV2InstructionDataSerializer().serialize({});
+  const data = getReleaseV2InstructionDataSerializer().serialize(
+    resolvedArgs as ReleaseV2InstructionDataArgs
+  );
 
   // Bytes Created On Chain.
   const bytesCreatedOnChain = 0;
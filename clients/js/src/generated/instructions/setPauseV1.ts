@@ -0,0 +1,179 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  mapSerializer,
+  struct,
+  u16,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import { PauseScope, PauseScopeArgs, getPauseScopeSerializer } from '../types';
+
+// Accounts.
+export type SetPauseV1InstructionAccounts = {
+  authority?: Signer;
+  recipe?: PublicKey | Pda;
+  collection?: PublicKey | Pda;
+  protocolConfig?: PublicKey | Pda;
+  /** Checked against the recipe in the handler. */
+  operators?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type SetPauseV1InstructionData = {
+  discriminator: Array<number>;
+  scope: PauseScope;
+  paused: boolean;
+  reason: number;
+};
+
+export type SetPauseV1InstructionDataArgs = {
+  scope: PauseScopeArgs;
+  paused: boolean;
+  reason: number;
+};
+
+export function getSetPauseV1InstructionDataSerializer(): Serializer<
+  SetPauseV1InstructionDataArgs,
+  SetPauseV1InstructionData
+> {
+  return mapSerializer<
+    SetPauseV1InstructionDataArgs,
+    any,
+    SetPauseV1InstructionData
+  >(
+    struct<SetPauseV1InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['scope', getPauseScopeSerializer()],
+        ['paused', bool()],
+        ['reason', u16()],
+      ],
+      { description: 'SetPauseV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [72, 174, 209, 198, 115, 3, 4, 128],
+    })
+  ) as Serializer<SetPauseV1InstructionDataArgs, SetPauseV1InstructionData>;
+}
+
+// Args.
+export type SetPauseV1InstructionArgs = SetPauseV1InstructionDataArgs;
+
+// Instruction.
+export function setPauseV1(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: SetPauseV1InstructionAccounts & SetPauseV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    authority: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    recipe: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.recipe ?? null,
+    },
+    collection: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.collection ?? null,
+    },
+    protocolConfig: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.protocolConfig ?? null,
+    },
+    operators: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.operators ?? null,
+    },
+    eventAuthority: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: SetPauseV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetPauseV1InstructionDataSerializer().serialize(
+    resolvedArgs as SetPauseV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
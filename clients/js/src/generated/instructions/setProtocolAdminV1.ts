@@ -0,0 +1,148 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findProtocolConfigV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetProtocolAdminV1InstructionAccounts = {
+  protocolConfig?: PublicKey | Pda;
+  admin: Signer;
+  /** The new admin co-signs so the settings can't be handed to a key nobody controls. */
+  newAdmin: Signer;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type SetProtocolAdminV1InstructionData = {
+  discriminator: Array<number>;
+};
+
+export type SetProtocolAdminV1InstructionDataArgs = {};
+
+export function getSetProtocolAdminV1InstructionDataSerializer(): Serializer<
+  SetProtocolAdminV1InstructionDataArgs,
+  SetProtocolAdminV1InstructionData
+> {
+  return mapSerializer<
+    SetProtocolAdminV1InstructionDataArgs,
+    any,
+    SetProtocolAdminV1InstructionData
+  >(
+    struct<SetProtocolAdminV1InstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'SetProtocolAdminV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [203, 183, 56, 77, 158, 141, 142, 143],
+    })
+  ) as Serializer<
+    SetProtocolAdminV1InstructionDataArgs,
+    SetProtocolAdminV1InstructionData
+  >;
+}
+
+// Instruction.
+export function setProtocolAdminV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: SetProtocolAdminV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    protocolConfig: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.protocolConfig ?? null,
+    },
+    admin: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.admin ?? null,
+    },
+    newAdmin: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.newAdmin ?? null,
+    },
+    eventAuthority: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.protocolConfig.value) {
+    resolvedAccounts.protocolConfig.value = findProtocolConfigV1Pda(context);
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetProtocolAdminV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
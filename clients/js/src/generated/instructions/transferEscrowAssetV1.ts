@@ -0,0 +1,186 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type TransferEscrowAssetV1InstructionAccounts = {
+  authority?: Signer;
+  escrowOld: PublicKey | Pda;
+  escrowNew: PublicKey | Pda;
+  recipe: PublicKey | Pda;
+  asset: PublicKey | Pda;
+  collection: PublicKey | Pda;
+  mplCore?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type TransferEscrowAssetV1InstructionData = {
+  discriminator: Array<number>;
+};
+
+export type TransferEscrowAssetV1InstructionDataArgs = {};
+
+export function getTransferEscrowAssetV1InstructionDataSerializer(): Serializer<
+  TransferEscrowAssetV1InstructionDataArgs,
+  TransferEscrowAssetV1InstructionData
+> {
+  return mapSerializer<
+    TransferEscrowAssetV1InstructionDataArgs,
+    any,
+    TransferEscrowAssetV1InstructionData
+  >(
+    struct<TransferEscrowAssetV1InstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'TransferEscrowAssetV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: [50, 25, 1, 218, 96, 252, 57, 116] })
+  ) as Serializer<
+    TransferEscrowAssetV1InstructionDataArgs,
+    TransferEscrowAssetV1InstructionData
+  >;
+}
+
+// Instruction.
+export function transferEscrowAssetV1(
+  context: Pick<Context, 'identity' | 'programs'>,
+  input: TransferEscrowAssetV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    authority: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    escrowOld: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.escrowOld ?? null,
+    },
+    escrowNew: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.escrowNew ?? null,
+    },
+    recipe: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.recipe ?? null,
+    },
+    asset: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    collection: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    mplCore: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.mplCore ?? null,
+    },
+    systemProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    eventAuthority: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.mplCore.value) {
+    resolvedAccounts.mplCore.value = publicKey(
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getTransferEscrowAssetV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
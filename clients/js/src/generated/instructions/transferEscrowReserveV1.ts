@@ -0,0 +1,244 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type TransferEscrowReserveV1InstructionAccounts = {
+  authority?: Signer;
+  escrowOld: PublicKey | Pda;
+  escrowNew: PublicKey | Pda;
+  recipe: PublicKey | Pda;
+  escrowNewTokenAccount?: PublicKey | Pda;
+  escrowOldTokenAccount?: PublicKey | Pda;
+  token: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  associatedTokenProgram?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type TransferEscrowReserveV1InstructionData = {
+  discriminator: Array<number>;
+  amount: bigint;
+};
+
+export type TransferEscrowReserveV1InstructionDataArgs = {
+  amount: number | bigint;
+};
+
+export function getTransferEscrowReserveV1InstructionDataSerializer(): Serializer<
+  TransferEscrowReserveV1InstructionDataArgs,
+  TransferEscrowReserveV1InstructionData
+> {
+  return mapSerializer<
+    TransferEscrowReserveV1InstructionDataArgs,
+    any,
+    TransferEscrowReserveV1InstructionData
+  >(
+    struct<TransferEscrowReserveV1InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['amount', u64()],
+      ],
+      { description: 'TransferEscrowReserveV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [29, 203, 247, 7, 106, 121, 131, 126],
+    })
+  ) as Serializer<
+    TransferEscrowReserveV1InstructionDataArgs,
+    TransferEscrowReserveV1InstructionData
+  >;
+}
+
+// Args.
+export type TransferEscrowReserveV1InstructionArgs =
+  TransferEscrowReserveV1InstructionDataArgs;
+
+// Instruction.
+export function transferEscrowReserveV1(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: TransferEscrowReserveV1InstructionAccounts &
+    TransferEscrowReserveV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    authority: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    escrowOld: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.escrowOld ?? null,
+    },
+    escrowNew: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.escrowNew ?? null,
+    },
+    recipe: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.recipe ?? null,
+    },
+    escrowNewTokenAccount: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.escrowNewTokenAccount ?? null,
+    },
+    escrowOldTokenAccount: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.escrowOldTokenAccount ?? null,
+    },
+    token: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.token ?? null,
+    },
+    systemProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    tokenProgram: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    associatedTokenProgram: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.associatedTokenProgram ?? null,
+    },
+    eventAuthority: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 11,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: TransferEscrowReserveV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.escrowNewTokenAccount.value) {
+    resolvedAccounts.escrowNewTokenAccount.value = findAssociatedTokenPda(
+      context,
+      {
+        mint: expectPublicKey(resolvedAccounts.token.value),
+        owner: expectPublicKey(resolvedAccounts.escrowNew.value),
+      }
+    );
+  }
+  if (!resolvedAccounts.escrowOldTokenAccount.value) {
+    resolvedAccounts.escrowOldTokenAccount.value = findAssociatedTokenPda(
+      context,
+      {
+        mint: expectPublicKey(resolvedAccounts.token.value),
+        owner: expectPublicKey(resolvedAccounts.escrowOld.value),
+      }
+    );
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.associatedTokenProgram.value) {
+    resolvedAccounts.associatedTokenProgram.value = publicKey(
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+    );
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getTransferEscrowReserveV1InstructionDataSerializer().serialize(
+    resolvedArgs as TransferEscrowReserveV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,197 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findBasketV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  BasketEntry,
+  BasketEntryArgs,
+  getBasketEntrySerializer,
+} from '../types';
+
+// Accounts.
+export type UpdateBasketV1InstructionAccounts = {
+  basket?: PublicKey | Pda;
+  recipe: PublicKey | Pda;
+  authority?: Signer;
+  collection: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  operators?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type UpdateBasketV1InstructionData = {
+  discriminator: Array<number>;
+  entries: Array<BasketEntry>;
+};
+
+export type UpdateBasketV1InstructionDataArgs = {
+  entries: Array<BasketEntryArgs>;
+};
+
+export function getUpdateBasketV1InstructionDataSerializer(): Serializer<
+  UpdateBasketV1InstructionDataArgs,
+  UpdateBasketV1InstructionData
+> {
+  return mapSerializer<
+    UpdateBasketV1InstructionDataArgs,
+    any,
+    UpdateBasketV1InstructionData
+  >(
+    struct<UpdateBasketV1InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['entries', array(getBasketEntrySerializer())],
+      ],
+      { description: 'UpdateBasketV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [24, 132, 66, 99, 220, 124, 94, 241],
+    })
+  ) as Serializer<
+    UpdateBasketV1InstructionDataArgs,
+    UpdateBasketV1InstructionData
+  >;
+}
+
+// Args.
+export type UpdateBasketV1InstructionArgs = UpdateBasketV1InstructionDataArgs;
+
+// Instruction.
+export function updateBasketV1(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: UpdateBasketV1InstructionAccounts & UpdateBasketV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    basket: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.basket ?? null,
+    },
+    recipe: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.recipe ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    collection: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.collection ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    operators: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.operators ?? null,
+    },
+    eventAuthority: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdateBasketV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.basket.value) {
+    resolvedAccounts.basket.value = findBasketV1Pda(context, {
+      recipe: expectPublicKey(resolvedAccounts.recipe.value),
+    });
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getUpdateBasketV1InstructionDataSerializer().serialize(
+    resolvedArgs as UpdateBasketV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
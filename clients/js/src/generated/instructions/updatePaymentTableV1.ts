@@ -0,0 +1,199 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findPaymentTableV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  PaymentEntry,
+  PaymentEntryArgs,
+  getPaymentEntrySerializer,
+} from '../types';
+
+// Accounts.
+export type UpdatePaymentTableV1InstructionAccounts = {
+  paymentTable?: PublicKey | Pda;
+  recipe: PublicKey | Pda;
+  authority?: Signer;
+  collection: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  operators?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type UpdatePaymentTableV1InstructionData = {
+  discriminator: Array<number>;
+  entries: Array<PaymentEntry>;
+};
+
+export type UpdatePaymentTableV1InstructionDataArgs = {
+  entries: Array<PaymentEntryArgs>;
+};
+
+export function getUpdatePaymentTableV1InstructionDataSerializer(): Serializer<
+  UpdatePaymentTableV1InstructionDataArgs,
+  UpdatePaymentTableV1InstructionData
+> {
+  return mapSerializer<
+    UpdatePaymentTableV1InstructionDataArgs,
+    any,
+    UpdatePaymentTableV1InstructionData
+  >(
+    struct<UpdatePaymentTableV1InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['entries', array(getPaymentEntrySerializer())],
+      ],
+      { description: 'UpdatePaymentTableV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [67, 159, 173, 51, 53, 73, 169, 34],
+    })
+  ) as Serializer<
+    UpdatePaymentTableV1InstructionDataArgs,
+    UpdatePaymentTableV1InstructionData
+  >;
+}
+
+// Args.
+export type UpdatePaymentTableV1InstructionArgs =
+  UpdatePaymentTableV1InstructionDataArgs;
+
+// Instruction.
+export function updatePaymentTableV1(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: UpdatePaymentTableV1InstructionAccounts &
+    UpdatePaymentTableV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    paymentTable: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.paymentTable ?? null,
+    },
+    recipe: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.recipe ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    collection: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.collection ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    operators: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.operators ?? null,
+    },
+    eventAuthority: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdatePaymentTableV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.paymentTable.value) {
+    resolvedAccounts.paymentTable.value = findPaymentTableV1Pda(context, {
+      recipe: expectPublicKey(resolvedAccounts.recipe.value),
+    });
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getUpdatePaymentTableV1InstructionDataSerializer().serialize(
+    resolvedArgs as UpdatePaymentTableV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -14,6 +14,7 @@ import {
   PublicKey,
   Signer,
   TransactionBuilder,
+  publicKey,
   transactionBuilder,
 } from '@metaplex-foundation/umi';
 import {
@@ -21,26 +22,56 @@ import {
   array,
   mapSerializer,
   option,
+  publicKey as publicKeySerializer,
   string,
   struct,
   u16,
   u64,
   u8,
 } from '@metaplex-foundation/umi/serializers';
+import { findRecipeHistoryV1Pda } from '../accounts';
 import {
   ResolvedAccount,
   ResolvedAccountsWithIndices,
+  expectPublicKey,
   getAccountMetasAndSigners,
 } from '../shared';
+import {
+  AssetCooldown,
+  AssetCooldownArgs,
+  CircuitBreaker,
+  CircuitBreakerArgs,
+  LoyaltyRate,
+  LoyaltyRateArgs,
+  PricingCurve,
+  PricingCurveArgs,
+  RecipeGate,
+  RecipeGateArgs,
+  SwapWindow,
+  SwapWindowArgs,
+  WalletLimits,
+  WalletLimitsArgs,
+  getAssetCooldownSerializer,
+  getCircuitBreakerSerializer,
+  getLoyaltyRateSerializer,
+  getPricingCurveSerializer,
+  getRecipeGateSerializer,
+  getSwapWindowSerializer,
+  getWalletLimitsSerializer,
+} from '../types';
 
 // Accounts.
 export type UpdateRecipeV1InstructionAccounts = {
   recipe: PublicKey | Pda;
   authority?: Signer;
   collection: PublicKey | Pda;
-  token: PublicKey | Pda;
+  token?: PublicKey | Pda;
   feeLocation: PublicKey | Pda;
   systemProgram?: PublicKey | Pda;
+  operators?: PublicKey | Pda;
+  history?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
 };
 
 // Data.
@@ -50,12 +81,26 @@ export type UpdateRecipeV1InstructionData = {
   uri: Option<string>;
   max: Option<bigint>;
   min: Option<bigint>;
-  amount: Option<bigint>;
+  captureAmount: Option<bigint>;
+  releaseAmount: Option<bigint>;
   feeAmountCapture: Option<bigint>;
   feeAmountRelease: Option<bigint>;
   solFeeAmountCapture: Option<bigint>;
   solFeeAmountRelease: Option<bigint>;
   path: Option<number>;
+  pricing: Option<PricingCurve>;
+  inventory: Option<bigint>;
+  quoteSigner: Option<PublicKey>;
+  captureWindow: Option<SwapWindow>;
+  releaseWindow: Option<SwapWindow>;
+  walletLimits: Option<WalletLimits>;
+  circuitBreaker: Option<CircuitBreaker>;
+  assetCooldown: Option<AssetCooldown>;
+  loyalty: Option<LoyaltyRate>;
+  timelockSlots: Option<bigint>;
+  assetRoot: Option<Array<number>>;
+  walletRoot: Option<Array<number>>;
+  gate: Option<RecipeGate>;
 };
 
 export type UpdateRecipeV1InstructionDataArgs = {
@@ -63,12 +108,26 @@ export type UpdateRecipeV1InstructionDataArgs = {
   uri: OptionOrNullable<string>;
   max: OptionOrNullable<number | bigint>;
   min: OptionOrNullable<number | bigint>;
-  amount: OptionOrNullable<number | bigint>;
+  captureAmount: OptionOrNullable<number | bigint>;
+  releaseAmount: OptionOrNullable<number | bigint>;
   feeAmountCapture: OptionOrNullable<number | bigint>;
   feeAmountRelease: OptionOrNullable<number | bigint>;
   solFeeAmountCapture: OptionOrNullable<number | bigint>;
   solFeeAmountRelease: OptionOrNullable<number | bigint>;
   path: OptionOrNullable<number>;
+  pricing: OptionOrNullable<PricingCurveArgs>;
+  inventory: OptionOrNullable<number | bigint>;
+  quoteSigner: OptionOrNullable<PublicKey>;
+  captureWindow: OptionOrNullable<SwapWindowArgs>;
+  releaseWindow: OptionOrNullable<SwapWindowArgs>;
+  walletLimits: OptionOrNullable<WalletLimitsArgs>;
+  circuitBreaker: OptionOrNullable<CircuitBreakerArgs>;
+  assetCooldown: OptionOrNullable<AssetCooldownArgs>;
+  loyalty: OptionOrNullable<LoyaltyRateArgs>;
+  timelockSlots: OptionOrNullable<number | bigint>;
+  assetRoot: OptionOrNullable<Array<number>>;
+  walletRoot: OptionOrNullable<Array<number>>;
+  gate: OptionOrNullable<RecipeGateArgs>;
 };
 
 export function getUpdateRecipeV1InstructionDataSerializer(): Serializer<
@@ -87,12 +146,26 @@ export function getUpdateRecipeV1InstructionDataSerializer(): Serializer<
         ['uri', option(string())],
         ['max', option(u64())],
         ['min', option(u64())],
-        ['amount', option(u64())],
+        ['captureAmount', option(u64())],
+        ['releaseAmount', option(u64())],
         ['feeAmountCapture', option(u64())],
         ['feeAmountRelease', option(u64())],
         ['solFeeAmountCapture', option(u64())],
         ['solFeeAmountRelease', option(u64())],
         ['path', option(u16())],
+        ['pricing', option(getPricingCurveSerializer())],
+        ['inventory', option(u64())],
+        ['quoteSigner', option(publicKeySerializer())],
+        ['captureWindow', option(getSwapWindowSerializer())],
+        ['releaseWindow', option(getSwapWindowSerializer())],
+        ['walletLimits', option(getWalletLimitsSerializer())],
+        ['circuitBreaker', option(getCircuitBreakerSerializer())],
+        ['assetCooldown', option(getAssetCooldownSerializer())],
+        ['loyalty', option(getLoyaltyRateSerializer())],
+        ['timelockSlots', option(u64())],
+        ['assetRoot', option(array(u8(), { size: 32 }))],
+        ['walletRoot', option(array(u8(), { size: 32 }))],
+        ['gate', option(getRecipeGateSerializer())],
       ],
       { description: 'UpdateRecipeV1InstructionData' }
     ),
@@ -111,7 +184,7 @@ export type UpdateRecipeV1InstructionArgs = UpdateRecipeV1InstructionDataArgs;
 
 // Instruction.
 export function updateRecipeV1(
-  context: Pick<Context, 'identity' | 'programs'>,
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
   input: UpdateRecipeV1InstructionAccounts & UpdateRecipeV1InstructionArgs
 ): TransactionBuilder {
   // Program ID.
@@ -152,6 +225,26 @@ export function updateRecipeV1(
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
+    operators: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.operators ?? null,
+    },
+    history: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.history ?? null,
+    },
+    eventAuthority: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Arguments.
@@ -168,6 +261,21 @@ export function updateRecipeV1(
     );
     resolvedAccounts.systemProgram.isWritable = false;
   }
+  if (!resolvedAccounts.history.value) {
+    resolvedAccounts.history.value = findRecipeHistoryV1Pda(context, {
+      recipe: expectPublicKey(resolvedAccounts.recipe.value),
+    });
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
 
   // Accounts in order.
   const orderedAccounts: ResolvedAccount[] = Object.values(
@@ -0,0 +1,261 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type WithdrawEscrowV1InstructionAccounts = {
+  authority?: Signer;
+  escrow: PublicKey | Pda;
+  recipe: PublicKey | Pda;
+  collection: PublicKey | Pda;
+  feeProjectAccount: PublicKey | Pda;
+  feeTokenAccount?: PublicKey | Pda;
+  escrowTokenAccount?: PublicKey | Pda;
+  /** Lamports are withdrawn instead when no mint is passed. */
+  token?: PublicKey | Pda;
+  operators?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+  tokenProgram?: PublicKey | Pda;
+  associatedTokenProgram?: PublicKey | Pda;
+  vault?: PublicKey | Pda;
+  eventAuthority?: PublicKey | Pda;
+  program?: PublicKey | Pda;
+};
+
+// Data.
+export type WithdrawEscrowV1InstructionData = {
+  discriminator: Array<number>;
+  amount: bigint;
+};
+
+export type WithdrawEscrowV1InstructionDataArgs = { amount: number | bigint };
+
+export function getWithdrawEscrowV1InstructionDataSerializer(): Serializer<
+  WithdrawEscrowV1InstructionDataArgs,
+  WithdrawEscrowV1InstructionData
+> {
+  return mapSerializer<
+    WithdrawEscrowV1InstructionDataArgs,
+    any,
+    WithdrawEscrowV1InstructionData
+  >(
+    struct<WithdrawEscrowV1InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['amount', u64()],
+      ],
+      { description: 'WithdrawEscrowV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [191, 228, 141, 52, 137, 234, 137, 45],
+    })
+  ) as Serializer<
+    WithdrawEscrowV1InstructionDataArgs,
+    WithdrawEscrowV1InstructionData
+  >;
+}
+
+// Args.
+export type WithdrawEscrowV1InstructionArgs =
+  WithdrawEscrowV1InstructionDataArgs;
+
+// Instruction.
+export function withdrawEscrowV1(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: WithdrawEscrowV1InstructionAccounts & WithdrawEscrowV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplHybrid',
+    'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    authority: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.authority ?? null,
+    },
+    escrow: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.escrow ?? null,
+    },
+    recipe: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.recipe ?? null,
+    },
+    collection: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.collection ?? null,
+    },
+    feeProjectAccount: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.feeProjectAccount ?? null,
+    },
+    feeTokenAccount: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.feeTokenAccount ?? null,
+    },
+    escrowTokenAccount: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.escrowTokenAccount ?? null,
+    },
+    token: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.token ?? null,
+    },
+    operators: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.operators ?? null,
+    },
+    systemProgram: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    tokenProgram: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+    associatedTokenProgram: {
+      index: 11,
+      isWritable: false as boolean,
+      value: input.associatedTokenProgram ?? null,
+    },
+    vault: {
+      index: 12,
+      isWritable: true as boolean,
+      value: input.vault ?? null,
+    },
+    eventAuthority: {
+      index: 13,
+      isWritable: false as boolean,
+      value: input.eventAuthority ?? null,
+    },
+    program: {
+      index: 14,
+      isWritable: false as boolean,
+      value: input.program ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: WithdrawEscrowV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.feeTokenAccount.value) {
+    if (resolvedAccounts.token.value) {
+      resolvedAccounts.feeTokenAccount.value = findAssociatedTokenPda(context, {
+        mint: expectPublicKey(resolvedAccounts.token.value),
+        owner: expectPublicKey(resolvedAccounts.feeProjectAccount.value),
+      });
+    }
+  }
+  if (!resolvedAccounts.escrowTokenAccount.value) {
+    if (resolvedAccounts.token.value) {
+      resolvedAccounts.escrowTokenAccount.value = findAssociatedTokenPda(
+        context,
+        {
+          mint: expectPublicKey(resolvedAccounts.token.value),
+          owner: expectPublicKey(resolvedAccounts.escrow.value),
+        }
+      );
+    }
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.tokenProgram.value) {
+    resolvedAccounts.tokenProgram.value = context.programs.getPublicKey(
+      'splToken',
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'
+    );
+    resolvedAccounts.tokenProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.associatedTokenProgram.value) {
+    resolvedAccounts.associatedTokenProgram.value = publicKey(
+      'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'
+    );
+  }
+  if (!resolvedAccounts.eventAuthority.value) {
+    resolvedAccounts.eventAuthority.value = publicKey(
+      '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc'
+    );
+  }
+  if (!resolvedAccounts.program.value) {
+    resolvedAccounts.program.value = publicKey(
+      'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb'
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getWithdrawEscrowV1InstructionDataSerializer().serialize(
+    resolvedArgs as WithdrawEscrowV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,29 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+
+export type AssetCooldown = { minSlotsOut: bigint; minSlotsIn: bigint };
+
+export type AssetCooldownArgs = {
+  minSlotsOut: number | bigint;
+  minSlotsIn: number | bigint;
+};
+
+export function getAssetCooldownSerializer(): Serializer<
+  AssetCooldownArgs,
+  AssetCooldown
+> {
+  return struct<AssetCooldown>(
+    [
+      ['minSlotsOut', u64()],
+      ['minSlotsIn', u64()],
+    ],
+    { description: 'AssetCooldown' }
+  ) as Serializer<AssetCooldownArgs, AssetCooldown>;
+}
@@ -0,0 +1,38 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bool,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type BasketEntry = { mint: PublicKey; amount: bigint; burn: boolean };
+
+export type BasketEntryArgs = {
+  mint: PublicKey;
+  amount: number | bigint;
+  burn: boolean;
+};
+
+export function getBasketEntrySerializer(): Serializer<
+  BasketEntryArgs,
+  BasketEntry
+> {
+  return struct<BasketEntry>(
+    [
+      ['mint', publicKeySerializer()],
+      ['amount', u64()],
+      ['burn', bool()],
+    ],
+    { description: 'BasketEntry' }
+  ) as Serializer<BasketEntryArgs, BasketEntry>;
+}
@@ -0,0 +1,40 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  struct,
+  u32,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type CircuitBreaker = {
+  windowSlots: bigint;
+  maxNetOutflow: bigint;
+  maxReleases: number;
+};
+
+export type CircuitBreakerArgs = {
+  windowSlots: number | bigint;
+  maxNetOutflow: number | bigint;
+  maxReleases: number;
+};
+
+export function getCircuitBreakerSerializer(): Serializer<
+  CircuitBreakerArgs,
+  CircuitBreaker
+> {
+  return struct<CircuitBreaker>(
+    [
+      ['windowSlots', u64()],
+      ['maxNetOutflow', u64()],
+      ['maxReleases', u32()],
+    ],
+    { description: 'CircuitBreaker' }
+  ) as Serializer<CircuitBreakerArgs, CircuitBreaker>;
+}
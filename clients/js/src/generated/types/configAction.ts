@@ -0,0 +1,26 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum ConfigAction {
+  Init,
+  Update,
+  Migrate,
+}
+
+export type ConfigActionArgs = ConfigAction;
+
+export function getConfigActionSerializer(): Serializer<
+  ConfigActionArgs,
+  ConfigAction
+> {
+  return scalarEnum<ConfigAction>(ConfigAction, {
+    description: 'ConfigAction',
+  }) as Serializer<ConfigActionArgs, ConfigAction>;
+}
@@ -0,0 +1,23 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum Currency {
+  Token,
+  NativeSol,
+  Basket,
+}
+
+export type CurrencyArgs = Currency;
+
+export function getCurrencySerializer(): Serializer<CurrencyArgs, Currency> {
+  return scalarEnum<Currency>(Currency, {
+    description: 'Currency',
+  }) as Serializer<CurrencyArgs, Currency>;
+}
@@ -0,0 +1,23 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum CurveKind {
+  Fixed,
+  Linear,
+  Exponential,
+}
+
+export type CurveKindArgs = CurveKind;
+
+export function getCurveKindSerializer(): Serializer<CurveKindArgs, CurveKind> {
+  return scalarEnum<CurveKind>(CurveKind, {
+    description: 'CurveKind',
+  }) as Serializer<CurveKindArgs, CurveKind>;
+}
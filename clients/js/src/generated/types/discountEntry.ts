@@ -0,0 +1,46 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u16,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import { DiscountKind, DiscountKindArgs, getDiscountKindSerializer } from '.';
+
+export type DiscountEntry = {
+  kind: DiscountKind;
+  key: PublicKey;
+  minAmount: bigint;
+  discountBps: number;
+};
+
+export type DiscountEntryArgs = {
+  kind: DiscountKindArgs;
+  key: PublicKey;
+  minAmount: number | bigint;
+  discountBps: number;
+};
+
+export function getDiscountEntrySerializer(): Serializer<
+  DiscountEntryArgs,
+  DiscountEntry
+> {
+  return struct<DiscountEntry>(
+    [
+      ['kind', getDiscountKindSerializer()],
+      ['key', publicKeySerializer()],
+      ['minAmount', u64()],
+      ['discountBps', u16()],
+    ],
+    { description: 'DiscountEntry' }
+  ) as Serializer<DiscountEntryArgs, DiscountEntry>;
+}
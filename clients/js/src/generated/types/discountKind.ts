@@ -0,0 +1,25 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum DiscountKind {
+  Collection,
+  TokenMint,
+}
+
+export type DiscountKindArgs = DiscountKind;
+
+export function getDiscountKindSerializer(): Serializer<
+  DiscountKindArgs,
+  DiscountKind
+> {
+  return scalarEnum<DiscountKind>(DiscountKind, {
+    description: 'DiscountKind',
+  }) as Serializer<DiscountKindArgs, DiscountKind>;
+}
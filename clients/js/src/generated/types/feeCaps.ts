@@ -0,0 +1,35 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+
+export type FeeCaps = {
+  feeAmountCapture: bigint;
+  feeAmountRelease: bigint;
+  solFeeAmountCapture: bigint;
+  solFeeAmountRelease: bigint;
+};
+
+export type FeeCapsArgs = {
+  feeAmountCapture: number | bigint;
+  feeAmountRelease: number | bigint;
+  solFeeAmountCapture: number | bigint;
+  solFeeAmountRelease: number | bigint;
+};
+
+export function getFeeCapsSerializer(): Serializer<FeeCapsArgs, FeeCaps> {
+  return struct<FeeCaps>(
+    [
+      ['feeAmountCapture', u64()],
+      ['feeAmountRelease', u64()],
+      ['solFeeAmountCapture', u64()],
+      ['solFeeAmountRelease', u64()],
+    ],
+    { description: 'FeeCaps' }
+  ) as Serializer<FeeCapsArgs, FeeCaps>;
+}
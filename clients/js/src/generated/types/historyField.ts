@@ -0,0 +1,49 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum HistoryField {
+  Token,
+  FeeLocation,
+  Name,
+  Uri,
+  Max,
+  Min,
+  CaptureAmount,
+  ReleaseAmount,
+  FeeAmountCapture,
+  FeeAmountRelease,
+  SolFeeAmountCapture,
+  SolFeeAmountRelease,
+  Path,
+  Pricing,
+  Inventory,
+  QuoteSigner,
+  CaptureWindow,
+  ReleaseWindow,
+  WalletLimits,
+  CircuitBreaker,
+  AssetCooldown,
+  Loyalty,
+  Timelock,
+  AssetRoot,
+  WalletRoot,
+  Gate,
+}
+
+export type HistoryFieldArgs = HistoryField;
+
+export function getHistoryFieldSerializer(): Serializer<
+  HistoryFieldArgs,
+  HistoryField
+> {
+  return scalarEnum<HistoryField>(HistoryField, {
+    description: 'HistoryField',
+  }) as Serializer<HistoryFieldArgs, HistoryField>;
+}
@@ -6,4 +6,33 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+export * from './assetCooldown';
+export * from './basketEntry';
+export * from './circuitBreaker';
+export * from './configAction';
+export * from './currency';
+export * from './curveBasis';
+export * from './curveKind';
+export * from './discountEntry';
+export * from './discountKind';
+export * from './feeCaps';
+export * from './historyField';
 export * from './internalPath';
+export * from './loyaltyRate';
+export * from './operatorEntry';
+export * from './operatorRole';
+export * from './outflowWindow';
+export * from './pauseScope';
+export * from './paymentEntry';
+export * from './pricingCurve';
+export * from './recipeChange';
+export * from './recipeField';
+export * from './recipeGate';
+export * from './recipeKind';
+export * from './recipeStats';
+export * from './recipeValues';
+export * from './swapDirection';
+export * from './swapQuoteV1';
+export * from './swapWindow';
+export * from './updateRecipeV1Ix';
+export * from './walletLimits';
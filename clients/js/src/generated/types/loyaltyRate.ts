@@ -0,0 +1,32 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+
+export type LoyaltyRate = {
+  pointsPerCapture: bigint;
+  pointsPerRelease: bigint;
+};
+
+export type LoyaltyRateArgs = {
+  pointsPerCapture: number | bigint;
+  pointsPerRelease: number | bigint;
+};
+
+export function getLoyaltyRateSerializer(): Serializer<
+  LoyaltyRateArgs,
+  LoyaltyRate
+> {
+  return struct<LoyaltyRate>(
+    [
+      ['pointsPerCapture', u64()],
+      ['pointsPerRelease', u64()],
+    ],
+    { description: 'LoyaltyRate' }
+  ) as Serializer<LoyaltyRateArgs, LoyaltyRate>;
+}
@@ -0,0 +1,32 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u16,
+} from '@metaplex-foundation/umi/serializers';
+
+export type OperatorEntry = { operator: PublicKey; roles: number };
+
+export type OperatorEntryArgs = OperatorEntry;
+
+export function getOperatorEntrySerializer(): Serializer<
+  OperatorEntryArgs,
+  OperatorEntry
+> {
+  return struct<OperatorEntry>(
+    [
+      ['operator', publicKeySerializer()],
+      ['roles', u16()],
+    ],
+    { description: 'OperatorEntry' }
+  ) as Serializer<OperatorEntryArgs, OperatorEntry>;
+}
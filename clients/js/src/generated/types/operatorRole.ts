@@ -0,0 +1,28 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum OperatorRole {
+  UpdatePricing,
+  UpdateMetadata,
+  Pause,
+  Withdraw,
+  ManageFees,
+}
+
+export type OperatorRoleArgs = OperatorRole;
+
+export function getOperatorRoleSerializer(): Serializer<
+  OperatorRoleArgs,
+  OperatorRole
+> {
+  return scalarEnum<OperatorRole>(OperatorRole, {
+    description: 'OperatorRole',
+  }) as Serializer<OperatorRoleArgs, OperatorRole>;
+}
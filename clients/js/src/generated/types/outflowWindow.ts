@@ -0,0 +1,41 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  i64,
+  struct,
+  u32,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type OutflowWindow = {
+  start: bigint;
+  netOutflow: bigint;
+  releases: number;
+};
+
+export type OutflowWindowArgs = {
+  start: number | bigint;
+  netOutflow: number | bigint;
+  releases: number;
+};
+
+export function getOutflowWindowSerializer(): Serializer<
+  OutflowWindowArgs,
+  OutflowWindow
+> {
+  return struct<OutflowWindow>(
+    [
+      ['start', u64()],
+      ['netOutflow', i64()],
+      ['releases', u32()],
+    ],
+    { description: 'OutflowWindow' }
+  ) as Serializer<OutflowWindowArgs, OutflowWindow>;
+}
@@ -0,0 +1,47 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type PaymentEntry = {
+  mint: PublicKey;
+  captureAmount: bigint;
+  releaseAmount: bigint;
+  feeAmountCapture: bigint;
+  feeAmountRelease: bigint;
+};
+
+export type PaymentEntryArgs = {
+  mint: PublicKey;
+  captureAmount: number | bigint;
+  releaseAmount: number | bigint;
+  feeAmountCapture: number | bigint;
+  feeAmountRelease: number | bigint;
+};
+
+export function getPaymentEntrySerializer(): Serializer<
+  PaymentEntryArgs,
+  PaymentEntry
+> {
+  return struct<PaymentEntry>(
+    [
+      ['mint', publicKeySerializer()],
+      ['captureAmount', u64()],
+      ['releaseAmount', u64()],
+      ['feeAmountCapture', u64()],
+      ['feeAmountRelease', u64()],
+    ],
+    { description: 'PaymentEntry' }
+  ) as Serializer<PaymentEntryArgs, PaymentEntry>;
+}
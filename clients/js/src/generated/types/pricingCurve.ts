@@ -0,0 +1,52 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+import {
+  CurveBasis,
+  CurveBasisArgs,
+  CurveKind,
+  CurveKindArgs,
+  getCurveBasisSerializer,
+  getCurveKindSerializer,
+} from '.';
+
+export type PricingCurve = {
+  kind: CurveKind;
+  basis: CurveBasis;
+  target: bigint;
+  factor: bigint;
+  minPrice: bigint;
+  maxPrice: bigint;
+};
+
+export type PricingCurveArgs = {
+  kind: CurveKindArgs;
+  basis: CurveBasisArgs;
+  target: number | bigint;
+  factor: number | bigint;
+  minPrice: number | bigint;
+  maxPrice: number | bigint;
+};
+
+export function getPricingCurveSerializer(): Serializer<
+  PricingCurveArgs,
+  PricingCurve
+> {
+  return struct<PricingCurve>(
+    [
+      ['kind', getCurveKindSerializer()],
+      ['basis', getCurveBasisSerializer()],
+      ['target', u64()],
+      ['factor', u64()],
+      ['minPrice', u64()],
+      ['maxPrice', u64()],
+    ],
+    { description: 'PricingCurve' }
+  ) as Serializer<PricingCurveArgs, PricingCurve>;
+}
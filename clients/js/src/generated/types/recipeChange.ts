@@ -0,0 +1,49 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u32,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import { RecipeValues, RecipeValuesArgs, getRecipeValuesSerializer } from '.';
+
+export type RecipeChange = {
+  slot: bigint;
+  signer: PublicKey;
+  changed: number;
+  old: RecipeValues;
+  new: RecipeValues;
+};
+
+export type RecipeChangeArgs = {
+  slot: number | bigint;
+  signer: PublicKey;
+  changed: number;
+  old: RecipeValuesArgs;
+  new: RecipeValuesArgs;
+};
+
+export function getRecipeChangeSerializer(): Serializer<
+  RecipeChangeArgs,
+  RecipeChange
+> {
+  return struct<RecipeChange>(
+    [
+      ['slot', u64()],
+      ['signer', publicKeySerializer()],
+      ['changed', u32()],
+      ['old', getRecipeValuesSerializer()],
+      ['new', getRecipeValuesSerializer()],
+    ],
+    { description: 'RecipeChange' }
+  ) as Serializer<RecipeChangeArgs, RecipeChange>;
+}
@@ -0,0 +1,33 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum RecipeField {
+  Metadata,
+  Path,
+  Token,
+  Pricing,
+  Fees,
+  FeeLocation,
+  SwapLimits,
+  Loyalty,
+  Timelock,
+  Allowlist,
+}
+
+export type RecipeFieldArgs = RecipeField;
+
+export function getRecipeFieldSerializer(): Serializer<
+  RecipeFieldArgs,
+  RecipeField
+> {
+  return scalarEnum<RecipeField>(RecipeField, {
+    description: 'RecipeField',
+  }) as Serializer<RecipeFieldArgs, RecipeField>;
+}
@@ -0,0 +1,32 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u32,
+} from '@metaplex-foundation/umi/serializers';
+
+export type RecipeGate = { collection: PublicKey; minCount: number };
+
+export type RecipeGateArgs = RecipeGate;
+
+export function getRecipeGateSerializer(): Serializer<
+  RecipeGateArgs,
+  RecipeGate
+> {
+  return struct<RecipeGate>(
+    [
+      ['collection', publicKeySerializer()],
+      ['minCount', u32()],
+    ],
+    { description: 'RecipeGate' }
+  ) as Serializer<RecipeGateArgs, RecipeGate>;
+}
@@ -0,0 +1,25 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum RecipeKind {
+  Collection,
+  Creator,
+}
+
+export type RecipeKindArgs = RecipeKind;
+
+export function getRecipeKindSerializer(): Serializer<
+  RecipeKindArgs,
+  RecipeKind
+> {
+  return scalarEnum<RecipeKind>(RecipeKind, {
+    description: 'RecipeKind',
+  }) as Serializer<RecipeKindArgs, RecipeKind>;
+}
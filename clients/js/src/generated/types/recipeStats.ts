@@ -0,0 +1,47 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+
+export type RecipeStats = {
+  captures: bigint;
+  releases: bigint;
+  tokensIn: bigint;
+  tokensOut: bigint;
+  tokensBurned: bigint;
+  tokenFees: bigint;
+  solFees: bigint;
+};
+
+export type RecipeStatsArgs = {
+  captures: number | bigint;
+  releases: number | bigint;
+  tokensIn: number | bigint;
+  tokensOut: number | bigint;
+  tokensBurned: number | bigint;
+  tokenFees: number | bigint;
+  solFees: number | bigint;
+};
+
+export function getRecipeStatsSerializer(): Serializer<
+  RecipeStatsArgs,
+  RecipeStats
+> {
+  return struct<RecipeStats>(
+    [
+      ['captures', u64()],
+      ['releases', u64()],
+      ['tokensIn', u64()],
+      ['tokensOut', u64()],
+      ['tokensBurned', u64()],
+      ['tokenFees', u64()],
+      ['solFees', u64()],
+    ],
+    { description: 'RecipeStats' }
+  ) as Serializer<RecipeStatsArgs, RecipeStats>;
+}
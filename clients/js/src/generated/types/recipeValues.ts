@@ -0,0 +1,64 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  struct,
+  u16,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type RecipeValues = {
+  max: bigint;
+  min: bigint;
+  captureAmount: bigint;
+  releaseAmount: bigint;
+  feeAmountCapture: bigint;
+  feeAmountRelease: bigint;
+  solFeeAmountCapture: bigint;
+  solFeeAmountRelease: bigint;
+  inventory: bigint;
+  timelockSlots: bigint;
+  path: number;
+};
+
+export type RecipeValuesArgs = {
+  max: number | bigint;
+  min: number | bigint;
+  captureAmount: number | bigint;
+  releaseAmount: number | bigint;
+  feeAmountCapture: number | bigint;
+  feeAmountRelease: number | bigint;
+  solFeeAmountCapture: number | bigint;
+  solFeeAmountRelease: number | bigint;
+  inventory: number | bigint;
+  timelockSlots: number | bigint;
+  path: number;
+};
+
+export function getRecipeValuesSerializer(): Serializer<
+  RecipeValuesArgs,
+  RecipeValues
+> {
+  return struct<RecipeValues>(
+    [
+      ['max', u64()],
+      ['min', u64()],
+      ['captureAmount', u64()],
+      ['releaseAmount', u64()],
+      ['feeAmountCapture', u64()],
+      ['feeAmountRelease', u64()],
+      ['solFeeAmountCapture', u64()],
+      ['solFeeAmountRelease', u64()],
+      ['inventory', u64()],
+      ['timelockSlots', u64()],
+      ['path', u16()],
+    ],
+    { description: 'RecipeValues' }
+  ) as Serializer<RecipeValuesArgs, RecipeValues>;
+}
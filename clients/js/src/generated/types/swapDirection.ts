@@ -0,0 +1,25 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum SwapDirection {
+  Capture,
+  Release,
+}
+
+export type SwapDirectionArgs = SwapDirection;
+
+export function getSwapDirectionSerializer(): Serializer<
+  SwapDirectionArgs,
+  SwapDirection
+> {
+  return scalarEnum<SwapDirection>(SwapDirection, {
+    description: 'SwapDirection',
+  }) as Serializer<SwapDirectionArgs, SwapDirection>;
+}
@@ -0,0 +1,58 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  SwapDirection,
+  SwapDirectionArgs,
+  getSwapDirectionSerializer,
+} from '.';
+
+export type SwapQuoteV1 = {
+  recipe: PublicKey;
+  user: PublicKey;
+  asset: PublicKey;
+  direction: SwapDirection;
+  amount: bigint;
+  expirySlot: bigint;
+  nonce: bigint;
+};
+
+export type SwapQuoteV1Args = {
+  recipe: PublicKey;
+  user: PublicKey;
+  asset: PublicKey;
+  direction: SwapDirectionArgs;
+  amount: number | bigint;
+  expirySlot: number | bigint;
+  nonce: number | bigint;
+};
+
+export function getSwapQuoteV1Serializer(): Serializer<
+  SwapQuoteV1Args,
+  SwapQuoteV1
+> {
+  return struct<SwapQuoteV1>(
+    [
+      ['recipe', publicKeySerializer()],
+      ['user', publicKeySerializer()],
+      ['asset', publicKeySerializer()],
+      ['direction', getSwapDirectionSerializer()],
+      ['amount', u64()],
+      ['expirySlot', u64()],
+      ['nonce', u64()],
+    ],
+    { description: 'SwapQuoteV1' }
+  ) as Serializer<SwapQuoteV1Args, SwapQuoteV1>;
+}
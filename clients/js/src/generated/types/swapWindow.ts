@@ -0,0 +1,26 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, i64, struct } from '@metaplex-foundation/umi/serializers';
+
+export type SwapWindow = { start: bigint; end: bigint };
+
+export type SwapWindowArgs = { start: number | bigint; end: number | bigint };
+
+export function getSwapWindowSerializer(): Serializer<
+  SwapWindowArgs,
+  SwapWindow
+> {
+  return struct<SwapWindow>(
+    [
+      ['start', i64()],
+      ['end', i64()],
+    ],
+    { description: 'SwapWindow' }
+  ) as Serializer<SwapWindowArgs, SwapWindow>;
+}
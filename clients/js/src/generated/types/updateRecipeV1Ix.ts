@@ -0,0 +1,132 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable, PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  option,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u16,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  AssetCooldown,
+  AssetCooldownArgs,
+  CircuitBreaker,
+  CircuitBreakerArgs,
+  LoyaltyRate,
+  LoyaltyRateArgs,
+  PricingCurve,
+  PricingCurveArgs,
+  RecipeGate,
+  RecipeGateArgs,
+  SwapWindow,
+  SwapWindowArgs,
+  WalletLimits,
+  WalletLimitsArgs,
+  getAssetCooldownSerializer,
+  getCircuitBreakerSerializer,
+  getLoyaltyRateSerializer,
+  getPricingCurveSerializer,
+  getRecipeGateSerializer,
+  getSwapWindowSerializer,
+  getWalletLimitsSerializer,
+} from '.';
+
+export type UpdateRecipeV1Ix = {
+  name: Option<string>;
+  uri: Option<string>;
+  max: Option<bigint>;
+  min: Option<bigint>;
+  captureAmount: Option<bigint>;
+  releaseAmount: Option<bigint>;
+  feeAmountCapture: Option<bigint>;
+  feeAmountRelease: Option<bigint>;
+  solFeeAmountCapture: Option<bigint>;
+  solFeeAmountRelease: Option<bigint>;
+  path: Option<number>;
+  pricing: Option<PricingCurve>;
+  inventory: Option<bigint>;
+  quoteSigner: Option<PublicKey>;
+  captureWindow: Option<SwapWindow>;
+  releaseWindow: Option<SwapWindow>;
+  walletLimits: Option<WalletLimits>;
+  circuitBreaker: Option<CircuitBreaker>;
+  assetCooldown: Option<AssetCooldown>;
+  loyalty: Option<LoyaltyRate>;
+  timelockSlots: Option<bigint>;
+  assetRoot: Option<Array<number>>;
+  walletRoot: Option<Array<number>>;
+  gate: Option<RecipeGate>;
+};
+
+export type UpdateRecipeV1IxArgs = {
+  name: OptionOrNullable<string>;
+  uri: OptionOrNullable<string>;
+  max: OptionOrNullable<number | bigint>;
+  min: OptionOrNullable<number | bigint>;
+  captureAmount: OptionOrNullable<number | bigint>;
+  releaseAmount: OptionOrNullable<number | bigint>;
+  feeAmountCapture: OptionOrNullable<number | bigint>;
+  feeAmountRelease: OptionOrNullable<number | bigint>;
+  solFeeAmountCapture: OptionOrNullable<number | bigint>;
+  solFeeAmountRelease: OptionOrNullable<number | bigint>;
+  path: OptionOrNullable<number>;
+  pricing: OptionOrNullable<PricingCurveArgs>;
+  inventory: OptionOrNullable<number | bigint>;
+  quoteSigner: OptionOrNullable<PublicKey>;
+  captureWindow: OptionOrNullable<SwapWindowArgs>;
+  releaseWindow: OptionOrNullable<SwapWindowArgs>;
+  walletLimits: OptionOrNullable<WalletLimitsArgs>;
+  circuitBreaker: OptionOrNullable<CircuitBreakerArgs>;
+  assetCooldown: OptionOrNullable<AssetCooldownArgs>;
+  loyalty: OptionOrNullable<LoyaltyRateArgs>;
+  timelockSlots: OptionOrNullable<number | bigint>;
+  assetRoot: OptionOrNullable<Array<number>>;
+  walletRoot: OptionOrNullable<Array<number>>;
+  gate: OptionOrNullable<RecipeGateArgs>;
+};
+
+export function getUpdateRecipeV1IxSerializer(): Serializer<
+  UpdateRecipeV1IxArgs,
+  UpdateRecipeV1Ix
+> {
+  return struct<UpdateRecipeV1Ix>(
+    [
+      ['name', option(string())],
+      ['uri', option(string())],
+      ['max', option(u64())],
+      ['min', option(u64())],
+      ['captureAmount', option(u64())],
+      ['releaseAmount', option(u64())],
+      ['feeAmountCapture', option(u64())],
+      ['feeAmountRelease', option(u64())],
+      ['solFeeAmountCapture', option(u64())],
+      ['solFeeAmountRelease', option(u64())],
+      ['path', option(u16())],
+      ['pricing', option(getPricingCurveSerializer())],
+      ['inventory', option(u64())],
+      ['quoteSigner', option(publicKeySerializer())],
+      ['captureWindow', option(getSwapWindowSerializer())],
+      ['releaseWindow', option(getSwapWindowSerializer())],
+      ['walletLimits', option(getWalletLimitsSerializer())],
+      ['circuitBreaker', option(getCircuitBreakerSerializer())],
+      ['assetCooldown', option(getAssetCooldownSerializer())],
+      ['loyalty', option(getLoyaltyRateSerializer())],
+      ['timelockSlots', option(u64())],
+      ['assetRoot', option(array(u8(), { size: 32 }))],
+      ['walletRoot', option(array(u8(), { size: 32 }))],
+      ['gate', option(getRecipeGateSerializer())],
+    ],
+    { description: 'UpdateRecipeV1Ix' }
+  ) as Serializer<UpdateRecipeV1IxArgs, UpdateRecipeV1Ix>;
+}
@@ -0,0 +1,43 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  struct,
+  u32,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type WalletLimits = {
+  windowSlots: bigint;
+  maxCaptures: number;
+  maxReleases: number;
+  cooldownSlots: bigint;
+};
+
+export type WalletLimitsArgs = {
+  windowSlots: number | bigint;
+  maxCaptures: number;
+  maxReleases: number;
+  cooldownSlots: number | bigint;
+};
+
+export function getWalletLimitsSerializer(): Serializer<
+  WalletLimitsArgs,
+  WalletLimits
+> {
+  return struct<WalletLimits>(
+    [
+      ['windowSlots', u64()],
+      ['maxCaptures', u32()],
+      ['maxReleases', u32()],
+      ['cooldownSlots', u64()],
+    ],
+    { description: 'WalletLimits' }
+  ) as Serializer<WalletLimitsArgs, WalletLimits>;
+}
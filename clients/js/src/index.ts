@@ -1,3 +1,6 @@
 export * from './generated';
+export * from './migrate';
 export * from './plugin';
+export * from './fields';
 export * from './path';
+export * from './roles';
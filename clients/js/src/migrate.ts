@@ -0,0 +1,67 @@
+import {
+  Context,
+  PublicKey,
+  RpcAccount,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import { publicKey as publicKeySerializer } from '@metaplex-foundation/umi/serializers';
+import {
+  deserializeRecipeV1,
+  getRecipeV1GpaBuilder,
+  migrateRecipeV1,
+} from './generated';
+
+const RECIPE_V1_DISCRIMINATOR = [137, 249, 37, 80, 19, 50, 78, 169];
+
+export type LegacyRecipe = {
+  publicKey: PublicKey;
+  collection: PublicKey;
+};
+
+// Recipes written before the layout grew are too short to deserialize.
+function isLegacyRecipe(account: RpcAccount): boolean {
+  try {
+    deserializeRecipeV1(account);
+    return false;
+  } catch {
+    return true;
+  }
+}
+
+/**
+ * Finds the recipes the program can't load until they are migrated with
+ * `migrateRecipeV1`.
+ */
+export async function findLegacyRecipes(
+  context: Pick<Context, 'rpc' | 'programs'>
+): Promise<LegacyRecipe[]> {
+  const accounts = await getRecipeV1GpaBuilder(context)
+    .whereField('discriminator', RECIPE_V1_DISCRIMINATOR)
+    .get();
+
+  return accounts.filter(isLegacyRecipe).map((account) => ({
+    publicKey: account.publicKey,
+    collection: publicKeySerializer().deserialize(account.data, 8)[0],
+  }));
+}
+
+/**
+ * Rewrites the given recipes with the current layout. The identity has to be
+ * the update authority of each collection, or one of its update delegates.
+ */
+export function migrateRecipes(
+  context: Parameters<typeof migrateRecipeV1>[0],
+  recipes: LegacyRecipe[]
+): TransactionBuilder {
+  return recipes.reduce(
+    (builder, recipe) =>
+      builder.add(
+        migrateRecipeV1(context, {
+          recipe: recipe.publicKey,
+          collection: recipe.collection,
+        })
+      ),
+    transactionBuilder()
+  );
+}
@@ -0,0 +1,7 @@
+import { OperatorRole } from './generated';
+
+// Packs the roles into the bitmask stored on each operator entry.
+export function buildRoles(roles: OperatorRole[]) {
+  // eslint-disable-next-line no-bitwise
+  return roles.reduce((bits, role) => bits | (1 << role), 0);
+}
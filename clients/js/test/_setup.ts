@@ -7,10 +7,50 @@ import {
   fetchAsset,
   fetchCollection,
   mplCore,
+  transfer,
 } from '@metaplex-foundation/mpl-core';
-import { generateSigner, PublicKey, Umi } from '@metaplex-foundation/umi';
-import { mplTokenMetadata } from '@metaplex-foundation/mpl-token-metadata';
-import { mplHybrid } from '../src';
+import {
+  AccountMeta,
+  generateSigner,
+  Keypair,
+  Pda,
+  publicKey,
+  PublicKey,
+  publicKeyBytes,
+  sol,
+  TransactionBuilder,
+  transactionBuilder,
+  Umi,
+} from '@metaplex-foundation/umi';
+import {
+  publicKey as publicKeySerializer,
+  string,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import { findAssociatedTokenPda } from '@metaplex-foundation/mpl-toolbox';
+import {
+  createFungible,
+  mintV1,
+  mplTokenMetadata,
+  TokenStandard,
+} from '@metaplex-foundation/mpl-token-metadata';
+import {
+  acceptRecipeAuthorityV1,
+  buildPath,
+  Currency,
+  findEscrowV2Pda,
+  findRecipeV1Pda,
+  findRecipeVaultV1Pda,
+  getSwapQuoteV1Serializer,
+  initEscrowV2,
+  initRecipeV1,
+  mplHybrid,
+  Path,
+  proposeRecipeAuthorityV1,
+  RecipeKind,
+  SwapQuoteV1Args,
+  UpdateRecipeV1InstructionDataArgs,
+} from '../src';
 
 export const DEFAULT_ASSET = {
   name: 'Test Asset',
@@ -22,6 +62,34 @@ export const DEFAULT_COLLECTION = {
   uri: 'https://example.com/collection',
 };
 
+// Recipe update arguments that leave every field unchanged.
+export const NO_RECIPE_CHANGES: UpdateRecipeV1InstructionDataArgs = {
+  name: null,
+  uri: null,
+  max: null,
+  min: null,
+  captureAmount: null,
+  releaseAmount: null,
+  feeAmountCapture: null,
+  feeAmountRelease: null,
+  solFeeAmountCapture: null,
+  solFeeAmountRelease: null,
+  path: null,
+  pricing: null,
+  inventory: null,
+  quoteSigner: null,
+  captureWindow: null,
+  releaseWindow: null,
+  walletLimits: null,
+  circuitBreaker: null,
+  assetCooldown: null,
+  loyalty: null,
+  timelockSlots: null,
+  assetRoot: null,
+  walletRoot: null,
+  gate: null,
+};
+
 export const createUmi = async () =>
   (await basecreateUmi())
     .use(mplHybrid())
@@ -54,3 +122,211 @@ export async function createCoreCollection(umi: Umi, owner?: PublicKey) {
 
   return { collection, assets };
 }
+
+export async function createFungibleToken(umi: Umi, amount = 1000) {
+  const tokenMint = generateSigner(umi);
+  await createFungible(umi, {
+    name: 'Test Token',
+    uri: 'www.fungible.com',
+    sellerFeeBasisPoints: {
+      basisPoints: 0n,
+      identifier: '%',
+      decimals: 2,
+    },
+    mint: tokenMint,
+  }).sendAndConfirm(umi);
+
+  await mintV1(umi, {
+    mint: tokenMint.publicKey,
+    tokenStandard: TokenStandard.Fungible,
+    tokenOwner: umi.identity.publicKey,
+    amount,
+  }).sendAndConfirm(umi);
+
+  return tokenMint;
+}
+
+// Creates a token recipe for a collection whose assets sit in the escrow.
+export async function createRecipe(
+  umi: Umi,
+  input: Partial<Parameters<typeof initRecipeV1>[1]> = {}
+) {
+  const feeLocation = generateSigner(umi);
+  const { assets, collection } = await createCoreCollection(umi);
+  const tokenMint = await createFungibleToken(umi);
+
+  // Recipes of the same authority share its escrow.
+  const escrow = findEscrowV2Pda(umi, { authority: umi.identity.publicKey });
+  if (!(await umi.rpc.accountExists(publicKey(escrow)))) {
+    await initEscrowV2(umi, {}).sendAndConfirm(umi);
+  }
+
+  // eslint-disable-next-line no-restricted-syntax
+  for (const asset of assets) {
+    // eslint-disable-next-line no-await-in-loop
+    await transfer(umi, {
+      asset,
+      collection,
+      newOwner: escrow,
+    }).sendAndConfirm(umi);
+  }
+
+  const recipe = findRecipeV1Pda(umi, { collection: collection.publicKey });
+  await initRecipeV1(umi, {
+    collection: collection.publicKey,
+    token: tokenMint.publicKey,
+    feeLocation: feeLocation.publicKey,
+    name: 'Test Escrow',
+    uri: 'www.test.com/',
+    max: 9,
+    min: 0,
+    captureAmount: 5,
+    releaseAmount: 5,
+    feeAmountCapture: 1,
+    feeAmountRelease: 1,
+    solFeeAmountCapture: 890_880n,
+    solFeeAmountRelease: 100_000n,
+    path: buildPath([Path.NoRerollMetadata]),
+    currency: Currency.Token,
+    kind: RecipeKind.Collection,
+    quoteSigner: null,
+    vault:
+      input.currency === Currency.NativeSol
+        ? findRecipeVaultV1Pda(umi, { recipe: publicKey(recipe) })
+        : undefined,
+    ...input,
+  }).sendAndConfirm(umi);
+
+  return { assets, collection, escrow, feeLocation, recipe, tokenMint };
+}
+
+// A new keypair with enough lamports to pay for its own accounts.
+export async function createFundedSigner(umi: Umi) {
+  const signer = generateSigner(umi);
+  await umi.rpc.airdrop(signer.publicKey, sol(1));
+
+  return signer;
+}
+
+// Hands the recipe over to a new authority with its own escrow.
+export async function handOverRecipe(umi: Umi, recipe: PublicKey | Pda) {
+  const newAuthority = await createFundedSigner(umi);
+  const newEscrow = findEscrowV2Pda(umi, {
+    authority: newAuthority.publicKey,
+  });
+  await initEscrowV2(umi, { authority: newAuthority }).sendAndConfirm(umi);
+
+  await proposeRecipeAuthorityV1(umi, {
+    recipe,
+    newAuthority: newAuthority.publicKey,
+  })
+    .add(
+      acceptRecipeAuthorityV1(umi, {
+        recipe,
+        authority: newAuthority,
+        escrow: newEscrow,
+      })
+    )
+    .sendAndConfirm(umi);
+
+  return { newAuthority, newEscrow };
+}
+
+// The mint, owner token account and escrow token account of each basket entry,
+// in the order the program expects them in the remaining accounts.
+export function basketAccounts(
+  umi: Umi,
+  escrow: PublicKey | Pda,
+  mints: PublicKey[]
+): AccountMeta[] {
+  return mints.flatMap((mint) => [
+    { pubkey: mint, isSigner: false, isWritable: true },
+    {
+      pubkey: findAssociatedTokenPda(umi, {
+        mint,
+        owner: umi.identity.publicKey,
+      })[0],
+      isSigner: false,
+      isWritable: true,
+    },
+    {
+      pubkey: findAssociatedTokenPda(umi, {
+        mint,
+        owner: publicKey(escrow),
+      })[0],
+      isSigner: false,
+      isWritable: true,
+    },
+  ]);
+}
+
+export function addRemainingAccounts(
+  builder: TransactionBuilder,
+  accounts: AccountMeta[]
+): TransactionBuilder {
+  return transactionBuilder(
+    builder.items.map((item) => ({
+      ...item,
+      instruction: {
+        ...item.instruction,
+        keys: [...item.instruction.keys, ...accounts],
+      },
+    }))
+  );
+}
+
+export const INSTRUCTIONS_SYSVAR = publicKey(
+  'Sysvar1nstructions1111111111111111111111111'
+);
+
+const ED25519_PROGRAM = publicKey(
+  'Ed25519SigVerify111111111111111111111111111'
+);
+
+// Verifies the quote signature with the ed25519 precompile, which has to run
+// right before the swap using the quote.
+export function signQuote(
+  umi: Umi,
+  signer: Keypair,
+  quote: SwapQuoteV1Args
+): TransactionBuilder {
+  const message = getSwapQuoteV1Serializer().serialize(quote);
+  const signature = umi.eddsa.sign(message, signer);
+
+  // One signature whose public key, signature and message follow the header.
+  const header = new Uint8Array(16);
+  const view = new DataView(header.buffer);
+  header[0] = 1;
+  [16 + 32, 0xffff, 16, 0xffff, 16 + 32 + 64, message.length, 0xffff].forEach(
+    (value, i) => view.setUint16(2 + i * 2, value, true)
+  );
+
+  return transactionBuilder([
+    {
+      instruction: {
+        programId: ED25519_PROGRAM,
+        keys: [],
+        data: new Uint8Array([
+          ...header,
+          ...publicKeyBytes(signer.publicKey),
+          ...signature,
+          ...message,
+        ]),
+      },
+      signers: [],
+      bytesCreatedOnChain: 0,
+    },
+  ]);
+}
+
+export function findQuoteNoncePda(
+  umi: Umi,
+  recipe: PublicKey | Pda,
+  nonce: number | bigint
+): Pda {
+  return umi.eddsa.findPda(umi.programs.getPublicKey('mplHybrid'), [
+    string({ size: 'variable' }).serialize('quote'),
+    publicKeySerializer().serialize(publicKey(recipe)),
+    u64().serialize(nonce),
+  ]);
+}
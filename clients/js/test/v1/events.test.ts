@@ -0,0 +1,210 @@
+import test from 'ava';
+import { createHash } from 'crypto';
+import {
+  generateSigner,
+  publicKey,
+  TransactionSignature,
+  Umi,
+} from '@metaplex-foundation/umi';
+import {
+  createFungible,
+  mintV1,
+  TokenStandard,
+} from '@metaplex-foundation/mpl-token-metadata';
+import {
+  bool,
+  string,
+  struct,
+  u64,
+  publicKey as publicKeySerializer,
+} from '@metaplex-foundation/umi/serializers';
+import { transfer } from '@metaplex-foundation/mpl-core';
+import {
+  buildPath,
+  captureV1,
+  initEscrowV1,
+  MPL_HYBRID_PROGRAM_ID,
+  Path,
+  releaseV1,
+} from '../../src';
+import { createCoreCollection, createUmi } from '../_setup';
+
+const DEFAULT_KEY = publicKey('11111111111111111111111111111111');
+
+// Anchor prefixes self-CPI event data with the event instruction tag.
+const EVENT_IX_TAG = new Uint8Array([
+  0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d,
+]);
+
+const discriminator = (name: string) =>
+  new Uint8Array(createHash('sha256').update(`event:${name}`).digest()).slice(
+    0,
+    8
+  );
+
+// Only the fixed leading fields are decoded, the trailing options and
+// basket entries aren't needed here.
+const captureEventSerializer = struct<any>([
+  ['recipe', publicKeySerializer()],
+  ['escrow', publicKeySerializer()],
+  ['asset', publicKeySerializer()],
+  ['user', publicKeySerializer()],
+  ['mint', publicKeySerializer()],
+  ['amount', u64()],
+  ['burned', u64()],
+  ['feeAmount', u64()],
+  ['solFeeAmount', u64()],
+  ['protocolFee', u64()],
+]);
+
+const releaseEventSerializer = struct<any>([
+  ['recipe', publicKeySerializer()],
+  ['escrow', publicKeySerializer()],
+  ['asset', publicKeySerializer()],
+  ['user', publicKeySerializer()],
+  ['mint', publicKeySerializer()],
+  ['amount', u64()],
+  ['assetBurned', bool()],
+  ['feeAmount', u64()],
+  ['solFeeAmount', u64()],
+  ['protocolFee', u64()],
+]);
+
+const findEventData = async (
+  umi: Umi,
+  signature: TransactionSignature,
+  name: string
+) => {
+  const transaction = await umi.rpc.getTransaction(signature);
+  const prefix = new Uint8Array([...EVENT_IX_TAG, ...discriminator(name)]);
+  const data = (transaction?.meta.innerInstructions ?? [])
+    .flatMap(({ instructions }) => instructions)
+    .map((instruction) => instruction.data)
+    .find((bytes) => prefix.every((byte, i) => bytes[i] === byte));
+  if (!data) {
+    throw new Error(`${name} not emitted`);
+  }
+  return data.slice(prefix.length);
+};
+
+const createV1Escrow = async (umi: Umi) => {
+  const feeLocation = generateSigner(umi);
+  const { assets, collection } = await createCoreCollection(umi);
+  const tokenMint = generateSigner(umi);
+  await createFungible(umi, {
+    name: 'Test Token',
+    uri: 'www.fungible.com',
+    sellerFeeBasisPoints: {
+      basisPoints: 0n,
+      identifier: '%',
+      decimals: 2,
+    },
+    mint: tokenMint,
+  }).sendAndConfirm(umi);
+
+  const escrow = umi.eddsa.findPda(MPL_HYBRID_PROGRAM_ID, [
+    string({ size: 'variable' }).serialize('escrow'),
+    publicKeySerializer().serialize(collection.publicKey),
+  ]);
+
+  await mintV1(umi, {
+    mint: tokenMint.publicKey,
+    tokenStandard: TokenStandard.Fungible,
+    tokenOwner: escrow,
+    amount: 1000,
+  }).sendAndConfirm(umi);
+
+  // The escrow holds the second asset, the user keeps the first.
+  await transfer(umi, {
+    asset: assets[1],
+    collection,
+    newOwner: escrow,
+  }).sendAndConfirm(umi);
+
+  await initEscrowV1(umi, {
+    escrow,
+    collection: collection.publicKey,
+    token: tokenMint.publicKey,
+    feeLocation: feeLocation.publicKey,
+    name: 'Test Escrow',
+    uri: 'www.test.com/',
+    max: 9,
+    min: 0,
+    amount: 5,
+    feeAmount: 1,
+    path: buildPath([Path.NoRerollMetadata]),
+    solFeeAmount: 1000000n,
+  }).sendAndConfirm(umi);
+
+  return { assets, collection, escrow, feeLocation, tokenMint };
+};
+
+test('a v1 release emits the escrow and no token fee', async (t) => {
+  // Given a v1 escrow and a user holding an asset.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, tokenMint } =
+    await createV1Escrow(umi);
+
+  // When the user releases the asset for tokens.
+  const { signature } = await releaseV1(umi, {
+    owner: umi.identity,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the release event names the escrow and charges no token fee.
+  const data = await findEventData(umi, signature, 'ReleaseEvent');
+  const [event] = releaseEventSerializer.deserialize(data);
+  t.like(event, {
+    recipe: DEFAULT_KEY,
+    escrow: publicKey(escrow),
+    asset: assets[0].publicKey,
+    user: umi.identity.publicKey,
+    mint: tokenMint.publicKey,
+    amount: 5n,
+    assetBurned: false,
+    feeAmount: 0n,
+    solFeeAmount: 1_000_000n,
+  });
+});
+
+test('a v1 capture emits the escrow and its token fee', async (t) => {
+  // Given a v1 escrow holding an asset and a user holding tokens.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, tokenMint } =
+    await createV1Escrow(umi);
+  await mintV1(umi, {
+    mint: tokenMint.publicKey,
+    tokenStandard: TokenStandard.Fungible,
+    tokenOwner: umi.identity.publicKey,
+    amount: 1000,
+  }).sendAndConfirm(umi);
+
+  // When the user captures the asset with tokens.
+  const { signature } = await captureV1(umi, {
+    owner: umi.identity,
+    escrow,
+    asset: assets[1].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the capture event names the escrow and the fee charged.
+  const data = await findEventData(umi, signature, 'CaptureEvent');
+  const [event] = captureEventSerializer.deserialize(data);
+  t.like(event, {
+    recipe: DEFAULT_KEY,
+    escrow: publicKey(escrow),
+    asset: assets[1].publicKey,
+    user: umi.identity.publicKey,
+    mint: tokenMint.publicKey,
+    amount: 5n,
+    burned: 0n,
+    feeAmount: 1n,
+    solFeeAmount: 1_000_000n,
+  });
+});
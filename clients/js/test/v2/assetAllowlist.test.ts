@@ -0,0 +1,106 @@
+import test from 'ava';
+import { fetchAsset } from '@metaplex-foundation/mpl-core';
+import { publicKey, Umi } from '@metaplex-foundation/umi';
+import {
+  buildRoles,
+  captureV2,
+  fetchRecipeV1,
+  findOperatorsV1Pda,
+  initOperatorsV1,
+  OperatorRole,
+  updateRecipeV1,
+} from '../../src';
+import {
+  createFundedSigner,
+  createRecipe,
+  createUmi,
+  NO_RECIPE_CHANGES,
+} from '../_setup';
+
+// Creates a recipe with an operator holding the given role.
+async function createRecipeWithOperator(umi: Umi, role: OperatorRole) {
+  const created = await createRecipe(umi);
+  const operator = await createFundedSigner(umi);
+  await initOperatorsV1(umi, {
+    recipe: created.recipe,
+    collection: created.collection.publicKey,
+    entries: [{ operator: operator.publicKey, roles: buildRoles([role]) }],
+  }).sendAndConfirm(umi);
+
+  return { ...created, operator };
+}
+
+test('it cannot capture an asset outside the asset allowlist', async (t) => {
+  // Given a recipe whose asset allowlist doesn't include its escrowed assets.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, recipe, tokenMint } =
+    await createRecipe(umi);
+  await updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    assetRoot: Array(32).fill(1),
+  }).sendAndConfirm(umi);
+
+  // When we try to capture one of them.
+  const result = captureV2(umi, {
+    owner: umi.identity,
+    authority: recipe,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  // Then the capture is rejected and the asset stays in the escrow.
+  await t.throwsAsync(result, { name: 'AssetNotAllowlisted' });
+  const asset = await fetchAsset(umi, assets[0].publicKey);
+  t.is(asset.owner, publicKey(escrow));
+});
+
+test('the operator managing access can set the asset allowlist', async (t) => {
+  // Given a recipe with an operator managing its access controls.
+  const umi = await createUmi();
+  const { collection, feeLocation, operator, recipe } =
+    await createRecipeWithOperator(umi, OperatorRole.Pause);
+
+  // When the operator sets the asset allowlist.
+  await updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    authority: operator,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    operators: findOperatorsV1Pda(umi, { recipe: publicKey(recipe) }),
+    assetRoot: Array(32).fill(1),
+  }).sendAndConfirm(umi);
+
+  // Then the allowlist is applied.
+  t.like(await fetchRecipeV1(umi, recipe), { assetRoot: Array(32).fill(1) });
+});
+
+test('a metadata operator cannot set the asset allowlist', async (t) => {
+  // Given a recipe with an operator managing its metadata.
+  const umi = await createUmi();
+  const { collection, feeLocation, operator, recipe } =
+    await createRecipeWithOperator(umi, OperatorRole.UpdateMetadata);
+
+  // When the operator tries to set the asset allowlist.
+  const result = updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    authority: operator,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    operators: findOperatorsV1Pda(umi, { recipe: publicKey(recipe) }),
+    assetRoot: Array(32).fill(1),
+  }).sendAndConfirm(umi);
+
+  // Then the update is rejected.
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+});
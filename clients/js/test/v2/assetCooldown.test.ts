@@ -0,0 +1,103 @@
+import test from 'ava';
+import { PublicKey, Umi } from '@metaplex-foundation/umi';
+import {
+  publicKey as publicKeySerializer,
+  string,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  captureV2,
+  fetchAssetCooldownV1,
+  releaseV2,
+  updateRecipeV1,
+} from '../../src';
+import { createRecipe, createUmi, NO_RECIPE_CHANGES } from '../_setup';
+
+const findAssetCooldownPda = (umi: Umi, asset: PublicKey) =>
+  umi.eddsa.findPda(umi.programs.getPublicKey('mplHybrid'), [
+    string({ size: 'variable' }).serialize('nft'),
+    publicKeySerializer().serialize(asset),
+    string({ size: 'variable' }).serialize('cooldown'),
+  ]);
+
+test('it keeps a captured asset out of escrow for the cooldown', async (t) => {
+  // Given a recipe whose captured assets stay out for a long time.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, recipe, tokenMint } =
+    await createRecipe(umi);
+  await updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    assetCooldown: { minSlotsOut: 1_000_000, minSlotsIn: 0 },
+  }).sendAndConfirm(umi);
+  const assetCooldown = findAssetCooldownPda(umi, assets[0].publicKey);
+
+  // When a user captures an asset.
+  await captureV2(umi, {
+    owner: umi.identity,
+    authority: recipe,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    assetCooldown,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  // Then the capture slot of the asset is recorded.
+  const cooldown = await fetchAssetCooldownV1(umi, assetCooldown);
+  t.is(cooldown.asset, assets[0].publicKey);
+  t.true(cooldown.lastCaptureSlot > 0n);
+  t.is(cooldown.lastReleaseSlot, 0n);
+
+  // And the asset can't be released back right away.
+  const result = releaseV2(umi, {
+    owner: umi.identity,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    assetCooldown,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+  await t.throwsAsync(result, { name: 'AssetCooldownActive' });
+});
+
+test('it rejects a cooldown account of another asset', async (t) => {
+  // Given a recipe with an asset cooldown.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, recipe, tokenMint } =
+    await createRecipe(umi);
+  await updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    assetCooldown: { minSlotsOut: 10, minSlotsIn: 10 },
+  }).sendAndConfirm(umi);
+
+  // When a user captures an asset with the cooldown account of another one.
+  const result = captureV2(umi, {
+    owner: umi.identity,
+    authority: recipe,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    assetCooldown: findAssetCooldownPda(umi, assets[1].publicKey),
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  // Then the capture fails.
+  await t.throwsAsync(result, { name: 'InvalidAssetCooldown' });
+});
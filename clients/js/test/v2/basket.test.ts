@@ -0,0 +1,138 @@
+import test from 'ava';
+import { fetchDigitalAssetWithAssociatedToken } from '@metaplex-foundation/mpl-token-metadata';
+import { generateSigner, publicKey } from '@metaplex-foundation/umi';
+import {
+  captureV2,
+  Currency,
+  fetchBasketV1,
+  findBasketV1Pda,
+  initBasketV1,
+  releaseV2,
+  updateBasketV1,
+} from '../../src';
+import {
+  addRemainingAccounts,
+  basketAccounts,
+  createFungibleToken,
+  createRecipe,
+  createUmi,
+} from '../_setup';
+
+test('it cannot set up a basket with the same token twice', async (t) => {
+  // Given a basket recipe.
+  const umi = await createUmi();
+  const { collection, recipe } = await createRecipe(umi, {
+    token: undefined,
+    currency: Currency.Basket,
+  });
+  const tokenMint = await createFungibleToken(umi);
+  const entry = { mint: tokenMint.publicKey, amount: 5, burn: false };
+
+  // When we list the same token twice.
+  const result = initBasketV1(umi, {
+    recipe,
+    collection: collection.publicKey,
+    entries: [entry, entry],
+  }).sendAndConfirm(umi);
+
+  // Then the basket is rejected.
+  await t.throwsAsync(result, { name: 'InvalidBasket' });
+});
+
+test('it cannot update a basket to be empty', async (t) => {
+  // Given a basket recipe with one token.
+  const umi = await createUmi();
+  const { collection, recipe } = await createRecipe(umi, {
+    token: undefined,
+    currency: Currency.Basket,
+  });
+  const tokenMint = await createFungibleToken(umi);
+  await initBasketV1(umi, {
+    recipe,
+    collection: collection.publicKey,
+    entries: [{ mint: tokenMint.publicKey, amount: 5, burn: false }],
+  }).sendAndConfirm(umi);
+
+  // When we remove every token from it.
+  const result = updateBasketV1(umi, {
+    recipe,
+    collection: collection.publicKey,
+    entries: [],
+  }).sendAndConfirm(umi);
+
+  // Then the update is rejected.
+  await t.throwsAsync(result, { name: 'InvalidBasket' });
+  const basket = findBasketV1Pda(umi, { recipe: publicKey(recipe) });
+  t.like(await fetchBasketV1(umi, basket), {
+    entries: [{ mint: tokenMint.publicKey, amount: 5n, burn: false }],
+  });
+});
+
+test('it releases a basket with extra remaining accounts', async (t) => {
+  // Given a basket recipe of two tokens.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, recipe } =
+    await createRecipe(umi, { token: undefined, currency: Currency.Basket });
+  const mints = [
+    (await createFungibleToken(umi)).publicKey,
+    (await createFungibleToken(umi)).publicKey,
+  ];
+  const basket = findBasketV1Pda(umi, { recipe: publicKey(recipe) });
+  await initBasketV1(umi, {
+    recipe,
+    collection: collection.publicKey,
+    entries: mints.map((mint) => ({ mint, amount: 5, burn: false })),
+  }).sendAndConfirm(umi);
+
+  // And an asset captured with the basket.
+  await addRemainingAccounts(
+    captureV2(umi, {
+      owner: umi.identity,
+      authority: recipe,
+      recipe,
+      escrow,
+      asset: assets[0].publicKey,
+      collection: collection.publicKey,
+      feeProjectAccount: feeLocation.publicKey,
+      basket,
+      assetProof: [],
+      walletProof: [],
+    }),
+    basketAccounts(umi, escrow, mints)
+  ).sendAndConfirm(umi);
+
+  // When the user releases it with an extra account after the basket ones.
+  await addRemainingAccounts(
+    releaseV2(umi, {
+      owner: umi.identity,
+      recipe,
+      escrow,
+      asset: assets[0].publicKey,
+      collection: collection.publicKey,
+      feeProjectAccount: feeLocation.publicKey,
+      basket,
+      assetProof: [],
+      walletProof: [],
+    }),
+    [
+      ...basketAccounts(umi, escrow, mints),
+      {
+        pubkey: generateSigner(umi).publicKey,
+        isSigner: false,
+        isWritable: false,
+      },
+    ]
+  ).sendAndConfirm(umi);
+
+  // Then every basket token is paid back out of the escrow.
+  // eslint-disable-next-line no-restricted-syntax
+  for (const mint of mints) {
+    // eslint-disable-next-line no-await-in-loop
+    const escrowToken = await fetchDigitalAssetWithAssociatedToken(
+      umi,
+      mint,
+      publicKey(escrow)
+    );
+    t.deepEqual(escrowToken.token.amount, 0n);
+  }
+});
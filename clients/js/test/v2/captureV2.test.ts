@@ -18,6 +18,7 @@ import {
 import {
   buildPath,
   captureV2,
+  Currency,
   EscrowV2,
   fetchEscrowV2,
   fetchRecipeV1,
@@ -25,6 +26,7 @@ import {
   initRecipeV1,
   MPL_HYBRID_PROGRAM_ID,
   Path,
+  RecipeKind,
 } from '../../src';
 import { createCoreCollection, createUmi } from '../_setup';
 
@@ -88,12 +90,16 @@ test('it can swap tokens for an asset with reroll', async (t) => {
     uri: 'www.test.com/',
     max: 9,
     min: 0,
-    amount: 5,
+    captureAmount: 5,
+    releaseAmount: 5,
     feeAmountCapture: 1,
     feeAmountRelease: 1,
     solFeeAmountCapture: 890_880n,
     solFeeAmountRelease: 100_000n,
     path: buildPath([Path.RerollMetadata]),
+    currency: Currency.Token,
+    kind: RecipeKind.Collection,
+    quoteSigner: null,
   }).sendAndConfirm(umi);
 
   const recipeData = await fetchRecipeV1(umi, recipe);
@@ -107,7 +113,8 @@ test('it can swap tokens for an asset with reroll', async (t) => {
     uri: 'www.test.com/',
     max: 9n,
     min: 0n,
-    amount: 5n,
+    captureAmount: 5n,
+    releaseAmount: 5n,
     feeAmountCapture: 1n,
     feeAmountRelease: 1n,
     solFeeAmountCapture: 890_880n,
@@ -146,6 +153,8 @@ test('it can swap tokens for an asset with reroll', async (t) => {
     collection: collection.publicKey,
     feeProjectAccount: feeLocation.publicKey,
     token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
   }).sendAndConfirm(umi);
 
   const escrowTokenAfter = await fetchDigitalAssetWithAssociatedToken(
@@ -234,12 +243,16 @@ test('it can swap tokens for an asset without reroll', async (t) => {
     uri: 'www.test.com/',
     max: 9,
     min: 0,
-    amount: 5,
+    captureAmount: 5,
+    releaseAmount: 5,
     feeAmountCapture: 1,
     feeAmountRelease: 1,
     solFeeAmountCapture: 890_880n,
     solFeeAmountRelease: 100_000n,
     path: buildPath([Path.NoRerollMetadata]),
+    currency: Currency.Token,
+    kind: RecipeKind.Collection,
+    quoteSigner: null,
   }).sendAndConfirm(umi);
 
   const recipeData = await fetchRecipeV1(umi, recipe);
@@ -253,7 +266,8 @@ test('it can swap tokens for an asset without reroll', async (t) => {
     uri: 'www.test.com/',
     max: 9n,
     min: 0n,
-    amount: 5n,
+    captureAmount: 5n,
+    releaseAmount: 5n,
     feeAmountCapture: 1n,
     feeAmountRelease: 1n,
     solFeeAmountCapture: 890_880n,
@@ -292,6 +306,8 @@ test('it can swap tokens for an asset without reroll', async (t) => {
     collection: collection.publicKey,
     feeProjectAccount: feeLocation.publicKey,
     token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
   }).sendAndConfirm(umi);
 
   const escrowTokenAfter = await fetchDigitalAssetWithAssociatedToken(
@@ -379,12 +395,16 @@ test('it can swap tokens for an asset as UpdateDelegate with reroll', async (t)
     uri: 'www.test.com/',
     max: 9,
     min: 0,
-    amount: 5,
+    captureAmount: 5,
+    releaseAmount: 5,
     feeAmountCapture: 1,
     feeAmountRelease: 1,
     solFeeAmountCapture: 890_880n,
     solFeeAmountRelease: 100_000n,
     path: buildPath([Path.RerollMetadata]),
+    currency: Currency.Token,
+    kind: RecipeKind.Collection,
+    quoteSigner: null,
   }).sendAndConfirm(umi);
 
   await addCollectionPlugin(umi, {
@@ -407,7 +427,8 @@ test('it can swap tokens for an asset as UpdateDelegate with reroll', async (t)
     uri: 'www.test.com/',
     max: 9n,
     min: 0n,
-    amount: 5n,
+    captureAmount: 5n,
+    releaseAmount: 5n,
     feeAmountCapture: 1n,
     feeAmountRelease: 1n,
     solFeeAmountCapture: 890_880n,
@@ -426,6 +447,8 @@ test('it can swap tokens for an asset as UpdateDelegate with reroll', async (t)
     collection: collection.publicKey,
     feeProjectAccount: feeLocation.publicKey,
     token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
   }).sendAndConfirm(umi);
 
   const escrowTokenAfter = await fetchDigitalAssetWithAssociatedToken(
@@ -514,12 +537,16 @@ test('it can swap tokens for an asset as UpdateDelegate without reroll', async (
     uri: 'www.test.com/',
     max: 9,
     min: 0,
-    amount: 5,
+    captureAmount: 5,
+    releaseAmount: 5,
     feeAmountCapture: 1,
     feeAmountRelease: 1,
     solFeeAmountCapture: 890_880n,
     solFeeAmountRelease: 100_000n,
     path: buildPath([Path.NoRerollMetadata]),
+    currency: Currency.Token,
+    kind: RecipeKind.Collection,
+    quoteSigner: null,
   }).sendAndConfirm(umi);
 
   await addCollectionPlugin(umi, {
@@ -542,7 +569,8 @@ test('it can swap tokens for an asset as UpdateDelegate without reroll', async (
     uri: 'www.test.com/',
     max: 9n,
     min: 0n,
-    amount: 5n,
+    captureAmount: 5n,
+    releaseAmount: 5n,
     feeAmountCapture: 1n,
     feeAmountRelease: 1n,
     solFeeAmountCapture: 890_880n,
@@ -561,6 +589,8 @@ test('it can swap tokens for an asset as UpdateDelegate without reroll', async (
     collection: collection.publicKey,
     feeProjectAccount: feeLocation.publicKey,
     token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
   }).sendAndConfirm(umi);
 
   const escrowTokenAfter = await fetchDigitalAssetWithAssociatedToken(
@@ -648,12 +678,16 @@ test('it cannot swap tokens for an asset with BlockCapture', async (t) => {
     uri: 'www.test.com/',
     max: 9,
     min: 0,
-    amount: 5,
+    captureAmount: 5,
+    releaseAmount: 5,
     feeAmountCapture: 1,
     feeAmountRelease: 1,
     solFeeAmountCapture: 890_880n,
     solFeeAmountRelease: 100_000n,
     path: buildPath([Path.NoRerollMetadata, Path.BlockCapture]),
+    currency: Currency.Token,
+    kind: RecipeKind.Collection,
+    quoteSigner: null,
   }).sendAndConfirm(umi);
 
   await addCollectionPlugin(umi, {
@@ -676,7 +710,8 @@ test('it cannot swap tokens for an asset with BlockCapture', async (t) => {
     uri: 'www.test.com/',
     max: 9n,
     min: 0n,
-    amount: 5n,
+    captureAmount: 5n,
+    releaseAmount: 5n,
     feeAmountCapture: 1n,
     feeAmountRelease: 1n,
     solFeeAmountCapture: 890_880n,
@@ -695,6 +730,8 @@ test('it cannot swap tokens for an asset with BlockCapture', async (t) => {
     collection: collection.publicKey,
     feeProjectAccount: feeLocation.publicKey,
     token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(result, { name: 'CaptureBlocked' });
@@ -760,12 +797,16 @@ test('it can burn tokens for an asset with BurnOnCapture', async (t) => {
     uri: 'www.test.com/',
     max: 9,
     min: 0,
-    amount: 5,
+    captureAmount: 5,
+    releaseAmount: 5,
     feeAmountCapture: 1,
     feeAmountRelease: 1,
     solFeeAmountCapture: 890_880n,
     solFeeAmountRelease: 100_000n,
     path: buildPath([Path.NoRerollMetadata, Path.BurnOnCapture]),
+    currency: Currency.Token,
+    kind: RecipeKind.Collection,
+    quoteSigner: null,
   }).sendAndConfirm(umi);
 
   await addCollectionPlugin(umi, {
@@ -788,7 +829,8 @@ test('it can burn tokens for an asset with BurnOnCapture', async (t) => {
     uri: 'www.test.com/',
     max: 9n,
     min: 0n,
-    amount: 5n,
+    captureAmount: 5n,
+    releaseAmount: 5n,
     feeAmountCapture: 1n,
     feeAmountRelease: 1n,
     solFeeAmountCapture: 890_880n,
@@ -807,6 +849,8 @@ test('it can burn tokens for an asset with BurnOnCapture', async (t) => {
     collection: collection.publicKey,
     feeProjectAccount: feeLocation.publicKey,
     token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
   }).sendAndConfirm(umi, { send: { skipPreflight: true } });
 
   const escrowTokenAfter = await fetchDigitalAssetWithAssociatedToken(
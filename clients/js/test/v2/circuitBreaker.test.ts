@@ -0,0 +1,164 @@
+import test from 'ava';
+import { fetchAsset } from '@metaplex-foundation/mpl-core';
+import { Umi } from '@metaplex-foundation/umi';
+import {
+  captureV2,
+  releaseV2,
+  resetCircuitBreakerV1,
+  updateRecipeV1,
+} from '../../src';
+import {
+  createFundedSigner,
+  createRecipe,
+  createUmi,
+  NO_RECIPE_CHANGES,
+} from '../_setup';
+
+// Creates a recipe allowing a single release per window whose first two
+// assets were captured by the identity.
+async function createBreakerRecipe(umi: Umi) {
+  const created = await createRecipe(umi);
+  const { assets, collection, escrow, feeLocation, recipe, tokenMint } =
+    created;
+  await updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    circuitBreaker: {
+      windowSlots: 1_000_000,
+      maxNetOutflow: 0,
+      maxReleases: 1,
+    },
+  }).sendAndConfirm(umi);
+  // eslint-disable-next-line no-restricted-syntax
+  for (const asset of assets.slice(0, 2)) {
+    // eslint-disable-next-line no-await-in-loop
+    await captureV2(umi, {
+      owner: umi.identity,
+      authority: recipe,
+      recipe,
+      escrow,
+      asset: asset.publicKey,
+      collection: collection.publicKey,
+      feeProjectAccount: feeLocation.publicKey,
+      token: tokenMint.publicKey,
+      assetProof: [],
+      walletProof: [],
+    }).sendAndConfirm(umi);
+  }
+  const release = (index: number) =>
+    releaseV2(umi, {
+      owner: umi.identity,
+      recipe,
+      escrow,
+      asset: assets[index].publicKey,
+      collection: collection.publicKey,
+      feeProjectAccount: feeLocation.publicKey,
+      token: tokenMint.publicKey,
+      assetProof: [],
+      walletProof: [],
+    }).sendAndConfirm(umi);
+
+  return { ...created, release };
+}
+
+test('it trips the circuit breaker past the release cap', async (t) => {
+  // Given a recipe allowing a single release per window.
+  const umi = await createUmi();
+  const { release } = await createBreakerRecipe(umi);
+
+  // When the user releases both assets.
+  await release(0);
+  const result = release(1);
+
+  // Then the second release trips the breaker.
+  await t.throwsAsync(result, { name: 'CircuitBreakerTripped' });
+});
+
+test('releases resume after the circuit breaker is reset', async (t) => {
+  // Given a recipe whose circuit breaker tripped.
+  const umi = await createUmi();
+  const { assets, collection, recipe, release } =
+    await createBreakerRecipe(umi);
+  await release(0);
+  await t.throwsAsync(release(1), { name: 'CircuitBreakerTripped' });
+
+  // When the recipe authority resets it.
+  await resetCircuitBreakerV1(umi, {
+    recipe,
+    collection: collection.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the next release goes through.
+  await release(1);
+  const asset = await fetchAsset(umi, assets[1].publicKey);
+  t.is(asset.owner, umi.identity.publicKey);
+});
+
+test('a stranger cannot reset the circuit breaker', async (t) => {
+  // Given a recipe whose circuit breaker tripped.
+  const umi = await createUmi();
+  const { collection, recipe, release } = await createBreakerRecipe(umi);
+  await release(0);
+  await t.throwsAsync(release(1), { name: 'CircuitBreakerTripped' });
+
+  // When another key tries to reset it.
+  const stranger = await createFundedSigner(umi);
+  const result = resetCircuitBreakerV1(umi, {
+    recipe,
+    authority: stranger,
+    collection: collection.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the reset is rejected and releases stay blocked.
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+  await t.throwsAsync(release(1), { name: 'CircuitBreakerTripped' });
+});
+
+test('it accepts an outflow cap above the signed range', async (t) => {
+  // Given a recipe whose outflow cap doesn't fit in an i64.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, recipe, tokenMint } =
+    await createRecipe(umi);
+  await updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    circuitBreaker: {
+      windowSlots: 1_000_000,
+      maxNetOutflow: 18_446_744_073_709_551_615n,
+      maxReleases: 0,
+    },
+  }).sendAndConfirm(umi);
+  await captureV2(umi, {
+    owner: umi.identity,
+    authority: recipe,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  // When the user releases the asset.
+  await releaseV2(umi, {
+    owner: umi.identity,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  // Then the release goes through and the asset is back with the user.
+  const asset = await fetchAsset(umi, assets[0].publicKey);
+  t.is(asset.owner, umi.identity.publicKey);
+});
@@ -0,0 +1,193 @@
+import test from 'ava';
+import { fetchDigitalAssetWithAssociatedToken } from '@metaplex-foundation/mpl-token-metadata';
+import { lamports, publicKey } from '@metaplex-foundation/umi';
+import {
+  captureV2,
+  DiscountKind,
+  fetchDiscountPolicyV1,
+  findDiscountPolicyV1Pda,
+  initDiscountPolicyV1,
+  updateDiscountPolicyV1,
+} from '../../src';
+import { createCoreCollection, createRecipe, createUmi } from '../_setup';
+
+test('it can set up a discount policy for a recipe', async (t) => {
+  // Given a recipe.
+  const umi = await createUmi();
+  const { collection, recipe } = await createRecipe(umi);
+  const holders = await createCoreCollection(umi);
+
+  // When we give holders of another collection a discount.
+  await initDiscountPolicyV1(umi, {
+    recipe,
+    collection: collection.publicKey,
+    entries: [
+      {
+        kind: DiscountKind.Collection,
+        key: holders.collection.publicKey,
+        minAmount: 0,
+        discountBps: 2500,
+      },
+    ],
+  }).sendAndConfirm(umi);
+
+  // Then the policy is stored for the recipe.
+  const discountPolicy = findDiscountPolicyV1Pda(umi, {
+    recipe: publicKey(recipe),
+  });
+  t.like(await fetchDiscountPolicyV1(umi, discountPolicy), {
+    recipe: publicKey(recipe),
+    entries: [
+      {
+        kind: DiscountKind.Collection,
+        key: holders.collection.publicKey,
+        minAmount: 0n,
+        discountBps: 2500,
+      },
+    ],
+    bump: discountPolicy[1],
+  });
+});
+
+test('it cannot set a discount above 100%', async (t) => {
+  // Given a recipe.
+  const umi = await createUmi();
+  const { collection, recipe } = await createRecipe(umi);
+  const holders = await createCoreCollection(umi);
+
+  // When we try to waive more than the whole fee.
+  const result = initDiscountPolicyV1(umi, {
+    recipe,
+    collection: collection.publicKey,
+    entries: [
+      {
+        kind: DiscountKind.Collection,
+        key: holders.collection.publicKey,
+        minAmount: 0,
+        discountBps: 10_001,
+      },
+    ],
+  }).sendAndConfirm(umi);
+
+  // Then the policy is rejected.
+  await t.throwsAsync(result, { name: 'InvalidDiscount' });
+});
+
+test('it cannot update a discount above 100%', async (t) => {
+  // Given a recipe with a discount policy.
+  const umi = await createUmi();
+  const { collection, recipe } = await createRecipe(umi);
+  const holders = await createCoreCollection(umi);
+  const entry = {
+    kind: DiscountKind.Collection,
+    key: holders.collection.publicKey,
+    minAmount: 0,
+    discountBps: 2500,
+  };
+  await initDiscountPolicyV1(umi, {
+    recipe,
+    collection: collection.publicKey,
+    entries: [entry],
+  }).sendAndConfirm(umi);
+
+  // When we try to raise the discount past the whole fee.
+  const result = updateDiscountPolicyV1(umi, {
+    recipe,
+    collection: collection.publicKey,
+    entries: [{ ...entry, discountBps: 10_001 }],
+  }).sendAndConfirm(umi);
+
+  // Then the update is rejected.
+  await t.throwsAsync(result, { name: 'InvalidDiscount' });
+});
+
+test('it discounts the project fees of holders', async (t) => {
+  // Given a recipe whose holders of another collection get half off the fees.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, recipe, tokenMint } =
+    await createRecipe(umi, {
+      feeAmountCapture: 2,
+      solFeeAmountCapture: 1_781_760n,
+    });
+  const holders = await createCoreCollection(umi);
+  await initDiscountPolicyV1(umi, {
+    recipe,
+    collection: collection.publicKey,
+    entries: [
+      {
+        kind: DiscountKind.Collection,
+        key: holders.collection.publicKey,
+        minAmount: 0,
+        discountBps: 5000,
+      },
+    ],
+  }).sendAndConfirm(umi);
+
+  // When a holder captures an asset with their holding as proof.
+  await captureV2(umi, {
+    owner: umi.identity,
+    authority: recipe,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    discountPolicy: findDiscountPolicyV1Pda(umi, { recipe: publicKey(recipe) }),
+    discountProof: holders.assets[0].publicKey,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  // Then both project fees are halved.
+  const feeTokenAfter = await fetchDigitalAssetWithAssociatedToken(
+    umi,
+    tokenMint.publicKey,
+    feeLocation.publicKey
+  );
+  t.deepEqual(feeTokenAfter.token.amount, 1n);
+  t.deepEqual(
+    await umi.rpc.getBalance(feeLocation.publicKey),
+    lamports(890_880n)
+  );
+});
+
+test('it rejects a discount proof that does not qualify', async (t) => {
+  // Given a recipe with a discount policy for holders of another collection.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, recipe, tokenMint } =
+    await createRecipe(umi);
+  const holders = await createCoreCollection(umi);
+  await initDiscountPolicyV1(umi, {
+    recipe,
+    collection: collection.publicKey,
+    entries: [
+      {
+        kind: DiscountKind.Collection,
+        key: holders.collection.publicKey,
+        minAmount: 0,
+        discountBps: 5000,
+      },
+    ],
+  }).sendAndConfirm(umi);
+  const others = await createCoreCollection(umi);
+
+  // When the user proves a holding of an unrelated collection.
+  const result = captureV2(umi, {
+    owner: umi.identity,
+    authority: recipe,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    discountPolicy: findDiscountPolicyV1Pda(umi, { recipe: publicKey(recipe) }),
+    discountProof: others.assets[0].publicKey,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  // Then the capture fails.
+  await t.throwsAsync(result, { name: 'InvalidDiscountProof' });
+});
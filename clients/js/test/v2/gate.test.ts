@@ -0,0 +1,107 @@
+import test from 'ava';
+import { fetchAsset } from '@metaplex-foundation/mpl-core';
+import { AccountMeta, PublicKey, Umi } from '@metaplex-foundation/umi';
+import { captureV2, updateRecipeV1 } from '../../src';
+import {
+  addRemainingAccounts,
+  createCoreCollection,
+  createRecipe,
+  createUmi,
+  NO_RECIPE_CHANGES,
+} from '../_setup';
+
+// Creates a recipe gated on holding assets of another collection, whose
+// assets are held by the identity.
+async function createGatedRecipe(umi: Umi, minCount: number) {
+  const created = await createRecipe(umi);
+  const gate = await createCoreCollection(umi);
+  await updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe: created.recipe,
+    collection: created.collection.publicKey,
+    feeLocation: created.feeLocation.publicKey,
+    gate: { collection: gate.collection.publicKey, minCount },
+  }).sendAndConfirm(umi);
+
+  return { ...created, gateAssets: gate.assets.map((a) => a.publicKey) };
+}
+
+// Captures the first escrowed asset, passing the gate assets after the
+// instruction accounts.
+function captureWithGate(
+  umi: Umi,
+  created: Awaited<ReturnType<typeof createGatedRecipe>>,
+  gateAssets: PublicKey[]
+) {
+  const accounts: AccountMeta[] = gateAssets.map((pubkey) => ({
+    pubkey,
+    isSigner: false,
+    isWritable: false,
+  }));
+
+  return addRemainingAccounts(
+    captureV2(umi, {
+      owner: umi.identity,
+      authority: created.recipe,
+      recipe: created.recipe,
+      escrow: created.escrow,
+      asset: created.assets[0].publicKey,
+      collection: created.collection.publicKey,
+      feeProjectAccount: created.feeLocation.publicKey,
+      token: created.tokenMint.publicKey,
+      assetProof: [],
+      walletProof: [],
+    }),
+    accounts
+  ).sendAndConfirm(umi);
+}
+
+test('a holder of the gate collection can capture', async (t) => {
+  // Given a recipe gated on holding two assets of another collection.
+  const umi = await createUmi();
+  const created = await createGatedRecipe(umi, 2);
+
+  // When a holder captures with two of them.
+  await captureWithGate(umi, created, created.gateAssets.slice(0, 2));
+
+  // Then the asset is captured.
+  const asset = await fetchAsset(umi, created.assets[0].publicKey);
+  t.is(asset.owner, umi.identity.publicKey);
+});
+
+test('it cannot capture without enough gate assets', async (t) => {
+  // Given a recipe gated on holding two assets of another collection.
+  const umi = await createUmi();
+  const created = await createGatedRecipe(umi, 2);
+
+  // When we try to capture with only one of them.
+  const result = captureWithGate(umi, created, created.gateAssets.slice(0, 1));
+
+  // Then the capture is rejected.
+  await t.throwsAsync(result, { name: 'GateNotMet' });
+});
+
+test('it cannot capture with assets of another collection', async (t) => {
+  // Given a gated recipe.
+  const umi = await createUmi();
+  const created = await createGatedRecipe(umi, 1);
+
+  // When we try to capture with an asset of the recipe's own collection.
+  const result = captureWithGate(umi, created, [created.assets[1].publicKey]);
+
+  // Then the gate asset is rejected.
+  await t.throwsAsync(result, { name: 'InvalidGateAsset' });
+});
+
+test('it does not count the same gate asset twice', async (t) => {
+  // Given a recipe gated on holding two assets of another collection.
+  const umi = await createUmi();
+  const created = await createGatedRecipe(umi, 2);
+
+  // When we try to capture passing one of them twice.
+  const [gateAsset] = created.gateAssets;
+  const result = captureWithGate(umi, created, [gateAsset, gateAsset]);
+
+  // Then the gate asset is rejected.
+  await t.throwsAsync(result, { name: 'InvalidGateAsset' });
+});
@@ -7,10 +7,12 @@ import {
 import { createFungible } from '@metaplex-foundation/mpl-token-metadata';
 import { createCoreCollection, createUmi } from '../_setup';
 import {
+  Currency,
   fetchRecipeV1,
   initRecipeV1,
   MPL_HYBRID_PROGRAM_ID,
   Path,
+  RecipeKind,
 } from '../../src';
 
 test('it can initialize the recipe', async (t) => {
@@ -43,12 +45,16 @@ test('it can initialize the recipe', async (t) => {
     uri: 'www.test.com/',
     max: 2,
     min: 1,
-    amount: 3,
+    captureAmount: 3,
+    releaseAmount: 3,
     feeAmountCapture: 4,
     feeAmountRelease: 6,
     solFeeAmountCapture: 7,
     solFeeAmountRelease: 8,
     path: Path.RerollMetadata,
+    currency: Currency.Token,
+    kind: RecipeKind.Collection,
+    quoteSigner: null,
   }).sendAndConfirm(umi);
 
   t.like(await fetchRecipeV1(umi, recipe), {
@@ -61,7 +67,8 @@ test('it can initialize the recipe', async (t) => {
     uri: 'www.test.com/',
     max: 2n,
     min: 1n,
-    amount: 3n,
+    captureAmount: 3n,
+    releaseAmount: 3n,
     feeAmountCapture: 4n,
     feeAmountRelease: 6n,
     solFeeAmountCapture: 7n,
@@ -97,12 +104,16 @@ test('it cannot use an invalid collection', async (t) => {
     uri: 'www.test.com/',
     max: 2,
     min: 1,
-    amount: 3,
+    captureAmount: 3,
+    releaseAmount: 3,
     feeAmountCapture: 4,
     feeAmountRelease: 6,
     solFeeAmountCapture: 7,
     solFeeAmountRelease: 8,
     path: Path.RerollMetadata,
+    currency: Currency.Token,
+    kind: RecipeKind.Collection,
+    quoteSigner: null,
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(result, { name: 'InvalidCollectionAccount' });
@@ -123,12 +134,16 @@ test('it cannot use an invalid token mint', async (t) => {
     uri: 'www.test.com/',
     max: 2,
     min: 1,
-    amount: 3,
+    captureAmount: 3,
+    releaseAmount: 3,
     feeAmountCapture: 4,
     feeAmountRelease: 6,
     solFeeAmountCapture: 7,
     solFeeAmountRelease: 8,
     path: Path.RerollMetadata,
+    currency: Currency.Token,
+    kind: RecipeKind.Collection,
+    quoteSigner: null,
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(result, {
@@ -162,12 +177,16 @@ test('it cannot set min higher than max', async (t) => {
     uri: 'www.test.com/',
     max: 1,
     min: 2,
-    amount: 3,
+    captureAmount: 3,
+    releaseAmount: 3,
     feeAmountCapture: 4,
     feeAmountRelease: 6,
     solFeeAmountCapture: 7,
     solFeeAmountRelease: 8,
     path: Path.RerollMetadata,
+    currency: Currency.Token,
+    kind: RecipeKind.Collection,
+    quoteSigner: null,
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(result, { name: 'MaxMustBeGreaterThanMin' });
@@ -0,0 +1,207 @@
+import test from 'ava';
+import { PublicKey, Umi } from '@metaplex-foundation/umi';
+import {
+  buildFields,
+  Currency,
+  DiscountKind,
+  initBasketV1,
+  initDiscountPolicyV1,
+  initPaymentTableV1,
+  lockRecipeV1,
+  RecipeField,
+  updateBasketV1,
+  updateDiscountPolicyV1,
+  updatePaymentTableV1,
+} from '../../src';
+import {
+  createCoreCollection,
+  createFungibleToken,
+  createRecipe,
+  createUmi,
+} from '../_setup';
+
+const payment = (mint: PublicKey, captureAmount = 7, feeAmountCapture = 0) => ({
+  mint,
+  captureAmount,
+  releaseAmount: 6,
+  feeAmountCapture,
+  feeAmountRelease: 0,
+});
+
+async function lockFields(
+  umi: Umi,
+  recipe: Awaited<ReturnType<typeof createRecipe>>,
+  fields: RecipeField[]
+) {
+  await lockRecipeV1(umi, {
+    recipe: recipe.recipe,
+    collection: recipe.collection.publicKey,
+    fields: buildFields(fields),
+    feeCaps: null,
+  }).sendAndConfirm(umi);
+}
+
+test('it cannot update the basket once pricing is locked', async (t) => {
+  // Given a basket recipe whose pricing is locked.
+  const umi = await createUmi();
+  const created = await createRecipe(umi, {
+    token: undefined,
+    currency: Currency.Basket,
+  });
+  const tokenMint = await createFungibleToken(umi);
+  await initBasketV1(umi, {
+    recipe: created.recipe,
+    collection: created.collection.publicKey,
+    entries: [{ mint: tokenMint.publicKey, amount: 5, burn: false }],
+  }).sendAndConfirm(umi);
+  await lockFields(umi, created, [RecipeField.Pricing]);
+
+  // When we try to change the basket amounts.
+  const result = updateBasketV1(umi, {
+    recipe: created.recipe,
+    collection: created.collection.publicKey,
+    entries: [{ mint: tokenMint.publicKey, amount: 1, burn: false }],
+  }).sendAndConfirm(umi);
+
+  // Then the update is rejected.
+  await t.throwsAsync(result, { name: 'RecipeFieldLocked' });
+});
+
+test('it cannot update the payment table once pricing is locked', async (t) => {
+  // Given a recipe accepting another token whose pricing is locked.
+  const umi = await createUmi();
+  const created = await createRecipe(umi);
+  const otherMint = await createFungibleToken(umi);
+  await initPaymentTableV1(umi, {
+    recipe: created.recipe,
+    collection: created.collection.publicKey,
+    entries: [payment(otherMint.publicKey)],
+  }).sendAndConfirm(umi);
+  await lockFields(umi, created, [RecipeField.Pricing]);
+
+  // When we try to reprice the other token.
+  const result = updatePaymentTableV1(umi, {
+    recipe: created.recipe,
+    collection: created.collection.publicKey,
+    entries: [payment(otherMint.publicKey, 1)],
+  }).sendAndConfirm(umi);
+
+  // Then the update is rejected.
+  await t.throwsAsync(result, { name: 'RecipeFieldLocked' });
+});
+
+test('it cannot add a payment table once the token is locked', async (t) => {
+  // Given a recipe whose token is locked.
+  const umi = await createUmi();
+  const created = await createRecipe(umi);
+  await lockFields(umi, created, [RecipeField.Token]);
+  const otherMint = await createFungibleToken(umi);
+
+  // When we try to accept another token.
+  const result = initPaymentTableV1(umi, {
+    recipe: created.recipe,
+    collection: created.collection.publicKey,
+    entries: [payment(otherMint.publicKey)],
+  }).sendAndConfirm(umi);
+
+  // Then the payment table is rejected.
+  await t.throwsAsync(result, { name: 'RecipeFieldLocked' });
+});
+
+test('it cannot update the discount policy once fees are locked', async (t) => {
+  // Given a recipe with a discount policy whose fees are locked.
+  const umi = await createUmi();
+  const created = await createRecipe(umi);
+  const holders = await createCoreCollection(umi);
+  const entry = {
+    kind: DiscountKind.Collection,
+    key: holders.collection.publicKey,
+    minAmount: 0,
+    discountBps: 2500,
+  };
+  await initDiscountPolicyV1(umi, {
+    recipe: created.recipe,
+    collection: created.collection.publicKey,
+    entries: [entry],
+  }).sendAndConfirm(umi);
+  await lockFields(umi, created, [RecipeField.Fees]);
+
+  // When we try to remove the discount.
+  const result = updateDiscountPolicyV1(umi, {
+    recipe: created.recipe,
+    collection: created.collection.publicKey,
+    entries: [{ ...entry, discountBps: 0 }],
+  }).sendAndConfirm(umi);
+
+  // Then the update is rejected.
+  await t.throwsAsync(result, { name: 'RecipeFieldLocked' });
+});
+
+test('it cannot raise payment table fees once fees are locked', async (t) => {
+  // Given a recipe accepting another token whose fees are locked.
+  const umi = await createUmi();
+  const created = await createRecipe(umi);
+  const otherMint = await createFungibleToken(umi);
+  await initPaymentTableV1(umi, {
+    recipe: created.recipe,
+    collection: created.collection.publicKey,
+    entries: [payment(otherMint.publicKey)],
+  }).sendAndConfirm(umi);
+  await lockFields(umi, created, [RecipeField.Fees]);
+
+  // When we try to charge a fee on the other token.
+  const result = updatePaymentTableV1(umi, {
+    recipe: created.recipe,
+    collection: created.collection.publicKey,
+    entries: [payment(otherMint.publicKey, 7, 1)],
+  }).sendAndConfirm(umi);
+
+  // Then the update is rejected.
+  await t.throwsAsync(result, { name: 'RecipeFieldLocked' });
+});
+
+test('it cannot charge payment table fees above the fee caps', async (t) => {
+  // Given a recipe whose token fees are capped at its current fees.
+  const umi = await createUmi();
+  const created = await createRecipe(umi);
+  await lockRecipeV1(umi, {
+    recipe: created.recipe,
+    collection: created.collection.publicKey,
+    fields: buildFields([]),
+    feeCaps: {
+      feeAmountCapture: 1,
+      feeAmountRelease: 1,
+      solFeeAmountCapture: 890_880n,
+      solFeeAmountRelease: 100_000n,
+    },
+  }).sendAndConfirm(umi);
+  const otherMint = await createFungibleToken(umi);
+
+  // When we accept another token with a higher capture fee.
+  const result = initPaymentTableV1(umi, {
+    recipe: created.recipe,
+    collection: created.collection.publicKey,
+    entries: [payment(otherMint.publicKey, 7, 2)],
+  }).sendAndConfirm(umi);
+
+  // Then the payment table is rejected.
+  await t.throwsAsync(result, { name: 'FeeCapExceeded' });
+});
+
+test('it cannot lock undefined recipe fields', async (t) => {
+  // Given a recipe.
+  const umi = await createUmi();
+  const created = await createRecipe(umi);
+
+  // When we set a lock bit past the defined field groups.
+  const result = lockRecipeV1(umi, {
+    recipe: created.recipe,
+    collection: created.collection.publicKey,
+    // eslint-disable-next-line no-bitwise
+    fields: 1 << 15,
+    feeCaps: null,
+  }).sendAndConfirm(umi);
+
+  // Then the lock is rejected.
+  await t.throwsAsync(result, { name: 'InvalidRecipeFields' });
+});
@@ -0,0 +1,236 @@
+import test from 'ava';
+import {
+  fetchAsset,
+  MPL_CORE_PROGRAM_ID,
+  transfer,
+} from '@metaplex-foundation/mpl-core';
+import {
+  fetchDigitalAssetWithAssociatedToken,
+  mintV1,
+  TokenStandard,
+} from '@metaplex-foundation/mpl-token-metadata';
+import {
+  findAssociatedTokenPda,
+  SPL_ASSOCIATED_TOKEN_PROGRAM_ID,
+} from '@metaplex-foundation/mpl-toolbox';
+import { generateSigner, publicKey, Umi } from '@metaplex-foundation/umi';
+import {
+  buildPath,
+  fetchRecipeV1,
+  findEscrowV1Pda,
+  findEscrowV2Pda,
+  findRecipeV1Pda,
+  initEscrowV1,
+  initEscrowV2,
+  migrateEscrowConfigV1,
+  migrateNftV1,
+  migrateTokensV1,
+  Path,
+} from '../../src';
+import {
+  createCoreCollection,
+  createFundedSigner,
+  createFungibleToken,
+  createUmi,
+  handOverRecipe,
+} from '../_setup';
+
+// Creates a legacy escrow holding an asset and tokens, then migrates its
+// config into a recipe, which closes the legacy escrow.
+async function createMigratedEscrow(umi: Umi) {
+  const { assets, collection } = await createCoreCollection(umi);
+  const tokenMint = await createFungibleToken(umi);
+  const escrowOld = findEscrowV1Pda(umi, {
+    collection: collection.publicKey,
+  });
+  await initEscrowV1(umi, {
+    escrow: escrowOld,
+    collection: collection.publicKey,
+    token: tokenMint.publicKey,
+    feeLocation: generateSigner(umi).publicKey,
+    name: 'Test Escrow',
+    uri: 'www.test.com/',
+    max: 9,
+    min: 0,
+    amount: 5,
+    feeAmount: 1,
+    path: buildPath([Path.NoRerollMetadata]),
+    solFeeAmount: 1000000n,
+  }).sendAndConfirm(umi);
+  await transfer(umi, {
+    asset: assets[0],
+    collection,
+    newOwner: publicKey(escrowOld),
+  }).sendAndConfirm(umi);
+  await mintV1(umi, {
+    mint: tokenMint.publicKey,
+    tokenStandard: TokenStandard.Fungible,
+    tokenOwner: publicKey(escrowOld),
+    amount: 50,
+  }).sendAndConfirm(umi);
+
+  await initEscrowV2(umi, {}).sendAndConfirm(umi);
+  await migrateEscrowConfigV1(umi, {
+    recipe: findRecipeV1Pda(umi, { collection: collection.publicKey }),
+    escrowOld,
+    collection: collection.publicKey,
+  }).sendAndConfirm(umi);
+
+  const escrowNew = findEscrowV2Pda(umi, {
+    authority: umi.identity.publicKey,
+  });
+
+  return { asset: assets[0], collection, escrowNew, escrowOld, tokenMint };
+}
+
+test('it migrates the legacy escrow config into a recipe', async (t) => {
+  // Given a legacy escrow.
+  const umi = await createUmi();
+
+  // When its config is migrated.
+  const { collection, escrowOld, tokenMint } = await createMigratedEscrow(umi);
+
+  // Then the recipe carries its terms in both directions.
+  const recipe = findRecipeV1Pda(umi, { collection: collection.publicKey });
+  t.like(await fetchRecipeV1(umi, recipe), {
+    authority: umi.identity.publicKey,
+    token: tokenMint.publicKey,
+    captureAmount: 5n,
+    releaseAmount: 5n,
+    feeAmountCapture: 1n,
+    feeAmountRelease: 1n,
+    legacyAuthority: umi.identity.publicKey,
+    legacyToken: tokenMint.publicKey,
+  });
+  // And the legacy escrow is closed.
+  t.false(await umi.rpc.accountExists(publicKey(escrowOld)));
+});
+
+test('it migrates assets out of a closed legacy escrow', async (t) => {
+  // Given a legacy escrow whose config was migrated while it held an asset.
+  const umi = await createUmi();
+  const { asset, collection, escrowNew, escrowOld } =
+    await createMigratedEscrow(umi);
+
+  // When the recipe authority migrates the asset.
+  await migrateNftV1(umi, {
+    escrowNew,
+    escrowOld,
+    asset: asset.publicKey,
+    collection: collection.publicKey,
+    mplCore: MPL_CORE_PROGRAM_ID,
+  }).sendAndConfirm(umi);
+
+  // Then it is moved to the new escrow.
+  const migrated = await fetchAsset(umi, asset.publicKey);
+  t.is(migrated.owner, publicKey(escrowNew));
+});
+
+test('it migrates tokens out of a closed legacy escrow', async (t) => {
+  // Given a legacy escrow whose config was migrated while it held tokens.
+  const umi = await createUmi();
+  const { collection, escrowNew, escrowOld, tokenMint } =
+    await createMigratedEscrow(umi);
+
+  // When the recipe authority migrates them.
+  await migrateTokensV1(umi, {
+    escrowNew,
+    escrowOld,
+    collection: collection.publicKey,
+    escrowNewTokenAccount: findAssociatedTokenPda(umi, {
+      mint: tokenMint.publicKey,
+      owner: publicKey(escrowNew),
+    }),
+    escrowOldTokenAccount: findAssociatedTokenPda(umi, {
+      mint: tokenMint.publicKey,
+      owner: publicKey(escrowOld),
+    }),
+    token: tokenMint.publicKey,
+    associatedTokenProgram: SPL_ASSOCIATED_TOKEN_PROGRAM_ID,
+    amount: 50,
+  }).sendAndConfirm(umi);
+
+  // Then they are moved to the new escrow.
+  const escrowToken = await fetchDigitalAssetWithAssociatedToken(
+    umi,
+    tokenMint.publicKey,
+    publicKey(escrowNew)
+  );
+  t.deepEqual(escrowToken.token.amount, 50n);
+});
+
+test('only the recipe authority can migrate the legacy escrow assets', async (t) => {
+  // Given a legacy escrow whose config was migrated while it held an asset.
+  const umi = await createUmi();
+  const { asset, collection, escrowOld } = await createMigratedEscrow(umi);
+
+  // When another key tries to move the asset into its own escrow.
+  const stranger = await createFundedSigner(umi);
+  await initEscrowV2(umi, { authority: stranger }).sendAndConfirm(umi);
+  const result = migrateNftV1(umi, {
+    authority: stranger,
+    escrowNew: findEscrowV2Pda(umi, { authority: stranger.publicKey }),
+    escrowOld,
+    asset: asset.publicKey,
+    collection: collection.publicKey,
+    mplCore: MPL_CORE_PROGRAM_ID,
+  }).sendAndConfirm(umi);
+
+  // Then the migration is rejected.
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+});
+
+test('a recipe authority other than the legacy escrow authority cannot migrate its assets', async (t) => {
+  // Given a migrated legacy escrow whose recipe was then handed over.
+  const umi = await createUmi();
+  const { asset, collection, escrowOld } = await createMigratedEscrow(umi);
+  const recipe = findRecipeV1Pda(umi, { collection: collection.publicKey });
+  const { newAuthority, newEscrow } = await handOverRecipe(umi, recipe);
+
+  // When the new recipe authority tries to move the asset into its escrow.
+  const result = migrateNftV1(umi, {
+    authority: newAuthority,
+    escrowNew: newEscrow,
+    escrowOld,
+    asset: asset.publicKey,
+    collection: collection.publicKey,
+    mplCore: MPL_CORE_PROGRAM_ID,
+  }).sendAndConfirm(umi);
+
+  // Then the migration is rejected.
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+});
+
+test('only the legacy escrow token can be migrated', async (t) => {
+  // Given a migrated legacy escrow that also received another token.
+  const umi = await createUmi();
+  const { collection, escrowNew, escrowOld } = await createMigratedEscrow(umi);
+  const otherMint = await createFungibleToken(umi);
+  await mintV1(umi, {
+    mint: otherMint.publicKey,
+    tokenStandard: TokenStandard.Fungible,
+    tokenOwner: publicKey(escrowOld),
+    amount: 50,
+  }).sendAndConfirm(umi);
+
+  // When the recipe authority tries to migrate the other token.
+  const result = migrateTokensV1(umi, {
+    escrowNew,
+    escrowOld,
+    collection: collection.publicKey,
+    escrowNewTokenAccount: findAssociatedTokenPda(umi, {
+      mint: otherMint.publicKey,
+      owner: publicKey(escrowNew),
+    }),
+    escrowOldTokenAccount: findAssociatedTokenPda(umi, {
+      mint: otherMint.publicKey,
+      owner: publicKey(escrowOld),
+    }),
+    token: otherMint.publicKey,
+    associatedTokenProgram: SPL_ASSOCIATED_TOKEN_PROGRAM_ID,
+    amount: 50,
+  }).sendAndConfirm(umi);
+
+  // Then the migration is rejected.
+  await t.throwsAsync(result, { name: 'InvalidMintAccount' });
+});
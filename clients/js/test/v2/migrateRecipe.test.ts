@@ -0,0 +1,59 @@
+import test from 'ava';
+import { generateSigner, publicKey } from '@metaplex-foundation/umi';
+import {
+  fetchRecipeV1,
+  findLegacyRecipes,
+  migrateRecipeV1,
+  migrateRecipes,
+} from '../../src';
+import { createRecipe, createUmi } from '../_setup';
+
+test('it does not list recipes written with the current layout', async (t) => {
+  // Given a recipe created by the current program.
+  const umi = await createUmi();
+  const { recipe } = await createRecipe(umi);
+
+  // When we look for recipes that need a migration.
+  const legacyRecipes = await findLegacyRecipes(umi);
+
+  // Then the recipe isn't one of them.
+  t.false(
+    legacyRecipes.some((legacy) => legacy.publicKey === publicKey(recipe))
+  );
+});
+
+test('it can migrate a recipe that already uses the current layout', async (t) => {
+  // Given a recipe created by the current program.
+  const umi = await createUmi();
+  const { collection, recipe } = await createRecipe(umi);
+
+  // When the collection authority migrates it.
+  await migrateRecipeV1(umi, {
+    recipe,
+    collection: collection.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the recipe is left unchanged.
+  t.like(await fetchRecipeV1(umi, recipe), {
+    collection: collection.publicKey,
+    authority: umi.identity.publicKey,
+    captureAmount: 5n,
+    releaseAmount: 5n,
+    count: 1n,
+  });
+});
+
+test('it builds one migration per legacy recipe', async (t) => {
+  // Given two recipes to migrate.
+  const umi = await createUmi();
+  const recipes = [0, 1].map(() => ({
+    publicKey: generateSigner(umi).publicKey,
+    collection: generateSigner(umi).publicKey,
+  }));
+
+  // When we build their migration.
+  const builder = migrateRecipes(umi, recipes);
+
+  // Then each recipe gets its own instruction.
+  t.is(builder.getInstructions().length, 2);
+});
@@ -0,0 +1,120 @@
+import test from 'ava';
+import { publicKey, sol } from '@metaplex-foundation/umi';
+import {
+  captureV2,
+  Currency,
+  fetchRecipeV1,
+  fetchRecipeVaultV1,
+  findRecipeVaultV1Pda,
+  releaseV2,
+} from '../../src';
+import { createRecipe, createUmi } from '../_setup';
+
+test('it keeps the lamports of a native SOL recipe in its vault', async (t) => {
+  // Given a native SOL recipe.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, recipe } =
+    await createRecipe(umi, {
+      token: undefined,
+      currency: Currency.NativeSol,
+      captureAmount: sol(1).basisPoints,
+      releaseAmount: sol(1).basisPoints,
+    });
+  const vault = findRecipeVaultV1Pda(umi, { recipe: publicKey(recipe) });
+  t.like(await fetchRecipeVaultV1(umi, vault), {
+    recipe: publicKey(recipe),
+    bump: vault[1],
+  });
+  const escrowBefore = await umi.rpc.getBalance(publicKey(escrow));
+  const vaultBefore = await umi.rpc.getBalance(publicKey(vault));
+
+  // When a user captures an asset.
+  await captureV2(umi, {
+    owner: umi.identity,
+    authority: recipe,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    vault,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  // Then the lamports go to the recipe vault rather than the shared escrow.
+  const vaultAfter = await umi.rpc.getBalance(publicKey(vault));
+  t.is(vaultAfter.basisPoints - vaultBefore.basisPoints, sol(1).basisPoints);
+  t.deepEqual(await umi.rpc.getBalance(publicKey(escrow)), escrowBefore);
+  t.like(await fetchRecipeV1(umi, recipe), { inventory: 0n, netSwaps: 1n });
+});
+
+test('it cannot release the lamports of another recipe', async (t) => {
+  // Given two native SOL recipes on one escrow, the first holding 1 SOL.
+  const umi = await createUmi();
+  const funded = await createRecipe(umi, {
+    token: undefined,
+    currency: Currency.NativeSol,
+    captureAmount: sol(1).basisPoints,
+    releaseAmount: sol(1).basisPoints,
+  });
+  await captureV2(umi, {
+    owner: umi.identity,
+    authority: funded.recipe,
+    recipe: funded.recipe,
+    escrow: funded.escrow,
+    asset: funded.assets[0].publicKey,
+    collection: funded.collection.publicKey,
+    feeProjectAccount: funded.feeLocation.publicKey,
+    vault: findRecipeVaultV1Pda(umi, { recipe: publicKey(funded.recipe) }),
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  const empty = await createRecipe(umi, {
+    token: undefined,
+    currency: Currency.NativeSol,
+    captureAmount: 0,
+    releaseAmount: sol(1).basisPoints,
+  });
+  const emptyVault = findRecipeVaultV1Pda(umi, {
+    recipe: publicKey(empty.recipe),
+  });
+  await captureV2(umi, {
+    owner: umi.identity,
+    authority: empty.recipe,
+    recipe: empty.recipe,
+    escrow: empty.escrow,
+    asset: empty.assets[0].publicKey,
+    collection: empty.collection.publicKey,
+    feeProjectAccount: empty.feeLocation.publicKey,
+    vault: emptyVault,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  // When the second recipe releases an asset it can't cover.
+  const releaseWith = (vault: typeof emptyVault) =>
+    releaseV2(umi, {
+      owner: umi.identity,
+      recipe: empty.recipe,
+      escrow: empty.escrow,
+      asset: empty.assets[0].publicKey,
+      collection: empty.collection.publicKey,
+      feeProjectAccount: empty.feeLocation.publicKey,
+      vault,
+      assetProof: [],
+      walletProof: [],
+    }).sendAndConfirm(umi);
+
+  // Then its own vault is short and the other recipe's vault is refused.
+  await t.throwsAsync(releaseWith(emptyVault), {
+    name: 'InsufficientEscrowBalance',
+  });
+  await t.throwsAsync(
+    releaseWith(
+      findRecipeVaultV1Pda(umi, { recipe: publicKey(funded.recipe) })
+    ),
+    { message: /ConstraintSeeds/ }
+  );
+});
@@ -0,0 +1,100 @@
+import test from 'ava';
+import { create } from '@metaplex-foundation/mpl-core';
+import {
+  generateSigner,
+  publicKey,
+  PublicKey,
+  Umi,
+} from '@metaplex-foundation/umi';
+import {
+  publicKey as publicKeySerializer,
+  string,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  fetchNftDataV1,
+  initNftDataV1,
+  MPL_HYBRID_PROGRAM_ID,
+  Path,
+} from '../../src';
+import {
+  createCoreCollection,
+  createFundedSigner,
+  createFungibleToken,
+  createUmi,
+  DEFAULT_ASSET,
+} from '../_setup';
+
+// Creates an asset outside any collection, updated by the identity.
+async function createCreatorAsset(umi: Umi) {
+  const asset = generateSigner(umi);
+  await create(umi, { asset, ...DEFAULT_ASSET }).sendAndConfirm(umi);
+
+  return asset.publicKey;
+}
+
+const findNftDataPda = (umi: Umi, asset: PublicKey) =>
+  umi.eddsa.findPda(MPL_HYBRID_PROGRAM_ID, [
+    string({ size: 'variable' }).serialize('nft'),
+    publicKeySerializer().serialize(asset),
+  ]);
+
+const nftDataArgs = {
+  name: 'Test Escrow',
+  uri: 'www.test.com/',
+  max: 2,
+  min: 1,
+  amount: 3,
+  feeAmount: 4,
+  solFeeAmount: 5,
+  path: Path.RerollMetadata,
+};
+
+test('the creator of standalone assets can set up nft data', async (t) => {
+  // Given a standalone asset updated by the identity.
+  const umi = await createUmi();
+  const tokenMint = await createFungibleToken(umi);
+  const feeLocation = generateSigner(umi);
+  const asset = await createCreatorAsset(umi);
+
+  // When the creator sets up nft data for one of its assets.
+  await initNftDataV1(umi, {
+    ...nftDataArgs,
+    nftData: findNftDataPda(umi, asset),
+    asset,
+    collection: umi.identity.publicKey,
+    token: tokenMint.publicKey,
+    feeLocation: feeLocation.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the nft data is created.
+  const nftData = findNftDataPda(umi, asset);
+  t.like(await fetchNftDataV1(umi, nftData), {
+    authority: umi.identity.publicKey,
+    token: tokenMint.publicKey,
+  });
+});
+
+test('a key that does not administer the collection cannot set up nft data', async (t) => {
+  // Given a collection.
+  const umi = await createUmi();
+  const { assets, collection } = await createCoreCollection(umi);
+  const tokenMint = await createFungibleToken(umi);
+  const feeLocation = generateSigner(umi);
+
+  // When another key tries to set up nft data for one of its assets.
+  const stranger = await createFundedSigner(umi);
+  const result = initNftDataV1(umi, {
+    ...nftDataArgs,
+    nftData: findNftDataPda(umi, assets[0].publicKey),
+    authority: stranger,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    token: tokenMint.publicKey,
+    feeLocation: feeLocation.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then it is rejected.
+  await t.throwsAsync(result, { name: 'InvalidCollectionAuthority' });
+  const nftData = findNftDataPda(umi, assets[0].publicKey);
+  t.false(await umi.rpc.accountExists(publicKey(nftData)));
+});
@@ -0,0 +1,97 @@
+import test from 'ava';
+import { fetchAsset } from '@metaplex-foundation/mpl-core';
+import { Umi } from '@metaplex-foundation/umi';
+import { captureV2, PauseScope, releaseV2, setPauseV1 } from '../../src';
+import { createRecipe, createUmi } from '../_setup';
+
+// Creates a recipe whose first asset was captured by the identity.
+async function createRecipeWithCapture(umi: Umi) {
+  const created = await createRecipe(umi);
+  const swap = {
+    owner: umi.identity,
+    recipe: created.recipe,
+    escrow: created.escrow,
+    collection: created.collection.publicKey,
+    feeProjectAccount: created.feeLocation.publicKey,
+    token: created.tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
+  };
+  await captureV2(umi, {
+    ...swap,
+    authority: created.recipe,
+    asset: created.assets[0].publicKey,
+  }).sendAndConfirm(umi);
+
+  return { ...created, swap };
+}
+
+async function pauseRecipe(
+  umi: Umi,
+  created: Awaited<ReturnType<typeof createRecipeWithCapture>>
+) {
+  await setPauseV1(umi, {
+    recipe: created.recipe,
+    collection: created.collection.publicKey,
+    scope: PauseScope.Recipe,
+    paused: true,
+    reason: 1,
+  }).sendAndConfirm(umi);
+}
+
+test('it cannot capture from a paused recipe', async (t) => {
+  // Given a paused recipe.
+  const umi = await createUmi();
+  const created = await createRecipeWithCapture(umi);
+  await pauseRecipe(umi, created);
+
+  // When a user tries to capture an asset.
+  const result = captureV2(umi, {
+    ...created.swap,
+    authority: created.recipe,
+    asset: created.assets[1].publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the capture is rejected.
+  await t.throwsAsync(result, { name: 'RecipePaused' });
+});
+
+test('it cannot release into a paused recipe', async (t) => {
+  // Given a paused recipe.
+  const umi = await createUmi();
+  const created = await createRecipeWithCapture(umi);
+  await pauseRecipe(umi, created);
+
+  // When the user tries to release the asset they captured.
+  const result = releaseV2(umi, {
+    ...created.swap,
+    asset: created.assets[0].publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the release is rejected.
+  await t.throwsAsync(result, { name: 'RecipePaused' });
+});
+
+test('swaps resume once the recipe is unpaused', async (t) => {
+  // Given a recipe that was paused.
+  const umi = await createUmi();
+  const created = await createRecipeWithCapture(umi);
+  await pauseRecipe(umi, created);
+
+  // When it is unpaused.
+  await setPauseV1(umi, {
+    recipe: created.recipe,
+    collection: created.collection.publicKey,
+    scope: PauseScope.Recipe,
+    paused: false,
+    reason: 0,
+  }).sendAndConfirm(umi);
+
+  // Then the user can release their asset.
+  await releaseV2(umi, {
+    ...created.swap,
+    asset: created.assets[0].publicKey,
+  }).sendAndConfirm(umi);
+  const asset = await fetchAsset(umi, created.assets[0].publicKey);
+  t.is(asset.owner, umi.identity.publicKey);
+});
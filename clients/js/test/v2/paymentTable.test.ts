@@ -0,0 +1,99 @@
+import test from 'ava';
+import { fetchDigitalAssetWithAssociatedToken } from '@metaplex-foundation/mpl-token-metadata';
+import { publicKey, PublicKey } from '@metaplex-foundation/umi';
+import {
+  captureV2,
+  fetchPaymentTableV1,
+  findPaymentTableV1Pda,
+  initPaymentTableV1,
+  updatePaymentTableV1,
+} from '../../src';
+import { createFungibleToken, createRecipe, createUmi } from '../_setup';
+
+const payment = (mint: PublicKey) => ({
+  mint,
+  captureAmount: 7,
+  releaseAmount: 6,
+  feeAmountCapture: 0,
+  feeAmountRelease: 0,
+});
+
+test('it cannot price the recipe token in the payment table', async (t) => {
+  // Given a token recipe.
+  const umi = await createUmi();
+  const { collection, recipe, tokenMint } = await createRecipe(umi);
+
+  // When we add the recipe token as an alternative payment.
+  const result = initPaymentTableV1(umi, {
+    recipe,
+    collection: collection.publicKey,
+    entries: [payment(tokenMint.publicKey)],
+  }).sendAndConfirm(umi);
+
+  // Then the payment table is rejected.
+  await t.throwsAsync(result, { name: 'InvalidPaymentTable' });
+});
+
+test('it cannot update a payment table to price a mint twice', async (t) => {
+  // Given a token recipe accepting another token.
+  const umi = await createUmi();
+  const { collection, recipe } = await createRecipe(umi);
+  const otherMint = await createFungibleToken(umi);
+  await initPaymentTableV1(umi, {
+    recipe,
+    collection: collection.publicKey,
+    entries: [payment(otherMint.publicKey)],
+  }).sendAndConfirm(umi);
+
+  // When we list the other token twice.
+  const result = updatePaymentTableV1(umi, {
+    recipe,
+    collection: collection.publicKey,
+    entries: [payment(otherMint.publicKey), payment(otherMint.publicKey)],
+  }).sendAndConfirm(umi);
+
+  // Then the update is rejected and the table is unchanged.
+  await t.throwsAsync(result, { name: 'InvalidPaymentTable' });
+  const paymentTable = findPaymentTableV1Pda(umi, {
+    recipe: publicKey(recipe),
+  });
+  t.like(await fetchPaymentTableV1(umi, paymentTable), {
+    entries: [{ mint: otherMint.publicKey, captureAmount: 7n }],
+  });
+});
+
+test('it captures at the price of an alternative payment', async (t) => {
+  // Given a token recipe accepting another token at its own price.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, recipe } =
+    await createRecipe(umi);
+  const otherMint = await createFungibleToken(umi);
+  await initPaymentTableV1(umi, {
+    recipe,
+    collection: collection.publicKey,
+    entries: [payment(otherMint.publicKey)],
+  }).sendAndConfirm(umi);
+
+  // When a user captures an asset paying with the other token.
+  await captureV2(umi, {
+    owner: umi.identity,
+    authority: recipe,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: otherMint.publicKey,
+    paymentTable: findPaymentTableV1Pda(umi, { recipe: publicKey(recipe) }),
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  // Then the escrow receives the price of the other token.
+  const escrowToken = await fetchDigitalAssetWithAssociatedToken(
+    umi,
+    otherMint.publicKey,
+    publicKey(escrow)
+  );
+  t.deepEqual(escrowToken.token.amount, 7n);
+});
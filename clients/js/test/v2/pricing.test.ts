@@ -0,0 +1,85 @@
+import test from 'ava';
+import { fetchDigitalAssetWithAssociatedToken } from '@metaplex-foundation/mpl-token-metadata';
+import { publicKey } from '@metaplex-foundation/umi';
+import {
+  captureV2,
+  CurveBasis,
+  CurveKind,
+  fetchRecipeV1,
+  updateRecipeV1,
+} from '../../src';
+import { createRecipe, createUmi, NO_RECIPE_CHANGES } from '../_setup';
+
+test('it prices captures along the recipe curve', async (t) => {
+  // Given a recipe whose capture price goes up by one token per net capture.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, recipe, tokenMint } =
+    await createRecipe(umi);
+  await updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    pricing: {
+      kind: CurveKind.Linear,
+      basis: CurveBasis.NetSwaps,
+      target: 0,
+      factor: 1,
+      minPrice: 0,
+      maxPrice: 100,
+    },
+  }).sendAndConfirm(umi);
+
+  // When a user captures two assets.
+  // eslint-disable-next-line no-restricted-syntax
+  for (const asset of assets.slice(0, 2)) {
+    // eslint-disable-next-line no-await-in-loop
+    await captureV2(umi, {
+      owner: umi.identity,
+      authority: recipe,
+      recipe,
+      escrow,
+      asset: asset.publicKey,
+      collection: collection.publicKey,
+      feeProjectAccount: feeLocation.publicKey,
+      token: tokenMint.publicKey,
+      assetProof: [],
+      walletProof: [],
+    }).sendAndConfirm(umi);
+  }
+
+  // Then the second capture costs one token more than the first.
+  const escrowTokenAfter = await fetchDigitalAssetWithAssociatedToken(
+    umi,
+    tokenMint.publicKey,
+    publicKey(escrow)
+  );
+  t.deepEqual(escrowTokenAfter.token.amount, 11n);
+  t.like(await fetchRecipeV1(umi, recipe), { netSwaps: 2n });
+});
+
+test('it can only seed the inventory once', async (t) => {
+  // Given a recipe created over an escrow that already holds assets.
+  const umi = await createUmi();
+  const { collection, feeLocation, recipe } = await createRecipe(umi);
+
+  // When the authority seeds the inventory counter.
+  await updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    inventory: 10,
+  }).sendAndConfirm(umi);
+  t.like(await fetchRecipeV1(umi, recipe), { inventory: 10n });
+
+  // Then the counter can't be overwritten afterwards.
+  const result = updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    inventory: 12,
+  }).sendAndConfirm(umi);
+  await t.throwsAsync(result, { name: 'InventoryCannotBeSet' });
+});
@@ -0,0 +1,104 @@
+import test from 'ava';
+import { generateSigner, publicKey } from '@metaplex-foundation/umi';
+import {
+  captureV2,
+  findProtocolConfigV1Pda,
+  MPL_HYBRID_PROGRAM_ID,
+  releaseV2,
+  setProtocolAdminV1,
+} from '../../src';
+import { createRecipe, createUmi } from '../_setup';
+
+test('it passes the protocol config to swaps by default', async (t) => {
+  // Given a recipe.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, recipe, tokenMint } =
+    await createRecipe(umi);
+
+  // When a user captures an asset without passing the protocol config.
+  const builder = captureV2(umi, {
+    owner: umi.identity,
+    authority: recipe,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
+  });
+  await builder.sendAndConfirm(umi);
+
+  // Then the client added it so the protocol pause applies to the capture.
+  const [instruction] = builder.getInstructions();
+  const protocolConfig = publicKey(findProtocolConfigV1Pda(umi));
+  t.true(instruction.keys.some((key) => key.pubkey === protocolConfig));
+});
+
+test('it cannot capture without the protocol config', async (t) => {
+  // Given a recipe.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, recipe, tokenMint } =
+    await createRecipe(umi);
+
+  // When a user captures an asset leaving the protocol config out.
+  const result = captureV2(umi, {
+    owner: umi.identity,
+    authority: recipe,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    protocolConfig: MPL_HYBRID_PROGRAM_ID,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  // Then the capture is rejected so the protocol pause can't be skipped.
+  await t.throwsAsync(result, { message: /ConstraintSeeds/ });
+});
+
+test('it cannot release with another account as the protocol config', async (t) => {
+  // Given a recipe with a captured asset.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, recipe, tokenMint } =
+    await createRecipe(umi);
+  const swap = {
+    owner: umi.identity,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
+  };
+  await captureV2(umi, { ...swap, authority: recipe }).sendAndConfirm(umi);
+
+  // When the user releases it passing an account they control instead.
+  const result = releaseV2(umi, {
+    ...swap,
+    protocolConfig: generateSigner(umi).publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the release is rejected.
+  await t.throwsAsync(result, { message: /ConstraintSeeds/ });
+});
+
+test('it cannot rotate the admin of a missing protocol config', async (t) => {
+  // Given a protocol without any config.
+  const umi = await createUmi();
+
+  // When someone tries to hand the admin to another key.
+  const result = setProtocolAdminV1(umi, {
+    admin: umi.identity,
+    newAdmin: generateSigner(umi),
+  }).sendAndConfirm(umi);
+
+  // Then nothing can be rotated.
+  await t.throwsAsync(result, { message: /AccountNotInitialized/ });
+});
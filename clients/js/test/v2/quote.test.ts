@@ -0,0 +1,150 @@
+import test from 'ava';
+import { fetchDigitalAssetWithAssociatedToken } from '@metaplex-foundation/mpl-token-metadata';
+import { publicKey } from '@metaplex-foundation/umi';
+import {
+  captureV2,
+  CurveBasis,
+  CurveKind,
+  fetchRecipeV1,
+  findPaymentTableV1Pda,
+  initPaymentTableV1,
+  SwapDirection,
+  updateRecipeV1,
+} from '../../src';
+import {
+  createFungibleToken,
+  createRecipe,
+  createUmi,
+  findQuoteNoncePda,
+  INSTRUCTIONS_SYSVAR,
+  NO_RECIPE_CHANGES,
+  signQuote,
+} from '../_setup';
+
+test('it can set the quote signer of a new recipe', async (t) => {
+  // Given a quote signer.
+  const umi = await createUmi();
+  const quoteSigner = umi.eddsa.generateKeypair();
+
+  // When we create a recipe priced by its quotes.
+  const { recipe } = await createRecipe(umi, {
+    quoteSigner: quoteSigner.publicKey,
+  });
+
+  // Then the recipe only accepts quotes of that signer.
+  t.like(await fetchRecipeV1(umi, recipe), {
+    quoteSigner: quoteSigner.publicKey,
+  });
+});
+
+test('it clamps quotes to the bounds of the recipe curve', async (t) => {
+  // Given a quoted recipe whose curve never charges more than 3 tokens.
+  const umi = await createUmi();
+  const quoteSigner = umi.eddsa.generateKeypair();
+  const { assets, collection, escrow, feeLocation, recipe, tokenMint } =
+    await createRecipe(umi, { quoteSigner: quoteSigner.publicKey });
+  await updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    pricing: {
+      kind: CurveKind.Fixed,
+      basis: CurveBasis.NetSwaps,
+      target: 0,
+      factor: 0,
+      minPrice: 0,
+      maxPrice: 3,
+    },
+  }).sendAndConfirm(umi);
+
+  // When a user captures an asset with a quote of 8 tokens.
+  await signQuote(umi, quoteSigner, {
+    recipe: publicKey(recipe),
+    user: umi.identity.publicKey,
+    asset: assets[0].publicKey,
+    direction: SwapDirection.Capture,
+    amount: 8,
+    expirySlot: 1_000_000_000,
+    nonce: 1,
+  })
+    .add(
+      captureV2(umi, {
+        owner: umi.identity,
+        authority: recipe,
+        recipe,
+        escrow,
+        asset: assets[0].publicKey,
+        collection: collection.publicKey,
+        feeProjectAccount: feeLocation.publicKey,
+        token: tokenMint.publicKey,
+        instructionsSysvar: INSTRUCTIONS_SYSVAR,
+        quoteNonce: findQuoteNoncePda(umi, recipe, 1),
+        assetProof: [],
+        walletProof: [],
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the user only pays the maximum price.
+  const escrowToken = await fetchDigitalAssetWithAssociatedToken(
+    umi,
+    tokenMint.publicKey,
+    publicKey(escrow)
+  );
+  t.deepEqual(escrowToken.token.amount, 3n);
+});
+
+test('it cannot use a quote with an alternative payment', async (t) => {
+  // Given a quoted recipe that also accepts another token.
+  const umi = await createUmi();
+  const quoteSigner = umi.eddsa.generateKeypair();
+  const { assets, collection, escrow, feeLocation, recipe } =
+    await createRecipe(umi, { quoteSigner: quoteSigner.publicKey });
+  const otherMint = await createFungibleToken(umi);
+  await initPaymentTableV1(umi, {
+    recipe,
+    collection: collection.publicKey,
+    entries: [
+      {
+        mint: otherMint.publicKey,
+        captureAmount: 7,
+        releaseAmount: 6,
+        feeAmountCapture: 0,
+        feeAmountRelease: 0,
+      },
+    ],
+  }).sendAndConfirm(umi);
+
+  // When a user pays with the other token under a quote.
+  const result = signQuote(umi, quoteSigner, {
+    recipe: publicKey(recipe),
+    user: umi.identity.publicKey,
+    asset: assets[0].publicKey,
+    direction: SwapDirection.Capture,
+    amount: 1,
+    expirySlot: 1_000_000_000,
+    nonce: 1,
+  })
+    .add(
+      captureV2(umi, {
+        owner: umi.identity,
+        authority: recipe,
+        recipe,
+        escrow,
+        asset: assets[0].publicKey,
+        collection: collection.publicKey,
+        feeProjectAccount: feeLocation.publicKey,
+        token: otherMint.publicKey,
+        paymentTable: findPaymentTableV1Pda(umi, { recipe: publicKey(recipe) }),
+        instructionsSysvar: INSTRUCTIONS_SYSVAR,
+        quoteNonce: findQuoteNoncePda(umi, recipe, 1),
+        assetProof: [],
+        walletProof: [],
+      })
+    )
+    .sendAndConfirm(umi);
+
+  // Then the capture fails.
+  await t.throwsAsync(result, { name: 'QuoteWithAlternativePayment' });
+});
@@ -0,0 +1,129 @@
+import test from 'ava';
+import { fetchAsset } from '@metaplex-foundation/mpl-core';
+import { publicKey } from '@metaplex-foundation/umi';
+import {
+  fetchRecipeV1,
+  findEscrowV2Pda,
+  initEscrowV2,
+  transferEscrowAssetV1,
+  transferEscrowReserveV1,
+  updateRecipeV1,
+} from '../../src';
+import {
+  createFundedSigner,
+  createRecipe,
+  createUmi,
+  handOverRecipe,
+  NO_RECIPE_CHANGES,
+} from '../_setup';
+
+test('the new authority administers the recipe after a handover', async (t) => {
+  // Given a recipe handed over to a new authority.
+  const umi = await createUmi();
+  const { collection, feeLocation, recipe } = await createRecipe(umi);
+  const { newAuthority } = await handOverRecipe(umi, recipe);
+
+  // When the new authority updates the recipe.
+  await updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    authority: newAuthority,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    captureAmount: 7,
+  }).sendAndConfirm(umi);
+
+  // Then the update is applied and the previous authority is recorded.
+  t.like(await fetchRecipeV1(umi, recipe), {
+    authority: newAuthority.publicKey,
+    previousAuthority: umi.identity.publicKey,
+    captureAmount: 7n,
+  });
+});
+
+test('the previous authority cannot administer the recipe', async (t) => {
+  // Given a recipe handed over by its authority.
+  const umi = await createUmi();
+  const { collection, feeLocation, recipe } = await createRecipe(umi);
+  await handOverRecipe(umi, recipe);
+
+  // When the previous authority tries to update the recipe.
+  const result = updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    captureAmount: 7,
+  }).sendAndConfirm(umi);
+
+  // Then the update is rejected.
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+});
+
+test('the previous authority can move escrowed assets to the new escrow', async (t) => {
+  // Given a recipe handed over to a new authority.
+  const umi = await createUmi();
+  const { assets, collection, escrow, recipe } = await createRecipe(umi);
+  const { newEscrow } = await handOverRecipe(umi, recipe);
+
+  // When the previous authority moves an asset over.
+  await transferEscrowAssetV1(umi, {
+    escrowOld: escrow,
+    escrowNew: newEscrow,
+    recipe,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the asset is held by the new escrow.
+  const asset = await fetchAsset(umi, assets[0].publicKey);
+  t.is(asset.owner, publicKey(newEscrow));
+});
+
+test('only the authority the recipe was handed over from can move escrowed assets', async (t) => {
+  // Given a recipe handed over to a new authority.
+  const umi = await createUmi();
+  const { assets, collection, recipe } = await createRecipe(umi);
+  const { newEscrow } = await handOverRecipe(umi, recipe);
+
+  // And an unrelated authority with its own escrow.
+  const stranger = await createFundedSigner(umi);
+  await initEscrowV2(umi, { authority: stranger }).sendAndConfirm(umi);
+
+  // When the unrelated authority tries to move an asset through the recipe.
+  const result = transferEscrowAssetV1(umi, {
+    authority: stranger,
+    escrowOld: findEscrowV2Pda(umi, { authority: stranger.publicKey }),
+    escrowNew: newEscrow,
+    recipe,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the transfer is rejected.
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+});
+
+test('only the authority the recipe was handed over from can move escrowed reserves', async (t) => {
+  // Given a recipe handed over to a new authority.
+  const umi = await createUmi();
+  const { recipe, tokenMint } = await createRecipe(umi);
+  const { newEscrow } = await handOverRecipe(umi, recipe);
+
+  // And an unrelated authority with its own escrow.
+  const stranger = await createFundedSigner(umi);
+  await initEscrowV2(umi, { authority: stranger }).sendAndConfirm(umi);
+
+  // When the unrelated authority tries to move a reserve through the recipe.
+  const result = transferEscrowReserveV1(umi, {
+    authority: stranger,
+    escrowOld: findEscrowV2Pda(umi, { authority: stranger.publicKey }),
+    escrowNew: newEscrow,
+    recipe,
+    token: tokenMint.publicKey,
+    amount: 1,
+  }).sendAndConfirm(umi);
+
+  // Then the transfer is rejected.
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+});
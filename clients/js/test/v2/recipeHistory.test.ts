@@ -0,0 +1,62 @@
+import test from 'ava';
+import { publicKey } from '@metaplex-foundation/umi';
+import {
+  fetchRecipeHistoryV1,
+  fetchRecipeV1,
+  findRecipeHistoryV1Pda,
+  HistoryField,
+  MPL_HYBRID_PROGRAM_ID,
+  updateRecipeV1,
+} from '../../src';
+import { createRecipe, createUmi, NO_RECIPE_CHANGES } from '../_setup';
+
+test('it records recipe updates in the history', async (t) => {
+  // Given a recipe.
+  const umi = await createUmi();
+  const { collection, feeLocation, recipe } = await createRecipe(umi);
+
+  // When we update its capture amount.
+  await updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    captureAmount: 7,
+  }).sendAndConfirm(umi);
+
+  // Then the change is recorded with the values before and after it.
+  const history = findRecipeHistoryV1Pda(umi, { recipe: publicKey(recipe) });
+  t.like(await fetchRecipeHistoryV1(umi, history), {
+    recipe: publicKey(recipe),
+    count: 1n,
+    entries: [
+      {
+        signer: umi.identity.publicKey,
+        // eslint-disable-next-line no-bitwise
+        changed: 1 << HistoryField.CaptureAmount,
+        old: { captureAmount: 5n },
+        new: { captureAmount: 7n },
+      },
+    ],
+  });
+});
+
+test('it cannot update a recipe without the history account', async (t) => {
+  // Given a recipe.
+  const umi = await createUmi();
+  const { collection, feeLocation, recipe } = await createRecipe(umi);
+
+  // When we update it without passing the history.
+  const result = updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    history: MPL_HYBRID_PROGRAM_ID,
+    captureAmount: 7,
+  }).sendAndConfirm(umi);
+
+  // Then the update is rejected and the recipe is unchanged.
+  await t.throwsAsync(result, { message: /ConstraintSeeds/ });
+  t.like(await fetchRecipeV1(umi, recipe), { captureAmount: 5n });
+});
@@ -13,6 +13,7 @@ import {
 import { addCollectionPlugin, fetchAsset } from '@metaplex-foundation/mpl-core';
 import {
   buildPath,
+  Currency,
   EscrowV2,
   fetchEscrowV2,
   fetchRecipeV1,
@@ -20,6 +21,7 @@ import {
   initRecipeV1,
   MPL_HYBRID_PROGRAM_ID,
   Path,
+  RecipeKind,
   releaseV2,
 } from '../../src';
 import { createCoreCollection, createUmi } from '../_setup';
@@ -68,12 +70,16 @@ test('it can swap an asset for tokens with reroll', async (t) => {
     uri: 'www.test.com/',
     max: 9,
     min: 0,
-    amount: 5,
+    captureAmount: 5,
+    releaseAmount: 5,
     feeAmountCapture: 1,
     feeAmountRelease: 0,
     solFeeAmountCapture: 100_000n,
     solFeeAmountRelease: 890_880n,
     path: buildPath([Path.RerollMetadata]),
+    currency: Currency.Token,
+    kind: RecipeKind.Collection,
+    quoteSigner: null,
   }).sendAndConfirm(umi);
 
   const recipe = umi.eddsa.findPda(MPL_HYBRID_PROGRAM_ID, [
@@ -92,7 +98,8 @@ test('it can swap an asset for tokens with reroll', async (t) => {
     uri: 'www.test.com/',
     max: 9n,
     min: 0n,
-    amount: 5n,
+    captureAmount: 5n,
+    releaseAmount: 5n,
     feeAmountCapture: 1n,
     feeAmountRelease: 0n,
     solFeeAmountCapture: 100_000n,
@@ -129,6 +136,8 @@ test('it can swap an asset for tokens with reroll', async (t) => {
     collection: collection.publicKey,
     feeProjectAccount: feeLocation.publicKey,
     token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
   }).sendAndConfirm(umi);
 
   const escrowTokenAfter = await fetchDigitalAssetWithAssociatedToken(
@@ -194,12 +203,16 @@ test('it can swap an asset for tokens without reroll', async (t) => {
     uri: 'www.test.com/',
     max: 9,
     min: 0,
-    amount: 5,
+    captureAmount: 5,
+    releaseAmount: 5,
     feeAmountCapture: 1,
     feeAmountRelease: 0,
     solFeeAmountCapture: 100_000n,
     solFeeAmountRelease: 890_880n,
     path: buildPath([Path.NoRerollMetadata]),
+    currency: Currency.Token,
+    kind: RecipeKind.Collection,
+    quoteSigner: null,
   }).sendAndConfirm(umi);
 
   const recipe = umi.eddsa.findPda(MPL_HYBRID_PROGRAM_ID, [
@@ -217,7 +230,8 @@ test('it can swap an asset for tokens without reroll', async (t) => {
     uri: 'www.test.com/',
     max: 9n,
     min: 0n,
-    amount: 5n,
+    captureAmount: 5n,
+    releaseAmount: 5n,
     feeAmountCapture: 1n,
     feeAmountRelease: 0n,
     solFeeAmountCapture: 100_000n,
@@ -254,6 +268,8 @@ test('it can swap an asset for tokens without reroll', async (t) => {
     collection: collection.publicKey,
     feeProjectAccount: feeLocation.publicKey,
     token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
   }).sendAndConfirm(umi);
 
   const escrowTokenAfter = await fetchDigitalAssetWithAssociatedToken(
@@ -319,12 +335,16 @@ test('it can swap an asset for tokens as UpdateDelegate with reroll', async (t)
     uri: 'www.test.com/',
     max: 9,
     min: 0,
-    amount: 5,
+    captureAmount: 5,
+    releaseAmount: 5,
     feeAmountCapture: 1,
     feeAmountRelease: 0,
     solFeeAmountCapture: 100_000n,
     solFeeAmountRelease: 890_880n,
     path: buildPath([Path.RerollMetadata]),
+    currency: Currency.Token,
+    kind: RecipeKind.Collection,
+    quoteSigner: null,
   }).sendAndConfirm(umi);
 
   const recipe = umi.eddsa.findPda(MPL_HYBRID_PROGRAM_ID, [
@@ -352,7 +372,8 @@ test('it can swap an asset for tokens as UpdateDelegate with reroll', async (t)
     uri: 'www.test.com/',
     max: 9n,
     min: 0n,
-    amount: 5n,
+    captureAmount: 5n,
+    releaseAmount: 5n,
     feeAmountCapture: 1n,
     feeAmountRelease: 0n,
     solFeeAmountCapture: 100_000n,
@@ -390,6 +411,8 @@ test('it can swap an asset for tokens as UpdateDelegate with reroll', async (t)
     collection: collection.publicKey,
     feeProjectAccount: feeLocation.publicKey,
     token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
   }).sendAndConfirm(umi);
 
   const escrowTokenAfter = await fetchDigitalAssetWithAssociatedToken(
@@ -455,12 +478,16 @@ test('it can swap an asset for tokens as UpdateDelegate without reroll', async (
     uri: 'www.test.com/',
     max: 9,
     min: 0,
-    amount: 5,
+    captureAmount: 5,
+    releaseAmount: 5,
     feeAmountCapture: 1,
     feeAmountRelease: 0,
     solFeeAmountCapture: 100_000n,
     solFeeAmountRelease: 890_880n,
     path: buildPath([Path.NoRerollMetadata]),
+    currency: Currency.Token,
+    kind: RecipeKind.Collection,
+    quoteSigner: null,
   }).sendAndConfirm(umi);
 
   const recipe = umi.eddsa.findPda(MPL_HYBRID_PROGRAM_ID, [
@@ -487,7 +514,8 @@ test('it can swap an asset for tokens as UpdateDelegate without reroll', async (
     uri: 'www.test.com/',
     max: 9n,
     min: 0n,
-    amount: 5n,
+    captureAmount: 5n,
+    releaseAmount: 5n,
     feeAmountCapture: 1n,
     feeAmountRelease: 0n,
     solFeeAmountCapture: 100_000n,
@@ -525,6 +553,8 @@ test('it can swap an asset for tokens as UpdateDelegate without reroll', async (
     collection: collection.publicKey,
     feeProjectAccount: feeLocation.publicKey,
     token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
   }).sendAndConfirm(umi);
 
   const escrowTokenAfter = await fetchDigitalAssetWithAssociatedToken(
@@ -590,12 +620,16 @@ test('it cannot swap an asset for tokens with BlockRelease', async (t) => {
     uri: 'www.test.com/',
     max: 9,
     min: 0,
-    amount: 5,
+    captureAmount: 5,
+    releaseAmount: 5,
     feeAmountCapture: 1,
     feeAmountRelease: 0,
     solFeeAmountCapture: 100_000n,
     solFeeAmountRelease: 890_880n,
     path: buildPath([Path.NoRerollMetadata, Path.BlockRelease]),
+    currency: Currency.Token,
+    kind: RecipeKind.Collection,
+    quoteSigner: null,
   }).sendAndConfirm(umi);
 
   const recipe = umi.eddsa.findPda(MPL_HYBRID_PROGRAM_ID, [
@@ -622,7 +656,8 @@ test('it cannot swap an asset for tokens with BlockRelease', async (t) => {
     uri: 'www.test.com/',
     max: 9n,
     min: 0n,
-    amount: 5n,
+    captureAmount: 5n,
+    releaseAmount: 5n,
     feeAmountCapture: 1n,
     feeAmountRelease: 0n,
     solFeeAmountCapture: 100_000n,
@@ -660,6 +695,8 @@ test('it cannot swap an asset for tokens with BlockRelease', async (t) => {
     collection: collection.publicKey,
     feeProjectAccount: feeLocation.publicKey,
     token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(result, { name: 'ReleaseBlocked' });
@@ -709,12 +746,16 @@ test('it can burn an asset for tokens with BurnOnRelease', async (t) => {
     uri: 'www.test.com/',
     max: 9,
     min: 0,
-    amount: 5,
+    captureAmount: 5,
+    releaseAmount: 5,
     feeAmountCapture: 1,
     feeAmountRelease: 0,
     solFeeAmountCapture: 100_000n,
     solFeeAmountRelease: 890_880n,
     path: buildPath([Path.NoRerollMetadata, Path.BurnOnRelease]),
+    currency: Currency.Token,
+    kind: RecipeKind.Collection,
+    quoteSigner: null,
   }).sendAndConfirm(umi);
 
   const recipe = umi.eddsa.findPda(MPL_HYBRID_PROGRAM_ID, [
@@ -741,7 +782,8 @@ test('it can burn an asset for tokens with BurnOnRelease', async (t) => {
     uri: 'www.test.com/',
     max: 9n,
     min: 0n,
-    amount: 5n,
+    captureAmount: 5n,
+    releaseAmount: 5n,
     feeAmountCapture: 1n,
     feeAmountRelease: 0n,
     solFeeAmountCapture: 100_000n,
@@ -779,6 +821,8 @@ test('it can burn an asset for tokens with BurnOnRelease', async (t) => {
     collection: collection.publicKey,
     feeProjectAccount: feeLocation.publicKey,
     token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
   }).sendAndConfirm(umi);
 
   const escrowTokenAfter = await fetchDigitalAssetWithAssociatedToken(
@@ -0,0 +1,87 @@
+import test from 'ava';
+import {
+  buildPath,
+  captureV2,
+  fetchRecipeV1,
+  Path,
+  releaseV2,
+} from '../../src';
+import { createRecipe, createUmi } from '../_setup';
+
+test('it tracks the swaps of a recipe', async (t) => {
+  // Given a recipe charging token and SOL fees on both sides.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, recipe, tokenMint } =
+    await createRecipe(umi);
+
+  // When a user captures an asset and releases it again.
+  await captureV2(umi, {
+    owner: umi.identity,
+    authority: recipe,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+  await releaseV2(umi, {
+    owner: umi.identity,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  // Then both swaps are counted with their amounts and fees.
+  t.like(await fetchRecipeV1(umi, recipe), {
+    stats: {
+      captures: 1n,
+      releases: 1n,
+      tokensIn: 5n,
+      tokensOut: 5n,
+      tokensBurned: 0n,
+      tokenFees: 2n,
+      solFees: 990_880n,
+    },
+  });
+});
+
+test('it counts burned tokens apart from escrowed ones', async (t) => {
+  // Given a recipe burning its tokens on capture.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, recipe, tokenMint } =
+    await createRecipe(umi, {
+      path: buildPath([Path.NoRerollMetadata, Path.BurnOnCapture]),
+    });
+
+  // When a user captures an asset.
+  await captureV2(umi, {
+    owner: umi.identity,
+    authority: recipe,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  // Then the capture amount is counted as burned.
+  t.like(await fetchRecipeV1(umi, recipe), {
+    stats: {
+      captures: 1n,
+      tokensIn: 0n,
+      tokensBurned: 5n,
+      tokenFees: 1n,
+    },
+  });
+});
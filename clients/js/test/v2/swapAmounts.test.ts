@@ -0,0 +1,59 @@
+import test from 'ava';
+import { fetchDigitalAssetWithAssociatedToken } from '@metaplex-foundation/mpl-token-metadata';
+import { publicKey } from '@metaplex-foundation/umi';
+import { captureV2, fetchRecipeV1, releaseV2 } from '../../src';
+import { createRecipe, createUmi } from '../_setup';
+
+test('it captures and releases at separate prices', async (t) => {
+  // Given a recipe that buys assets back for less than it sells them.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, recipe, tokenMint } =
+    await createRecipe(umi, { captureAmount: 5, releaseAmount: 3 });
+  t.like(await fetchRecipeV1(umi, recipe), {
+    captureAmount: 5n,
+    releaseAmount: 3n,
+  });
+
+  // When a user captures an asset.
+  await captureV2(umi, {
+    owner: umi.identity,
+    authority: recipe,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  // Then the escrow receives the capture amount.
+  const escrowTokenAfterCapture = await fetchDigitalAssetWithAssociatedToken(
+    umi,
+    tokenMint.publicKey,
+    publicKey(escrow)
+  );
+  t.deepEqual(escrowTokenAfterCapture.token.amount, 5n);
+
+  // When the user releases it again.
+  await releaseV2(umi, {
+    owner: umi.identity,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  // Then the escrow only pays out the release amount.
+  const escrowTokenAfterRelease = await fetchDigitalAssetWithAssociatedToken(
+    umi,
+    tokenMint.publicKey,
+    publicKey(escrow)
+  );
+  t.deepEqual(escrowTokenAfterRelease.token.amount, 2n);
+});
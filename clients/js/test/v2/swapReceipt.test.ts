@@ -0,0 +1,70 @@
+import test from 'ava';
+import { publicKey } from '@metaplex-foundation/umi';
+import {
+  captureV2,
+  fetchSwapReceiptV1,
+  findSwapReceiptV1Pda,
+  releaseV2,
+  updateRecipeV1,
+} from '../../src';
+import { createRecipe, createUmi, NO_RECIPE_CHANGES } from '../_setup';
+
+test('it creates a swap receipt on the first swap and adds to it', async (t) => {
+  // Given a recipe awarding loyalty points on both sides.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, recipe, tokenMint } =
+    await createRecipe(umi);
+  await updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    loyalty: { pointsPerCapture: 3, pointsPerRelease: 2 },
+  }).sendAndConfirm(umi);
+  const swapReceipt = findSwapReceiptV1Pda(umi, {
+    recipe: publicKey(recipe),
+    user: umi.identity.publicKey,
+  });
+
+  // When a user captures an asset and releases it with their receipt.
+  await captureV2(umi, {
+    owner: umi.identity,
+    authority: recipe,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    swapReceipt,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+  t.like(await fetchSwapReceiptV1(umi, swapReceipt), {
+    captures: 1n,
+    releases: 0n,
+    loyaltyPoints: 3n,
+  });
+  await releaseV2(umi, {
+    owner: umi.identity,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    swapReceipt,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  // Then the same receipt counts both swaps and their points.
+  t.like(await fetchSwapReceiptV1(umi, swapReceipt), {
+    recipe: publicKey(recipe),
+    user: umi.identity.publicKey,
+    captures: 1n,
+    releases: 1n,
+    loyaltyPoints: 5n,
+    bump: swapReceipt[1],
+  });
+});
@@ -0,0 +1,128 @@
+import test from 'ava';
+import { captureV2, releaseV2, updateRecipeV1 } from '../../src';
+import { createRecipe, createUmi, NO_RECIPE_CHANGES } from '../_setup';
+
+// A timestamp far enough ahead to never be reached by the test validator.
+const FAR_FUTURE = 4_000_000_000;
+
+test('it cannot capture before the capture window opens', async (t) => {
+  // Given a recipe whose captures open in the future.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, recipe, tokenMint } =
+    await createRecipe(umi);
+  await updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    captureWindow: { start: FAR_FUTURE, end: 0 },
+  }).sendAndConfirm(umi);
+
+  // When a user tries to capture an asset.
+  const result = captureV2(umi, {
+    owner: umi.identity,
+    authority: recipe,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  // Then the capture fails.
+  await t.throwsAsync(result, { name: 'CaptureNotOpen' });
+});
+
+test('it cannot capture after the capture window closed', async (t) => {
+  // Given a recipe whose captures closed at the start of the epoch.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, recipe, tokenMint } =
+    await createRecipe(umi);
+  await updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    captureWindow: { start: 0, end: 1 },
+  }).sendAndConfirm(umi);
+
+  // When a user tries to capture an asset.
+  const result = captureV2(umi, {
+    owner: umi.identity,
+    authority: recipe,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  // Then the capture fails.
+  await t.throwsAsync(result, { name: 'CaptureClosed' });
+});
+
+test('it cannot release before the release window opens', async (t) => {
+  // Given a captured asset of a recipe whose releases open in the future.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, recipe, tokenMint } =
+    await createRecipe(umi);
+  await captureV2(umi, {
+    owner: umi.identity,
+    authority: recipe,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+  await updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    releaseWindow: { start: FAR_FUTURE, end: 0 },
+  }).sendAndConfirm(umi);
+
+  // When the user tries to release it.
+  const result = releaseV2(umi, {
+    owner: umi.identity,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  // Then the release fails.
+  await t.throwsAsync(result, { name: 'ReleaseNotOpen' });
+});
+
+test('it cannot set a window that closes before it opens', async (t) => {
+  // Given a recipe.
+  const umi = await createUmi();
+  const { collection, feeLocation, recipe } = await createRecipe(umi);
+
+  // When we set a release window ending before its start.
+  const result = updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    releaseWindow: { start: FAR_FUTURE, end: FAR_FUTURE - 1 },
+  }).sendAndConfirm(umi);
+
+  // Then the update is rejected.
+  await t.throwsAsync(result, { name: 'InvalidSwapWindow' });
+});
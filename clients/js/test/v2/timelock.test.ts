@@ -0,0 +1,210 @@
+import test from 'ava';
+import { publicKey, Umi } from '@metaplex-foundation/umi';
+import {
+  buildRoles,
+  executeRecipeUpdateV1,
+  fetchRecipeV1,
+  findOperatorsV1Pda,
+  initOperatorsV1,
+  initPaymentTableV1,
+  OperatorRole,
+  queueRecipeUpdateV1,
+  updateOperatorsV1,
+  updateRecipeV1,
+} from '../../src';
+import {
+  createFundedSigner,
+  createFungibleToken,
+  createRecipe,
+  createUmi,
+  NO_RECIPE_CHANGES,
+} from '../_setup';
+
+// Creates a recipe whose changes wait the given number of slots.
+async function createTimelockedRecipe(umi: Umi, timelockSlots: number) {
+  const created = await createRecipe(umi);
+  await updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe: created.recipe,
+    collection: created.collection.publicKey,
+    feeLocation: created.feeLocation.publicKey,
+    timelockSlots,
+  }).sendAndConfirm(umi);
+
+  return created;
+}
+
+test('it keeps capture window changes immediate on a timelocked recipe', async (t) => {
+  // Given a timelocked recipe.
+  const umi = await createUmi();
+  const { collection, feeLocation, recipe } = await createTimelockedRecipe(
+    umi,
+    1_000
+  );
+
+  // When we close the capture window.
+  await updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    captureWindow: { start: 0, end: 1 },
+  }).sendAndConfirm(umi);
+
+  // Then the change is applied right away.
+  t.like(await fetchRecipeV1(umi, recipe), {
+    captureWindow: { start: 0n, end: 1n },
+  });
+});
+
+test('passing the current token is not treated as a token change', async (t) => {
+  // Given a timelocked recipe with an operator managing its swap limits.
+  const umi = await createUmi();
+  const { collection, feeLocation, recipe, tokenMint } =
+    await createTimelockedRecipe(umi, 1_000);
+  const operator = await createFundedSigner(umi);
+  await initOperatorsV1(umi, {
+    recipe,
+    collection: collection.publicKey,
+    entries: [
+      {
+        operator: operator.publicKey,
+        roles: buildRoles([OperatorRole.Pause]),
+      },
+    ],
+  }).sendAndConfirm(umi);
+
+  // When the operator closes the capture window passing the current token.
+  await updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    authority: operator,
+    collection: collection.publicKey,
+    token: tokenMint.publicKey,
+    feeLocation: feeLocation.publicKey,
+    operators: findOperatorsV1Pda(umi, { recipe: publicKey(recipe) }),
+    captureWindow: { start: 0, end: 1 },
+  }).sendAndConfirm(umi);
+
+  // Then it needs neither the pricing role nor the timelock.
+  t.like(await fetchRecipeV1(umi, recipe), {
+    captureWindow: { start: 0n, end: 1n },
+    token: tokenMint.publicKey,
+  });
+});
+
+test('it queues release limits behind the recipe timelock', async (t) => {
+  // Given a timelocked recipe.
+  const umi = await createUmi();
+  const { collection, feeLocation, recipe } = await createTimelockedRecipe(
+    umi,
+    1_000
+  );
+
+  // When we try to cap releases right away.
+  const result = updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    circuitBreaker: { windowSlots: 100, maxNetOutflow: 1, maxReleases: 1 },
+  }).sendAndConfirm(umi);
+
+  // Then the change has to be queued.
+  await t.throwsAsync(result, { name: 'TimelockActive' });
+});
+
+test('it queues allowlist changes behind the recipe timelock', async (t) => {
+  // Given a timelocked recipe.
+  const umi = await createUmi();
+  const { collection, feeLocation, recipe } = await createTimelockedRecipe(
+    umi,
+    1_000
+  );
+
+  // When we try to restrict the wallets right away.
+  const result = updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    walletRoot: Array(32).fill(1),
+  }).sendAndConfirm(umi);
+
+  // Then the change has to be queued.
+  await t.throwsAsync(result, { name: 'TimelockActive' });
+});
+
+test('it freezes the payment table of a timelocked recipe', async (t) => {
+  // Given a timelocked recipe.
+  const umi = await createUmi();
+  const { collection, recipe } = await createTimelockedRecipe(umi, 1_000);
+  const otherMint = await createFungibleToken(umi);
+
+  // When we try to accept another token.
+  const result = initPaymentTableV1(umi, {
+    recipe,
+    collection: collection.publicKey,
+    entries: [
+      {
+        mint: otherMint.publicKey,
+        captureAmount: 7,
+        releaseAmount: 6,
+        feeAmountCapture: 0,
+        feeAmountRelease: 0,
+      },
+    ],
+  }).sendAndConfirm(umi);
+
+  // Then the payment table is rejected.
+  await t.throwsAsync(result, { name: 'RecipeTimelocked' });
+});
+
+test('it does not execute an update queued by a removed operator', async (t) => {
+  // Given a timelocked recipe with a pricing operator.
+  const umi = await createUmi();
+  const { collection, feeLocation, recipe } = await createTimelockedRecipe(
+    umi,
+    1
+  );
+  const operator = await createFundedSigner(umi);
+  const operators = findOperatorsV1Pda(umi, { recipe: publicKey(recipe) });
+  await initOperatorsV1(umi, {
+    recipe,
+    collection: collection.publicKey,
+    entries: [
+      {
+        operator: operator.publicKey,
+        roles: buildRoles([OperatorRole.UpdatePricing]),
+      },
+    ],
+  }).sendAndConfirm(umi);
+
+  // And a price change queued by the operator.
+  await queueRecipeUpdateV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    authority: operator,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    operators,
+    captureAmount: 50,
+  }).sendAndConfirm(umi);
+
+  // And the operator removed before the timelock elapsed.
+  await updateOperatorsV1(umi, {
+    recipe,
+    collection: collection.publicKey,
+    entries: [],
+  }).sendAndConfirm(umi);
+
+  // When the update is executed.
+  const result = executeRecipeUpdateV1(umi, {
+    recipe,
+    authority: operator.publicKey,
+    operators,
+  }).sendAndConfirm(umi);
+
+  // Then it is rejected.
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+});
@@ -0,0 +1,240 @@
+import test from 'ava';
+import {
+  create,
+  createCollection,
+  fetchCollection,
+} from '@metaplex-foundation/mpl-core';
+import {
+  generateSigner,
+  publicKey,
+  PublicKey,
+  Signer,
+  Umi,
+} from '@metaplex-foundation/umi';
+import {
+  string,
+  publicKey as publicKeySerializer,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  buildPath,
+  Currency,
+  fetchEscrowV1,
+  fetchNftDataV1,
+  fetchRecipeV1,
+  findRecipeV1Pda,
+  initEscrowV1,
+  initEscrowV2,
+  initNftDataV1,
+  initRecipeV1,
+  MPL_HYBRID_PROGRAM_ID,
+  Path,
+  RecipeKind,
+  updateRecipeV1,
+} from '../../src';
+import {
+  createFundedSigner,
+  createFungibleToken,
+  createUmi,
+  DEFAULT_ASSET,
+  DEFAULT_COLLECTION,
+  handOverRecipe,
+  NO_RECIPE_CHANGES,
+} from '../_setup';
+
+// Creates a collection whose UpdateDelegate plugin lists the delegate.
+async function createDelegatedCollection(umi: Umi, delegate: PublicKey) {
+  const collection = generateSigner(umi);
+  await createCollection(umi, {
+    collection,
+    ...DEFAULT_COLLECTION,
+    plugins: [{ type: 'UpdateDelegate', additionalDelegates: [delegate] }],
+  }).sendAndConfirm(umi);
+
+  return collection.publicKey;
+}
+
+async function initRecipe(umi: Umi, authority: Signer, collection: PublicKey) {
+  const tokenMint = await createFungibleToken(umi);
+  await initEscrowV2(umi, { authority }).sendAndConfirm(umi);
+
+  return initRecipeV1(umi, {
+    authority,
+    collection,
+    token: tokenMint.publicKey,
+    feeLocation: generateSigner(umi).publicKey,
+    name: 'Test Escrow',
+    uri: 'www.test.com/',
+    max: 9,
+    min: 0,
+    captureAmount: 5,
+    releaseAmount: 5,
+    feeAmountCapture: 1,
+    feeAmountRelease: 1,
+    solFeeAmountCapture: 890_880n,
+    solFeeAmountRelease: 100_000n,
+    path: buildPath([Path.NoRerollMetadata]),
+    currency: Currency.Token,
+    kind: RecipeKind.Collection,
+    quoteSigner: null,
+  }).sendAndConfirm(umi);
+}
+
+test('an update delegate of the collection can create a recipe', async (t) => {
+  // Given a collection delegating updates to an operational key.
+  const umi = await createUmi();
+  const delegate = await createFundedSigner(umi);
+  const collection = await createDelegatedCollection(umi, delegate.publicKey);
+
+  // When the delegate creates a recipe for it.
+  await initRecipe(umi, delegate, collection);
+
+  // Then the delegate administers the recipe.
+  const recipe = findRecipeV1Pda(umi, { collection });
+  t.like(await fetchRecipeV1(umi, recipe), {
+    collection,
+    authority: delegate.publicKey,
+  });
+});
+
+test('a key that is not an update delegate cannot create a recipe', async (t) => {
+  // Given a collection delegating updates to an operational key.
+  const umi = await createUmi();
+  const delegate = await createFundedSigner(umi);
+  const collection = await createDelegatedCollection(umi, delegate.publicKey);
+
+  // When another key tries to create a recipe for it.
+  const stranger = await createFundedSigner(umi);
+  const result = initRecipe(umi, stranger, collection);
+
+  // Then the recipe is rejected.
+  await t.throwsAsync(result, { name: 'InvalidCollectionAuthority' });
+  const recipe = findRecipeV1Pda(umi, { collection });
+  t.false(await umi.rpc.accountExists(publicKey(recipe)));
+});
+
+test('an update delegate of the collection can update its recipe', async (t) => {
+  // Given a recipe created by the collection update authority.
+  const umi = await createUmi();
+  const delegate = await createFundedSigner(umi);
+  const collection = await createDelegatedCollection(umi, delegate.publicKey);
+  await initRecipe(umi, umi.identity, collection);
+  const recipe = findRecipeV1Pda(umi, { collection });
+  const { feeLocation } = await fetchRecipeV1(umi, recipe);
+
+  // When the update delegate changes its capture amount.
+  await updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    authority: delegate,
+    collection,
+    feeLocation,
+    captureAmount: 7,
+  }).sendAndConfirm(umi);
+
+  // Then the change is applied.
+  t.like(await fetchRecipeV1(umi, recipe), {
+    authority: umi.identity.publicKey,
+    captureAmount: 7n,
+  });
+});
+
+test('an update delegate cannot update a recipe handed over outside the collection', async (t) => {
+  // Given a recipe of a delegated collection handed over to an outside key.
+  const umi = await createUmi();
+  const delegate = await createFundedSigner(umi);
+  const collection = await createDelegatedCollection(umi, delegate.publicKey);
+  await initRecipe(umi, umi.identity, collection);
+  const recipe = findRecipeV1Pda(umi, { collection });
+  const { feeLocation } = await fetchRecipeV1(umi, recipe);
+  await handOverRecipe(umi, recipe);
+
+  // When the update delegate tries to change it.
+  const result = updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    authority: delegate,
+    collection,
+    feeLocation,
+    captureAmount: 7,
+  }).sendAndConfirm(umi);
+
+  // Then the update is rejected.
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+});
+
+test('an update delegate of the collection can create a v1 escrow', async (t) => {
+  // Given a collection delegating updates to an operational key.
+  const umi = await createUmi();
+  const delegate = await createFundedSigner(umi);
+  const collection = await createDelegatedCollection(umi, delegate.publicKey);
+  const tokenMint = await createFungibleToken(umi);
+  const escrow = umi.eddsa.findPda(MPL_HYBRID_PROGRAM_ID, [
+    string({ size: 'variable' }).serialize('escrow'),
+    publicKeySerializer().serialize(collection),
+  ]);
+
+  // When the delegate creates a v1 escrow for it.
+  await initEscrowV1(umi, {
+    escrow,
+    authority: delegate,
+    collection,
+    token: tokenMint.publicKey,
+    feeLocation: generateSigner(umi).publicKey,
+    name: 'Test Escrow',
+    uri: 'www.test.com/',
+    max: 9,
+    min: 0,
+    amount: 5,
+    feeAmount: 1,
+    path: buildPath([Path.NoRerollMetadata]),
+    solFeeAmount: 1_000_000n,
+  }).sendAndConfirm(umi);
+
+  // Then the delegate administers the escrow.
+  t.like(await fetchEscrowV1(umi, escrow), {
+    collection,
+    authority: delegate.publicKey,
+  });
+});
+
+test('an update delegate of the collection can initialize nft data', async (t) => {
+  // Given an asset of a collection delegating updates to an operational key.
+  const umi = await createUmi();
+  const delegate = await createFundedSigner(umi);
+  const collection = await createDelegatedCollection(umi, delegate.publicKey);
+  const asset = generateSigner(umi);
+  await create(umi, {
+    asset,
+    collection: await fetchCollection(umi, collection),
+    ...DEFAULT_ASSET,
+  }).sendAndConfirm(umi);
+  const tokenMint = await createFungibleToken(umi);
+  const nftData = umi.eddsa.findPda(MPL_HYBRID_PROGRAM_ID, [
+    string({ size: 'variable' }).serialize('nft'),
+    publicKeySerializer().serialize(asset.publicKey),
+  ]);
+
+  // When the delegate initializes the asset's nft data.
+  await initNftDataV1(umi, {
+    nftData,
+    authority: delegate,
+    asset: asset.publicKey,
+    collection,
+    token: tokenMint.publicKey,
+    feeLocation: generateSigner(umi).publicKey,
+    name: 'Test Escrow',
+    uri: 'www.test.com/',
+    max: 2,
+    min: 1,
+    amount: 3,
+    feeAmount: 4,
+    path: Path.RerollMetadata,
+    solFeeAmount: 5,
+  }).sendAndConfirm(umi);
+
+  // Then the delegate administers the nft data.
+  t.like(await fetchNftDataV1(umi, nftData), {
+    authority: delegate.publicKey,
+    token: tokenMint.publicKey,
+  });
+});
@@ -14,6 +14,7 @@ import test from 'ava';
 import {
   buildPath,
   captureV2,
+  Currency,
   EscrowV2,
   fetchEscrowV2,
   fetchRecipeV1,
@@ -21,6 +22,7 @@ import {
   initRecipeV1,
   MPL_HYBRID_PROGRAM_ID,
   Path,
+  RecipeKind,
   updateRecipeV1,
 } from '../../src';
 import { createCoreCollection, createUmi } from '../_setup';
@@ -85,12 +87,16 @@ test('it can update a recipe basic data', async (t) => {
     uri: 'www.test.com/',
     max: 9,
     min: 0,
-    amount: 5,
+    captureAmount: 5,
+    releaseAmount: 5,
     feeAmountCapture: 1,
     feeAmountRelease: 1,
     solFeeAmountCapture: 890_880n,
     solFeeAmountRelease: 100_000n,
     path: buildPath([Path.RerollMetadata]),
+    currency: Currency.Token,
+    kind: RecipeKind.Collection,
+    quoteSigner: null,
   }).sendAndConfirm(umi);
 
   const recipeData = await fetchRecipeV1(umi, recipe);
@@ -104,7 +110,8 @@ test('it can update a recipe basic data', async (t) => {
     uri: 'www.test.com/',
     max: 9n,
     min: 0n,
-    amount: 5n,
+    captureAmount: 5n,
+    releaseAmount: 5n,
     feeAmountCapture: 1n,
     feeAmountRelease: 1n,
     solFeeAmountCapture: 890_880n,
@@ -123,12 +130,26 @@ test('it can update a recipe basic data', async (t) => {
     uri: 'www.test.com/updated',
     max: 20n,
     min: 0n,
-    amount: 10n,
+    captureAmount: 10n,
+    releaseAmount: 10n,
     feeAmountCapture: 2,
     feeAmountRelease: 2,
     solFeeAmountCapture: 990_880n,
     solFeeAmountRelease: 200_000n,
     path: buildPath([Path.NoRerollMetadata]),
+    pricing: null,
+    inventory: null,
+    quoteSigner: null,
+    captureWindow: null,
+    releaseWindow: null,
+    walletLimits: null,
+    circuitBreaker: null,
+    assetCooldown: null,
+    loyalty: null,
+    timelockSlots: null,
+    assetRoot: null,
+    walletRoot: null,
+    gate: null,
   }).sendAndConfirm(umi);
 
   const updatedRecipeData = await fetchRecipeV1(umi, recipe);
@@ -141,7 +162,8 @@ test('it can update a recipe basic data', async (t) => {
     uri: 'www.test.com/updated',
     max: 20n,
     min: 0n,
-    amount: 10n,
+    captureAmount: 10n,
+    releaseAmount: 10n,
     feeAmountCapture: 2n,
     feeAmountRelease: 2n,
     solFeeAmountCapture: 990_880n,
@@ -213,12 +235,16 @@ test('it can update a recipe path if recipe swap count === 1', async (t) => {
     uri: 'www.test.com/',
     max: 9,
     min: 0,
-    amount: 5,
+    captureAmount: 5,
+    releaseAmount: 5,
     feeAmountCapture: 1,
     feeAmountRelease: 1,
     solFeeAmountCapture: 890_880n,
     solFeeAmountRelease: 100_000n,
     path: buildPath([Path.RerollMetadata]),
+    currency: Currency.Token,
+    kind: RecipeKind.Collection,
+    quoteSigner: null,
   }).sendAndConfirm(umi);
 
   const recipeData = await fetchRecipeV1(umi, recipe);
@@ -232,7 +258,8 @@ test('it can update a recipe path if recipe swap count === 1', async (t) => {
     uri: 'www.test.com/',
     max: 9n,
     min: 0n,
-    amount: 5n,
+    captureAmount: 5n,
+    releaseAmount: 5n,
     feeAmountCapture: 1n,
     feeAmountRelease: 1n,
     solFeeAmountCapture: 890_880n,
@@ -251,12 +278,26 @@ test('it can update a recipe path if recipe swap count === 1', async (t) => {
     uri: 'www.test.com/',
     max: 9,
     min: 0,
-    amount: 5,
+    captureAmount: 5,
+    releaseAmount: 5,
     feeAmountCapture: 1,
     feeAmountRelease: 1,
     solFeeAmountCapture: 890_880n,
     solFeeAmountRelease: 100_000n,
     path: buildPath([Path.NoRerollMetadata]),
+    pricing: null,
+    inventory: null,
+    quoteSigner: null,
+    captureWindow: null,
+    releaseWindow: null,
+    walletLimits: null,
+    circuitBreaker: null,
+    assetCooldown: null,
+    loyalty: null,
+    timelockSlots: null,
+    assetRoot: null,
+    walletRoot: null,
+    gate: null,
   }).sendAndConfirm(umi);
 
   const updatedRecipeData = await fetchRecipeV1(umi, recipe);
@@ -270,7 +311,8 @@ test('it can update a recipe path if recipe swap count === 1', async (t) => {
     uri: 'www.test.com/',
     max: 9n,
     min: 0n,
-    amount: 5n,
+    captureAmount: 5n,
+    releaseAmount: 5n,
     feeAmountCapture: 1n,
     feeAmountRelease: 1n,
     solFeeAmountCapture: 890_880n,
@@ -342,12 +384,16 @@ test('it fails to update a recipe path if recipe swap count > 1', async (t) => {
     uri: 'www.test.com/',
     max: 9,
     min: 0,
-    amount: 5,
+    captureAmount: 5,
+    releaseAmount: 5,
     feeAmountCapture: 1,
     feeAmountRelease: 1,
     solFeeAmountCapture: 890_880n,
     solFeeAmountRelease: 100_000n,
     path: buildPath([Path.RerollMetadata]),
+    currency: Currency.Token,
+    kind: RecipeKind.Collection,
+    quoteSigner: null,
   }).sendAndConfirm(umi);
 
   const recipeData = await fetchRecipeV1(umi, recipe);
@@ -361,7 +407,8 @@ test('it fails to update a recipe path if recipe swap count > 1', async (t) => {
     uri: 'www.test.com/',
     max: 9n,
     min: 0n,
-    amount: 5n,
+    captureAmount: 5n,
+    releaseAmount: 5n,
     feeAmountCapture: 1n,
     feeAmountRelease: 1n,
     solFeeAmountCapture: 890_880n,
@@ -400,6 +447,8 @@ test('it fails to update a recipe path if recipe swap count > 1', async (t) => {
     collection: collection.publicKey,
     feeProjectAccount: feeLocation.publicKey,
     token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
   }).sendAndConfirm(umi);
 
   const escrowTokenAfter = await fetchDigitalAssetWithAssociatedToken(
@@ -436,12 +485,26 @@ test('it fails to update a recipe path if recipe swap count > 1', async (t) => {
     uri: 'www.test.com/',
     max: 9n,
     min: 0n,
-    amount: 5n,
+    captureAmount: 5n,
+    releaseAmount: 5n,
     feeAmountCapture: 1n,
     feeAmountRelease: 1n,
     solFeeAmountCapture: 890_880n,
     solFeeAmountRelease: 100_000n,
     path: buildPath([Path.NoRerollMetadata]),
+    pricing: null,
+    inventory: null,
+    quoteSigner: null,
+    captureWindow: null,
+    releaseWindow: null,
+    walletLimits: null,
+    circuitBreaker: null,
+    assetCooldown: null,
+    loyalty: null,
+    timelockSlots: null,
+    assetRoot: null,
+    walletRoot: null,
+    gate: null,
   }).sendAndConfirm(umi);
 
   await t.throwsAsync(result, { name: 'PathCannotBeSet' });
@@ -0,0 +1,126 @@
+import test from 'ava';
+import { publicKey } from '@metaplex-foundation/umi';
+import {
+  captureV2,
+  closeWalletActivityV1,
+  fetchWalletActivityV1,
+  findWalletActivityV1Pda,
+  releaseV2,
+  updateRecipeV1,
+} from '../../src';
+import { createRecipe, createUmi, NO_RECIPE_CHANGES } from '../_setup';
+
+test('it limits the captures of a wallet', async (t) => {
+  // Given a recipe allowing a single capture per wallet.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, recipe, tokenMint } =
+    await createRecipe(umi);
+  await updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    walletLimits: {
+      windowSlots: 0,
+      maxCaptures: 1,
+      maxReleases: 0,
+      cooldownSlots: 0,
+    },
+  }).sendAndConfirm(umi);
+  const walletActivity = findWalletActivityV1Pda(umi, {
+    recipe: publicKey(recipe),
+    user: umi.identity.publicKey,
+  });
+  const capture = (asset: (typeof assets)[number]) =>
+    captureV2(umi, {
+      owner: umi.identity,
+      authority: recipe,
+      recipe,
+      escrow,
+      asset: asset.publicKey,
+      collection: collection.publicKey,
+      feeProjectAccount: feeLocation.publicKey,
+      token: tokenMint.publicKey,
+      walletActivity,
+      assetProof: [],
+      walletProof: [],
+    }).sendAndConfirm(umi);
+
+  // When the wallet captures a first asset.
+  await capture(assets[0]);
+
+  // Then its capture is tracked.
+  t.like(await fetchWalletActivityV1(umi, walletActivity), {
+    recipe: publicKey(recipe),
+    user: umi.identity.publicKey,
+    captures: 1,
+  });
+
+  // And it can't capture a second one.
+  await t.throwsAsync(capture(assets[1]), {
+    name: 'WalletCaptureLimitReached',
+  });
+});
+
+test('it enforces the cooldown between swaps of a wallet', async (t) => {
+  // Given a recipe with a long cooldown between the swaps of a wallet.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, recipe, tokenMint } =
+    await createRecipe(umi);
+  await updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    collection: collection.publicKey,
+    feeLocation: feeLocation.publicKey,
+    walletLimits: {
+      windowSlots: 0,
+      maxCaptures: 0,
+      maxReleases: 0,
+      cooldownSlots: 1_000_000,
+    },
+  }).sendAndConfirm(umi);
+  const walletActivity = findWalletActivityV1Pda(umi, {
+    recipe: publicKey(recipe),
+    user: umi.identity.publicKey,
+  });
+  await captureV2(umi, {
+    owner: umi.identity,
+    authority: recipe,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    walletActivity,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  // When the wallet releases the asset right away.
+  const result = releaseV2(umi, {
+    owner: umi.identity,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    walletActivity,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  // Then the release fails.
+  await t.throwsAsync(result, { name: 'WalletCooldownActive' });
+
+  // And the wallet can't reset its activity before the cooldown passed.
+  await t.throwsAsync(
+    closeWalletActivityV1(umi, {
+      walletActivity,
+      recipe,
+      user: umi.identity,
+    }).sendAndConfirm(umi),
+    { name: 'WalletActivityNotExpired' }
+  );
+});
@@ -0,0 +1,252 @@
+import test from 'ava';
+import { fetchDigitalAssetWithAssociatedToken } from '@metaplex-foundation/mpl-token-metadata';
+import { publicKey, sol } from '@metaplex-foundation/umi';
+import {
+  buildRoles,
+  captureV2,
+  Currency,
+  findOperatorsV1Pda,
+  findRecipeVaultV1Pda,
+  initOperatorsV1,
+  OperatorRole,
+  updateRecipeV1,
+  withdrawEscrowV1,
+} from '../../src';
+import {
+  createFundedSigner,
+  createFungibleToken,
+  createRecipe,
+  createUmi,
+  handOverRecipe,
+  NO_RECIPE_CHANGES,
+} from '../_setup';
+
+test('the recipe authority can withdraw the escrow reserve', async (t) => {
+  // Given a recipe whose escrow received tokens from a capture.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, recipe, tokenMint } =
+    await createRecipe(umi);
+  await captureV2(umi, {
+    owner: umi.identity,
+    authority: recipe,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  // When the recipe authority withdraws the capture amount.
+  await withdrawEscrowV1(umi, {
+    escrow,
+    recipe,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    amount: 5,
+  }).sendAndConfirm(umi);
+
+  // Then it is sent to the project fee wallet next to the capture fee.
+  const feeToken = await fetchDigitalAssetWithAssociatedToken(
+    umi,
+    tokenMint.publicKey,
+    feeLocation.publicKey
+  );
+  t.deepEqual(feeToken.token.amount, 6n);
+});
+
+test('an operator cannot repoint the recipe token', async (t) => {
+  // Given a recipe with an operator managing its pricing and withdrawals.
+  const umi = await createUmi();
+  const { collection, feeLocation, recipe } = await createRecipe(umi);
+  const operator = await createFundedSigner(umi);
+  await initOperatorsV1(umi, {
+    recipe,
+    collection: collection.publicKey,
+    entries: [
+      {
+        operator: operator.publicKey,
+        roles: buildRoles([OperatorRole.UpdatePricing, OperatorRole.Withdraw]),
+      },
+    ],
+  }).sendAndConfirm(umi);
+  const otherMint = await createFungibleToken(umi);
+
+  // When the operator tries to point the recipe at another mint.
+  const result = updateRecipeV1(umi, {
+    ...NO_RECIPE_CHANGES,
+    recipe,
+    authority: operator,
+    collection: collection.publicKey,
+    token: otherMint.publicKey,
+    feeLocation: feeLocation.publicKey,
+    operators: findOperatorsV1Pda(umi, { recipe: publicKey(recipe) }),
+  }).sendAndConfirm(umi);
+
+  // Then the update is rejected.
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+});
+
+test('operator roles granted by a previous authority lapse on handover', async (t) => {
+  // Given a recipe whose authority made itself a withdrawal operator.
+  const umi = await createUmi();
+  const { collection, feeLocation, recipe, tokenMint } =
+    await createRecipe(umi);
+  await initOperatorsV1(umi, {
+    recipe,
+    collection: collection.publicKey,
+    entries: [
+      {
+        operator: umi.identity.publicKey,
+        roles: buildRoles([OperatorRole.Withdraw]),
+      },
+    ],
+  }).sendAndConfirm(umi);
+
+  // And then handed the recipe over.
+  const { newEscrow } = await handOverRecipe(umi, recipe);
+
+  // When the previous authority tries to withdraw from the new escrow.
+  const result = withdrawEscrowV1(umi, {
+    escrow: newEscrow,
+    recipe,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    operators: findOperatorsV1Pda(umi, { recipe: publicKey(recipe) }),
+    amount: 1,
+  }).sendAndConfirm(umi);
+
+  // Then the withdrawal is rejected.
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+});
+
+test('a withdrawal operator cannot drain the shared escrow', async (t) => {
+  // Given a recipe whose escrow received tokens from a capture.
+  const umi = await createUmi();
+  const { assets, collection, escrow, feeLocation, recipe, tokenMint } =
+    await createRecipe(umi);
+  await captureV2(umi, {
+    owner: umi.identity,
+    authority: recipe,
+    recipe,
+    escrow,
+    asset: assets[0].publicKey,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  // And an operator managing its withdrawals.
+  const operator = await createFundedSigner(umi);
+  await initOperatorsV1(umi, {
+    recipe,
+    collection: collection.publicKey,
+    entries: [
+      {
+        operator: operator.publicKey,
+        roles: buildRoles([OperatorRole.Withdraw]),
+      },
+    ],
+  }).sendAndConfirm(umi);
+
+  // When the operator tries to withdraw tokens from the shared escrow.
+  const result = withdrawEscrowV1(umi, {
+    authority: operator,
+    escrow,
+    recipe,
+    collection: collection.publicKey,
+    feeProjectAccount: feeLocation.publicKey,
+    token: tokenMint.publicKey,
+    operators: findOperatorsV1Pda(umi, { recipe: publicKey(recipe) }),
+    amount: 5,
+  }).sendAndConfirm(umi);
+
+  // Then the withdrawal is rejected.
+  await t.throwsAsync(result, { name: 'InvalidAuthority' });
+});
+
+test('operators can only withdraw the vault of their own recipe with the withdraw role', async (t) => {
+  // Given two native SOL recipes of the same authority.
+  const umi = await createUmi();
+  const createSolRecipe = () =>
+    createRecipe(umi, {
+      token: undefined,
+      currency: Currency.NativeSol,
+      captureAmount: sol(1).basisPoints,
+      releaseAmount: sol(1).basisPoints,
+    });
+  const funded = await createSolRecipe();
+  const other = await createSolRecipe();
+  const vault = findRecipeVaultV1Pda(umi, { recipe: publicKey(funded.recipe) });
+
+  // And a capture that paid into the vault of the first one.
+  await captureV2(umi, {
+    owner: umi.identity,
+    authority: funded.recipe,
+    recipe: funded.recipe,
+    escrow: funded.escrow,
+    asset: funded.assets[0].publicKey,
+    collection: funded.collection.publicKey,
+    feeProjectAccount: funded.feeLocation.publicKey,
+    vault,
+    assetProof: [],
+    walletProof: [],
+  }).sendAndConfirm(umi);
+
+  // And operators granted the pricing role on the first recipe and the
+  // withdraw role on the second one.
+  const pricingOperator = await createFundedSigner(umi);
+  const withdrawOperator = await createFundedSigner(umi);
+  await initOperatorsV1(umi, {
+    recipe: funded.recipe,
+    collection: funded.collection.publicKey,
+    entries: [
+      {
+        operator: pricingOperator.publicKey,
+        roles: buildRoles([OperatorRole.UpdatePricing]),
+      },
+    ],
+  }).sendAndConfirm(umi);
+  await initOperatorsV1(umi, {
+    recipe: other.recipe,
+    collection: other.collection.publicKey,
+    entries: [
+      {
+        operator: withdrawOperator.publicKey,
+        roles: buildRoles([OperatorRole.Withdraw]),
+      },
+    ],
+  }).sendAndConfirm(umi);
+
+  // When either operator tries to withdraw the first recipe's vault.
+  const withdrawWith = (
+    operator: typeof pricingOperator,
+    operatorsOf: typeof funded
+  ) =>
+    withdrawEscrowV1(umi, {
+      authority: operator,
+      escrow: funded.escrow,
+      recipe: funded.recipe,
+      collection: funded.collection.publicKey,
+      feeProjectAccount: funded.feeLocation.publicKey,
+      vault,
+      operators: findOperatorsV1Pda(umi, {
+        recipe: publicKey(operatorsOf.recipe),
+      }),
+      amount: sol(1).basisPoints,
+    }).sendAndConfirm(umi);
+
+  // Then the wrong role and the other recipe's operators are both refused.
+  await t.throwsAsync(withdrawWith(pricingOperator, funded), {
+    name: 'InvalidAuthority',
+  });
+  await t.throwsAsync(withdrawWith(withdrawOperator, other), {
+    message: /ConstraintSeeds/,
+  });
+});
@@ -5,12 +5,36 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#asset_cooldown_v1;
+pub(crate) mod r#basket_v1;
+pub(crate) mod r#discount_policy_v1;
 pub(crate) mod r#escrow_v1;
 pub(crate) mod r#escrow_v2;
 pub(crate) mod r#nft_data_v1;
+pub(crate) mod r#operators_v1;
+pub(crate) mod r#payment_table_v1;
+pub(crate) mod r#pending_recipe_update_v1;
+pub(crate) mod r#protocol_config_v1;
+pub(crate) mod r#quote_nonce_v1;
+pub(crate) mod r#recipe_history_v1;
 pub(crate) mod r#recipe_v1;
+pub(crate) mod r#recipe_vault_v1;
+pub(crate) mod r#swap_receipt_v1;
+pub(crate) mod r#wallet_activity_v1;
 
+pub use self::r#asset_cooldown_v1::*;
+pub use self::r#basket_v1::*;
+pub use self::r#discount_policy_v1::*;
 pub use self::r#escrow_v1::*;
 pub use self::r#escrow_v2::*;
 pub use self::r#nft_data_v1::*;
+pub use self::r#operators_v1::*;
+pub use self::r#payment_table_v1::*;
+pub use self::r#pending_recipe_update_v1::*;
+pub use self::r#protocol_config_v1::*;
+pub use self::r#quote_nonce_v1::*;
+pub use self::r#recipe_history_v1::*;
 pub use self::r#recipe_v1::*;
+pub use self::r#recipe_vault_v1::*;
+pub use self::r#swap_receipt_v1::*;
+pub use self::r#wallet_activity_v1::*;
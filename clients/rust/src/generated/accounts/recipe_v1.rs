@@ -5,6 +5,18 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::AssetCooldown;
+use crate::generated::types::CircuitBreaker;
+use crate::generated::types::Currency;
+use crate::generated::types::FeeCaps;
+use crate::generated::types::LoyaltyRate;
+use crate::generated::types::OutflowWindow;
+use crate::generated::types::PricingCurve;
+use crate::generated::types::RecipeGate;
+use crate::generated::types::RecipeKind;
+use crate::generated::types::RecipeStats;
+use crate::generated::types::SwapWindow;
+use crate::generated::types::WalletLimits;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
 #[cfg(not(feature = "anchor"))]
@@ -41,7 +53,7 @@ pub struct RecipeV1 {
     pub uri: String,
     pub max: u64,
     pub min: u64,
-    pub amount: u64,
+    pub capture_amount: u64,
     pub fee_amount_capture: u64,
     pub sol_fee_amount_capture: u64,
     pub fee_amount_release: u64,
@@ -49,6 +61,53 @@ pub struct RecipeV1 {
     pub count: u64,
     pub path: u16,
     pub bump: u8,
+    pub release_amount: u64,
+    pub pricing: PricingCurve,
+    pub inventory: u64,
+    pub net_swaps: i64,
+    pub currency: Currency,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub quote_signer: Pubkey,
+    pub capture_window: SwapWindow,
+    pub release_window: SwapWindow,
+    pub wallet_limits: WalletLimits,
+    pub circuit_breaker: CircuitBreaker,
+    pub outflow_window: OutflowWindow,
+    pub paused: bool,
+    pub pause_reason: u16,
+    pub asset_cooldown: AssetCooldown,
+    pub stats: RecipeStats,
+    pub loyalty: LoyaltyRate,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub pending_authority: Pubkey,
+    pub timelock_slots: u64,
+    pub locked: u16,
+    pub fee_caps: FeeCaps,
+    pub kind: RecipeKind,
+    pub asset_root: [u8; 32],
+    pub wallet_root: [u8; 32],
+    pub gate: RecipeGate,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub previous_authority: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub legacy_authority: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub legacy_token: Pubkey,
 }
 
 impl RecipeV1 {
@@ -70,6 +70,150 @@ pub enum MplHybridError {
     /// 6019 (0x1783) - Release is blocked for this recipe
     #[error("Release is blocked for this recipe")]
     ReleaseBlocked,
+    /// 6020 (0x1784) - Discount exceeds 100%
+    #[error("Discount exceeds 100%")]
+    InvalidDiscount,
+    /// 6021 (0x1785) - Discount proof does not qualify for a discount
+    #[error("Discount proof does not qualify for a discount")]
+    InvalidDiscountProof,
+    /// 6022 (0x1786) - Pricing curve minimum exceeds its maximum
+    #[error("Pricing curve minimum exceeds its maximum")]
+    InvalidPricingCurve,
+    /// 6023 (0x1787) - Token account is required for this recipe
+    #[error("Token account is required for this recipe")]
+    MissingTokenAccount,
+    /// 6024 (0x1788) - Escrow balance is too low
+    #[error("Escrow balance is too low")]
+    InsufficientEscrowBalance,
+    /// 6025 (0x1789) - Path is not supported for this currency
+    #[error("Path is not supported for this currency")]
+    InvalidPathForCurrency,
+    /// 6026 (0x178A) - Basket entries are invalid
+    #[error("Basket entries are invalid")]
+    InvalidBasket,
+    /// 6027 (0x178B) - Basket account is required for this recipe
+    #[error("Basket account is required for this recipe")]
+    MissingBasketAccount,
+    /// 6028 (0x178C) - Payment table entries are invalid
+    #[error("Payment table entries are invalid")]
+    InvalidPaymentTable,
+    /// 6029 (0x178D) - Mint is not accepted by this recipe
+    #[error("Mint is not accepted by this recipe")]
+    PaymentMintNotAccepted,
+    /// 6030 (0x178E) - Price quote is invalid
+    #[error("Price quote is invalid")]
+    InvalidQuote,
+    /// 6031 (0x178F) - Price quote has expired
+    #[error("Price quote has expired")]
+    QuoteExpired,
+    /// 6032 (0x1790) - Price quote has already been used
+    #[error("Price quote has already been used")]
+    QuoteAlreadyUsed,
+    /// 6033 (0x1791) - Capture window has not opened yet
+    #[error("Capture window has not opened yet")]
+    CaptureNotOpen,
+    /// 6034 (0x1792) - Capture window has closed
+    #[error("Capture window has closed")]
+    CaptureClosed,
+    /// 6035 (0x1793) - Release window has not opened yet
+    #[error("Release window has not opened yet")]
+    ReleaseNotOpen,
+    /// 6036 (0x1794) - Release window has closed
+    #[error("Release window has closed")]
+    ReleaseClosed,
+    /// 6037 (0x1795) - Swap window must start before it ends
+    #[error("Swap window must start before it ends")]
+    InvalidSwapWindow,
+    /// 6038 (0x1796) - Wallet is still cooling down from its last swap
+    #[error("Wallet is still cooling down from its last swap")]
+    WalletCooldownActive,
+    /// 6039 (0x1797) - Wallet has reached its capture limit
+    #[error("Wallet has reached its capture limit")]
+    WalletCaptureLimitReached,
+    /// 6040 (0x1798) - Wallet has reached its release limit
+    #[error("Wallet has reached its release limit")]
+    WalletReleaseLimitReached,
+    /// 6041 (0x1799) - Wallet activity account is invalid
+    #[error("Wallet activity account is invalid")]
+    InvalidWalletActivity,
+    /// 6042 (0x179A) - Wallet activity window has not passed
+    #[error("Wallet activity window has not passed")]
+    WalletActivityNotExpired,
+    /// 6043 (0x179B) - Release limit of the recipe is reached for this window
+    #[error("Release limit of the recipe is reached for this window")]
+    CircuitBreakerTripped,
+    /// 6044 (0x179C) - Recipe is paused
+    #[error("Recipe is paused")]
+    RecipePaused,
+    /// 6045 (0x179D) - Protocol is paused
+    #[error("Protocol is paused")]
+    ProtocolPaused,
+    /// 6046 (0x179E) - Signer is not the protocol admin
+    #[error("Signer is not the protocol admin")]
+    InvalidProtocolAdmin,
+    /// 6047 (0x179F) - Account to pause is missing
+    #[error("Account to pause is missing")]
+    MissingPauseAccount,
+    /// 6048 (0x17A0) - Asset is still cooling down from its last swap
+    #[error("Asset is still cooling down from its last swap")]
+    AssetCooldownActive,
+    /// 6049 (0x17A1) - Asset cooldown account is invalid
+    #[error("Asset cooldown account is invalid")]
+    InvalidAssetCooldown,
+    /// 6050 (0x17A2) - Swap receipt account is invalid
+    #[error("Swap receipt account is invalid")]
+    InvalidSwapReceipt,
+    /// 6051 (0x17A3) - Signer is not the pending authority
+    #[error("Signer is not the pending authority")]
+    InvalidPendingAuthority,
+    /// 6052 (0x17A4) - Operator is listed more than once
+    #[error("Operator is listed more than once")]
+    DuplicateOperator,
+    /// 6053 (0x17A5) - Change must be queued behind the recipe timelock
+    #[error("Change must be queued behind the recipe timelock")]
+    TimelockActive,
+    /// 6054 (0x17A6) - A recipe update is already queued
+    #[error("A recipe update is already queued")]
+    RecipeUpdateAlreadyQueued,
+    /// 6055 (0x17A7) - Queued recipe update is not active yet
+    #[error("Queued recipe update is not active yet")]
+    TimelockNotElapsed,
+    /// 6056 (0x17A8) - Recipe field is locked
+    #[error("Recipe field is locked")]
+    RecipeFieldLocked,
+    /// 6057 (0x17A9) - Fee is above the recipe fee cap
+    #[error("Fee is above the recipe fee cap")]
+    FeeCapExceeded,
+    /// 6058 (0x17AA) - Fee caps can only be lowered
+    #[error("Fee caps can only be lowered")]
+    InvalidFeeCap,
+    /// 6059 (0x17AB) - Asset is not in the recipe allowlist
+    #[error("Asset is not in the recipe allowlist")]
+    AssetNotAllowlisted,
+    /// 6060 (0x17AC) - Wallet is not in the recipe allowlist
+    #[error("Wallet is not in the recipe allowlist")]
+    WalletNotAllowlisted,
+    /// 6061 (0x17AD) - Gate asset is invalid
+    #[error("Gate asset is invalid")]
+    InvalidGateAsset,
+    /// 6062 (0x17AE) - Not enough gate collection assets held
+    #[error("Not enough gate collection assets held")]
+    GateNotMet,
+    /// 6063 (0x17AF) - Inventory can only be set once before the first swap
+    #[error("Inventory can only be set once before the first swap")]
+    InventoryCannotBeSet,
+    /// 6064 (0x17B0) - Native SOL recipes need their vault account
+    #[error("Native SOL recipes need their vault account")]
+    MissingVaultAccount,
+    /// 6065 (0x17B1) - Quoted recipes can't be paid with an alternative payment
+    #[error("Quoted recipes can't be paid with an alternative payment")]
+    QuoteWithAlternativePayment,
+    /// 6066 (0x17B2) - Recipe configuration can't change while the recipe is timelocked
+    #[error("Recipe configuration can't change while the recipe is timelocked")]
+    RecipeTimelocked,
+    /// 6067 (0x17B3) - Lock fields include undefined recipe fields
+    #[error("Lock fields include undefined recipe fields")]
+    InvalidRecipeFields,
 }
 
 impl solana_program::program_error::PrintProgramError for MplHybridError {
@@ -0,0 +1,578 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct CancelRecipeUpdateV1 {
+    pub pending_update: solana_program::pubkey::Pubkey,
+
+    pub recipe: solana_program::pubkey::Pubkey,
+
+    pub authority: solana_program::pubkey::Pubkey,
+
+    pub collection: solana_program::pubkey::Pubkey,
+
+    pub queued_by: solana_program::pubkey::Pubkey,
+
+    pub operators: Option<solana_program::pubkey::Pubkey>,
+
+    pub event_authority: solana_program::pubkey::Pubkey,
+
+    pub program: solana_program::pubkey::Pubkey,
+}
+
+impl CancelRecipeUpdateV1 {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.pending_update,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.recipe,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.authority,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.collection,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.queued_by,
+            false,
+        ));
+        if let Some(operators) = self.operators {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                operators, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = CancelRecipeUpdateV1InstructionData::new()
+            .try_to_vec()
+            .unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_HYBRID_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct CancelRecipeUpdateV1InstructionData {
+    discriminator: [u8; 8],
+}
+
+impl CancelRecipeUpdateV1InstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: [155, 96, 193, 171, 86, 38, 6, 81],
+        }
+    }
+}
+
+/// Instruction builder for `CancelRecipeUpdateV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` pending_update
+///   1. `[]` recipe
+///   2. `[signer]` authority
+///   3. `[]` collection
+///   4. `[writable]` queued_by
+///   5. `[optional]` operators
+///   6. `[optional]` event_authority (default to `8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc`)
+///   7. `[optional]` program (default to `MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb`)
+#[derive(Default)]
+pub struct CancelRecipeUpdateV1Builder {
+    pending_update: Option<solana_program::pubkey::Pubkey>,
+    recipe: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    collection: Option<solana_program::pubkey::Pubkey>,
+    queued_by: Option<solana_program::pubkey::Pubkey>,
+    operators: Option<solana_program::pubkey::Pubkey>,
+    event_authority: Option<solana_program::pubkey::Pubkey>,
+    program: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl CancelRecipeUpdateV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn pending_update(&mut self, pending_update: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.pending_update = Some(pending_update);
+        self
+    }
+    #[inline(always)]
+    pub fn recipe(&mut self, recipe: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.recipe = Some(recipe);
+        self
+    }
+    #[inline(always)]
+    pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    #[inline(always)]
+    pub fn collection(&mut self, collection: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.collection = Some(collection);
+        self
+    }
+    #[inline(always)]
+    pub fn queued_by(&mut self, queued_by: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.queued_by = Some(queued_by);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn operators(&mut self, operators: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.operators = operators;
+        self
+    }
+    /// `[optional account, default to '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc']`
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    /// `[optional account, default to 'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = CancelRecipeUpdateV1 {
+            pending_update: self.pending_update.expect("pending_update is not set"),
+            recipe: self.recipe.expect("recipe is not set"),
+            authority: self.authority.expect("authority is not set"),
+            collection: self.collection.expect("collection is not set"),
+            queued_by: self.queued_by.expect("queued_by is not set"),
+            operators: self.operators,
+            event_authority: self.event_authority.unwrap_or(solana_program::pubkey!(
+                "8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc"
+            )),
+            program: self.program.unwrap_or(solana_program::pubkey!(
+                "MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb"
+            )),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `cancel_recipe_update_v1` CPI accounts.
+pub struct CancelRecipeUpdateV1CpiAccounts<'a, 'b> {
+    pub pending_update: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub recipe: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub collection: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub queued_by: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub operators: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `cancel_recipe_update_v1` CPI instruction.
+pub struct CancelRecipeUpdateV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub pending_update: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub recipe: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub collection: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub queued_by: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub operators: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> CancelRecipeUpdateV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: CancelRecipeUpdateV1CpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            pending_update: accounts.pending_update,
+            recipe: accounts.recipe,
+            authority: accounts.authority,
+            collection: accounts.collection,
+            queued_by: accounts.queued_by,
+            operators: accounts.operators,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.pending_update.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.recipe.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.authority.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.collection.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.queued_by.key,
+            false,
+        ));
+        if let Some(operators) = self.operators {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *operators.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let data = CancelRecipeUpdateV1InstructionData::new()
+            .try_to_vec()
+            .unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_HYBRID_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.pending_update.clone());
+        account_infos.push(self.recipe.clone());
+        account_infos.push(self.authority.clone());
+        account_infos.push(self.collection.clone());
+        account_infos.push(self.queued_by.clone());
+        if let Some(operators) = self.operators {
+            account_infos.push(operators.clone());
+        }
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `CancelRecipeUpdateV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` pending_update
+///   1. `[]` recipe
+///   2. `[signer]` authority
+///   3. `[]` collection
+///   4. `[writable]` queued_by
+///   5. `[optional]` operators
+///   6. `[]` event_authority
+///   7. `[]` program
+pub struct CancelRecipeUpdateV1CpiBuilder<'a, 'b> {
+    instruction: Box<CancelRecipeUpdateV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> CancelRecipeUpdateV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(CancelRecipeUpdateV1CpiBuilderInstruction {
+            __program: program,
+            pending_update: None,
+            recipe: None,
+            authority: None,
+            collection: None,
+            queued_by: None,
+            operators: None,
+            event_authority: None,
+            program: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn pending_update(
+        &mut self,
+        pending_update: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.pending_update = Some(pending_update);
+        self
+    }
+    #[inline(always)]
+    pub fn recipe(
+        &mut self,
+        recipe: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.recipe = Some(recipe);
+        self
+    }
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    #[inline(always)]
+    pub fn collection(
+        &mut self,
+        collection: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.collection = Some(collection);
+        self
+    }
+    #[inline(always)]
+    pub fn queued_by(
+        &mut self,
+        queued_by: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.queued_by = Some(queued_by);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn operators(
+        &mut self,
+        operators: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.operators = operators;
+        self
+    }
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(
+        &mut self,
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = CancelRecipeUpdateV1Cpi {
+            __program: self.instruction.__program,
+
+            pending_update: self
+                .instruction
+                .pending_update
+                .expect("pending_update is not set"),
+
+            recipe: self.instruction.recipe.expect("recipe is not set"),
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            collection: self.instruction.collection.expect("collection is not set"),
+
+            queued_by: self.instruction.queued_by.expect("queued_by is not set"),
+
+            operators: self.instruction.operators,
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct CancelRecipeUpdateV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    pending_update: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    recipe: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    queued_by: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    operators: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -43,6 +43,12 @@ pub struct CaptureV1 {
     pub token_program: solana_program::pubkey::Pubkey,
 
     pub associated_token_program: solana_program::pubkey::Pubkey,
+
+    pub protocol_config: solana_program::pubkey::Pubkey,
+
+    pub event_authority: solana_program::pubkey::Pubkey,
+
+    pub program: solana_program::pubkey::Pubkey,
 }
 
 impl CaptureV1 {
@@ -54,7 +60,7 @@ impl CaptureV1 {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(16 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(19 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.owner, true,
         ));
@@ -116,6 +122,18 @@ impl CaptureV1 {
             self.associated_token_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.protocol_config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let data = CaptureV1InstructionData::new().try_to_vec().unwrap();
 
@@ -161,6 +179,9 @@ impl CaptureV1InstructionData {
 ///   13. `[optional]` system_program (default to `11111111111111111111111111111111`)
 ///   14. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
 ///   15. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+///   16. `[]` protocol_config
+///   17. `[optional]` event_authority (default to `8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc`)
+///   18. `[optional]` program (default to `MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb`)
 #[derive(Default)]
 pub struct CaptureV1Builder {
     owner: Option<solana_program::pubkey::Pubkey>,
@@ -179,6 +200,9 @@ pub struct CaptureV1Builder {
     system_program: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
     associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    protocol_config: Option<solana_program::pubkey::Pubkey>,
+    event_authority: Option<solana_program::pubkey::Pubkey>,
+    program: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -297,6 +321,29 @@ impl CaptureV1Builder {
         self.associated_token_program = Some(associated_token_program);
         self
     }
+    #[inline(always)]
+    pub fn protocol_config(
+        &mut self,
+        protocol_config: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.protocol_config = Some(protocol_config);
+        self
+    }
+    /// `[optional account, default to '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc']`
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    /// `[optional account, default to 'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -354,6 +401,13 @@ impl CaptureV1Builder {
             associated_token_program: self.associated_token_program.unwrap_or(
                 solana_program::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
             ),
+            protocol_config: self.protocol_config.expect("protocol_config is not set"),
+            event_authority: self.event_authority.unwrap_or(solana_program::pubkey!(
+                "8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc"
+            )),
+            program: self.program.unwrap_or(solana_program::pubkey!(
+                "MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb"
+            )),
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -393,6 +447,12 @@ pub struct CaptureV1CpiAccounts<'a, 'b> {
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub protocol_config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `capture_v1` CPI instruction.
@@ -431,6 +491,12 @@ pub struct CaptureV1Cpi<'a, 'b> {
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub protocol_config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 impl<'a, 'b> CaptureV1Cpi<'a, 'b> {
@@ -456,6 +522,9 @@ impl<'a, 'b> CaptureV1Cpi<'a, 'b> {
             system_program: accounts.system_program,
             token_program: accounts.token_program,
             associated_token_program: accounts.associated_token_program,
+            protocol_config: accounts.protocol_config,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
         }
     }
     #[inline(always)]
@@ -491,7 +560,7 @@ impl<'a, 'b> CaptureV1Cpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(16 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(19 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.owner.key,
             true,
@@ -556,6 +625,18 @@ impl<'a, 'b> CaptureV1Cpi<'a, 'b> {
             *self.associated_token_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.protocol_config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -570,7 +651,7 @@ impl<'a, 'b> CaptureV1Cpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(16 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(19 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.authority.0.clone());
@@ -588,6 +669,9 @@ impl<'a, 'b> CaptureV1Cpi<'a, 'b> {
         account_infos.push(self.system_program.clone());
         account_infos.push(self.token_program.clone());
         account_infos.push(self.associated_token_program.clone());
+        account_infos.push(self.protocol_config.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -620,6 +704,9 @@ impl<'a, 'b> CaptureV1Cpi<'a, 'b> {
 ///   13. `[]` system_program
 ///   14. `[]` token_program
 ///   15. `[]` associated_token_program
+///   16. `[]` protocol_config
+///   17. `[]` event_authority
+///   18. `[]` program
 pub struct CaptureV1CpiBuilder<'a, 'b> {
     instruction: Box<CaptureV1CpiBuilderInstruction<'a, 'b>>,
 }
@@ -644,6 +731,9 @@ impl<'a, 'b> CaptureV1CpiBuilder<'a, 'b> {
             system_program: None,
             token_program: None,
             associated_token_program: None,
+            protocol_config: None,
+            event_authority: None,
+            program: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -768,6 +858,30 @@ impl<'a, 'b> CaptureV1CpiBuilder<'a, 'b> {
         self.instruction.associated_token_program = Some(associated_token_program);
         self
     }
+    #[inline(always)]
+    pub fn protocol_config(
+        &mut self,
+        protocol_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.protocol_config = Some(protocol_config);
+        self
+    }
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(
+        &mut self,
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -870,6 +984,18 @@ impl<'a, 'b> CaptureV1CpiBuilder<'a, 'b> {
                 .instruction
                 .associated_token_program
                 .expect("associated_token_program is not set"),
+
+            protocol_config: self
+                .instruction
+                .protocol_config
+                .expect("protocol_config is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -896,6 +1022,9 @@ struct CaptureV1CpiBuilderInstruction<'a, 'b> {
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    protocol_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
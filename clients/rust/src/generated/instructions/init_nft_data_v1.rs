@@ -25,6 +25,10 @@ pub struct InitNftDataV1 {
     pub fee_location: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+
+    pub event_authority: solana_program::pubkey::Pubkey,
+
+    pub program: solana_program::pubkey::Pubkey,
 }
 
 impl InitNftDataV1 {
@@ -40,7 +44,7 @@ impl InitNftDataV1 {
         args: InitNftDataV1InstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.nft_data,
             false,
@@ -67,6 +71,14 @@ impl InitNftDataV1 {
             self.system_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = InitNftDataV1InstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -120,6 +132,8 @@ pub struct InitNftDataV1InstructionArgs {
 ///   4. `[]` token
 ///   5. `[]` fee_location
 ///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   7. `[optional]` event_authority (default to `8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc`)
+///   8. `[optional]` program (default to `MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb`)
 #[derive(Default)]
 pub struct InitNftDataV1Builder {
     nft_data: Option<solana_program::pubkey::Pubkey>,
@@ -129,6 +143,8 @@ pub struct InitNftDataV1Builder {
     token: Option<solana_program::pubkey::Pubkey>,
     fee_location: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    event_authority: Option<solana_program::pubkey::Pubkey>,
+    program: Option<solana_program::pubkey::Pubkey>,
     name: Option<String>,
     uri: Option<String>,
     max: Option<u64>,
@@ -180,6 +196,21 @@ impl InitNftDataV1Builder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account, default to '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc']`
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    /// `[optional account, default to 'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
     #[inline(always)]
     pub fn name(&mut self, name: String) -> &mut Self {
         self.name = Some(name);
@@ -250,6 +281,12 @@ impl InitNftDataV1Builder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            event_authority: self.event_authority.unwrap_or(solana_program::pubkey!(
+                "8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc"
+            )),
+            program: self.program.unwrap_or(solana_program::pubkey!(
+                "MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb"
+            )),
         };
         let args = InitNftDataV1InstructionArgs {
             name: self.name.clone().expect("name is not set"),
@@ -284,6 +321,10 @@ pub struct InitNftDataV1CpiAccounts<'a, 'b> {
     pub fee_location: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `init_nft_data_v1` CPI instruction.
@@ -304,6 +345,10 @@ pub struct InitNftDataV1Cpi<'a, 'b> {
     pub fee_location: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: InitNftDataV1InstructionArgs,
 }
@@ -323,6 +368,8 @@ impl<'a, 'b> InitNftDataV1Cpi<'a, 'b> {
             token: accounts.token,
             fee_location: accounts.fee_location,
             system_program: accounts.system_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -359,7 +406,7 @@ impl<'a, 'b> InitNftDataV1Cpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.nft_data.key,
             false,
@@ -388,6 +435,14 @@ impl<'a, 'b> InitNftDataV1Cpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -404,7 +459,7 @@ impl<'a, 'b> InitNftDataV1Cpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(9 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.nft_data.clone());
         account_infos.push(self.authority.clone());
@@ -413,6 +468,8 @@ impl<'a, 'b> InitNftDataV1Cpi<'a, 'b> {
         account_infos.push(self.token.clone());
         account_infos.push(self.fee_location.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -436,6 +493,8 @@ impl<'a, 'b> InitNftDataV1Cpi<'a, 'b> {
 ///   4. `[]` token
 ///   5. `[]` fee_location
 ///   6. `[]` system_program
+///   7. `[]` event_authority
+///   8. `[]` program
 pub struct InitNftDataV1CpiBuilder<'a, 'b> {
     instruction: Box<InitNftDataV1CpiBuilderInstruction<'a, 'b>>,
 }
@@ -451,6 +510,8 @@ impl<'a, 'b> InitNftDataV1CpiBuilder<'a, 'b> {
             token: None,
             fee_location: None,
             system_program: None,
+            event_authority: None,
+            program: None,
             name: None,
             uri: None,
             max: None,
@@ -514,6 +575,22 @@ impl<'a, 'b> InitNftDataV1CpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(
+        &mut self,
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn name(&mut self, name: String) -> &mut Self {
         self.instruction.name = Some(name);
         self
@@ -634,6 +711,13 @@ impl<'a, 'b> InitNftDataV1CpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -652,6 +736,8 @@ struct InitNftDataV1CpiBuilderInstruction<'a, 'b> {
     token: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     fee_location: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     name: Option<String>,
     uri: Option<String>,
     max: Option<u64>,
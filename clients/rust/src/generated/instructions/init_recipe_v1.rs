@@ -5,10 +5,13 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::Currency;
+use crate::generated::types::RecipeKind;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
 #[cfg(not(feature = "anchor"))]
 use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
 
 /// Accounts.
 pub struct InitRecipeV1 {
@@ -18,17 +21,23 @@ pub struct InitRecipeV1 {
 
     pub collection: solana_program::pubkey::Pubkey,
 
-    pub token: solana_program::pubkey::Pubkey,
+    pub token: Option<solana_program::pubkey::Pubkey>,
 
     pub fee_location: solana_program::pubkey::Pubkey,
     /// The ATA for token fees to be stored
-    pub fee_ata: solana_program::pubkey::Pubkey,
+    pub fee_ata: Option<solana_program::pubkey::Pubkey>,
 
     pub system_program: solana_program::pubkey::Pubkey,
 
     pub token_program: solana_program::pubkey::Pubkey,
 
     pub associated_token_program: solana_program::pubkey::Pubkey,
+    /// The lamports of native SOL recipes are kept here
+    pub vault: Option<solana_program::pubkey::Pubkey>,
+
+    pub event_authority: solana_program::pubkey::Pubkey,
+
+    pub program: solana_program::pubkey::Pubkey,
 }
 
 impl InitRecipeV1 {
@@ -44,7 +53,7 @@ impl InitRecipeV1 {
         args: InitRecipeV1InstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.recipe,
             false,
@@ -57,17 +66,30 @@ impl InitRecipeV1 {
             self.collection,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            self.token, false,
-        ));
+        if let Some(token) = self.token {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                token, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.fee_location,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new(
-            self.fee_ata,
-            false,
-        ));
+        if let Some(fee_ata) = self.fee_ata {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                fee_ata, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.system_program,
             false,
@@ -80,6 +102,22 @@ impl InitRecipeV1 {
             self.associated_token_program,
             false,
         ));
+        if let Some(vault) = self.vault {
+            accounts.push(solana_program::instruction::AccountMeta::new(vault, false));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = InitRecipeV1InstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -116,12 +154,16 @@ pub struct InitRecipeV1InstructionArgs {
     pub uri: String,
     pub max: u64,
     pub min: u64,
-    pub amount: u64,
+    pub capture_amount: u64,
+    pub release_amount: u64,
     pub fee_amount_capture: u64,
     pub fee_amount_release: u64,
     pub sol_fee_amount_capture: u64,
     pub sol_fee_amount_release: u64,
     pub path: u16,
+    pub currency: Currency,
+    pub kind: RecipeKind,
+    pub quote_signer: Option<Pubkey>,
 }
 
 /// Instruction builder for `InitRecipeV1`.
@@ -131,12 +173,15 @@ pub struct InitRecipeV1InstructionArgs {
 ///   0. `[writable]` recipe
 ///   1. `[writable, signer]` authority
 ///   2. `[]` collection
-///   3. `[]` token
+///   3. `[optional]` token
 ///   4. `[]` fee_location
-///   5. `[writable]` fee_ata
+///   5. `[writable, optional]` fee_ata
 ///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
 ///   7. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
 ///   8. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+///   9. `[writable, optional]` vault
+///   10. `[optional]` event_authority (default to `8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc`)
+///   11. `[optional]` program (default to `MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb`)
 #[derive(Default)]
 pub struct InitRecipeV1Builder {
     recipe: Option<solana_program::pubkey::Pubkey>,
@@ -148,16 +193,23 @@ pub struct InitRecipeV1Builder {
     system_program: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
     associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    vault: Option<solana_program::pubkey::Pubkey>,
+    event_authority: Option<solana_program::pubkey::Pubkey>,
+    program: Option<solana_program::pubkey::Pubkey>,
     name: Option<String>,
     uri: Option<String>,
     max: Option<u64>,
     min: Option<u64>,
-    amount: Option<u64>,
+    capture_amount: Option<u64>,
+    release_amount: Option<u64>,
     fee_amount_capture: Option<u64>,
     fee_amount_release: Option<u64>,
     sol_fee_amount_capture: Option<u64>,
     sol_fee_amount_release: Option<u64>,
     path: Option<u16>,
+    currency: Option<Currency>,
+    kind: Option<RecipeKind>,
+    quote_signer: Option<Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -180,9 +232,10 @@ impl InitRecipeV1Builder {
         self.collection = Some(collection);
         self
     }
+    /// `[optional account]`
     #[inline(always)]
-    pub fn token(&mut self, token: solana_program::pubkey::Pubkey) -> &mut Self {
-        self.token = Some(token);
+    pub fn token(&mut self, token: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.token = token;
         self
     }
     #[inline(always)]
@@ -190,10 +243,11 @@ impl InitRecipeV1Builder {
         self.fee_location = Some(fee_location);
         self
     }
+    /// `[optional account]`
     /// The ATA for token fees to be stored
     #[inline(always)]
-    pub fn fee_ata(&mut self, fee_ata: solana_program::pubkey::Pubkey) -> &mut Self {
-        self.fee_ata = Some(fee_ata);
+    pub fn fee_ata(&mut self, fee_ata: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.fee_ata = fee_ata;
         self
     }
     /// `[optional account, default to '11111111111111111111111111111111']`
@@ -217,6 +271,28 @@ impl InitRecipeV1Builder {
         self.associated_token_program = Some(associated_token_program);
         self
     }
+    /// `[optional account]`
+    /// The lamports of native SOL recipes are kept here
+    #[inline(always)]
+    pub fn vault(&mut self, vault: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.vault = vault;
+        self
+    }
+    /// `[optional account, default to '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc']`
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    /// `[optional account, default to 'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
     #[inline(always)]
     pub fn name(&mut self, name: String) -> &mut Self {
         self.name = Some(name);
@@ -238,8 +314,13 @@ impl InitRecipeV1Builder {
         self
     }
     #[inline(always)]
-    pub fn amount(&mut self, amount: u64) -> &mut Self {
-        self.amount = Some(amount);
+    pub fn capture_amount(&mut self, capture_amount: u64) -> &mut Self {
+        self.capture_amount = Some(capture_amount);
+        self
+    }
+    #[inline(always)]
+    pub fn release_amount(&mut self, release_amount: u64) -> &mut Self {
+        self.release_amount = Some(release_amount);
         self
     }
     #[inline(always)]
@@ -267,6 +348,22 @@ impl InitRecipeV1Builder {
         self.path = Some(path);
         self
     }
+    #[inline(always)]
+    pub fn currency(&mut self, currency: Currency) -> &mut Self {
+        self.currency = Some(currency);
+        self
+    }
+    #[inline(always)]
+    pub fn kind(&mut self, kind: RecipeKind) -> &mut Self {
+        self.kind = Some(kind);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn quote_signer(&mut self, quote_signer: Pubkey) -> &mut Self {
+        self.quote_signer = Some(quote_signer);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -291,9 +388,9 @@ impl InitRecipeV1Builder {
             recipe: self.recipe.expect("recipe is not set"),
             authority: self.authority.expect("authority is not set"),
             collection: self.collection.expect("collection is not set"),
-            token: self.token.expect("token is not set"),
+            token: self.token,
             fee_location: self.fee_location.expect("fee_location is not set"),
-            fee_ata: self.fee_ata.expect("fee_ata is not set"),
+            fee_ata: self.fee_ata,
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
@@ -303,13 +400,27 @@ impl InitRecipeV1Builder {
             associated_token_program: self.associated_token_program.unwrap_or(
                 solana_program::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
             ),
+            vault: self.vault,
+            event_authority: self.event_authority.unwrap_or(solana_program::pubkey!(
+                "8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc"
+            )),
+            program: self.program.unwrap_or(solana_program::pubkey!(
+                "MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb"
+            )),
         };
         let args = InitRecipeV1InstructionArgs {
             name: self.name.clone().expect("name is not set"),
             uri: self.uri.clone().expect("uri is not set"),
             max: self.max.clone().expect("max is not set"),
             min: self.min.clone().expect("min is not set"),
-            amount: self.amount.clone().expect("amount is not set"),
+            capture_amount: self
+                .capture_amount
+                .clone()
+                .expect("capture_amount is not set"),
+            release_amount: self
+                .release_amount
+                .clone()
+                .expect("release_amount is not set"),
             fee_amount_capture: self
                 .fee_amount_capture
                 .clone()
@@ -327,6 +438,9 @@ impl InitRecipeV1Builder {
                 .clone()
                 .expect("sol_fee_amount_release is not set"),
             path: self.path.clone().expect("path is not set"),
+            currency: self.currency.clone().expect("currency is not set"),
+            kind: self.kind.clone().expect("kind is not set"),
+            quote_signer: self.quote_signer.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -341,17 +455,23 @@ pub struct InitRecipeV1CpiAccounts<'a, 'b> {
 
     pub collection: &'b solana_program::account_info::AccountInfo<'a>,
 
-    pub token: &'b solana_program::account_info::AccountInfo<'a>,
+    pub token: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub fee_location: &'b solana_program::account_info::AccountInfo<'a>,
     /// The ATA for token fees to be stored
-    pub fee_ata: &'b solana_program::account_info::AccountInfo<'a>,
+    pub fee_ata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The lamports of native SOL recipes are kept here
+    pub vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `init_recipe_v1` CPI instruction.
@@ -365,17 +485,23 @@ pub struct InitRecipeV1Cpi<'a, 'b> {
 
     pub collection: &'b solana_program::account_info::AccountInfo<'a>,
 
-    pub token: &'b solana_program::account_info::AccountInfo<'a>,
+    pub token: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub fee_location: &'b solana_program::account_info::AccountInfo<'a>,
     /// The ATA for token fees to be stored
-    pub fee_ata: &'b solana_program::account_info::AccountInfo<'a>,
+    pub fee_ata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The lamports of native SOL recipes are kept here
+    pub vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: InitRecipeV1InstructionArgs,
 }
@@ -397,6 +523,9 @@ impl<'a, 'b> InitRecipeV1Cpi<'a, 'b> {
             system_program: accounts.system_program,
             token_program: accounts.token_program,
             associated_token_program: accounts.associated_token_program,
+            vault: accounts.vault,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -433,7 +562,7 @@ impl<'a, 'b> InitRecipeV1Cpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(9 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(12 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.recipe.key,
             false,
@@ -446,28 +575,59 @@ impl<'a, 'b> InitRecipeV1Cpi<'a, 'b> {
             *self.collection.key,
             false,
         ));
+        if let Some(token) = self.token {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *token.key, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            *self.token.key,
+            *self.fee_location.key,
             false,
         ));
+        if let Some(fee_ata) = self.fee_ata {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *fee_ata.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            *self.fee_location.key,
+            *self.system_program.key,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new(
-            *self.fee_ata.key,
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
             false,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            *self.system_program.key,
+            *self.associated_token_program.key,
             false,
         ));
+        if let Some(vault) = self.vault {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *vault.key, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            *self.token_program.key,
+            *self.event_authority.key,
             false,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            *self.associated_token_program.key,
+            *self.program.key,
             false,
         ));
         remaining_accounts.iter().for_each(|remaining_account| {
@@ -486,17 +646,26 @@ impl<'a, 'b> InitRecipeV1Cpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(9 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(12 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.recipe.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.collection.clone());
-        account_infos.push(self.token.clone());
+        if let Some(token) = self.token {
+            account_infos.push(token.clone());
+        }
         account_infos.push(self.fee_location.clone());
-        account_infos.push(self.fee_ata.clone());
+        if let Some(fee_ata) = self.fee_ata {
+            account_infos.push(fee_ata.clone());
+        }
         account_infos.push(self.system_program.clone());
         account_infos.push(self.token_program.clone());
         account_infos.push(self.associated_token_program.clone());
+        if let Some(vault) = self.vault {
+            account_infos.push(vault.clone());
+        }
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -516,12 +685,15 @@ impl<'a, 'b> InitRecipeV1Cpi<'a, 'b> {
 ///   0. `[writable]` recipe
 ///   1. `[writable, signer]` authority
 ///   2. `[]` collection
-///   3. `[]` token
+///   3. `[optional]` token
 ///   4. `[]` fee_location
-///   5. `[writable]` fee_ata
+///   5. `[writable, optional]` fee_ata
 ///   6. `[]` system_program
 ///   7. `[]` token_program
 ///   8. `[]` associated_token_program
+///   9. `[writable, optional]` vault
+///   10. `[]` event_authority
+///   11. `[]` program
 pub struct InitRecipeV1CpiBuilder<'a, 'b> {
     instruction: Box<InitRecipeV1CpiBuilderInstruction<'a, 'b>>,
 }
@@ -539,16 +711,23 @@ impl<'a, 'b> InitRecipeV1CpiBuilder<'a, 'b> {
             system_program: None,
             token_program: None,
             associated_token_program: None,
+            vault: None,
+            event_authority: None,
+            program: None,
             name: None,
             uri: None,
             max: None,
             min: None,
-            amount: None,
+            capture_amount: None,
+            release_amount: None,
             fee_amount_capture: None,
             fee_amount_release: None,
             sol_fee_amount_capture: None,
             sol_fee_amount_release: None,
             path: None,
+            currency: None,
+            kind: None,
+            quote_signer: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -577,9 +756,13 @@ impl<'a, 'b> InitRecipeV1CpiBuilder<'a, 'b> {
         self.instruction.collection = Some(collection);
         self
     }
+    /// `[optional account]`
     #[inline(always)]
-    pub fn token(&mut self, token: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
-        self.instruction.token = Some(token);
+    pub fn token(
+        &mut self,
+        token: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token = token;
         self
     }
     #[inline(always)]
@@ -590,13 +773,14 @@ impl<'a, 'b> InitRecipeV1CpiBuilder<'a, 'b> {
         self.instruction.fee_location = Some(fee_location);
         self
     }
+    /// `[optional account]`
     /// The ATA for token fees to be stored
     #[inline(always)]
     pub fn fee_ata(
         &mut self,
-        fee_ata: &'b solana_program::account_info::AccountInfo<'a>,
+        fee_ata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ) -> &mut Self {
-        self.instruction.fee_ata = Some(fee_ata);
+        self.instruction.fee_ata = fee_ata;
         self
     }
     #[inline(always)]
@@ -623,6 +807,32 @@ impl<'a, 'b> InitRecipeV1CpiBuilder<'a, 'b> {
         self.instruction.associated_token_program = Some(associated_token_program);
         self
     }
+    /// `[optional account]`
+    /// The lamports of native SOL recipes are kept here
+    #[inline(always)]
+    pub fn vault(
+        &mut self,
+        vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.vault = vault;
+        self
+    }
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(
+        &mut self,
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
     #[inline(always)]
     pub fn name(&mut self, name: String) -> &mut Self {
         self.instruction.name = Some(name);
@@ -644,8 +854,13 @@ impl<'a, 'b> InitRecipeV1CpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
-    pub fn amount(&mut self, amount: u64) -> &mut Self {
-        self.instruction.amount = Some(amount);
+    pub fn capture_amount(&mut self, capture_amount: u64) -> &mut Self {
+        self.instruction.capture_amount = Some(capture_amount);
+        self
+    }
+    #[inline(always)]
+    pub fn release_amount(&mut self, release_amount: u64) -> &mut Self {
+        self.instruction.release_amount = Some(release_amount);
         self
     }
     #[inline(always)]
@@ -673,6 +888,22 @@ impl<'a, 'b> InitRecipeV1CpiBuilder<'a, 'b> {
         self.instruction.path = Some(path);
         self
     }
+    #[inline(always)]
+    pub fn currency(&mut self, currency: Currency) -> &mut Self {
+        self.instruction.currency = Some(currency);
+        self
+    }
+    #[inline(always)]
+    pub fn kind(&mut self, kind: RecipeKind) -> &mut Self {
+        self.instruction.kind = Some(kind);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn quote_signer(&mut self, quote_signer: Pubkey) -> &mut Self {
+        self.instruction.quote_signer = Some(quote_signer);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -719,7 +950,16 @@ impl<'a, 'b> InitRecipeV1CpiBuilder<'a, 'b> {
             uri: self.instruction.uri.clone().expect("uri is not set"),
             max: self.instruction.max.clone().expect("max is not set"),
             min: self.instruction.min.clone().expect("min is not set"),
-            amount: self.instruction.amount.clone().expect("amount is not set"),
+            capture_amount: self
+                .instruction
+                .capture_amount
+                .clone()
+                .expect("capture_amount is not set"),
+            release_amount: self
+                .instruction
+                .release_amount
+                .clone()
+                .expect("release_amount is not set"),
             fee_amount_capture: self
                 .instruction
                 .fee_amount_capture
@@ -741,6 +981,13 @@ impl<'a, 'b> InitRecipeV1CpiBuilder<'a, 'b> {
                 .clone()
                 .expect("sol_fee_amount_release is not set"),
             path: self.instruction.path.clone().expect("path is not set"),
+            currency: self
+                .instruction
+                .currency
+                .clone()
+                .expect("currency is not set"),
+            kind: self.instruction.kind.clone().expect("kind is not set"),
+            quote_signer: self.instruction.quote_signer.clone(),
         };
         let instruction = InitRecipeV1Cpi {
             __program: self.instruction.__program,
@@ -751,14 +998,14 @@ impl<'a, 'b> InitRecipeV1CpiBuilder<'a, 'b> {
 
             collection: self.instruction.collection.expect("collection is not set"),
 
-            token: self.instruction.token.expect("token is not set"),
+            token: self.instruction.token,
 
             fee_location: self
                 .instruction
                 .fee_location
                 .expect("fee_location is not set"),
 
-            fee_ata: self.instruction.fee_ata.expect("fee_ata is not set"),
+            fee_ata: self.instruction.fee_ata,
 
             system_program: self
                 .instruction
@@ -774,6 +1021,15 @@ impl<'a, 'b> InitRecipeV1CpiBuilder<'a, 'b> {
                 .instruction
                 .associated_token_program
                 .expect("associated_token_program is not set"),
+
+            vault: self.instruction.vault,
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -794,16 +1050,23 @@ struct InitRecipeV1CpiBuilderInstruction<'a, 'b> {
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     name: Option<String>,
     uri: Option<String>,
     max: Option<u64>,
     min: Option<u64>,
-    amount: Option<u64>,
+    capture_amount: Option<u64>,
+    release_amount: Option<u64>,
     fee_amount_capture: Option<u64>,
     fee_amount_release: Option<u64>,
     sol_fee_amount_capture: Option<u64>,
     sol_fee_amount_release: Option<u64>,
     path: Option<u16>,
+    currency: Option<Currency>,
+    kind: Option<RecipeKind>,
+    quote_signer: Option<Pubkey>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -23,7 +23,7 @@ pub struct MigrateTokensV1 {
     pub escrow_new_token_account: solana_program::pubkey::Pubkey,
 
     pub escrow_old_token_account: solana_program::pubkey::Pubkey,
-
+    /// The old escrow's token, the recipe token may have been repointed since.
     pub token: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
@@ -31,6 +31,12 @@ pub struct MigrateTokensV1 {
     pub token_program: solana_program::pubkey::Pubkey,
 
     pub associated_token_program: solana_program::pubkey::Pubkey,
+
+    pub recipe: solana_program::pubkey::Pubkey,
+
+    pub event_authority: solana_program::pubkey::Pubkey,
+
+    pub program: solana_program::pubkey::Pubkey,
 }
 
 impl MigrateTokensV1 {
@@ -46,7 +52,7 @@ impl MigrateTokensV1 {
         args: MigrateTokensV1InstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.authority,
             true,
@@ -86,6 +92,18 @@ impl MigrateTokensV1 {
             self.associated_token_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.recipe,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = MigrateTokensV1InstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -135,6 +153,9 @@ pub struct MigrateTokensV1InstructionArgs {
 ///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
 ///   8. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
 ///   9. `[]` associated_token_program
+///   10. `[]` recipe
+///   11. `[optional]` event_authority (default to `8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc`)
+///   12. `[optional]` program (default to `MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb`)
 #[derive(Default)]
 pub struct MigrateTokensV1Builder {
     authority: Option<solana_program::pubkey::Pubkey>,
@@ -147,6 +168,9 @@ pub struct MigrateTokensV1Builder {
     system_program: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
     associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    recipe: Option<solana_program::pubkey::Pubkey>,
+    event_authority: Option<solana_program::pubkey::Pubkey>,
+    program: Option<solana_program::pubkey::Pubkey>,
     amount: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -191,6 +215,7 @@ impl MigrateTokensV1Builder {
         self.escrow_old_token_account = Some(escrow_old_token_account);
         self
     }
+    /// The old escrow's token, the recipe token may have been repointed since.
     #[inline(always)]
     pub fn token(&mut self, token: solana_program::pubkey::Pubkey) -> &mut Self {
         self.token = Some(token);
@@ -217,6 +242,26 @@ impl MigrateTokensV1Builder {
         self
     }
     #[inline(always)]
+    pub fn recipe(&mut self, recipe: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.recipe = Some(recipe);
+        self
+    }
+    /// `[optional account, default to '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc']`
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    /// `[optional account, default to 'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.amount = Some(amount);
         self
@@ -262,6 +307,13 @@ impl MigrateTokensV1Builder {
             associated_token_program: self
                 .associated_token_program
                 .expect("associated_token_program is not set"),
+            recipe: self.recipe.expect("recipe is not set"),
+            event_authority: self.event_authority.unwrap_or(solana_program::pubkey!(
+                "8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc"
+            )),
+            program: self.program.unwrap_or(solana_program::pubkey!(
+                "MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb"
+            )),
         };
         let args = MigrateTokensV1InstructionArgs {
             amount: self.amount.clone().expect("amount is not set"),
@@ -284,7 +336,7 @@ pub struct MigrateTokensV1CpiAccounts<'a, 'b> {
     pub escrow_new_token_account: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub escrow_old_token_account: &'b solana_program::account_info::AccountInfo<'a>,
-
+    /// The old escrow's token, the recipe token may have been repointed since.
     pub token: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -292,6 +344,12 @@ pub struct MigrateTokensV1CpiAccounts<'a, 'b> {
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub recipe: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `migrate_tokens_v1` CPI instruction.
@@ -310,7 +368,7 @@ pub struct MigrateTokensV1Cpi<'a, 'b> {
     pub escrow_new_token_account: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub escrow_old_token_account: &'b solana_program::account_info::AccountInfo<'a>,
-
+    /// The old escrow's token, the recipe token may have been repointed since.
     pub token: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -318,6 +376,12 @@ pub struct MigrateTokensV1Cpi<'a, 'b> {
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub recipe: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: MigrateTokensV1InstructionArgs,
 }
@@ -340,6 +404,9 @@ impl<'a, 'b> MigrateTokensV1Cpi<'a, 'b> {
             system_program: accounts.system_program,
             token_program: accounts.token_program,
             associated_token_program: accounts.associated_token_program,
+            recipe: accounts.recipe,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -376,7 +443,7 @@ impl<'a, 'b> MigrateTokensV1Cpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(13 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.authority.key,
             true,
@@ -417,6 +484,18 @@ impl<'a, 'b> MigrateTokensV1Cpi<'a, 'b> {
             *self.associated_token_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.recipe.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -433,7 +512,7 @@ impl<'a, 'b> MigrateTokensV1Cpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(10 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(13 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.escrow_new.clone());
@@ -445,6 +524,9 @@ impl<'a, 'b> MigrateTokensV1Cpi<'a, 'b> {
         account_infos.push(self.system_program.clone());
         account_infos.push(self.token_program.clone());
         account_infos.push(self.associated_token_program.clone());
+        account_infos.push(self.recipe.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -471,6 +553,9 @@ impl<'a, 'b> MigrateTokensV1Cpi<'a, 'b> {
 ///   7. `[]` system_program
 ///   8. `[]` token_program
 ///   9. `[]` associated_token_program
+///   10. `[]` recipe
+///   11. `[]` event_authority
+///   12. `[]` program
 pub struct MigrateTokensV1CpiBuilder<'a, 'b> {
     instruction: Box<MigrateTokensV1CpiBuilderInstruction<'a, 'b>>,
 }
@@ -489,6 +574,9 @@ impl<'a, 'b> MigrateTokensV1CpiBuilder<'a, 'b> {
             system_program: None,
             token_program: None,
             associated_token_program: None,
+            recipe: None,
+            event_authority: None,
+            program: None,
             amount: None,
             __remaining_accounts: Vec::new(),
         });
@@ -542,6 +630,7 @@ impl<'a, 'b> MigrateTokensV1CpiBuilder<'a, 'b> {
         self.instruction.escrow_old_token_account = Some(escrow_old_token_account);
         self
     }
+    /// The old escrow's token, the recipe token may have been repointed since.
     #[inline(always)]
     pub fn token(&mut self, token: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
         self.instruction.token = Some(token);
@@ -572,6 +661,30 @@ impl<'a, 'b> MigrateTokensV1CpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn recipe(
+        &mut self,
+        recipe: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.recipe = Some(recipe);
+        self
+    }
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(
+        &mut self,
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    #[inline(always)]
     pub fn amount(&mut self, amount: u64) -> &mut Self {
         self.instruction.amount = Some(amount);
         self
@@ -657,6 +770,15 @@ impl<'a, 'b> MigrateTokensV1CpiBuilder<'a, 'b> {
                 .instruction
                 .associated_token_program
                 .expect("associated_token_program is not set"),
+
+            recipe: self.instruction.recipe.expect("recipe is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -678,6 +800,9 @@ struct MigrateTokensV1CpiBuilderInstruction<'a, 'b> {
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    recipe: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     amount: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
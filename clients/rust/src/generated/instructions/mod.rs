@@ -5,30 +5,78 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#accept_recipe_authority_v1;
+pub(crate) mod r#cancel_recipe_update_v1;
 pub(crate) mod r#capture_v1;
 pub(crate) mod r#capture_v2;
+pub(crate) mod r#close_wallet_activity_v1;
+pub(crate) mod r#execute_recipe_update_v1;
+pub(crate) mod r#init_basket_v1;
+pub(crate) mod r#init_discount_policy_v1;
 pub(crate) mod r#init_escrow_v1;
 pub(crate) mod r#init_escrow_v2;
 pub(crate) mod r#init_nft_data_v1;
+pub(crate) mod r#init_operators_v1;
+pub(crate) mod r#init_payment_table_v1;
+pub(crate) mod r#init_protocol_config_v1;
 pub(crate) mod r#init_recipe_v1;
+pub(crate) mod r#lock_recipe_v1;
+pub(crate) mod r#migrate_escrow_config_v1;
 pub(crate) mod r#migrate_nft_v1;
+pub(crate) mod r#migrate_recipe_v1;
 pub(crate) mod r#migrate_tokens_v1;
+pub(crate) mod r#propose_recipe_authority_v1;
+pub(crate) mod r#queue_recipe_update_v1;
 pub(crate) mod r#release_v1;
 pub(crate) mod r#release_v2;
+pub(crate) mod r#reset_circuit_breaker_v1;
+pub(crate) mod r#set_pause_v1;
+pub(crate) mod r#set_protocol_admin_v1;
+pub(crate) mod r#transfer_escrow_asset_v1;
+pub(crate) mod r#transfer_escrow_reserve_v1;
+pub(crate) mod r#update_basket_v1;
+pub(crate) mod r#update_discount_policy_v1;
 pub(crate) mod r#update_escrow_v1;
 pub(crate) mod r#update_new_data_v1;
+pub(crate) mod r#update_operators_v1;
+pub(crate) mod r#update_payment_table_v1;
 pub(crate) mod r#update_recipe_v1;
+pub(crate) mod r#withdraw_escrow_v1;
 
+pub use self::r#accept_recipe_authority_v1::*;
+pub use self::r#cancel_recipe_update_v1::*;
 pub use self::r#capture_v1::*;
 pub use self::r#capture_v2::*;
+pub use self::r#close_wallet_activity_v1::*;
+pub use self::r#execute_recipe_update_v1::*;
+pub use self::r#init_basket_v1::*;
+pub use self::r#init_discount_policy_v1::*;
 pub use self::r#init_escrow_v1::*;
 pub use self::r#init_escrow_v2::*;
 pub use self::r#init_nft_data_v1::*;
+pub use self::r#init_operators_v1::*;
+pub use self::r#init_payment_table_v1::*;
+pub use self::r#init_protocol_config_v1::*;
 pub use self::r#init_recipe_v1::*;
+pub use self::r#lock_recipe_v1::*;
+pub use self::r#migrate_escrow_config_v1::*;
 pub use self::r#migrate_nft_v1::*;
+pub use self::r#migrate_recipe_v1::*;
 pub use self::r#migrate_tokens_v1::*;
+pub use self::r#propose_recipe_authority_v1::*;
+pub use self::r#queue_recipe_update_v1::*;
 pub use self::r#release_v1::*;
 pub use self::r#release_v2::*;
+pub use self::r#reset_circuit_breaker_v1::*;
+pub use self::r#set_pause_v1::*;
+pub use self::r#set_protocol_admin_v1::*;
+pub use self::r#transfer_escrow_asset_v1::*;
+pub use self::r#transfer_escrow_reserve_v1::*;
+pub use self::r#update_basket_v1::*;
+pub use self::r#update_discount_policy_v1::*;
 pub use self::r#update_escrow_v1::*;
 pub use self::r#update_new_data_v1::*;
+pub use self::r#update_operators_v1::*;
+pub use self::r#update_payment_table_v1::*;
 pub use self::r#update_recipe_v1::*;
+pub use self::r#withdraw_escrow_v1::*;
@@ -0,0 +1,1141 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::AssetCooldown;
+use crate::generated::types::CircuitBreaker;
+use crate::generated::types::LoyaltyRate;
+use crate::generated::types::PricingCurve;
+use crate::generated::types::RecipeGate;
+use crate::generated::types::SwapWindow;
+use crate::generated::types::WalletLimits;
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+/// Accounts.
+pub struct QueueRecipeUpdateV1 {
+    pub pending_update: solana_program::pubkey::Pubkey,
+
+    pub recipe: solana_program::pubkey::Pubkey,
+
+    pub authority: solana_program::pubkey::Pubkey,
+
+    pub collection: solana_program::pubkey::Pubkey,
+
+    pub token: Option<solana_program::pubkey::Pubkey>,
+
+    pub fee_location: solana_program::pubkey::Pubkey,
+
+    pub operators: Option<solana_program::pubkey::Pubkey>,
+
+    pub system_program: solana_program::pubkey::Pubkey,
+
+    pub event_authority: solana_program::pubkey::Pubkey,
+
+    pub program: solana_program::pubkey::Pubkey,
+}
+
+impl QueueRecipeUpdateV1 {
+    pub fn instruction(
+        &self,
+        args: QueueRecipeUpdateV1InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: QueueRecipeUpdateV1InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.pending_update,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.recipe,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.authority,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.collection,
+            false,
+        ));
+        if let Some(token) = self.token {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                token, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.fee_location,
+            false,
+        ));
+        if let Some(operators) = self.operators {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                operators, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = QueueRecipeUpdateV1InstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_HYBRID_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct QueueRecipeUpdateV1InstructionData {
+    discriminator: [u8; 8],
+}
+
+impl QueueRecipeUpdateV1InstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: [24, 64, 139, 118, 46, 52, 131, 107],
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueueRecipeUpdateV1InstructionArgs {
+    pub name: Option<String>,
+    pub uri: Option<String>,
+    pub max: Option<u64>,
+    pub min: Option<u64>,
+    pub capture_amount: Option<u64>,
+    pub release_amount: Option<u64>,
+    pub fee_amount_capture: Option<u64>,
+    pub fee_amount_release: Option<u64>,
+    pub sol_fee_amount_capture: Option<u64>,
+    pub sol_fee_amount_release: Option<u64>,
+    pub path: Option<u16>,
+    pub pricing: Option<PricingCurve>,
+    pub inventory: Option<u64>,
+    pub quote_signer: Option<Pubkey>,
+    pub capture_window: Option<SwapWindow>,
+    pub release_window: Option<SwapWindow>,
+    pub wallet_limits: Option<WalletLimits>,
+    pub circuit_breaker: Option<CircuitBreaker>,
+    pub asset_cooldown: Option<AssetCooldown>,
+    pub loyalty: Option<LoyaltyRate>,
+    pub timelock_slots: Option<u64>,
+    pub asset_root: Option<[u8; 32]>,
+    pub wallet_root: Option<[u8; 32]>,
+    pub gate: Option<RecipeGate>,
+}
+
+/// Instruction builder for `QueueRecipeUpdateV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` pending_update
+///   1. `[]` recipe
+///   2. `[writable, signer]` authority
+///   3. `[]` collection
+///   4. `[optional]` token
+///   5. `[]` fee_location
+///   6. `[optional]` operators
+///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   8. `[optional]` event_authority (default to `8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc`)
+///   9. `[optional]` program (default to `MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb`)
+#[derive(Default)]
+pub struct QueueRecipeUpdateV1Builder {
+    pending_update: Option<solana_program::pubkey::Pubkey>,
+    recipe: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    collection: Option<solana_program::pubkey::Pubkey>,
+    token: Option<solana_program::pubkey::Pubkey>,
+    fee_location: Option<solana_program::pubkey::Pubkey>,
+    operators: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    event_authority: Option<solana_program::pubkey::Pubkey>,
+    program: Option<solana_program::pubkey::Pubkey>,
+    name: Option<String>,
+    uri: Option<String>,
+    max: Option<u64>,
+    min: Option<u64>,
+    capture_amount: Option<u64>,
+    release_amount: Option<u64>,
+    fee_amount_capture: Option<u64>,
+    fee_amount_release: Option<u64>,
+    sol_fee_amount_capture: Option<u64>,
+    sol_fee_amount_release: Option<u64>,
+    path: Option<u16>,
+    pricing: Option<PricingCurve>,
+    inventory: Option<u64>,
+    quote_signer: Option<Pubkey>,
+    capture_window: Option<SwapWindow>,
+    release_window: Option<SwapWindow>,
+    wallet_limits: Option<WalletLimits>,
+    circuit_breaker: Option<CircuitBreaker>,
+    asset_cooldown: Option<AssetCooldown>,
+    loyalty: Option<LoyaltyRate>,
+    timelock_slots: Option<u64>,
+    asset_root: Option<[u8; 32]>,
+    wallet_root: Option<[u8; 32]>,
+    gate: Option<RecipeGate>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl QueueRecipeUpdateV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn pending_update(&mut self, pending_update: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.pending_update = Some(pending_update);
+        self
+    }
+    #[inline(always)]
+    pub fn recipe(&mut self, recipe: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.recipe = Some(recipe);
+        self
+    }
+    #[inline(always)]
+    pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    #[inline(always)]
+    pub fn collection(&mut self, collection: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.collection = Some(collection);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn token(&mut self, token: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.token = token;
+        self
+    }
+    #[inline(always)]
+    pub fn fee_location(&mut self, fee_location: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.fee_location = Some(fee_location);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn operators(&mut self, operators: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.operators = operators;
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// `[optional account, default to '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc']`
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    /// `[optional account, default to 'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn name(&mut self, name: String) -> &mut Self {
+        self.name = Some(name);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn uri(&mut self, uri: String) -> &mut Self {
+        self.uri = Some(uri);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max(&mut self, max: u64) -> &mut Self {
+        self.max = Some(max);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn min(&mut self, min: u64) -> &mut Self {
+        self.min = Some(min);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn capture_amount(&mut self, capture_amount: u64) -> &mut Self {
+        self.capture_amount = Some(capture_amount);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn release_amount(&mut self, release_amount: u64) -> &mut Self {
+        self.release_amount = Some(release_amount);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn fee_amount_capture(&mut self, fee_amount_capture: u64) -> &mut Self {
+        self.fee_amount_capture = Some(fee_amount_capture);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn fee_amount_release(&mut self, fee_amount_release: u64) -> &mut Self {
+        self.fee_amount_release = Some(fee_amount_release);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn sol_fee_amount_capture(&mut self, sol_fee_amount_capture: u64) -> &mut Self {
+        self.sol_fee_amount_capture = Some(sol_fee_amount_capture);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn sol_fee_amount_release(&mut self, sol_fee_amount_release: u64) -> &mut Self {
+        self.sol_fee_amount_release = Some(sol_fee_amount_release);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn path(&mut self, path: u16) -> &mut Self {
+        self.path = Some(path);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn pricing(&mut self, pricing: PricingCurve) -> &mut Self {
+        self.pricing = Some(pricing);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn inventory(&mut self, inventory: u64) -> &mut Self {
+        self.inventory = Some(inventory);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn quote_signer(&mut self, quote_signer: Pubkey) -> &mut Self {
+        self.quote_signer = Some(quote_signer);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn capture_window(&mut self, capture_window: SwapWindow) -> &mut Self {
+        self.capture_window = Some(capture_window);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn release_window(&mut self, release_window: SwapWindow) -> &mut Self {
+        self.release_window = Some(release_window);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn wallet_limits(&mut self, wallet_limits: WalletLimits) -> &mut Self {
+        self.wallet_limits = Some(wallet_limits);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn circuit_breaker(&mut self, circuit_breaker: CircuitBreaker) -> &mut Self {
+        self.circuit_breaker = Some(circuit_breaker);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn asset_cooldown(&mut self, asset_cooldown: AssetCooldown) -> &mut Self {
+        self.asset_cooldown = Some(asset_cooldown);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn loyalty(&mut self, loyalty: LoyaltyRate) -> &mut Self {
+        self.loyalty = Some(loyalty);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn timelock_slots(&mut self, timelock_slots: u64) -> &mut Self {
+        self.timelock_slots = Some(timelock_slots);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn asset_root(&mut self, asset_root: [u8; 32]) -> &mut Self {
+        self.asset_root = Some(asset_root);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn wallet_root(&mut self, wallet_root: [u8; 32]) -> &mut Self {
+        self.wallet_root = Some(wallet_root);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn gate(&mut self, gate: RecipeGate) -> &mut Self {
+        self.gate = Some(gate);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = QueueRecipeUpdateV1 {
+            pending_update: self.pending_update.expect("pending_update is not set"),
+            recipe: self.recipe.expect("recipe is not set"),
+            authority: self.authority.expect("authority is not set"),
+            collection: self.collection.expect("collection is not set"),
+            token: self.token,
+            fee_location: self.fee_location.expect("fee_location is not set"),
+            operators: self.operators,
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            event_authority: self.event_authority.unwrap_or(solana_program::pubkey!(
+                "8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc"
+            )),
+            program: self.program.unwrap_or(solana_program::pubkey!(
+                "MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb"
+            )),
+        };
+        let args = QueueRecipeUpdateV1InstructionArgs {
+            name: self.name.clone(),
+            uri: self.uri.clone(),
+            max: self.max.clone(),
+            min: self.min.clone(),
+            capture_amount: self.capture_amount.clone(),
+            release_amount: self.release_amount.clone(),
+            fee_amount_capture: self.fee_amount_capture.clone(),
+            fee_amount_release: self.fee_amount_release.clone(),
+            sol_fee_amount_capture: self.sol_fee_amount_capture.clone(),
+            sol_fee_amount_release: self.sol_fee_amount_release.clone(),
+            path: self.path.clone(),
+            pricing: self.pricing.clone(),
+            inventory: self.inventory.clone(),
+            quote_signer: self.quote_signer.clone(),
+            capture_window: self.capture_window.clone(),
+            release_window: self.release_window.clone(),
+            wallet_limits: self.wallet_limits.clone(),
+            circuit_breaker: self.circuit_breaker.clone(),
+            asset_cooldown: self.asset_cooldown.clone(),
+            loyalty: self.loyalty.clone(),
+            timelock_slots: self.timelock_slots.clone(),
+            asset_root: self.asset_root.clone(),
+            wallet_root: self.wallet_root.clone(),
+            gate: self.gate.clone(),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `queue_recipe_update_v1` CPI accounts.
+pub struct QueueRecipeUpdateV1CpiAccounts<'a, 'b> {
+    pub pending_update: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub recipe: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub collection: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub fee_location: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub operators: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `queue_recipe_update_v1` CPI instruction.
+pub struct QueueRecipeUpdateV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub pending_update: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub recipe: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub collection: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub token: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub fee_location: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub operators: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: QueueRecipeUpdateV1InstructionArgs,
+}
+
+impl<'a, 'b> QueueRecipeUpdateV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: QueueRecipeUpdateV1CpiAccounts<'a, 'b>,
+        args: QueueRecipeUpdateV1InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            pending_update: accounts.pending_update,
+            recipe: accounts.recipe,
+            authority: accounts.authority,
+            collection: accounts.collection,
+            token: accounts.token,
+            fee_location: accounts.fee_location,
+            operators: accounts.operators,
+            system_program: accounts.system_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.pending_update.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.recipe.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.authority.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.collection.key,
+            false,
+        ));
+        if let Some(token) = self.token {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *token.key, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.fee_location.key,
+            false,
+        ));
+        if let Some(operators) = self.operators {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *operators.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_signer: remaining_account.1,
+                is_writable: remaining_account.2,
+            })
+        });
+        let mut data = QueueRecipeUpdateV1InstructionData::new()
+            .try_to_vec()
+            .unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_HYBRID_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(10 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.pending_update.clone());
+        account_infos.push(self.recipe.clone());
+        account_infos.push(self.authority.clone());
+        account_infos.push(self.collection.clone());
+        if let Some(token) = self.token {
+            account_infos.push(token.clone());
+        }
+        account_infos.push(self.fee_location.clone());
+        if let Some(operators) = self.operators {
+            account_infos.push(operators.clone());
+        }
+        account_infos.push(self.system_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `QueueRecipeUpdateV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` pending_update
+///   1. `[]` recipe
+///   2. `[writable, signer]` authority
+///   3. `[]` collection
+///   4. `[optional]` token
+///   5. `[]` fee_location
+///   6. `[optional]` operators
+///   7. `[]` system_program
+///   8. `[]` event_authority
+///   9. `[]` program
+pub struct QueueRecipeUpdateV1CpiBuilder<'a, 'b> {
+    instruction: Box<QueueRecipeUpdateV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> QueueRecipeUpdateV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(QueueRecipeUpdateV1CpiBuilderInstruction {
+            __program: program,
+            pending_update: None,
+            recipe: None,
+            authority: None,
+            collection: None,
+            token: None,
+            fee_location: None,
+            operators: None,
+            system_program: None,
+            event_authority: None,
+            program: None,
+            name: None,
+            uri: None,
+            max: None,
+            min: None,
+            capture_amount: None,
+            release_amount: None,
+            fee_amount_capture: None,
+            fee_amount_release: None,
+            sol_fee_amount_capture: None,
+            sol_fee_amount_release: None,
+            path: None,
+            pricing: None,
+            inventory: None,
+            quote_signer: None,
+            capture_window: None,
+            release_window: None,
+            wallet_limits: None,
+            circuit_breaker: None,
+            asset_cooldown: None,
+            loyalty: None,
+            timelock_slots: None,
+            asset_root: None,
+            wallet_root: None,
+            gate: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn pending_update(
+        &mut self,
+        pending_update: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.pending_update = Some(pending_update);
+        self
+    }
+    #[inline(always)]
+    pub fn recipe(
+        &mut self,
+        recipe: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.recipe = Some(recipe);
+        self
+    }
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    #[inline(always)]
+    pub fn collection(
+        &mut self,
+        collection: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.collection = Some(collection);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn token(
+        &mut self,
+        token: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token = token;
+        self
+    }
+    #[inline(always)]
+    pub fn fee_location(
+        &mut self,
+        fee_location: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.fee_location = Some(fee_location);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn operators(
+        &mut self,
+        operators: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.operators = operators;
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(
+        &mut self,
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn name(&mut self, name: String) -> &mut Self {
+        self.instruction.name = Some(name);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn uri(&mut self, uri: String) -> &mut Self {
+        self.instruction.uri = Some(uri);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn max(&mut self, max: u64) -> &mut Self {
+        self.instruction.max = Some(max);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn min(&mut self, min: u64) -> &mut Self {
+        self.instruction.min = Some(min);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn capture_amount(&mut self, capture_amount: u64) -> &mut Self {
+        self.instruction.capture_amount = Some(capture_amount);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn release_amount(&mut self, release_amount: u64) -> &mut Self {
+        self.instruction.release_amount = Some(release_amount);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn fee_amount_capture(&mut self, fee_amount_capture: u64) -> &mut Self {
+        self.instruction.fee_amount_capture = Some(fee_amount_capture);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn fee_amount_release(&mut self, fee_amount_release: u64) -> &mut Self {
+        self.instruction.fee_amount_release = Some(fee_amount_release);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn sol_fee_amount_capture(&mut self, sol_fee_amount_capture: u64) -> &mut Self {
+        self.instruction.sol_fee_amount_capture = Some(sol_fee_amount_capture);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn sol_fee_amount_release(&mut self, sol_fee_amount_release: u64) -> &mut Self {
+        self.instruction.sol_fee_amount_release = Some(sol_fee_amount_release);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn path(&mut self, path: u16) -> &mut Self {
+        self.instruction.path = Some(path);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn pricing(&mut self, pricing: PricingCurve) -> &mut Self {
+        self.instruction.pricing = Some(pricing);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn inventory(&mut self, inventory: u64) -> &mut Self {
+        self.instruction.inventory = Some(inventory);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn quote_signer(&mut self, quote_signer: Pubkey) -> &mut Self {
+        self.instruction.quote_signer = Some(quote_signer);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn capture_window(&mut self, capture_window: SwapWindow) -> &mut Self {
+        self.instruction.capture_window = Some(capture_window);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn release_window(&mut self, release_window: SwapWindow) -> &mut Self {
+        self.instruction.release_window = Some(release_window);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn wallet_limits(&mut self, wallet_limits: WalletLimits) -> &mut Self {
+        self.instruction.wallet_limits = Some(wallet_limits);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn circuit_breaker(&mut self, circuit_breaker: CircuitBreaker) -> &mut Self {
+        self.instruction.circuit_breaker = Some(circuit_breaker);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn asset_cooldown(&mut self, asset_cooldown: AssetCooldown) -> &mut Self {
+        self.instruction.asset_cooldown = Some(asset_cooldown);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn loyalty(&mut self, loyalty: LoyaltyRate) -> &mut Self {
+        self.instruction.loyalty = Some(loyalty);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn timelock_slots(&mut self, timelock_slots: u64) -> &mut Self {
+        self.instruction.timelock_slots = Some(timelock_slots);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn asset_root(&mut self, asset_root: [u8; 32]) -> &mut Self {
+        self.instruction.asset_root = Some(asset_root);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn wallet_root(&mut self, wallet_root: [u8; 32]) -> &mut Self {
+        self.instruction.wallet_root = Some(wallet_root);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn gate(&mut self, gate: RecipeGate) -> &mut Self {
+        self.instruction.gate = Some(gate);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = QueueRecipeUpdateV1InstructionArgs {
+            name: self.instruction.name.clone(),
+            uri: self.instruction.uri.clone(),
+            max: self.instruction.max.clone(),
+            min: self.instruction.min.clone(),
+            capture_amount: self.instruction.capture_amount.clone(),
+            release_amount: self.instruction.release_amount.clone(),
+            fee_amount_capture: self.instruction.fee_amount_capture.clone(),
+            fee_amount_release: self.instruction.fee_amount_release.clone(),
+            sol_fee_amount_capture: self.instruction.sol_fee_amount_capture.clone(),
+            sol_fee_amount_release: self.instruction.sol_fee_amount_release.clone(),
+            path: self.instruction.path.clone(),
+            pricing: self.instruction.pricing.clone(),
+            inventory: self.instruction.inventory.clone(),
+            quote_signer: self.instruction.quote_signer.clone(),
+            capture_window: self.instruction.capture_window.clone(),
+            release_window: self.instruction.release_window.clone(),
+            wallet_limits: self.instruction.wallet_limits.clone(),
+            circuit_breaker: self.instruction.circuit_breaker.clone(),
+            asset_cooldown: self.instruction.asset_cooldown.clone(),
+            loyalty: self.instruction.loyalty.clone(),
+            timelock_slots: self.instruction.timelock_slots.clone(),
+            asset_root: self.instruction.asset_root.clone(),
+            wallet_root: self.instruction.wallet_root.clone(),
+            gate: self.instruction.gate.clone(),
+        };
+        let instruction = QueueRecipeUpdateV1Cpi {
+            __program: self.instruction.__program,
+
+            pending_update: self
+                .instruction
+                .pending_update
+                .expect("pending_update is not set"),
+
+            recipe: self.instruction.recipe.expect("recipe is not set"),
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            collection: self.instruction.collection.expect("collection is not set"),
+
+            token: self.instruction.token,
+
+            fee_location: self
+                .instruction
+                .fee_location
+                .expect("fee_location is not set"),
+
+            operators: self.instruction.operators,
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct QueueRecipeUpdateV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    pending_update: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    recipe: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    fee_location: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    operators: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    name: Option<String>,
+    uri: Option<String>,
+    max: Option<u64>,
+    min: Option<u64>,
+    capture_amount: Option<u64>,
+    release_amount: Option<u64>,
+    fee_amount_capture: Option<u64>,
+    fee_amount_release: Option<u64>,
+    sol_fee_amount_capture: Option<u64>,
+    sol_fee_amount_release: Option<u64>,
+    path: Option<u16>,
+    pricing: Option<PricingCurve>,
+    inventory: Option<u64>,
+    quote_signer: Option<Pubkey>,
+    capture_window: Option<SwapWindow>,
+    release_window: Option<SwapWindow>,
+    wallet_limits: Option<WalletLimits>,
+    circuit_breaker: Option<CircuitBreaker>,
+    asset_cooldown: Option<AssetCooldown>,
+    loyalty: Option<LoyaltyRate>,
+    timelock_slots: Option<u64>,
+    asset_root: Option<[u8; 32]>,
+    wallet_root: Option<[u8; 32]>,
+    gate: Option<RecipeGate>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -24,13 +24,13 @@ pub struct ReleaseV2 {
 
     pub collection: solana_program::pubkey::Pubkey,
 
-    pub user_token_account: solana_program::pubkey::Pubkey,
+    pub user_token_account: Option<solana_program::pubkey::Pubkey>,
 
-    pub escrow_token_account: solana_program::pubkey::Pubkey,
+    pub escrow_token_account: Option<solana_program::pubkey::Pubkey>,
+    /// Checked against the recipe token and its payment table in the handler.
+    pub token: Option<solana_program::pubkey::Pubkey>,
 
-    pub token: solana_program::pubkey::Pubkey,
-
-    pub fee_token_account: solana_program::pubkey::Pubkey,
+    pub fee_token_account: Option<solana_program::pubkey::Pubkey>,
 
     pub fee_sol_account: solana_program::pubkey::Pubkey,
 
@@ -45,18 +45,48 @@ pub struct ReleaseV2 {
     pub token_program: solana_program::pubkey::Pubkey,
 
     pub associated_token_program: solana_program::pubkey::Pubkey,
+
+    pub discount_policy: Option<solana_program::pubkey::Pubkey>,
+
+    pub discount_proof: Option<solana_program::pubkey::Pubkey>,
+
+    pub basket: Option<solana_program::pubkey::Pubkey>,
+
+    pub payment_table: Option<solana_program::pubkey::Pubkey>,
+
+    pub instructions_sysvar: Option<solana_program::pubkey::Pubkey>,
+
+    pub quote_nonce: Option<solana_program::pubkey::Pubkey>,
+
+    pub wallet_activity: Option<solana_program::pubkey::Pubkey>,
+
+    pub asset_cooldown: Option<solana_program::pubkey::Pubkey>,
+
+    pub swap_receipt: Option<solana_program::pubkey::Pubkey>,
+
+    pub vault: Option<solana_program::pubkey::Pubkey>,
+
+    pub protocol_config: solana_program::pubkey::Pubkey,
+
+    pub event_authority: solana_program::pubkey::Pubkey,
+
+    pub program: solana_program::pubkey::Pubkey,
 }
 
 impl ReleaseV2 {
-    pub fn instruction(&self) -> solana_program::instruction::Instruction {
-        self.instruction_with_remaining_accounts(&[])
+    pub fn instruction(
+        &self,
+        args: ReleaseV2InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
     }
     #[allow(clippy::vec_init_then_push)]
     pub fn instruction_with_remaining_accounts(
         &self,
+        args: ReleaseV2InstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(17 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(30 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.owner, true,
         ));
@@ -79,21 +109,49 @@ impl ReleaseV2 {
             self.collection,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new(
-            self.user_token_account,
-            false,
-        ));
-        accounts.push(solana_program::instruction::AccountMeta::new(
-            self.escrow_token_account,
-            false,
-        ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            self.token, false,
-        ));
-        accounts.push(solana_program::instruction::AccountMeta::new(
-            self.fee_token_account,
-            false,
-        ));
+        if let Some(user_token_account) = self.user_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                user_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        if let Some(escrow_token_account) = self.escrow_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                escrow_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        if let Some(token) = self.token {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                token, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        if let Some(fee_token_account) = self.fee_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                fee_token_account,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.fee_sol_account,
             false,
@@ -122,8 +180,128 @@ impl ReleaseV2 {
             self.associated_token_program,
             false,
         ));
+        if let Some(discount_policy) = self.discount_policy {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                discount_policy,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        if let Some(discount_proof) = self.discount_proof {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                discount_proof,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        if let Some(basket) = self.basket {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                basket, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        if let Some(payment_table) = self.payment_table {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                payment_table,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        if let Some(instructions_sysvar) = self.instructions_sysvar {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                instructions_sysvar,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        if let Some(quote_nonce) = self.quote_nonce {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                quote_nonce,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        if let Some(wallet_activity) = self.wallet_activity {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                wallet_activity,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        if let Some(asset_cooldown) = self.asset_cooldown {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                asset_cooldown,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        if let Some(swap_receipt) = self.swap_receipt {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                swap_receipt,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        if let Some(vault) = self.vault {
+            accounts.push(solana_program::instruction::AccountMeta::new(vault, false));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.protocol_config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
-        let data = ReleaseV2InstructionData::new().try_to_vec().unwrap();
+        let mut data = ReleaseV2InstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
 
         solana_program::instruction::Instruction {
             program_id: crate::MPL_HYBRID_ID,
@@ -147,6 +325,15 @@ impl ReleaseV2InstructionData {
     }
 }
 
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseV2InstructionArgs {
+    pub asset_proof: Vec<[u8; 32]>,
+    pub wallet_proof: Vec<[u8; 32]>,
+}
+
 /// Instruction builder for `ReleaseV2`.
 ///
 /// ### Accounts:
@@ -157,10 +344,10 @@ impl ReleaseV2InstructionData {
 ///   3. `[writable]` escrow
 ///   4. `[writable]` asset
 ///   5. `[writable]` collection
-///   6. `[writable]` user_token_account
-///   7. `[writable]` escrow_token_account
-///   8. `[]` token
-///   9. `[writable]` fee_token_account
+///   6. `[writable, optional]` user_token_account
+///   7. `[writable, optional]` escrow_token_account
+///   8. `[optional]` token
+///   9. `[writable, optional]` fee_token_account
 ///   10. `[writable, optional]` fee_sol_account (default to `C3iyKknpNPeZXQEVLkR8ZJxcgB8xdsqXkyrV1RwEmdrD`)
 ///   11. `[writable]` fee_project_account
 ///   12. `[optional]` recent_blockhashes (default to `SysvarS1otHashes111111111111111111111111111`)
@@ -168,6 +355,19 @@ impl ReleaseV2InstructionData {
 ///   14. `[optional]` system_program (default to `11111111111111111111111111111111`)
 ///   15. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
 ///   16. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+///   17. `[optional]` discount_policy
+///   18. `[optional]` discount_proof
+///   19. `[optional]` basket
+///   20. `[optional]` payment_table
+///   21. `[optional]` instructions_sysvar
+///   22. `[writable, optional]` quote_nonce
+///   23. `[writable, optional]` wallet_activity
+///   24. `[writable, optional]` asset_cooldown
+///   25. `[writable, optional]` swap_receipt
+///   26. `[writable, optional]` vault
+///   27. `[]` protocol_config
+///   28. `[optional]` event_authority (default to `8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc`)
+///   29. `[optional]` program (default to `MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb`)
 #[derive(Default)]
 pub struct ReleaseV2Builder {
     owner: Option<solana_program::pubkey::Pubkey>,
@@ -187,6 +387,21 @@ pub struct ReleaseV2Builder {
     system_program: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
     associated_token_program: Option<solana_program::pubkey::Pubkey>,
+    discount_policy: Option<solana_program::pubkey::Pubkey>,
+    discount_proof: Option<solana_program::pubkey::Pubkey>,
+    basket: Option<solana_program::pubkey::Pubkey>,
+    payment_table: Option<solana_program::pubkey::Pubkey>,
+    instructions_sysvar: Option<solana_program::pubkey::Pubkey>,
+    quote_nonce: Option<solana_program::pubkey::Pubkey>,
+    wallet_activity: Option<solana_program::pubkey::Pubkey>,
+    asset_cooldown: Option<solana_program::pubkey::Pubkey>,
+    swap_receipt: Option<solana_program::pubkey::Pubkey>,
+    vault: Option<solana_program::pubkey::Pubkey>,
+    protocol_config: Option<solana_program::pubkey::Pubkey>,
+    event_authority: Option<solana_program::pubkey::Pubkey>,
+    program: Option<solana_program::pubkey::Pubkey>,
+    asset_proof: Option<Vec<[u8; 32]>>,
+    wallet_proof: Option<Vec<[u8; 32]>>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -228,33 +443,38 @@ impl ReleaseV2Builder {
         self.collection = Some(collection);
         self
     }
+    /// `[optional account]`
     #[inline(always)]
     pub fn user_token_account(
         &mut self,
-        user_token_account: solana_program::pubkey::Pubkey,
+        user_token_account: Option<solana_program::pubkey::Pubkey>,
     ) -> &mut Self {
-        self.user_token_account = Some(user_token_account);
+        self.user_token_account = user_token_account;
         self
     }
+    /// `[optional account]`
     #[inline(always)]
     pub fn escrow_token_account(
         &mut self,
-        escrow_token_account: solana_program::pubkey::Pubkey,
+        escrow_token_account: Option<solana_program::pubkey::Pubkey>,
     ) -> &mut Self {
-        self.escrow_token_account = Some(escrow_token_account);
+        self.escrow_token_account = escrow_token_account;
         self
     }
+    /// `[optional account]`
+    /// Checked against the recipe token and its payment table in the handler.
     #[inline(always)]
-    pub fn token(&mut self, token: solana_program::pubkey::Pubkey) -> &mut Self {
-        self.token = Some(token);
+    pub fn token(&mut self, token: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.token = token;
         self
     }
+    /// `[optional account]`
     #[inline(always)]
     pub fn fee_token_account(
         &mut self,
-        fee_token_account: solana_program::pubkey::Pubkey,
+        fee_token_account: Option<solana_program::pubkey::Pubkey>,
     ) -> &mut Self {
-        self.fee_token_account = Some(fee_token_account);
+        self.fee_token_account = fee_token_account;
         self
     }
     /// `[optional account, default to 'C3iyKknpNPeZXQEVLkR8ZJxcgB8xdsqXkyrV1RwEmdrD']`
@@ -310,6 +530,123 @@ impl ReleaseV2Builder {
         self.associated_token_program = Some(associated_token_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn discount_policy(
+        &mut self,
+        discount_policy: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.discount_policy = discount_policy;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn discount_proof(
+        &mut self,
+        discount_proof: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.discount_proof = discount_proof;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn basket(&mut self, basket: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.basket = basket;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn payment_table(
+        &mut self,
+        payment_table: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.payment_table = payment_table;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn instructions_sysvar(
+        &mut self,
+        instructions_sysvar: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.instructions_sysvar = instructions_sysvar;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn quote_nonce(
+        &mut self,
+        quote_nonce: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.quote_nonce = quote_nonce;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn wallet_activity(
+        &mut self,
+        wallet_activity: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.wallet_activity = wallet_activity;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn asset_cooldown(
+        &mut self,
+        asset_cooldown: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.asset_cooldown = asset_cooldown;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn swap_receipt(
+        &mut self,
+        swap_receipt: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.swap_receipt = swap_receipt;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn vault(&mut self, vault: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.vault = vault;
+        self
+    }
+    #[inline(always)]
+    pub fn protocol_config(
+        &mut self,
+        protocol_config: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.protocol_config = Some(protocol_config);
+        self
+    }
+    /// `[optional account, default to '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc']`
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    /// `[optional account, default to 'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
+    #[inline(always)]
+    pub fn asset_proof(&mut self, asset_proof: Vec<[u8; 32]>) -> &mut Self {
+        self.asset_proof = Some(asset_proof);
+        self
+    }
+    #[inline(always)]
+    pub fn wallet_proof(&mut self, wallet_proof: Vec<[u8; 32]>) -> &mut Self {
+        self.wallet_proof = Some(wallet_proof);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -337,16 +674,10 @@ impl ReleaseV2Builder {
             escrow: self.escrow.expect("escrow is not set"),
             asset: self.asset.expect("asset is not set"),
             collection: self.collection.expect("collection is not set"),
-            user_token_account: self
-                .user_token_account
-                .expect("user_token_account is not set"),
-            escrow_token_account: self
-                .escrow_token_account
-                .expect("escrow_token_account is not set"),
-            token: self.token.expect("token is not set"),
-            fee_token_account: self
-                .fee_token_account
-                .expect("fee_token_account is not set"),
+            user_token_account: self.user_token_account,
+            escrow_token_account: self.escrow_token_account,
+            token: self.token,
+            fee_token_account: self.fee_token_account,
             fee_sol_account: self.fee_sol_account.unwrap_or(solana_program::pubkey!(
                 "C3iyKknpNPeZXQEVLkR8ZJxcgB8xdsqXkyrV1RwEmdrD"
             )),
@@ -368,9 +699,30 @@ impl ReleaseV2Builder {
             associated_token_program: self.associated_token_program.unwrap_or(
                 solana_program::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
             ),
+            discount_policy: self.discount_policy,
+            discount_proof: self.discount_proof,
+            basket: self.basket,
+            payment_table: self.payment_table,
+            instructions_sysvar: self.instructions_sysvar,
+            quote_nonce: self.quote_nonce,
+            wallet_activity: self.wallet_activity,
+            asset_cooldown: self.asset_cooldown,
+            swap_receipt: self.swap_receipt,
+            vault: self.vault,
+            protocol_config: self.protocol_config.expect("protocol_config is not set"),
+            event_authority: self.event_authority.unwrap_or(solana_program::pubkey!(
+                "8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc"
+            )),
+            program: self.program.unwrap_or(solana_program::pubkey!(
+                "MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb"
+            )),
+        };
+        let args = ReleaseV2InstructionArgs {
+            asset_proof: self.asset_proof.clone().expect("asset_proof is not set"),
+            wallet_proof: self.wallet_proof.clone().expect("wallet_proof is not set"),
         };
 
-        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
     }
 }
 
@@ -388,13 +740,13 @@ pub struct ReleaseV2CpiAccounts<'a, 'b> {
 
     pub collection: &'b solana_program::account_info::AccountInfo<'a>,
 
-    pub user_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    pub user_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
-    pub escrow_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    pub escrow_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Checked against the recipe token and its payment table in the handler.
+    pub token: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
-    pub token: &'b solana_program::account_info::AccountInfo<'a>,
-
-    pub fee_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    pub fee_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub fee_sol_account: &'b solana_program::account_info::AccountInfo<'a>,
 
@@ -409,6 +761,32 @@ pub struct ReleaseV2CpiAccounts<'a, 'b> {
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub discount_policy: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub discount_proof: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub basket: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub payment_table: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub instructions_sysvar: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub quote_nonce: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub wallet_activity: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub asset_cooldown: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub swap_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub protocol_config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `release_v2` CPI instruction.
@@ -428,13 +806,13 @@ pub struct ReleaseV2Cpi<'a, 'b> {
 
     pub collection: &'b solana_program::account_info::AccountInfo<'a>,
 
-    pub user_token_account: &'b solana_program::account_info::AccountInfo<'a>,
-
-    pub escrow_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    pub user_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
-    pub token: &'b solana_program::account_info::AccountInfo<'a>,
+    pub escrow_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Checked against the recipe token and its payment table in the handler.
+    pub token: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
-    pub fee_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+    pub fee_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub fee_sol_account: &'b solana_program::account_info::AccountInfo<'a>,
 
@@ -449,12 +827,41 @@ pub struct ReleaseV2Cpi<'a, 'b> {
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub associated_token_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub discount_policy: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub discount_proof: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub basket: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub payment_table: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub instructions_sysvar: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub quote_nonce: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub wallet_activity: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub asset_cooldown: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub swap_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub protocol_config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: ReleaseV2InstructionArgs,
 }
 
 impl<'a, 'b> ReleaseV2Cpi<'a, 'b> {
     pub fn new(
         program: &'b solana_program::account_info::AccountInfo<'a>,
         accounts: ReleaseV2CpiAccounts<'a, 'b>,
+        args: ReleaseV2InstructionArgs,
     ) -> Self {
         Self {
             __program: program,
@@ -475,6 +882,20 @@ impl<'a, 'b> ReleaseV2Cpi<'a, 'b> {
             system_program: accounts.system_program,
             token_program: accounts.token_program,
             associated_token_program: accounts.associated_token_program,
+            discount_policy: accounts.discount_policy,
+            discount_proof: accounts.discount_proof,
+            basket: accounts.basket,
+            payment_table: accounts.payment_table,
+            instructions_sysvar: accounts.instructions_sysvar,
+            quote_nonce: accounts.quote_nonce,
+            wallet_activity: accounts.wallet_activity,
+            asset_cooldown: accounts.asset_cooldown,
+            swap_receipt: accounts.swap_receipt,
+            vault: accounts.vault,
+            protocol_config: accounts.protocol_config,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
+            __args: args,
         }
     }
     #[inline(always)]
@@ -510,7 +931,7 @@ impl<'a, 'b> ReleaseV2Cpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(17 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(30 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.owner.key,
             true,
@@ -535,22 +956,49 @@ impl<'a, 'b> ReleaseV2Cpi<'a, 'b> {
             *self.collection.key,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new(
-            *self.user_token_account.key,
-            false,
-        ));
-        accounts.push(solana_program::instruction::AccountMeta::new(
-            *self.escrow_token_account.key,
-            false,
-        ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            *self.token.key,
-            false,
-        ));
-        accounts.push(solana_program::instruction::AccountMeta::new(
-            *self.fee_token_account.key,
-            false,
-        ));
+        if let Some(user_token_account) = self.user_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *user_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        if let Some(escrow_token_account) = self.escrow_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *escrow_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        if let Some(token) = self.token {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *token.key, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        if let Some(fee_token_account) = self.fee_token_account {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *fee_token_account.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.fee_sol_account.key,
             false,
@@ -579,6 +1027,127 @@ impl<'a, 'b> ReleaseV2Cpi<'a, 'b> {
             *self.associated_token_program.key,
             false,
         ));
+        if let Some(discount_policy) = self.discount_policy {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *discount_policy.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        if let Some(discount_proof) = self.discount_proof {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *discount_proof.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        if let Some(basket) = self.basket {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *basket.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        if let Some(payment_table) = self.payment_table {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *payment_table.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        if let Some(instructions_sysvar) = self.instructions_sysvar {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *instructions_sysvar.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        if let Some(quote_nonce) = self.quote_nonce {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *quote_nonce.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        if let Some(wallet_activity) = self.wallet_activity {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *wallet_activity.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        if let Some(asset_cooldown) = self.asset_cooldown {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *asset_cooldown.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        if let Some(swap_receipt) = self.swap_receipt {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *swap_receipt.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        if let Some(vault) = self.vault {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *vault.key, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.protocol_config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -586,14 +1155,16 @@ impl<'a, 'b> ReleaseV2Cpi<'a, 'b> {
                 is_writable: remaining_account.2,
             })
         });
-        let data = ReleaseV2InstructionData::new().try_to_vec().unwrap();
+        let mut data = ReleaseV2InstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
 
         let instruction = solana_program::instruction::Instruction {
             program_id: crate::MPL_HYBRID_ID,
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(17 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(30 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.owner.clone());
         account_infos.push(self.authority.0.clone());
@@ -601,10 +1172,18 @@ impl<'a, 'b> ReleaseV2Cpi<'a, 'b> {
         account_infos.push(self.escrow.clone());
         account_infos.push(self.asset.clone());
         account_infos.push(self.collection.clone());
-        account_infos.push(self.user_token_account.clone());
-        account_infos.push(self.escrow_token_account.clone());
-        account_infos.push(self.token.clone());
-        account_infos.push(self.fee_token_account.clone());
+        if let Some(user_token_account) = self.user_token_account {
+            account_infos.push(user_token_account.clone());
+        }
+        if let Some(escrow_token_account) = self.escrow_token_account {
+            account_infos.push(escrow_token_account.clone());
+        }
+        if let Some(token) = self.token {
+            account_infos.push(token.clone());
+        }
+        if let Some(fee_token_account) = self.fee_token_account {
+            account_infos.push(fee_token_account.clone());
+        }
         account_infos.push(self.fee_sol_account.clone());
         account_infos.push(self.fee_project_account.clone());
         account_infos.push(self.recent_blockhashes.clone());
@@ -612,6 +1191,39 @@ impl<'a, 'b> ReleaseV2Cpi<'a, 'b> {
         account_infos.push(self.system_program.clone());
         account_infos.push(self.token_program.clone());
         account_infos.push(self.associated_token_program.clone());
+        if let Some(discount_policy) = self.discount_policy {
+            account_infos.push(discount_policy.clone());
+        }
+        if let Some(discount_proof) = self.discount_proof {
+            account_infos.push(discount_proof.clone());
+        }
+        if let Some(basket) = self.basket {
+            account_infos.push(basket.clone());
+        }
+        if let Some(payment_table) = self.payment_table {
+            account_infos.push(payment_table.clone());
+        }
+        if let Some(instructions_sysvar) = self.instructions_sysvar {
+            account_infos.push(instructions_sysvar.clone());
+        }
+        if let Some(quote_nonce) = self.quote_nonce {
+            account_infos.push(quote_nonce.clone());
+        }
+        if let Some(wallet_activity) = self.wallet_activity {
+            account_infos.push(wallet_activity.clone());
+        }
+        if let Some(asset_cooldown) = self.asset_cooldown {
+            account_infos.push(asset_cooldown.clone());
+        }
+        if let Some(swap_receipt) = self.swap_receipt {
+            account_infos.push(swap_receipt.clone());
+        }
+        if let Some(vault) = self.vault {
+            account_infos.push(vault.clone());
+        }
+        account_infos.push(self.protocol_config.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -634,10 +1246,10 @@ impl<'a, 'b> ReleaseV2Cpi<'a, 'b> {
 ///   3. `[writable]` escrow
 ///   4. `[writable]` asset
 ///   5. `[writable]` collection
-///   6. `[writable]` user_token_account
-///   7. `[writable]` escrow_token_account
-///   8. `[]` token
-///   9. `[writable]` fee_token_account
+///   6. `[writable, optional]` user_token_account
+///   7. `[writable, optional]` escrow_token_account
+///   8. `[optional]` token
+///   9. `[writable, optional]` fee_token_account
 ///   10. `[writable]` fee_sol_account
 ///   11. `[writable]` fee_project_account
 ///   12. `[]` recent_blockhashes
@@ -645,6 +1257,19 @@ impl<'a, 'b> ReleaseV2Cpi<'a, 'b> {
 ///   14. `[]` system_program
 ///   15. `[]` token_program
 ///   16. `[]` associated_token_program
+///   17. `[optional]` discount_policy
+///   18. `[optional]` discount_proof
+///   19. `[optional]` basket
+///   20. `[optional]` payment_table
+///   21. `[optional]` instructions_sysvar
+///   22. `[writable, optional]` quote_nonce
+///   23. `[writable, optional]` wallet_activity
+///   24. `[writable, optional]` asset_cooldown
+///   25. `[writable, optional]` swap_receipt
+///   26. `[writable, optional]` vault
+///   27. `[]` protocol_config
+///   28. `[]` event_authority
+///   29. `[]` program
 pub struct ReleaseV2CpiBuilder<'a, 'b> {
     instruction: Box<ReleaseV2CpiBuilderInstruction<'a, 'b>>,
 }
@@ -670,6 +1295,21 @@ impl<'a, 'b> ReleaseV2CpiBuilder<'a, 'b> {
             system_program: None,
             token_program: None,
             associated_token_program: None,
+            discount_policy: None,
+            discount_proof: None,
+            basket: None,
+            payment_table: None,
+            instructions_sysvar: None,
+            quote_nonce: None,
+            wallet_activity: None,
+            asset_cooldown: None,
+            swap_receipt: None,
+            vault: None,
+            protocol_config: None,
+            event_authority: None,
+            program: None,
+            asset_proof: None,
+            wallet_proof: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -717,33 +1357,41 @@ impl<'a, 'b> ReleaseV2CpiBuilder<'a, 'b> {
         self.instruction.collection = Some(collection);
         self
     }
+    /// `[optional account]`
     #[inline(always)]
     pub fn user_token_account(
         &mut self,
-        user_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+        user_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ) -> &mut Self {
-        self.instruction.user_token_account = Some(user_token_account);
+        self.instruction.user_token_account = user_token_account;
         self
     }
+    /// `[optional account]`
     #[inline(always)]
     pub fn escrow_token_account(
         &mut self,
-        escrow_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+        escrow_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ) -> &mut Self {
-        self.instruction.escrow_token_account = Some(escrow_token_account);
+        self.instruction.escrow_token_account = escrow_token_account;
         self
     }
+    /// `[optional account]`
+    /// Checked against the recipe token and its payment table in the handler.
     #[inline(always)]
-    pub fn token(&mut self, token: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
-        self.instruction.token = Some(token);
+    pub fn token(
+        &mut self,
+        token: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token = token;
         self
     }
+    /// `[optional account]`
     #[inline(always)]
     pub fn fee_token_account(
         &mut self,
-        fee_token_account: &'b solana_program::account_info::AccountInfo<'a>,
+        fee_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ) -> &mut Self {
-        self.instruction.fee_token_account = Some(fee_token_account);
+        self.instruction.fee_token_account = fee_token_account;
         self
     }
     #[inline(always)]
@@ -802,6 +1450,130 @@ impl<'a, 'b> ReleaseV2CpiBuilder<'a, 'b> {
         self.instruction.associated_token_program = Some(associated_token_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn discount_policy(
+        &mut self,
+        discount_policy: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.discount_policy = discount_policy;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn discount_proof(
+        &mut self,
+        discount_proof: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.discount_proof = discount_proof;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn basket(
+        &mut self,
+        basket: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.basket = basket;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn payment_table(
+        &mut self,
+        payment_table: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.payment_table = payment_table;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn instructions_sysvar(
+        &mut self,
+        instructions_sysvar: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.instructions_sysvar = instructions_sysvar;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn quote_nonce(
+        &mut self,
+        quote_nonce: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.quote_nonce = quote_nonce;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn wallet_activity(
+        &mut self,
+        wallet_activity: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.wallet_activity = wallet_activity;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn asset_cooldown(
+        &mut self,
+        asset_cooldown: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.asset_cooldown = asset_cooldown;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn swap_receipt(
+        &mut self,
+        swap_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.swap_receipt = swap_receipt;
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn vault(
+        &mut self,
+        vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.vault = vault;
+        self
+    }
+    #[inline(always)]
+    pub fn protocol_config(
+        &mut self,
+        protocol_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.protocol_config = Some(protocol_config);
+        self
+    }
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(
+        &mut self,
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
+    #[inline(always)]
+    pub fn asset_proof(&mut self, asset_proof: Vec<[u8; 32]>) -> &mut Self {
+        self.instruction.asset_proof = Some(asset_proof);
+        self
+    }
+    #[inline(always)]
+    pub fn wallet_proof(&mut self, wallet_proof: Vec<[u8; 32]>) -> &mut Self {
+        self.instruction.wallet_proof = Some(wallet_proof);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -843,6 +1615,18 @@ impl<'a, 'b> ReleaseV2CpiBuilder<'a, 'b> {
         &self,
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
+        let args = ReleaseV2InstructionArgs {
+            asset_proof: self
+                .instruction
+                .asset_proof
+                .clone()
+                .expect("asset_proof is not set"),
+            wallet_proof: self
+                .instruction
+                .wallet_proof
+                .clone()
+                .expect("wallet_proof is not set"),
+        };
         let instruction = ReleaseV2Cpi {
             __program: self.instruction.__program,
 
@@ -858,22 +1642,13 @@ impl<'a, 'b> ReleaseV2CpiBuilder<'a, 'b> {
 
             collection: self.instruction.collection.expect("collection is not set"),
 
-            user_token_account: self
-                .instruction
-                .user_token_account
-                .expect("user_token_account is not set"),
+            user_token_account: self.instruction.user_token_account,
 
-            escrow_token_account: self
-                .instruction
-                .escrow_token_account
-                .expect("escrow_token_account is not set"),
+            escrow_token_account: self.instruction.escrow_token_account,
 
-            token: self.instruction.token.expect("token is not set"),
+            token: self.instruction.token,
 
-            fee_token_account: self
-                .instruction
-                .fee_token_account
-                .expect("fee_token_account is not set"),
+            fee_token_account: self.instruction.fee_token_account,
 
             fee_sol_account: self
                 .instruction
@@ -906,6 +1681,39 @@ impl<'a, 'b> ReleaseV2CpiBuilder<'a, 'b> {
                 .instruction
                 .associated_token_program
                 .expect("associated_token_program is not set"),
+
+            discount_policy: self.instruction.discount_policy,
+
+            discount_proof: self.instruction.discount_proof,
+
+            basket: self.instruction.basket,
+
+            payment_table: self.instruction.payment_table,
+
+            instructions_sysvar: self.instruction.instructions_sysvar,
+
+            quote_nonce: self.instruction.quote_nonce,
+
+            wallet_activity: self.instruction.wallet_activity,
+
+            asset_cooldown: self.instruction.asset_cooldown,
+
+            swap_receipt: self.instruction.swap_receipt,
+
+            vault: self.instruction.vault,
+
+            protocol_config: self
+                .instruction
+                .protocol_config
+                .expect("protocol_config is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
+            __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -933,6 +1741,21 @@ struct ReleaseV2CpiBuilderInstruction<'a, 'b> {
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     associated_token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    discount_policy: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    discount_proof: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    basket: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payment_table: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    instructions_sysvar: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    quote_nonce: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    wallet_activity: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    asset_cooldown: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    swap_receipt: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    protocol_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    asset_proof: Option<Vec<[u8; 32]>>,
+    wallet_proof: Option<Vec<[u8; 32]>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
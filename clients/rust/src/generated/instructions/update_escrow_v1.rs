@@ -23,6 +23,10 @@ pub struct UpdateEscrowV1 {
     pub fee_location: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+
+    pub event_authority: solana_program::pubkey::Pubkey,
+
+    pub program: solana_program::pubkey::Pubkey,
 }
 
 impl UpdateEscrowV1 {
@@ -38,7 +42,7 @@ impl UpdateEscrowV1 {
         args: UpdateEscrowV1InstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.escrow,
             false,
@@ -62,6 +66,14 @@ impl UpdateEscrowV1 {
             self.system_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = UpdateEscrowV1InstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -114,6 +126,8 @@ pub struct UpdateEscrowV1InstructionArgs {
 ///   3. `[]` token
 ///   4. `[]` fee_location
 ///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   6. `[optional]` event_authority (default to `8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc`)
+///   7. `[optional]` program (default to `MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb`)
 #[derive(Default)]
 pub struct UpdateEscrowV1Builder {
     escrow: Option<solana_program::pubkey::Pubkey>,
@@ -122,6 +136,8 @@ pub struct UpdateEscrowV1Builder {
     token: Option<solana_program::pubkey::Pubkey>,
     fee_location: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    event_authority: Option<solana_program::pubkey::Pubkey>,
+    program: Option<solana_program::pubkey::Pubkey>,
     name: Option<String>,
     uri: Option<String>,
     max: Option<u64>,
@@ -168,6 +184,21 @@ impl UpdateEscrowV1Builder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account, default to '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc']`
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    /// `[optional account, default to 'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
     /// `[optional argument]`
     #[inline(always)]
     pub fn name(&mut self, name: String) -> &mut Self {
@@ -245,6 +276,12 @@ impl UpdateEscrowV1Builder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            event_authority: self.event_authority.unwrap_or(solana_program::pubkey!(
+                "8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc"
+            )),
+            program: self.program.unwrap_or(solana_program::pubkey!(
+                "MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb"
+            )),
         };
         let args = UpdateEscrowV1InstructionArgs {
             name: self.name.clone(),
@@ -274,6 +311,10 @@ pub struct UpdateEscrowV1CpiAccounts<'a, 'b> {
     pub fee_location: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `update_escrow_v1` CPI instruction.
@@ -292,6 +333,10 @@ pub struct UpdateEscrowV1Cpi<'a, 'b> {
     pub fee_location: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: UpdateEscrowV1InstructionArgs,
 }
@@ -310,6 +355,8 @@ impl<'a, 'b> UpdateEscrowV1Cpi<'a, 'b> {
             token: accounts.token,
             fee_location: accounts.fee_location,
             system_program: accounts.system_program,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -346,7 +393,7 @@ impl<'a, 'b> UpdateEscrowV1Cpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.escrow.key,
             false,
@@ -371,6 +418,14 @@ impl<'a, 'b> UpdateEscrowV1Cpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.program.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -387,7 +442,7 @@ impl<'a, 'b> UpdateEscrowV1Cpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.escrow.clone());
         account_infos.push(self.authority.clone());
@@ -395,6 +450,8 @@ impl<'a, 'b> UpdateEscrowV1Cpi<'a, 'b> {
         account_infos.push(self.token.clone());
         account_infos.push(self.fee_location.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -417,6 +474,8 @@ impl<'a, 'b> UpdateEscrowV1Cpi<'a, 'b> {
 ///   3. `[]` token
 ///   4. `[]` fee_location
 ///   5. `[]` system_program
+///   6. `[]` event_authority
+///   7. `[]` program
 pub struct UpdateEscrowV1CpiBuilder<'a, 'b> {
     instruction: Box<UpdateEscrowV1CpiBuilderInstruction<'a, 'b>>,
 }
@@ -431,6 +490,8 @@ impl<'a, 'b> UpdateEscrowV1CpiBuilder<'a, 'b> {
             token: None,
             fee_location: None,
             system_program: None,
+            event_authority: None,
+            program: None,
             name: None,
             uri: None,
             max: None,
@@ -488,6 +549,22 @@ impl<'a, 'b> UpdateEscrowV1CpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(
+        &mut self,
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
     /// `[optional argument]`
     #[inline(always)]
     pub fn name(&mut self, name: String) -> &mut Self {
@@ -607,6 +684,13 @@ impl<'a, 'b> UpdateEscrowV1CpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -624,6 +708,8 @@ struct UpdateEscrowV1CpiBuilderInstruction<'a, 'b> {
     token: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     fee_location: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     name: Option<String>,
     uri: Option<String>,
     max: Option<u64>,
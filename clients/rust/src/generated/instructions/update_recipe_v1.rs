@@ -5,10 +5,18 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::AssetCooldown;
+use crate::generated::types::CircuitBreaker;
+use crate::generated::types::LoyaltyRate;
+use crate::generated::types::PricingCurve;
+use crate::generated::types::RecipeGate;
+use crate::generated::types::SwapWindow;
+use crate::generated::types::WalletLimits;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
 #[cfg(not(feature = "anchor"))]
 use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
 
 /// Accounts.
 pub struct UpdateRecipeV1 {
@@ -18,11 +26,19 @@ pub struct UpdateRecipeV1 {
 
     pub collection: solana_program::pubkey::Pubkey,
 
-    pub token: solana_program::pubkey::Pubkey,
+    pub token: Option<solana_program::pubkey::Pubkey>,
 
     pub fee_location: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+
+    pub operators: Option<solana_program::pubkey::Pubkey>,
+
+    pub history: solana_program::pubkey::Pubkey,
+
+    pub event_authority: solana_program::pubkey::Pubkey,
+
+    pub program: solana_program::pubkey::Pubkey,
 }
 
 impl UpdateRecipeV1 {
@@ -38,7 +54,7 @@ impl UpdateRecipeV1 {
         args: UpdateRecipeV1InstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.recipe,
             false,
@@ -51,9 +67,16 @@ impl UpdateRecipeV1 {
             self.collection,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            self.token, false,
-        ));
+        if let Some(token) = self.token {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                token, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.fee_location,
             false,
@@ -62,6 +85,28 @@ impl UpdateRecipeV1 {
             self.system_program,
             false,
         ));
+        if let Some(operators) = self.operators {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                operators, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.history,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.event_authority,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.program,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = UpdateRecipeV1InstructionData::new().try_to_vec().unwrap();
         let mut args = args.try_to_vec().unwrap();
@@ -98,12 +143,26 @@ pub struct UpdateRecipeV1InstructionArgs {
     pub uri: Option<String>,
     pub max: Option<u64>,
     pub min: Option<u64>,
-    pub amount: Option<u64>,
+    pub capture_amount: Option<u64>,
+    pub release_amount: Option<u64>,
     pub fee_amount_capture: Option<u64>,
     pub fee_amount_release: Option<u64>,
     pub sol_fee_amount_capture: Option<u64>,
     pub sol_fee_amount_release: Option<u64>,
     pub path: Option<u16>,
+    pub pricing: Option<PricingCurve>,
+    pub inventory: Option<u64>,
+    pub quote_signer: Option<Pubkey>,
+    pub capture_window: Option<SwapWindow>,
+    pub release_window: Option<SwapWindow>,
+    pub wallet_limits: Option<WalletLimits>,
+    pub circuit_breaker: Option<CircuitBreaker>,
+    pub asset_cooldown: Option<AssetCooldown>,
+    pub loyalty: Option<LoyaltyRate>,
+    pub timelock_slots: Option<u64>,
+    pub asset_root: Option<[u8; 32]>,
+    pub wallet_root: Option<[u8; 32]>,
+    pub gate: Option<RecipeGate>,
 }
 
 /// Instruction builder for `UpdateRecipeV1`.
@@ -113,9 +172,13 @@ pub struct UpdateRecipeV1InstructionArgs {
 ///   0. `[writable]` recipe
 ///   1. `[writable, signer]` authority
 ///   2. `[writable]` collection
-///   3. `[]` token
+///   3. `[optional]` token
 ///   4. `[]` fee_location
 ///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   6. `[optional]` operators
+///   7. `[writable]` history
+///   8. `[optional]` event_authority (default to `8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc`)
+///   9. `[optional]` program (default to `MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb`)
 #[derive(Default)]
 pub struct UpdateRecipeV1Builder {
     recipe: Option<solana_program::pubkey::Pubkey>,
@@ -124,16 +187,34 @@ pub struct UpdateRecipeV1Builder {
     token: Option<solana_program::pubkey::Pubkey>,
     fee_location: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    operators: Option<solana_program::pubkey::Pubkey>,
+    history: Option<solana_program::pubkey::Pubkey>,
+    event_authority: Option<solana_program::pubkey::Pubkey>,
+    program: Option<solana_program::pubkey::Pubkey>,
     name: Option<String>,
     uri: Option<String>,
     max: Option<u64>,
     min: Option<u64>,
-    amount: Option<u64>,
+    capture_amount: Option<u64>,
+    release_amount: Option<u64>,
     fee_amount_capture: Option<u64>,
     fee_amount_release: Option<u64>,
     sol_fee_amount_capture: Option<u64>,
     sol_fee_amount_release: Option<u64>,
     path: Option<u16>,
+    pricing: Option<PricingCurve>,
+    inventory: Option<u64>,
+    quote_signer: Option<Pubkey>,
+    capture_window: Option<SwapWindow>,
+    release_window: Option<SwapWindow>,
+    wallet_limits: Option<WalletLimits>,
+    circuit_breaker: Option<CircuitBreaker>,
+    asset_cooldown: Option<AssetCooldown>,
+    loyalty: Option<LoyaltyRate>,
+    timelock_slots: Option<u64>,
+    asset_root: Option<[u8; 32]>,
+    wallet_root: Option<[u8; 32]>,
+    gate: Option<RecipeGate>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -156,9 +237,10 @@ impl UpdateRecipeV1Builder {
         self.collection = Some(collection);
         self
     }
+    /// `[optional account]`
     #[inline(always)]
-    pub fn token(&mut self, token: solana_program::pubkey::Pubkey) -> &mut Self {
-        self.token = Some(token);
+    pub fn token(&mut self, token: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.token = token;
         self
     }
     #[inline(always)]
@@ -172,6 +254,32 @@ impl UpdateRecipeV1Builder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn operators(&mut self, operators: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.operators = operators;
+        self
+    }
+    #[inline(always)]
+    pub fn history(&mut self, history: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.history = Some(history);
+        self
+    }
+    /// `[optional account, default to '8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc']`
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.event_authority = Some(event_authority);
+        self
+    }
+    /// `[optional account, default to 'MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb']`
+    #[inline(always)]
+    pub fn program(&mut self, program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.program = Some(program);
+        self
+    }
     /// `[optional argument]`
     #[inline(always)]
     pub fn name(&mut self, name: String) -> &mut Self {
@@ -198,8 +306,14 @@ impl UpdateRecipeV1Builder {
     }
     /// `[optional argument]`
     #[inline(always)]
-    pub fn amount(&mut self, amount: u64) -> &mut Self {
-        self.amount = Some(amount);
+    pub fn capture_amount(&mut self, capture_amount: u64) -> &mut Self {
+        self.capture_amount = Some(capture_amount);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn release_amount(&mut self, release_amount: u64) -> &mut Self {
+        self.release_amount = Some(release_amount);
         self
     }
     /// `[optional argument]`
@@ -232,6 +346,84 @@ impl UpdateRecipeV1Builder {
         self.path = Some(path);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn pricing(&mut self, pricing: PricingCurve) -> &mut Self {
+        self.pricing = Some(pricing);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn inventory(&mut self, inventory: u64) -> &mut Self {
+        self.inventory = Some(inventory);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn quote_signer(&mut self, quote_signer: Pubkey) -> &mut Self {
+        self.quote_signer = Some(quote_signer);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn capture_window(&mut self, capture_window: SwapWindow) -> &mut Self {
+        self.capture_window = Some(capture_window);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn release_window(&mut self, release_window: SwapWindow) -> &mut Self {
+        self.release_window = Some(release_window);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn wallet_limits(&mut self, wallet_limits: WalletLimits) -> &mut Self {
+        self.wallet_limits = Some(wallet_limits);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn circuit_breaker(&mut self, circuit_breaker: CircuitBreaker) -> &mut Self {
+        self.circuit_breaker = Some(circuit_breaker);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn asset_cooldown(&mut self, asset_cooldown: AssetCooldown) -> &mut Self {
+        self.asset_cooldown = Some(asset_cooldown);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn loyalty(&mut self, loyalty: LoyaltyRate) -> &mut Self {
+        self.loyalty = Some(loyalty);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn timelock_slots(&mut self, timelock_slots: u64) -> &mut Self {
+        self.timelock_slots = Some(timelock_slots);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn asset_root(&mut self, asset_root: [u8; 32]) -> &mut Self {
+        self.asset_root = Some(asset_root);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn wallet_root(&mut self, wallet_root: [u8; 32]) -> &mut Self {
+        self.wallet_root = Some(wallet_root);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn gate(&mut self, gate: RecipeGate) -> &mut Self {
+        self.gate = Some(gate);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -256,23 +448,45 @@ impl UpdateRecipeV1Builder {
             recipe: self.recipe.expect("recipe is not set"),
             authority: self.authority.expect("authority is not set"),
             collection: self.collection.expect("collection is not set"),
-            token: self.token.expect("token is not set"),
+            token: self.token,
             fee_location: self.fee_location.expect("fee_location is not set"),
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            operators: self.operators,
+            history: self.history.expect("history is not set"),
+            event_authority: self.event_authority.unwrap_or(solana_program::pubkey!(
+                "8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc"
+            )),
+            program: self.program.unwrap_or(solana_program::pubkey!(
+                "MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb"
+            )),
         };
         let args = UpdateRecipeV1InstructionArgs {
             name: self.name.clone(),
             uri: self.uri.clone(),
             max: self.max.clone(),
             min: self.min.clone(),
-            amount: self.amount.clone(),
+            capture_amount: self.capture_amount.clone(),
+            release_amount: self.release_amount.clone(),
             fee_amount_capture: self.fee_amount_capture.clone(),
             fee_amount_release: self.fee_amount_release.clone(),
             sol_fee_amount_capture: self.sol_fee_amount_capture.clone(),
             sol_fee_amount_release: self.sol_fee_amount_release.clone(),
             path: self.path.clone(),
+            pricing: self.pricing.clone(),
+            inventory: self.inventory.clone(),
+            quote_signer: self.quote_signer.clone(),
+            capture_window: self.capture_window.clone(),
+            release_window: self.release_window.clone(),
+            wallet_limits: self.wallet_limits.clone(),
+            circuit_breaker: self.circuit_breaker.clone(),
+            asset_cooldown: self.asset_cooldown.clone(),
+            loyalty: self.loyalty.clone(),
+            timelock_slots: self.timelock_slots.clone(),
+            asset_root: self.asset_root.clone(),
+            wallet_root: self.wallet_root.clone(),
+            gate: self.gate.clone(),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -287,11 +501,19 @@ pub struct UpdateRecipeV1CpiAccounts<'a, 'b> {
 
     pub collection: &'b solana_program::account_info::AccountInfo<'a>,
 
-    pub token: &'b solana_program::account_info::AccountInfo<'a>,
+    pub token: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub fee_location: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub operators: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub history: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `update_recipe_v1` CPI instruction.
@@ -305,11 +527,19 @@ pub struct UpdateRecipeV1Cpi<'a, 'b> {
 
     pub collection: &'b solana_program::account_info::AccountInfo<'a>,
 
-    pub token: &'b solana_program::account_info::AccountInfo<'a>,
+    pub token: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub fee_location: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub operators: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub history: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program: &'b solana_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: UpdateRecipeV1InstructionArgs,
 }
@@ -328,6 +558,10 @@ impl<'a, 'b> UpdateRecipeV1Cpi<'a, 'b> {
             token: accounts.token,
             fee_location: accounts.fee_location,
             system_program: accounts.system_program,
+            operators: accounts.operators,
+            history: accounts.history,
+            event_authority: accounts.event_authority,
+            program: accounts.program,
             __args: args,
         }
     }
@@ -364,7 +598,7 @@ impl<'a, 'b> UpdateRecipeV1Cpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.recipe.key,
             false,
@@ -377,16 +611,45 @@ impl<'a, 'b> UpdateRecipeV1Cpi<'a, 'b> {
             *self.collection.key,
             false,
         ));
+        if let Some(token) = self.token {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *token.key, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            *self.token.key,
+            *self.fee_location.key,
             false,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            *self.fee_location.key,
+            *self.system_program.key,
+            false,
+        ));
+        if let Some(operators) = self.operators {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *operators.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_HYBRID_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.history.key,
             false,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
-            *self.system_program.key,
+            *self.event_authority.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.program.key,
             false,
         ));
         remaining_accounts.iter().for_each(|remaining_account| {
@@ -405,14 +668,22 @@ impl<'a, 'b> UpdateRecipeV1Cpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(10 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.recipe.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.collection.clone());
-        account_infos.push(self.token.clone());
+        if let Some(token) = self.token {
+            account_infos.push(token.clone());
+        }
         account_infos.push(self.fee_location.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(operators) = self.operators {
+            account_infos.push(operators.clone());
+        }
+        account_infos.push(self.history.clone());
+        account_infos.push(self.event_authority.clone());
+        account_infos.push(self.program.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -432,9 +703,13 @@ impl<'a, 'b> UpdateRecipeV1Cpi<'a, 'b> {
 ///   0. `[writable]` recipe
 ///   1. `[writable, signer]` authority
 ///   2. `[writable]` collection
-///   3. `[]` token
+///   3. `[optional]` token
 ///   4. `[]` fee_location
 ///   5. `[]` system_program
+///   6. `[optional]` operators
+///   7. `[writable]` history
+///   8. `[]` event_authority
+///   9. `[]` program
 pub struct UpdateRecipeV1CpiBuilder<'a, 'b> {
     instruction: Box<UpdateRecipeV1CpiBuilderInstruction<'a, 'b>>,
 }
@@ -449,16 +724,34 @@ impl<'a, 'b> UpdateRecipeV1CpiBuilder<'a, 'b> {
             token: None,
             fee_location: None,
             system_program: None,
+            operators: None,
+            history: None,
+            event_authority: None,
+            program: None,
             name: None,
             uri: None,
             max: None,
             min: None,
-            amount: None,
+            capture_amount: None,
+            release_amount: None,
             fee_amount_capture: None,
             fee_amount_release: None,
             sol_fee_amount_capture: None,
             sol_fee_amount_release: None,
             path: None,
+            pricing: None,
+            inventory: None,
+            quote_signer: None,
+            capture_window: None,
+            release_window: None,
+            wallet_limits: None,
+            circuit_breaker: None,
+            asset_cooldown: None,
+            loyalty: None,
+            timelock_slots: None,
+            asset_root: None,
+            wallet_root: None,
+            gate: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -487,9 +780,13 @@ impl<'a, 'b> UpdateRecipeV1CpiBuilder<'a, 'b> {
         self.instruction.collection = Some(collection);
         self
     }
+    /// `[optional account]`
     #[inline(always)]
-    pub fn token(&mut self, token: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
-        self.instruction.token = Some(token);
+    pub fn token(
+        &mut self,
+        token: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.token = token;
         self
     }
     #[inline(always)]
@@ -508,6 +805,39 @@ impl<'a, 'b> UpdateRecipeV1CpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn operators(
+        &mut self,
+        operators: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.operators = operators;
+        self
+    }
+    #[inline(always)]
+    pub fn history(
+        &mut self,
+        history: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.history = Some(history);
+        self
+    }
+    #[inline(always)]
+    pub fn event_authority(
+        &mut self,
+        event_authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.event_authority = Some(event_authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program(
+        &mut self,
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.program = Some(program);
+        self
+    }
     /// `[optional argument]`
     #[inline(always)]
     pub fn name(&mut self, name: String) -> &mut Self {
@@ -534,8 +864,14 @@ impl<'a, 'b> UpdateRecipeV1CpiBuilder<'a, 'b> {
     }
     /// `[optional argument]`
     #[inline(always)]
-    pub fn amount(&mut self, amount: u64) -> &mut Self {
-        self.instruction.amount = Some(amount);
+    pub fn capture_amount(&mut self, capture_amount: u64) -> &mut Self {
+        self.instruction.capture_amount = Some(capture_amount);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn release_amount(&mut self, release_amount: u64) -> &mut Self {
+        self.instruction.release_amount = Some(release_amount);
         self
     }
     /// `[optional argument]`
@@ -568,6 +904,84 @@ impl<'a, 'b> UpdateRecipeV1CpiBuilder<'a, 'b> {
         self.instruction.path = Some(path);
         self
     }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn pricing(&mut self, pricing: PricingCurve) -> &mut Self {
+        self.instruction.pricing = Some(pricing);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn inventory(&mut self, inventory: u64) -> &mut Self {
+        self.instruction.inventory = Some(inventory);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn quote_signer(&mut self, quote_signer: Pubkey) -> &mut Self {
+        self.instruction.quote_signer = Some(quote_signer);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn capture_window(&mut self, capture_window: SwapWindow) -> &mut Self {
+        self.instruction.capture_window = Some(capture_window);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn release_window(&mut self, release_window: SwapWindow) -> &mut Self {
+        self.instruction.release_window = Some(release_window);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn wallet_limits(&mut self, wallet_limits: WalletLimits) -> &mut Self {
+        self.instruction.wallet_limits = Some(wallet_limits);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn circuit_breaker(&mut self, circuit_breaker: CircuitBreaker) -> &mut Self {
+        self.instruction.circuit_breaker = Some(circuit_breaker);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn asset_cooldown(&mut self, asset_cooldown: AssetCooldown) -> &mut Self {
+        self.instruction.asset_cooldown = Some(asset_cooldown);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn loyalty(&mut self, loyalty: LoyaltyRate) -> &mut Self {
+        self.instruction.loyalty = Some(loyalty);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn timelock_slots(&mut self, timelock_slots: u64) -> &mut Self {
+        self.instruction.timelock_slots = Some(timelock_slots);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn asset_root(&mut self, asset_root: [u8; 32]) -> &mut Self {
+        self.instruction.asset_root = Some(asset_root);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn wallet_root(&mut self, wallet_root: [u8; 32]) -> &mut Self {
+        self.instruction.wallet_root = Some(wallet_root);
+        self
+    }
+    /// `[optional argument]`
+    #[inline(always)]
+    pub fn gate(&mut self, gate: RecipeGate) -> &mut Self {
+        self.instruction.gate = Some(gate);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -614,12 +1028,26 @@ impl<'a, 'b> UpdateRecipeV1CpiBuilder<'a, 'b> {
             uri: self.instruction.uri.clone(),
             max: self.instruction.max.clone(),
             min: self.instruction.min.clone(),
-            amount: self.instruction.amount.clone(),
+            capture_amount: self.instruction.capture_amount.clone(),
+            release_amount: self.instruction.release_amount.clone(),
             fee_amount_capture: self.instruction.fee_amount_capture.clone(),
             fee_amount_release: self.instruction.fee_amount_release.clone(),
             sol_fee_amount_capture: self.instruction.sol_fee_amount_capture.clone(),
             sol_fee_amount_release: self.instruction.sol_fee_amount_release.clone(),
             path: self.instruction.path.clone(),
+            pricing: self.instruction.pricing.clone(),
+            inventory: self.instruction.inventory.clone(),
+            quote_signer: self.instruction.quote_signer.clone(),
+            capture_window: self.instruction.capture_window.clone(),
+            release_window: self.instruction.release_window.clone(),
+            wallet_limits: self.instruction.wallet_limits.clone(),
+            circuit_breaker: self.instruction.circuit_breaker.clone(),
+            asset_cooldown: self.instruction.asset_cooldown.clone(),
+            loyalty: self.instruction.loyalty.clone(),
+            timelock_slots: self.instruction.timelock_slots.clone(),
+            asset_root: self.instruction.asset_root.clone(),
+            wallet_root: self.instruction.wallet_root.clone(),
+            gate: self.instruction.gate.clone(),
         };
         let instruction = UpdateRecipeV1Cpi {
             __program: self.instruction.__program,
@@ -630,7 +1058,7 @@ impl<'a, 'b> UpdateRecipeV1CpiBuilder<'a, 'b> {
 
             collection: self.instruction.collection.expect("collection is not set"),
 
-            token: self.instruction.token.expect("token is not set"),
+            token: self.instruction.token,
 
             fee_location: self
                 .instruction
@@ -641,6 +1069,17 @@ impl<'a, 'b> UpdateRecipeV1CpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            operators: self.instruction.operators,
+
+            history: self.instruction.history.expect("history is not set"),
+
+            event_authority: self
+                .instruction
+                .event_authority
+                .expect("event_authority is not set"),
+
+            program: self.instruction.program.expect("program is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -658,16 +1097,34 @@ struct UpdateRecipeV1CpiBuilderInstruction<'a, 'b> {
     token: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     fee_location: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    operators: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    event_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     name: Option<String>,
     uri: Option<String>,
     max: Option<u64>,
     min: Option<u64>,
-    amount: Option<u64>,
+    capture_amount: Option<u64>,
+    release_amount: Option<u64>,
     fee_amount_capture: Option<u64>,
     fee_amount_release: Option<u64>,
     sol_fee_amount_capture: Option<u64>,
     sol_fee_amount_release: Option<u64>,
     path: Option<u16>,
+    pricing: Option<PricingCurve>,
+    inventory: Option<u64>,
+    quote_signer: Option<Pubkey>,
+    capture_window: Option<SwapWindow>,
+    release_window: Option<SwapWindow>,
+    wallet_limits: Option<WalletLimits>,
+    circuit_breaker: Option<CircuitBreaker>,
+    asset_cooldown: Option<AssetCooldown>,
+    loyalty: Option<LoyaltyRate>,
+    timelock_slots: Option<u64>,
+    asset_root: Option<[u8; 32]>,
+    wallet_root: Option<[u8; 32]>,
+    gate: Option<RecipeGate>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -0,0 +1,45 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use num_derive::FromPrimitive;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Hash, FromPrimitive)]
+pub enum HistoryField {
+    Token,
+    FeeLocation,
+    Name,
+    Uri,
+    Max,
+    Min,
+    CaptureAmount,
+    ReleaseAmount,
+    FeeAmountCapture,
+    FeeAmountRelease,
+    SolFeeAmountCapture,
+    SolFeeAmountRelease,
+    Path,
+    Pricing,
+    Inventory,
+    QuoteSigner,
+    CaptureWindow,
+    ReleaseWindow,
+    WalletLimits,
+    CircuitBreaker,
+    AssetCooldown,
+    Loyalty,
+    Timelock,
+    AssetRoot,
+    WalletRoot,
+    Gate,
+}
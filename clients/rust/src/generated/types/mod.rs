@@ -5,6 +5,64 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#asset_cooldown;
+pub(crate) mod r#basket_entry;
+pub(crate) mod r#circuit_breaker;
+pub(crate) mod r#config_action;
+pub(crate) mod r#currency;
+pub(crate) mod r#curve_basis;
+pub(crate) mod r#curve_kind;
+pub(crate) mod r#discount_entry;
+pub(crate) mod r#discount_kind;
+pub(crate) mod r#fee_caps;
+pub(crate) mod r#history_field;
 pub(crate) mod r#internal_path;
+pub(crate) mod r#loyalty_rate;
+pub(crate) mod r#operator_entry;
+pub(crate) mod r#operator_role;
+pub(crate) mod r#outflow_window;
+pub(crate) mod r#pause_scope;
+pub(crate) mod r#payment_entry;
+pub(crate) mod r#pricing_curve;
+pub(crate) mod r#recipe_change;
+pub(crate) mod r#recipe_field;
+pub(crate) mod r#recipe_gate;
+pub(crate) mod r#recipe_kind;
+pub(crate) mod r#recipe_stats;
+pub(crate) mod r#recipe_values;
+pub(crate) mod r#swap_direction;
+pub(crate) mod r#swap_quote_v1;
+pub(crate) mod r#swap_window;
+pub(crate) mod r#update_recipe_v1_ix;
+pub(crate) mod r#wallet_limits;
 
+pub use self::r#asset_cooldown::*;
+pub use self::r#basket_entry::*;
+pub use self::r#circuit_breaker::*;
+pub use self::r#config_action::*;
+pub use self::r#currency::*;
+pub use self::r#curve_basis::*;
+pub use self::r#curve_kind::*;
+pub use self::r#discount_entry::*;
+pub use self::r#discount_kind::*;
+pub use self::r#fee_caps::*;
+pub use self::r#history_field::*;
 pub use self::r#internal_path::*;
+pub use self::r#loyalty_rate::*;
+pub use self::r#operator_entry::*;
+pub use self::r#operator_role::*;
+pub use self::r#outflow_window::*;
+pub use self::r#pause_scope::*;
+pub use self::r#payment_entry::*;
+pub use self::r#pricing_curve::*;
+pub use self::r#recipe_change::*;
+pub use self::r#recipe_field::*;
+pub use self::r#recipe_gate::*;
+pub use self::r#recipe_kind::*;
+pub use self::r#recipe_stats::*;
+pub use self::r#recipe_values::*;
+pub use self::r#swap_direction::*;
+pub use self::r#swap_quote_v1::*;
+pub use self::r#swap_window::*;
+pub use self::r#update_recipe_v1_ix::*;
+pub use self::r#wallet_limits::*;
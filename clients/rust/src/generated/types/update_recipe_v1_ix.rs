@@ -0,0 +1,50 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::AssetCooldown;
+use crate::generated::types::CircuitBreaker;
+use crate::generated::types::LoyaltyRate;
+use crate::generated::types::PricingCurve;
+use crate::generated::types::RecipeGate;
+use crate::generated::types::SwapWindow;
+use crate::generated::types::WalletLimits;
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpdateRecipeV1Ix {
+    pub name: Option<String>,
+    pub uri: Option<String>,
+    pub max: Option<u64>,
+    pub min: Option<u64>,
+    pub capture_amount: Option<u64>,
+    pub release_amount: Option<u64>,
+    pub fee_amount_capture: Option<u64>,
+    pub fee_amount_release: Option<u64>,
+    pub sol_fee_amount_capture: Option<u64>,
+    pub sol_fee_amount_release: Option<u64>,
+    pub path: Option<u16>,
+    pub pricing: Option<PricingCurve>,
+    pub inventory: Option<u64>,
+    pub quote_signer: Option<Pubkey>,
+    pub capture_window: Option<SwapWindow>,
+    pub release_window: Option<SwapWindow>,
+    pub wallet_limits: Option<WalletLimits>,
+    pub circuit_breaker: Option<CircuitBreaker>,
+    pub asset_cooldown: Option<AssetCooldown>,
+    pub loyalty: Option<LoyaltyRate>,
+    pub timelock_slots: Option<u64>,
+    pub asset_root: Option<[u8; 32]>,
+    pub wallet_root: Option<[u8; 32]>,
+    pub gate: Option<RecipeGate>,
+}
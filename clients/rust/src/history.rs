@@ -0,0 +1,160 @@
+//! Decoder for the recipe history account.
+//!
+//! `update_recipe_v1` and `execute_recipe_update_v1` append every change to a ring buffer kept at
+//! `["history", recipe]`. These helpers read the generated account and render each change as a
+//! diff.
+
+use std::fmt;
+
+use num_traits::FromPrimitive;
+
+use crate::accounts::RecipeHistoryV1;
+use crate::types::{HistoryField, RecipeChange, RecipeValues};
+
+impl HistoryField {
+    /// Whether the field's bit is set in a [`RecipeChange::changed`] bitmask.
+    pub fn check(&self, bits: u32) -> bool {
+        bits & (1 << self.clone() as u32) != 0
+    }
+}
+
+impl RecipeValues {
+    /// The recorded value of a numeric field, `None` for fields only tracked in the bitmask.
+    pub fn get(&self, field: &HistoryField) -> Option<u64> {
+        Some(match field {
+            HistoryField::Max => self.max,
+            HistoryField::Min => self.min,
+            HistoryField::CaptureAmount => self.capture_amount,
+            HistoryField::ReleaseAmount => self.release_amount,
+            HistoryField::FeeAmountCapture => self.fee_amount_capture,
+            HistoryField::FeeAmountRelease => self.fee_amount_release,
+            HistoryField::SolFeeAmountCapture => self.sol_fee_amount_capture,
+            HistoryField::SolFeeAmountRelease => self.sol_fee_amount_release,
+            HistoryField::Inventory => self.inventory,
+            HistoryField::Timelock => self.timelock_slots,
+            HistoryField::Path => self.path as u64,
+            _ => return None,
+        })
+    }
+}
+
+impl RecipeChange {
+    /// The fields the change touched, in the order of their bit.
+    pub fn changed_fields(&self) -> impl Iterator<Item = HistoryField> + '_ {
+        (0..u32::BITS)
+            .filter_map(HistoryField::from_u32)
+            .filter(|field| field.check(self.changed))
+    }
+}
+
+impl fmt::Display for RecipeChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "slot {} by {}", self.slot, self.signer)?;
+        for field in self.changed_fields() {
+            match (self.old.get(&field), self.new.get(&field)) {
+                (Some(old), Some(new)) => write!(f, "\n  {:?}: {} -> {}", field, old, new)?,
+                _ => write!(f, "\n  {:?}: changed", field)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl RecipeHistoryV1 {
+    pub const CAPACITY: usize = 16;
+
+    /// The recorded changes from oldest to newest.
+    pub fn changes(&self) -> impl Iterator<Item = &RecipeChange> {
+        // Once the buffer wraps, the oldest entry is the next one to be overwritten.
+        let start = if self.entries.len() < Self::CAPACITY {
+            0
+        } else {
+            (self.count % Self::CAPACITY as u64) as usize
+        };
+        self.entries[start..]
+            .iter()
+            .chain(self.entries[..start].iter())
+    }
+}
+
+impl fmt::Display for RecipeHistoryV1 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, change) in self.changes().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::pubkey::Pubkey;
+
+    use super::*;
+
+    fn values(capture_amount: u64) -> RecipeValues {
+        RecipeValues {
+            max: 0,
+            min: 0,
+            capture_amount,
+            release_amount: 0,
+            fee_amount_capture: 0,
+            fee_amount_release: 0,
+            sol_fee_amount_capture: 0,
+            sol_fee_amount_release: 0,
+            inventory: 0,
+            timelock_slots: 0,
+            path: 0,
+        }
+    }
+
+    fn change(slot: u64, changed: u32) -> RecipeChange {
+        RecipeChange {
+            slot,
+            signer: Pubkey::default(),
+            changed,
+            old: values(5),
+            new: values(7),
+        }
+    }
+
+    #[test]
+    fn changes_render_as_a_diff() {
+        let change = change(
+            1,
+            1 << HistoryField::CaptureAmount as u32 | 1 << HistoryField::Gate as u32,
+        );
+
+        assert_eq!(
+            change.changed_fields().collect::<Vec<_>>(),
+            vec![HistoryField::CaptureAmount, HistoryField::Gate]
+        );
+        assert_eq!(
+            change.to_string(),
+            format!(
+                "slot 1 by {}\n  CaptureAmount: 5 -> 7\n  Gate: changed",
+                Pubkey::default()
+            )
+        );
+    }
+
+    #[test]
+    fn a_full_history_starts_at_the_oldest_change() {
+        let history = RecipeHistoryV1 {
+            discriminator: [0; 8],
+            recipe: Pubkey::default(),
+            count: RecipeHistoryV1::CAPACITY as u64 + 2,
+            bump: 0,
+            entries: (0..RecipeHistoryV1::CAPACITY as u64)
+                .map(|slot| change(slot, 0))
+                .collect(),
+        };
+
+        let slots: Vec<u64> = history.changes().map(|change| change.slot).collect();
+        assert_eq!(slots[0], 2);
+        assert_eq!(slots[RecipeHistoryV1::CAPACITY - 1], 1);
+    }
+}
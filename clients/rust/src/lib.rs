@@ -1,4 +1,6 @@
 mod generated;
+pub mod history;
+pub mod pricing;
 
 pub use generated::programs::MPL_HYBRID_ID as ID;
 pub use generated::*;
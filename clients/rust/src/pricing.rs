@@ -0,0 +1,117 @@
+//! Quoting helpers for recipes with a pricing curve.
+//!
+//! These mirror the on-chain curve math so a client can show the price `capture_v2` or
+//! `release_v2` will use before sending the transaction.
+
+use crate::accounts::RecipeV1;
+use crate::types::{CurveBasis, CurveKind, PricingCurve};
+
+const MAX_BASIS_POINTS: u128 = 10_000;
+
+// Fixed point scale used when compounding exponential curves.
+const CURVE_SCALE: u128 = 1_000_000_000_000;
+
+impl PricingCurve {
+    /// How far the curve input is past the target, positive when the escrow is running short.
+    pub fn deviation(&self, inventory: u64, net_swaps: i64) -> i128 {
+        match self.basis {
+            CurveBasis::EscrowInventory => self.target as i128 - inventory as i128,
+            CurveBasis::NetSwaps => net_swaps as i128 - self.target as i128,
+        }
+    }
+
+    /// The price the program computes for `base` at the given curve deviation.
+    pub fn price(&self, base: u64, deviation: i128) -> u64 {
+        let price = match self.kind {
+            CurveKind::Fixed => return base,
+            CurveKind::Linear => (base as i128)
+                .saturating_add((self.factor as i128).saturating_mul(deviation))
+                .clamp(0, u64::MAX as i128) as u128,
+            CurveKind::Exponential => {
+                let multiplier = compound(self.factor, deviation.unsigned_abs());
+                if deviation >= 0 {
+                    (base as u128).saturating_mul(multiplier) / CURVE_SCALE
+                } else {
+                    (base as u128).saturating_mul(CURVE_SCALE) / multiplier
+                }
+            }
+        };
+
+        (price.min(u64::MAX as u128) as u64).clamp(self.min_price, self.max_price)
+    }
+}
+
+impl RecipeV1 {
+    /// The price `capture_v2` charges at the recipe's current counters.
+    pub fn capture_price(&self) -> u64 {
+        self.pricing.price(
+            self.capture_amount,
+            self.pricing.deviation(self.inventory, self.net_swaps),
+        )
+    }
+
+    /// The price `release_v2` pays out at the recipe's current counters.
+    pub fn release_price(&self) -> u64 {
+        self.pricing.price(
+            self.release_amount,
+            self.pricing.deviation(self.inventory, self.net_swaps),
+        )
+    }
+}
+
+fn compound(factor_bps: u64, exponent: u128) -> u128 {
+    let mut base = MAX_BASIS_POINTS
+        .saturating_add(factor_bps as u128)
+        .saturating_mul(CURVE_SCALE)
+        / MAX_BASIS_POINTS;
+    let mut result = CURVE_SCALE;
+    let mut exponent = exponent;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_scaled(result, base);
+        }
+        base = mul_scaled(base, base);
+        exponent >>= 1;
+    }
+
+    result
+}
+
+fn mul_scaled(a: u128, b: u128) -> u128 {
+    a.checked_mul(b)
+        .map_or(u128::MAX, |value| value / CURVE_SCALE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn curve(basis: CurveBasis) -> PricingCurve {
+        PricingCurve {
+            kind: CurveKind::Linear,
+            basis,
+            target: 10,
+            factor: 5,
+            min_price: 0,
+            max_price: u64::MAX,
+        }
+    }
+
+    #[test]
+    fn an_escrow_running_short_raises_the_price() {
+        let curve = curve(CurveBasis::EscrowInventory);
+
+        assert_eq!(curve.deviation(4, 0), 6);
+        assert_eq!(curve.price(100, curve.deviation(4, 0)), 130);
+        assert_eq!(curve.price(100, curve.deviation(16, 0)), 70);
+    }
+
+    #[test]
+    fn captures_outpacing_releases_raise_the_price() {
+        let curve = curve(CurveBasis::NetSwaps);
+
+        assert_eq!(curve.deviation(0, 13), 3);
+        assert_eq!(curve.price(100, curve.deviation(0, 7)), 85);
+    }
+}
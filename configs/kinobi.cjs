@@ -25,6 +25,15 @@ kinobi.update(
     })
 );
 
+// Flatten the recipe update arguments shared by the update instructions.
+kinobi.update(
+    k.unwrapTypeDefinedLinksVisitor([
+        "[instructionNode]updateRecipeV1.[definedTypeLinkNode]updateRecipeV1Ix",
+        "[instructionNode]queueRecipeUpdateV1.[definedTypeLinkNode]updateRecipeV1Ix",
+    ])
+);
+kinobi.update(k.flattenInstructionDataArgumentsVisitor());
+
 // Update Accounts.
 kinobi.update(
     k.updateAccountsVisitor({
@@ -58,9 +67,135 @@ kinobi.update(
                 ),
             ],
         },
+        operatorsV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("operators"),
+                k.variablePdaSeedNode(
+                    "recipe",
+                    k.publicKeyTypeNode(),
+                    "The address of the recipe"
+                ),
+            ],
+        },
+        paymentTableV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("payments"),
+                k.variablePdaSeedNode(
+                    "recipe",
+                    k.publicKeyTypeNode(),
+                    "The address of the recipe"
+                ),
+            ],
+        },
+        discountPolicyV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("discount"),
+                k.variablePdaSeedNode(
+                    "recipe",
+                    k.publicKeyTypeNode(),
+                    "The address of the recipe"
+                ),
+            ],
+        },
+        basketV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("basket"),
+                k.variablePdaSeedNode(
+                    "recipe",
+                    k.publicKeyTypeNode(),
+                    "The address of the recipe"
+                ),
+            ],
+        },
+        pendingRecipeUpdateV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("pending"),
+                k.variablePdaSeedNode(
+                    "recipe",
+                    k.publicKeyTypeNode(),
+                    "The address of the recipe"
+                ),
+            ],
+        },
+        recipeHistoryV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("history"),
+                k.variablePdaSeedNode(
+                    "recipe",
+                    k.publicKeyTypeNode(),
+                    "The address of the recipe"
+                ),
+            ],
+        },
+        recipeVaultV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("vault"),
+                k.variablePdaSeedNode(
+                    "recipe",
+                    k.publicKeyTypeNode(),
+                    "The address of the recipe"
+                ),
+            ],
+        },
+        walletActivityV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("wallet"),
+                k.variablePdaSeedNode(
+                    "recipe",
+                    k.publicKeyTypeNode(),
+                    "The address of the recipe"
+                ),
+                k.variablePdaSeedNode(
+                    "user",
+                    k.publicKeyTypeNode(),
+                    "The address of the user"
+                ),
+            ],
+        },
+        swapReceiptV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("receipt"),
+                k.variablePdaSeedNode(
+                    "recipe",
+                    k.publicKeyTypeNode(),
+                    "The address of the recipe"
+                ),
+                k.variablePdaSeedNode(
+                    "user",
+                    k.publicKeyTypeNode(),
+                    "The address of the user"
+                ),
+            ],
+        },
+        protocolConfigV1: {
+            seeds: [k.constantPdaSeedNodeFromString("protocol")],
+        },
     })
 );
 
+// Set default account values shared by several instructions.
+kinobi.update(
+    k.setInstructionAccountDefaultValuesVisitor([
+        {
+            account: "eventAuthority",
+            defaultValue: k.publicKeyValueNode("8fRXGhZVtcFcqXuQBLAnYKHwCnbDAjBbvUERYGVcfSRc"),
+        },
+        {
+            account: "program",
+            defaultValue: k.publicKeyValueNode("MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb"),
+        },
+        { account: "protocolConfig", ignoreIfOptional: true, defaultValue: k.pdaValueNode("protocolConfigV1") },
+        { account: "operators", ignoreIfOptional: true, defaultValue: k.pdaValueNode("operatorsV1") },
+        { account: "paymentTable", ignoreIfOptional: true, defaultValue: k.pdaValueNode("paymentTableV1") },
+        { account: "discountPolicy", ignoreIfOptional: true, defaultValue: k.pdaValueNode("discountPolicyV1") },
+        { account: "basket", ignoreIfOptional: true, defaultValue: k.pdaValueNode("basketV1") },
+        { account: "pendingUpdate", ignoreIfOptional: true, defaultValue: k.pdaValueNode("pendingRecipeUpdateV1") },
+        { account: "history", ignoreIfOptional: true, defaultValue: k.pdaValueNode("recipeHistoryV1") },
+        { account: "walletActivity", ignoreIfOptional: true, defaultValue: k.pdaValueNode("walletActivityV1") },
+        { account: "vault", ignoreIfOptional: true, defaultValue: k.pdaValueNode("recipeVaultV1") },
+    ])
+);
+
 // Update Instructions.
 const ataPdaDefault = (mint = "mint", owner = "owner") =>
     k.pdaValueNode(k.pdaLinkNode("associatedToken", "mplToolbox"), [
@@ -68,6 +203,13 @@ const ataPdaDefault = (mint = "mint", owner = "owner") =>
         k.pdaSeedValueNode("owner", k.accountValueNode(owner))
     ]);
 
+// Token accounts are only derived when the recipe swaps against a token.
+const tokenAtaPdaDefault = (owner) =>
+    k.conditionalValueNode({
+        condition: k.accountValueNode("token"),
+        ifTrue: ataPdaDefault("token", owner),
+    });
+
 kinobi.update(
     k.updateInstructionsVisitor({
         initEscrowV1: {
@@ -89,7 +231,8 @@ kinobi.update(
                 },
                 mplCore: { defaultValue: k.publicKeyValueNode("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d") },
                 recentBlockhashes: { defaultValue: k.publicKeyValueNode("SysvarS1otHashes111111111111111111111111111") },
-                feeSolAccount: { defaultValue: k.publicKeyValueNode("GjF4LqmEhV33riVyAwHwiEeAHx4XXFn2yMY3fmMigoP3") }
+                feeSolAccount: { defaultValue: k.publicKeyValueNode("GjF4LqmEhV33riVyAwHwiEeAHx4XXFn2yMY3fmMigoP3") },
+                protocolConfig: { defaultValue: k.pdaValueNode("protocolConfigV1") }
             }
         },
         releaseV1: {
@@ -103,7 +246,8 @@ kinobi.update(
                 },
                 mplCore: { defaultValue: k.publicKeyValueNode("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d") },
                 recentBlockhashes: { defaultValue: k.publicKeyValueNode("SysvarS1otHashes111111111111111111111111111") },
-                feeSolAccount: { defaultValue: k.publicKeyValueNode("GjF4LqmEhV33riVyAwHwiEeAHx4XXFn2yMY3fmMigoP3") }
+                feeSolAccount: { defaultValue: k.publicKeyValueNode("GjF4LqmEhV33riVyAwHwiEeAHx4XXFn2yMY3fmMigoP3") },
+                protocolConfig: { defaultValue: k.pdaValueNode("protocolConfigV1") }
             }
         },
         initEscrowV2: {
@@ -116,7 +260,7 @@ kinobi.update(
         initRecipeV1: {
             accounts: {
                 recipe: { defaultValue: k.pdaValueNode("recipeV1") },
-                feeAta: { defaultValue: ataPdaDefault("token", "feeLocation") },
+                feeAta: { defaultValue: tokenAtaPdaDefault("feeLocation") },
                 associatedTokenProgram: {
                     defaultValue: k.publicKeyValueNode("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")
                 }
@@ -125,29 +269,64 @@ kinobi.update(
         captureV2: {
             accounts: {
                 authority: { isSigner: 'either' },
-                feeTokenAccount: { defaultValue: ataPdaDefault("token", "feeProjectAccount") },
-                escrowTokenAccount: { defaultValue: ataPdaDefault("token", "escrow") },
-                userTokenAccount: { defaultValue: ataPdaDefault("token", "owner") },
+                feeTokenAccount: { defaultValue: tokenAtaPdaDefault("feeProjectAccount") },
+                escrowTokenAccount: { defaultValue: tokenAtaPdaDefault("escrow") },
+                userTokenAccount: { defaultValue: tokenAtaPdaDefault("owner") },
                 associatedTokenProgram: {
                     defaultValue: k.publicKeyValueNode("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")
                 },
                 mplCore: { defaultValue: k.publicKeyValueNode("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d") },
                 recentBlockhashes: { defaultValue: k.publicKeyValueNode("SysvarS1otHashes111111111111111111111111111") },
-                feeSolAccount: { defaultValue: k.publicKeyValueNode("C3iyKknpNPeZXQEVLkR8ZJxcgB8xdsqXkyrV1RwEmdrD") }
+                feeSolAccount: { defaultValue: k.publicKeyValueNode("C3iyKknpNPeZXQEVLkR8ZJxcgB8xdsqXkyrV1RwEmdrD") },
+                protocolConfig: { defaultValue: k.pdaValueNode("protocolConfigV1") }
             }
         },
         releaseV2: {
             accounts: {
                 authority: { isSigner: 'either' },
-                feeTokenAccount: { defaultValue: ataPdaDefault("token", "feeProjectAccount") },
-                escrowTokenAccount: { defaultValue: ataPdaDefault("token", "escrow") },
-                userTokenAccount: { defaultValue: ataPdaDefault("token", "owner") },
+                feeTokenAccount: { defaultValue: tokenAtaPdaDefault("feeProjectAccount") },
+                escrowTokenAccount: { defaultValue: tokenAtaPdaDefault("escrow") },
+                userTokenAccount: { defaultValue: tokenAtaPdaDefault("owner") },
                 associatedTokenProgram: {
                     defaultValue: k.publicKeyValueNode("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")
                 },
                 mplCore: { defaultValue: k.publicKeyValueNode("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d") },
                 recentBlockhashes: { defaultValue: k.publicKeyValueNode("SysvarS1otHashes111111111111111111111111111") },
-                feeSolAccount: { defaultValue: k.publicKeyValueNode("C3iyKknpNPeZXQEVLkR8ZJxcgB8xdsqXkyrV1RwEmdrD") }
+                feeSolAccount: { defaultValue: k.publicKeyValueNode("C3iyKknpNPeZXQEVLkR8ZJxcgB8xdsqXkyrV1RwEmdrD") },
+                protocolConfig: { defaultValue: k.pdaValueNode("protocolConfigV1") }
+            }
+        },
+        migrateNftV1: {
+            accounts: {
+                recipe: { defaultValue: k.pdaValueNode("recipeV1") }
+            }
+        },
+        migrateTokensV1: {
+            accounts: {
+                recipe: { defaultValue: k.pdaValueNode("recipeV1") }
+            }
+        },
+        transferEscrowAssetV1: {
+            accounts: {
+                mplCore: { defaultValue: k.publicKeyValueNode("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d") },
+            }
+        },
+        transferEscrowReserveV1: {
+            accounts: {
+                escrowNewTokenAccount: { defaultValue: ataPdaDefault("token", "escrowNew") },
+                escrowOldTokenAccount: { defaultValue: ataPdaDefault("token", "escrowOld") },
+                associatedTokenProgram: {
+                    defaultValue: k.publicKeyValueNode("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")
+                },
+            }
+        },
+        withdrawEscrowV1: {
+            accounts: {
+                feeTokenAccount: { defaultValue: tokenAtaPdaDefault("feeProjectAccount") },
+                escrowTokenAccount: { defaultValue: tokenAtaPdaDefault("escrow") },
+                associatedTokenProgram: {
+                    defaultValue: k.publicKeyValueNode("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")
+                },
             }
         },
     })
@@ -7,6 +7,8 @@ pub const FEE_WALLET_V2: Pubkey = pubkey!("C3iyKknpNPeZXQEVLkR8ZJxcgB8xdsqXkyrV1
 pub const SLOT_HASHES: Pubkey = pubkey!("SysvarS1otHashes111111111111111111111111111");
 pub const MPL_CORE: Pubkey = pubkey!("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d");
 
+pub const MAX_BASIS_POINTS: u16 = 10_000;
+
 const PROTOCOL_FEE_SCALAR: usize = 590;
 const PROTOCOL_FEE_OFFSET: u64 = 2_720;
 pub fn get_protocol_fee() -> Result<u64> {
@@ -81,4 +81,196 @@ pub enum MplHybridError {
     /// 6019 (0x1783) - Release is blocked for this recipe
     #[msg("Release is blocked for this recipe")]
     ReleaseBlocked,
+
+    /// 6020 (0x1784) - Discount exceeds 100%
+    #[msg("Discount exceeds 100%")]
+    InvalidDiscount,
+
+    /// 6021 (0x1785) - Discount proof does not qualify for a discount
+    #[msg("Discount proof does not qualify for a discount")]
+    InvalidDiscountProof,
+
+    /// 6022 (0x1786) - Pricing curve minimum exceeds its maximum
+    #[msg("Pricing curve minimum exceeds its maximum")]
+    InvalidPricingCurve,
+
+    /// 6023 (0x1787) - Token account is required for this recipe
+    #[msg("Token account is required for this recipe")]
+    MissingTokenAccount,
+
+    /// 6024 (0x1788) - Escrow balance is too low
+    #[msg("Escrow balance is too low")]
+    InsufficientEscrowBalance,
+
+    /// 6025 (0x1789) - Path is not supported for this currency
+    #[msg("Path is not supported for this currency")]
+    InvalidPathForCurrency,
+
+    /// 6026 (0x178A) - Basket entries are invalid
+    #[msg("Basket entries are invalid")]
+    InvalidBasket,
+
+    /// 6027 (0x178B) - Basket account is required for this recipe
+    #[msg("Basket account is required for this recipe")]
+    MissingBasketAccount,
+
+    /// 6028 (0x178C) - Payment table entries are invalid
+    #[msg("Payment table entries are invalid")]
+    InvalidPaymentTable,
+
+    /// 6029 (0x178D) - Mint is not accepted by this recipe
+    #[msg("Mint is not accepted by this recipe")]
+    PaymentMintNotAccepted,
+
+    /// 6030 (0x178E) - Price quote is invalid
+    #[msg("Price quote is invalid")]
+    InvalidQuote,
+
+    /// 6031 (0x178F) - Price quote has expired
+    #[msg("Price quote has expired")]
+    QuoteExpired,
+
+    /// 6032 (0x1790) - Price quote has already been used
+    #[msg("Price quote has already been used")]
+    QuoteAlreadyUsed,
+
+    /// 6033 (0x1791) - Capture window has not opened yet
+    #[msg("Capture window has not opened yet")]
+    CaptureNotOpen,
+
+    /// 6034 (0x1792) - Capture window has closed
+    #[msg("Capture window has closed")]
+    CaptureClosed,
+
+    /// 6035 (0x1793) - Release window has not opened yet
+    #[msg("Release window has not opened yet")]
+    ReleaseNotOpen,
+
+    /// 6036 (0x1794) - Release window has closed
+    #[msg("Release window has closed")]
+    ReleaseClosed,
+
+    /// 6037 (0x1795) - Swap window must start before it ends
+    #[msg("Swap window must start before it ends")]
+    InvalidSwapWindow,
+
+    /// 6038 (0x1796) - Wallet is still cooling down from its last swap
+    #[msg("Wallet is still cooling down from its last swap")]
+    WalletCooldownActive,
+
+    /// 6039 (0x1797) - Wallet has reached its capture limit
+    #[msg("Wallet has reached its capture limit")]
+    WalletCaptureLimitReached,
+
+    /// 6040 (0x1798) - Wallet has reached its release limit
+    #[msg("Wallet has reached its release limit")]
+    WalletReleaseLimitReached,
+
+    /// 6041 (0x1799) - Wallet activity account is invalid
+    #[msg("Wallet activity account is invalid")]
+    InvalidWalletActivity,
+
+    /// 6042 (0x179A) - Wallet activity window has not passed
+    #[msg("Wallet activity window has not passed")]
+    WalletActivityNotExpired,
+
+    /// 6043 (0x179B) - Release limit of the recipe is reached for this window
+    #[msg("Release limit of the recipe is reached for this window")]
+    CircuitBreakerTripped,
+
+    /// 6044 (0x179C) - Recipe is paused
+    #[msg("Recipe is paused")]
+    RecipePaused,
+
+    /// 6045 (0x179D) - Protocol is paused
+    #[msg("Protocol is paused")]
+    ProtocolPaused,
+
+    /// 6046 (0x179E) - Signer is not the protocol admin
+    #[msg("Signer is not the protocol admin")]
+    InvalidProtocolAdmin,
+
+    /// 6047 (0x179F) - Account to pause is missing
+    #[msg("Account to pause is missing")]
+    MissingPauseAccount,
+
+    /// 6048 (0x17A0) - Asset is still cooling down from its last swap
+    #[msg("Asset is still cooling down from its last swap")]
+    AssetCooldownActive,
+
+    /// 6049 (0x17A1) - Asset cooldown account is invalid
+    #[msg("Asset cooldown account is invalid")]
+    InvalidAssetCooldown,
+
+    /// 6050 (0x17A2) - Swap receipt account is invalid
+    #[msg("Swap receipt account is invalid")]
+    InvalidSwapReceipt,
+
+    /// 6051 (0x17A3) - Signer is not the pending authority
+    #[msg("Signer is not the pending authority")]
+    InvalidPendingAuthority,
+
+    /// 6052 (0x17A4) - Operator is listed more than once
+    #[msg("Operator is listed more than once")]
+    DuplicateOperator,
+
+    /// 6053 (0x17A5) - Change must be queued behind the recipe timelock
+    #[msg("Change must be queued behind the recipe timelock")]
+    TimelockActive,
+
+    /// 6054 (0x17A6) - A recipe update is already queued
+    #[msg("A recipe update is already queued")]
+    RecipeUpdateAlreadyQueued,
+
+    /// 6055 (0x17A7) - Queued recipe update is not active yet
+    #[msg("Queued recipe update is not active yet")]
+    TimelockNotElapsed,
+
+    /// 6056 (0x17A8) - Recipe field is locked
+    #[msg("Recipe field is locked")]
+    RecipeFieldLocked,
+
+    /// 6057 (0x17A9) - Fee is above the recipe fee cap
+    #[msg("Fee is above the recipe fee cap")]
+    FeeCapExceeded,
+
+    /// 6058 (0x17AA) - Fee caps can only be lowered
+    #[msg("Fee caps can only be lowered")]
+    InvalidFeeCap,
+
+    /// 6059 (0x17AB) - Asset is not in the recipe allowlist
+    #[msg("Asset is not in the recipe allowlist")]
+    AssetNotAllowlisted,
+
+    /// 6060 (0x17AC) - Wallet is not in the recipe allowlist
+    #[msg("Wallet is not in the recipe allowlist")]
+    WalletNotAllowlisted,
+
+    /// 6061 (0x17AD) - Gate asset is invalid
+    #[msg("Gate asset is invalid")]
+    InvalidGateAsset,
+
+    /// 6062 (0x17AE) - Not enough gate collection assets held
+    #[msg("Not enough gate collection assets held")]
+    GateNotMet,
+
+    /// 6063 (0x17AF) - Inventory can only be set once before the first swap
+    #[msg("Inventory can only be set once before the first swap")]
+    InventoryCannotBeSet,
+
+    /// 6064 (0x17B0) - Native SOL recipes need their vault account
+    #[msg("Native SOL recipes need their vault account")]
+    MissingVaultAccount,
+
+    /// 6065 (0x17B1) - Quoted recipes can't be paid with an alternative payment
+    #[msg("Quoted recipes can't be paid with an alternative payment")]
+    QuoteWithAlternativePayment,
+
+    /// 6066 (0x17B2) - Recipe configuration can't change while the recipe is timelocked
+    #[msg("Recipe configuration can't change while the recipe is timelocked")]
+    RecipeTimelocked,
+
+    /// 6067 (0x17B3) - Lock fields include undefined recipe fields
+    #[msg("Lock fields include undefined recipe fields")]
+    InvalidRecipeFields,
 }
@@ -0,0 +1,192 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+// Whether a config event comes from creating, updating or migrating the account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum ConfigAction {
+    Init,
+    Update,
+    Migrate,
+}
+
+#[event]
+pub struct CaptureEvent {
+    // the recipe, the default key for v1 swaps which have none
+    pub recipe: Pubkey,
+    pub escrow: Pubkey,
+    pub asset: Pubkey,
+    pub user: Pubkey,
+    // the mint paid with, the native mint for SOL recipes and the default key for baskets
+    pub mint: Pubkey,
+    // the amount moved into the escrow
+    pub amount: u64,
+    // the amount burned instead of moved into the escrow
+    pub burned: u64,
+    pub fee_amount: u64,
+    pub sol_fee_amount: u64,
+    pub protocol_fee: u64,
+    // the metadata index rolled for the asset, when the metadata is rerolled
+    pub index: Option<u64>,
+    pub uri: Option<String>,
+    // the entries charged by basket recipes, with their per-token amounts
+    pub basket: Vec<BasketEntry>,
+}
+
+#[event]
+pub struct ReleaseEvent {
+    // the recipe, the default key for v1 swaps which have none
+    pub recipe: Pubkey,
+    pub escrow: Pubkey,
+    pub asset: Pubkey,
+    pub user: Pubkey,
+    // the mint paid out, the native mint for SOL recipes and the default key for baskets
+    pub mint: Pubkey,
+    // the amount paid out of the escrow
+    pub amount: u64,
+    pub asset_burned: bool,
+    // always 0 for v1 swaps, v1 escrows only charge a token fee on capture
+    pub fee_amount: u64,
+    pub sol_fee_amount: u64,
+    pub protocol_fee: u64,
+    // the captured metadata uri, when the metadata is rerolled
+    pub uri: Option<String>,
+    // the entries paid out by basket recipes, with their per-token amounts
+    pub basket: Vec<BasketEntry>,
+}
+
+#[event]
+pub struct RecipeEvent {
+    pub action: ConfigAction,
+    pub recipe: Pubkey,
+    pub data: RecipeV1,
+}
+
+#[event]
+pub struct EscrowEvent {
+    pub action: ConfigAction,
+    pub escrow: Pubkey,
+    pub data: EscrowV1,
+}
+
+#[event]
+pub struct EscrowV2Event {
+    pub action: ConfigAction,
+    pub escrow: Pubkey,
+    pub data: EscrowV2,
+}
+
+#[event]
+pub struct NftDataEvent {
+    pub action: ConfigAction,
+    pub nft_data: Pubkey,
+    pub data: NftDataV1,
+}
+
+#[event]
+pub struct DiscountPolicyEvent {
+    pub action: ConfigAction,
+    pub discount_policy: Pubkey,
+    pub data: DiscountPolicyV1,
+}
+
+#[event]
+pub struct BasketEvent {
+    pub action: ConfigAction,
+    pub basket: Pubkey,
+    pub data: BasketV1,
+}
+
+#[event]
+pub struct PaymentTableEvent {
+    pub action: ConfigAction,
+    pub payment_table: Pubkey,
+    pub data: PaymentTableV1,
+}
+
+#[event]
+pub struct ProtocolConfigEvent {
+    pub action: ConfigAction,
+    pub protocol_config: Pubkey,
+    pub data: ProtocolConfigV1,
+}
+
+#[event]
+pub struct OperatorsEvent {
+    pub action: ConfigAction,
+    pub operators: Pubkey,
+    pub data: OperatorsV1,
+}
+
+#[event]
+pub struct RecipeUpdateQueuedEvent {
+    pub recipe: Pubkey,
+    pub pending_update: Pubkey,
+    pub data: PendingRecipeUpdateV1,
+}
+
+#[event]
+pub struct RecipeUpdateCancelledEvent {
+    pub recipe: Pubkey,
+    pub pending_update: Pubkey,
+}
+
+#[event]
+pub struct MigrateNftEvent {
+    pub escrow_old: Pubkey,
+    pub escrow_new: Pubkey,
+    pub asset: Pubkey,
+}
+
+#[event]
+pub struct MigrateTokensEvent {
+    pub escrow_old: Pubkey,
+    pub escrow_new: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct EscrowAssetTransferEvent {
+    pub escrow_old: Pubkey,
+    pub escrow_new: Pubkey,
+    pub asset: Pubkey,
+}
+
+#[event]
+pub struct EscrowReserveTransferEvent {
+    pub escrow_old: Pubkey,
+    pub escrow_new: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct EscrowWithdrawEvent {
+    pub recipe: Pubkey,
+    pub escrow: Pubkey,
+    // the collection update authority or the operator that withdrew
+    pub authority: Pubkey,
+    // the native mint when lamports are withdrawn
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PauseEvent {
+    // the paused recipe, none for the whole protocol
+    pub recipe: Option<Pubkey>,
+    pub paused: bool,
+    pub reason: u16,
+}
+
+#[event]
+pub struct CircuitBreakerResetEvent {
+    pub recipe: Pubkey,
+    pub window_start: u64,
+}
+
+#[event]
+pub struct WalletActivityClosedEvent {
+    pub recipe: Pubkey,
+    pub user: Pubkey,
+}
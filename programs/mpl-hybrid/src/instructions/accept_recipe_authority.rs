@@ -0,0 +1,51 @@
+use crate::error::MplHybridError;
+use crate::events::{ConfigAction, RecipeEvent};
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptRecipeAuthorityV1Ctx<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "recipe".as_bytes(), 
+            recipe.collection.as_ref()
+            ],
+        bump=recipe.bump,
+    )]
+    recipe: Account<'info, RecipeV1>,
+
+    #[account(
+        address = recipe.pending_authority @MplHybridError::InvalidPendingAuthority
+    )]
+    authority: Signer<'info>,
+
+    // Swaps need the escrow of the recipe authority, so it has to exist before the handover.
+    #[account(
+        seeds = [
+            "escrow".as_bytes(), 
+            authority.key().as_ref()
+            ],
+        bump=escrow.bump,
+    )]
+    escrow: Account<'info, EscrowV2>,
+}
+
+pub fn handler_accept_recipe_authority_v1(ctx: Context<AcceptRecipeAuthorityV1Ctx>) -> Result<()> {
+    let recipe = &mut ctx.accounts.recipe;
+    let authority = &ctx.accounts.authority;
+
+    // The previous authority can then move its escrowed assets and reserves over.
+    recipe.previous_authority = recipe.authority;
+    recipe.authority = authority.key();
+    recipe.pending_authority = Pubkey::default();
+
+    emit_cpi!(RecipeEvent {
+        action: ConfigAction::Update,
+        recipe: recipe.key(),
+        data: (**recipe).clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use crate::events::RecipeUpdateCancelledEvent;
+use crate::state::*;
+use crate::utils::assert_recipe_admin;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelRecipeUpdateV1Ctx<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "pending".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=pending_update.bump,
+        close = queued_by,
+    )]
+    pending_update: Account<'info, PendingRecipeUpdateV1>,
+
+    #[account(
+        seeds = [
+            "recipe".as_bytes(), 
+            collection.key().as_ref()
+            ],
+        bump=recipe.bump,
+    )]
+    recipe: Account<'info, RecipeV1>,
+
+    authority: Signer<'info>,
+
+    /// CHECK: We check against recipe
+    #[account(
+        address = recipe.collection
+    )]
+    collection: AccountInfo<'info>,
+
+    /// CHECK: We check against the pending update
+    #[account(mut,
+        address = pending_update.authority
+    )]
+    queued_by: AccountInfo<'info>,
+
+    #[account(
+        seeds = [
+            "operators".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=operators.bump,
+    )]
+    operators: Option<Account<'info, OperatorsV1>>,
+}
+
+pub fn handler_cancel_recipe_update_v1(ctx: Context<CancelRecipeUpdateV1Ctx>) -> Result<()> {
+    let pending_update = &ctx.accounts.pending_update;
+    let recipe = &ctx.accounts.recipe;
+
+    // Cancelling needs the same roles as queueing the update.
+    assert_recipe_admin(
+        recipe,
+        &ctx.accounts.authority.key(),
+        ctx.accounts.operators.as_deref(),
+        &pending_update
+            .update
+            .roles(recipe, pending_update.token, pending_update.fee_location),
+    )?;
+
+    emit_cpi!(RecipeUpdateCancelledEvent {
+        recipe: recipe.key(),
+        pending_update: pending_update.key(),
+    });
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use crate::error::MplHybridError;
+use crate::events::CaptureEvent;
 use crate::state::*;
-use crate::utils::validate_token_account;
+use crate::utils::{assert_protocol_not_paused, validate_token_account};
 use crate::{constants::*, utils::create_associated_token_account};
 use anchor_lang::{
     accounts::{program::Program, signer::Signer, unchecked_account::UncheckedAccount},
@@ -20,6 +21,7 @@ use mpl_core::types::UpdateAuthority;
 use mpl_utils::assert_signer;
 use solana_program::program::invoke;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CaptureV1Ctx<'info> {
     #[account(mut)]
@@ -94,6 +96,13 @@ pub struct CaptureV1Ctx<'info> {
     system_program: Program<'info, System>,
     token_program: Program<'info, Token>,
     associated_token_program: Program<'info, AssociatedToken>,
+
+    /// CHECK: Checked by seeds, it is required so the protocol pause can't be skipped.
+    #[account(
+        seeds = ["protocol".as_bytes()],
+        bump,
+    )]
+    protocol_config: AccountInfo<'info>,
 }
 
 pub fn handler_capture_v1(ctx: Context<CaptureV1Ctx>) -> Result<()> {
@@ -116,6 +125,8 @@ pub fn handler_capture_v1(ctx: Context<CaptureV1Ctx>) -> Result<()> {
     let escrow_info = &escrow.to_account_info();
     let system_info = &system_program.to_account_info();
 
+    assert_protocol_not_paused(&ctx.accounts.protocol_config)?;
+
     // The user token account should already exist.
     validate_token_account(user_token_account, &owner.key(), &ctx.accounts.token.key())?;
 
@@ -164,6 +175,9 @@ pub fn handler_capture_v1(ctx: Context<CaptureV1Ctx>) -> Result<()> {
         assert_signer(&ctx.accounts.authority)?;
     }
 
+    let mut index = None;
+    let mut new_uri = None;
+
     //If the path has bit 0 unset, we need to update the metadata onchain
     if !Path::NoRerollMetadata.check(escrow.path) {
         let clock = Clock::get()?;
@@ -190,6 +204,9 @@ pub fn handler_capture_v1(ctx: Context<CaptureV1Ctx>) -> Result<()> {
         uri.push_str(&remainder.to_string());
         uri.push_str(&json_extension);
 
+        index = Some(remainder);
+        new_uri = Some(uri.clone());
+
         //create update instruction
         let update_ix = UpdateV1Cpi {
             __program: &mpl_core.to_account_info(),
@@ -261,10 +278,11 @@ pub fn handler_capture_v1(ctx: Context<CaptureV1Ctx>) -> Result<()> {
     token::transfer(transfer_fees_cpi_ctx, escrow.fee_amount)?;
 
     //create protocol transfer fee sol instruction
+    let protocol_fee = get_protocol_fee()?;
     let sol_fee_ix = anchor_lang::solana_program::system_instruction::transfer(
         &owner.key(),
         &fee_sol_account.key(),
-        get_protocol_fee()?,
+        protocol_fee,
     );
 
     //invoke protocol the transfer fee sol instruction
@@ -292,5 +310,21 @@ pub fn handler_capture_v1(ctx: Context<CaptureV1Ctx>) -> Result<()> {
     //increment the swap count
     escrow.count += 1;
 
+    emit_cpi!(CaptureEvent {
+        recipe: Pubkey::default(),
+        escrow: escrow.key(),
+        asset: asset.key(),
+        user: owner.key(),
+        mint: escrow.token,
+        amount: escrow.amount,
+        burned: 0,
+        fee_amount: escrow.fee_amount,
+        sol_fee_amount: escrow.sol_fee_amount,
+        protocol_fee,
+        index,
+        uri: new_uri,
+        basket: Vec::new(),
+    });
+
     Ok(())
 }
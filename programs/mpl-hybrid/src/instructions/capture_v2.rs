@@ -1,6 +1,11 @@
 use crate::error::MplHybridError;
+use crate::events::CaptureEvent;
 use crate::state::*;
-use crate::utils::validate_token_account;
+use crate::utils::{
+    apply_discount, assert_gate_holder, assert_protocol_not_paused, capture_basket,
+    consume_quote_nonce, get_holder_discount_bps, record_asset_swap, record_swap_receipt,
+    record_wallet_swap, validate_token_account, verify_merkle_proof, verify_swap_quote,
+};
 use crate::{constants::*, utils::create_associated_token_account};
 use anchor_lang::{
     accounts::{program::Program, signer::Signer, unchecked_account::UncheckedAccount},
@@ -16,10 +21,18 @@ use mpl_core::accounts::BaseAssetV1;
 use mpl_core::instructions::{
     TransferV1Cpi, TransferV1InstructionArgs, UpdateV1Cpi, UpdateV1InstructionArgs,
 };
-use mpl_core::types::UpdateAuthority;
 use mpl_utils::assert_signer;
 use solana_program::program::invoke;
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CaptureV2Ix {
+    // The proof of the asset against the recipe asset root, empty when the root is unset.
+    asset_proof: Vec<[u8; 32]>,
+    // The proof of the owner against the recipe wallet root, empty when the root is unset.
+    wallet_proof: Vec<[u8; 32]>,
+}
+
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CaptureV2Ctx<'info> {
     #[account(mut)]
@@ -61,22 +74,20 @@ pub struct CaptureV2Ctx<'info> {
 
     /// CHECK: We check and initialize the token account below.
     #[account(mut)]
-    user_token_account: AccountInfo<'info>,
+    user_token_account: Option<AccountInfo<'info>>,
 
     /// CHECK: We check and initialize the token account below.
     #[account(mut)]
-    escrow_token_account: AccountInfo<'info>,
+    escrow_token_account: Option<AccountInfo<'info>>,
 
     /// CHECK: This is a user defined account
-    #[account(
-        mut,
-        address = recipe.token @MplHybridError::InvalidMintAccount
-    )]
-    token: Account<'info, Mint>,
+    /// Checked against the recipe token and its payment table in the handler.
+    #[account(mut)]
+    token: Option<Box<Account<'info, Mint>>>,
 
     /// CHECK: We check and initialize the token account below.
     #[account(mut)]
-    fee_token_account: AccountInfo<'info>,
+    fee_token_account: Option<AccountInfo<'info>>,
 
     /// CHECK: We check against constant
     #[account(mut,
@@ -105,9 +116,81 @@ pub struct CaptureV2Ctx<'info> {
     system_program: Program<'info, System>,
     token_program: Program<'info, Token>,
     associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(
+        seeds = [
+            "discount".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=discount_policy.bump,
+    )]
+    discount_policy: Option<Box<Account<'info, DiscountPolicyV1>>>,
+
+    /// CHECK: We check against the discount policy in the handler.
+    discount_proof: Option<AccountInfo<'info>>,
+
+    #[account(
+        seeds = [
+            "basket".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=basket.bump,
+    )]
+    basket: Option<Box<Account<'info, BasketV1>>>,
+
+    #[account(
+        seeds = [
+            "payments".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=payment_table.bump,
+    )]
+    payment_table: Option<Box<Account<'info, PaymentTableV1>>>,
+
+    /// CHECK: Checked by address, needed to read the quote of quoted recipes.
+    #[account(
+        address = solana_program::sysvar::instructions::ID @MplHybridError::InvalidQuote
+    )]
+    instructions_sysvar: Option<AccountInfo<'info>>,
+
+    /// CHECK: We check and initialize the quote nonce in the handler.
+    #[account(mut)]
+    quote_nonce: Option<AccountInfo<'info>>,
+
+    /// CHECK: We check and initialize the wallet activity in the handler.
+    #[account(mut)]
+    wallet_activity: Option<AccountInfo<'info>>,
+
+    /// CHECK: We check and initialize the asset cooldown in the handler.
+    #[account(mut)]
+    asset_cooldown: Option<AccountInfo<'info>>,
+
+    /// CHECK: We check and initialize the swap receipt in the handler.
+    #[account(mut)]
+    swap_receipt: Option<AccountInfo<'info>>,
+
+    #[account(
+        mut,
+        seeds = [
+            "vault".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=vault.bump,
+    )]
+    vault: Option<Box<Account<'info, RecipeVaultV1>>>,
+
+    /// CHECK: Checked by seeds, it is required so the protocol pause can't be skipped.
+    #[account(
+        seeds = ["protocol".as_bytes()],
+        bump,
+    )]
+    protocol_config: AccountInfo<'info>,
 }
 
-pub fn handler_capture_v2(ctx: Context<CaptureV2Ctx>) -> Result<()> {
+pub fn handler_capture_v2<'info>(
+    ctx: Context<'_, '_, '_, 'info, CaptureV2Ctx<'info>>,
+    ix: CaptureV2Ix,
+) -> Result<()> {
     let owner = &mut ctx.accounts.owner;
     let escrow = &mut ctx.accounts.escrow;
     let recipe = &mut ctx.accounts.recipe;
@@ -115,15 +198,17 @@ pub fn handler_capture_v2(ctx: Context<CaptureV2Ctx>) -> Result<()> {
     let authority = &mut ctx.accounts.authority;
     let collection = &mut ctx.accounts.collection;
     let mpl_core = &mut ctx.accounts.mpl_core;
-    let user_token_account = &mut ctx.accounts.user_token_account;
-    let escrow_token_account = &mut ctx.accounts.escrow_token_account;
-    let fee_token_account = &mut ctx.accounts.fee_token_account;
     let fee_sol_account = &mut ctx.accounts.fee_sol_account;
     let fee_project_account = &mut ctx.accounts.fee_project_account;
     let system_program = &mut ctx.accounts.system_program;
     let token_program = &mut ctx.accounts.token_program;
 
     let collection_info = &collection.to_account_info();
+    // Standalone assets aren't part of a collection.
+    let core_collection = match recipe.kind {
+        RecipeKind::Collection => Some(collection_info),
+        RecipeKind::Creator => None,
+    };
     let authority_info = &authority.to_account_info();
     let escrow_info = &escrow.to_account_info();
     let system_info = &system_program.to_account_info();
@@ -132,58 +217,222 @@ pub fn handler_capture_v2(ctx: Context<CaptureV2Ctx>) -> Result<()> {
         return Err(MplHybridError::InvalidAuthority.into());
     }
 
+    assert_protocol_not_paused(&ctx.accounts.protocol_config)?;
+
+    if recipe.paused {
+        msg!("Pause reason: {}", recipe.pause_reason);
+        return Err(MplHybridError::RecipePaused.into());
+    }
+
     if Path::BlockCapture.check(recipe.path) {
         return Err(MplHybridError::CaptureBlocked.into());
     }
 
-    // The user token account should already exist.
-    validate_token_account(user_token_account, &owner.key(), &ctx.accounts.token.key())?;
+    // Scheduled recipes only swap inside their window.
+    let now = Clock::get()?.unix_timestamp;
+    if !recipe.capture_window.has_opened(now) {
+        return Err(MplHybridError::CaptureNotOpen.into());
+    } else if recipe.capture_window.has_closed(now) {
+        return Err(MplHybridError::CaptureClosed.into());
+    }
+
+    // Allowlisted recipes only swap with the wallets in the list.
+    if recipe.wallet_root != [0; 32]
+        && !verify_merkle_proof(&recipe.wallet_root, &owner.key(), &ix.wallet_proof)
+    {
+        return Err(MplHybridError::WalletNotAllowlisted.into());
+    }
+
+    // Basket accounts come first in the remaining accounts, the gate assets follow them.
+    let (basket_accounts, gate_assets) = match (&recipe.currency, &ctx.accounts.basket) {
+        (Currency::Basket, Some(basket)) => basket.split_accounts(ctx.remaining_accounts),
+        _ => (&[][..], ctx.remaining_accounts),
+    };
+
+    // Gated recipes only capture for holders of the gate collection.
+    if recipe.gate.is_enabled() {
+        assert_gate_holder(&recipe.gate, gate_assets, &owner.key())?;
+    }
 
-    if escrow_token_account.owner == &system_program::ID {
-        create_associated_token_account(
+    // Limited recipes track the swaps of every wallet.
+    if recipe.wallet_limits.is_enabled() {
+        record_wallet_swap(
+            ctx.accounts
+                .wallet_activity
+                .as_ref()
+                .ok_or(MplHybridError::InvalidWalletActivity)?,
+            &recipe.key(),
+            &recipe.wallet_limits,
+            SwapDirection::Capture,
             owner,
-            &escrow.to_account_info(),
-            &ctx.accounts.token.to_account_info(),
-            escrow_token_account,
-            token_program,
             system_program,
         )?;
-    } else {
-        validate_token_account(
-            escrow_token_account,
-            &escrow.key(),
-            &ctx.accounts.token.key(),
+    }
+
+    // Assets have to stay on each side of the escrow for the recipe's cooldown.
+    if recipe.asset_cooldown.is_enabled() {
+        record_asset_swap(
+            ctx.accounts
+                .asset_cooldown
+                .as_ref()
+                .ok_or(MplHybridError::InvalidAssetCooldown)?,
+            &asset.key(),
+            &recipe.asset_cooldown,
+            SwapDirection::Capture,
+            owner,
+            system_program,
         )?;
     }
 
-    if fee_token_account.owner == &system_program::ID {
-        create_associated_token_account(
+    // Holders of a qualifying asset or token balance get a discount on the project fees.
+    let discount_bps = match (&ctx.accounts.discount_policy, &ctx.accounts.discount_proof) {
+        (Some(discount_policy), Some(discount_proof)) => {
+            get_holder_discount_bps(discount_policy, discount_proof, &owner.key())?
+        }
+        (None, None) => 0,
+        _ => return Err(MplHybridError::InvalidDiscountProof.into()),
+    };
+    let sol_fee_amount = apply_discount(recipe.sol_fee_amount_capture, discount_bps)?;
+
+    // Quoted recipes take their price from a quote signed off-chain for this swap.
+    let quoted_amount = if recipe.quotes_enabled() {
+        let (instructions_sysvar, quote_nonce) =
+            match (&ctx.accounts.instructions_sysvar, &ctx.accounts.quote_nonce) {
+                (Some(instructions_sysvar), Some(quote_nonce)) => {
+                    (instructions_sysvar, quote_nonce)
+                }
+                _ => return Err(MplHybridError::InvalidQuote.into()),
+            };
+        let quote = verify_swap_quote(
+            instructions_sysvar,
+            &recipe.quote_signer,
+            &recipe.key(),
+            &owner.key(),
+            &asset.key(),
+            SwapDirection::Capture,
+        )?;
+        consume_quote_nonce(
+            quote_nonce,
+            &recipe.key(),
+            quote.nonce,
             owner,
-            &fee_project_account.to_account_info(),
-            &ctx.accounts.token.to_account_info(),
-            fee_token_account,
-            token_program,
             system_program,
         )?;
+        // Quotes can't price the swap outside of the bounds of the recipe's curve.
+        Some(recipe.pricing.clamp(quote.amount))
     } else {
-        validate_token_account(
-            fee_token_account,
-            &fee_project_account.key(),
-            &ctx.accounts.token.key(),
-        )?;
+        None
+    };
+
+    // Native SOL recipes are paid in lamports and don't use any token accounts.
+    let token_accounts = match recipe.currency {
+        Currency::Token => Some((
+            ctx.accounts
+                .token
+                .as_ref()
+                .ok_or(MplHybridError::MissingTokenAccount)?,
+            ctx.accounts
+                .user_token_account
+                .as_ref()
+                .ok_or(MplHybridError::MissingTokenAccount)?,
+            ctx.accounts
+                .escrow_token_account
+                .as_ref()
+                .ok_or(MplHybridError::MissingTokenAccount)?,
+            ctx.accounts
+                .fee_token_account
+                .as_ref()
+                .ok_or(MplHybridError::MissingTokenAccount)?,
+        )),
+        Currency::NativeSol | Currency::Basket => None,
+    };
+
+    // Any mint other than the recipe token has to be priced by the recipe's payment table.
+    let alternative_payment = match token_accounts {
+        Some((token, ..)) if token.key() != recipe.token => Some(
+            ctx.accounts
+                .payment_table
+                .as_ref()
+                .and_then(|payment_table| {
+                    payment_table
+                        .entries
+                        .iter()
+                        .find(|entry| entry.mint == token.key())
+                })
+                .ok_or(MplHybridError::PaymentMintNotAccepted)?,
+        ),
+        _ => None,
+    };
+
+    // Quotes price the recipe token, alternative payments keep their fixed price.
+    if quoted_amount.is_some() && alternative_payment.is_some() {
+        return Err(MplHybridError::QuoteWithAlternativePayment.into());
     }
 
-    // We only fetch the Base assets because we only need to check the collection here.
+    let (amount, fee_amount) = match alternative_payment {
+        Some(payment) => (payment.capture_amount, payment.fee_amount_capture),
+        // Otherwise the curve moves the recipe amount with the escrow's inventory.
+        None => (
+            quoted_amount.unwrap_or_else(|| recipe.capture_price()),
+            recipe.fee_amount_capture,
+        ),
+    };
+    let fee_amount = apply_discount(fee_amount, discount_bps)?;
+
+    if let Some((token, user_token_account, escrow_token_account, fee_token_account)) =
+        token_accounts
+    {
+        // The user token account should already exist.
+        validate_token_account(user_token_account, &owner.key(), &token.key())?;
+
+        if escrow_token_account.owner == &system_program::ID {
+            create_associated_token_account(
+                owner,
+                &escrow.to_account_info(),
+                &token.to_account_info(),
+                escrow_token_account,
+                token_program,
+                system_program,
+            )?;
+        } else {
+            validate_token_account(escrow_token_account, &escrow.key(), &token.key())?;
+        }
+
+        if fee_token_account.owner == &system_program::ID {
+            create_associated_token_account(
+                owner,
+                &fee_project_account.to_account_info(),
+                &token.to_account_info(),
+                fee_token_account,
+                token_program,
+                system_program,
+            )?;
+        } else {
+            validate_token_account(fee_token_account, &fee_project_account.key(), &token.key())?;
+        }
+    }
+
+    // We only fetch the Base assets because we only need to check the update authority here.
     let asset_data = BaseAssetV1::from_bytes(&asset.to_account_info().data.borrow())?;
-    // Check that the collection that the asset is a part of is the one this recipe is configured for.
-    if asset_data.update_authority != UpdateAuthority::Collection(recipe.collection) {
+    // Check that the asset belongs to the collection or creator this recipe is configured for.
+    if asset_data.update_authority != recipe.asset_update_authority() {
         return Err(MplHybridError::InvalidCollection.into());
     }
 
+    // Assets that aren't allowlisted can't be captured even if they were sent to the escrow.
+    if recipe.asset_root != [0; 32]
+        && !verify_merkle_proof(&recipe.asset_root, &asset.key(), &ix.asset_proof)
+    {
+        return Err(MplHybridError::AssetNotAllowlisted.into());
+    }
+
     if authority_info.key == &recipe.authority {
         assert_signer(authority)?;
     }
 
+    let mut index = None;
+    let mut new_uri = None;
+
     //If the path has bit 0 unset, we need to update the metadata onchain
     if !Path::NoRerollMetadata.check(recipe.path) {
         let clock = Clock::get()?;
@@ -210,11 +459,14 @@ pub fn handler_capture_v2(ctx: Context<CaptureV2Ctx>) -> Result<()> {
         uri.push_str(&remainder.to_string());
         uri.push_str(&json_extension);
 
+        index = Some(remainder);
+        new_uri = Some(uri.clone());
+
         //create update instruction
         let update_ix = UpdateV1Cpi {
             __program: &mpl_core.to_account_info(),
             asset: &asset.to_account_info(),
-            collection: Some(collection_info),
+            collection: core_collection,
             payer: &owner.to_account_info(),
             authority: Some(authority_info),
             system_program: &system_program.to_account_info(),
@@ -241,7 +493,7 @@ pub fn handler_capture_v2(ctx: Context<CaptureV2Ctx>) -> Result<()> {
     let transfer_nft_ix = TransferV1Cpi {
         __program: &mpl_core.to_account_info(),
         asset: &asset.to_account_info(),
-        collection: Some(collection_info),
+        collection: core_collection,
         payer: &owner.to_account_info(),
         authority: Some(escrow_info),
         new_owner: &owner.to_account_info(),
@@ -255,51 +507,103 @@ pub fn handler_capture_v2(ctx: Context<CaptureV2Ctx>) -> Result<()> {
     //invoke the transfer instruction with seeds
     transfer_nft_ix.invoke_signed(&[&[b"escrow", recipe.authority.as_ref(), &[escrow.bump]]])?;
 
-    let cpi_program = token_program.to_account_info();
+    // The basket entries charged by this capture, empty for other currencies.
+    let mut basket_entries = Vec::new();
+
+    if let Some((token, user_token_account, escrow_token_account, fee_token_account)) =
+        token_accounts
+    {
+        let cpi_program = token_program.to_account_info();
+
+        // If the path has burn on capture, we burn the token
+        if Path::BurnOnCapture.check(recipe.path) && alternative_payment.is_none() {
+            //create burn instruction
+            let cpi_accounts_burn = Burn {
+                mint: token.to_account_info(),
+                from: user_token_account.to_account_info(),
+                authority: owner.to_account_info(),
+            };
 
-    // If the path has burn on capture, we burn the token
-    if Path::BurnOnCapture.check(recipe.path) {
-        //create burn instruction
-        let cpi_accounts_burn = Burn {
-            mint: ctx.accounts.token.to_account_info(),
+            let burn_cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts_burn);
+
+            token::burn(burn_cpi_ctx, amount)?;
+        }
+        // Otherwise, we transfer the token to the escrow
+        else {
+            //create transfer token instruction
+            let cpi_accounts_transfer = Transfer {
+                from: user_token_account.to_account_info(),
+                to: escrow_token_account.to_account_info(),
+                authority: owner.to_account_info(),
+            };
+
+            let transfer_cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts_transfer);
+
+            token::transfer(transfer_cpi_ctx, amount)?;
+        }
+
+        //create transfer fee token instruction
+        let cpi_accounts_fee_transfer = Transfer {
             from: user_token_account.to_account_info(),
+            to: fee_token_account.to_account_info(),
             authority: owner.to_account_info(),
         };
 
-        let burn_cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts_burn);
+        let transfer_fees_cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts_fee_transfer);
 
-        token::burn(burn_cpi_ctx, recipe.amount)?;
-    }
-    // Otherwise, we transfer the token to the escrow
-    else {
-        //create transfer token instruction
-        let cpi_accounts_transfer = Transfer {
-            from: user_token_account.to_account_info(),
-            to: escrow_token_account.to_account_info(),
-            authority: owner.to_account_info(),
+        token::transfer(transfer_fees_cpi_ctx, fee_amount)?;
+    } else if recipe.currency == Currency::NativeSol {
+        let vault = ctx
+            .accounts
+            .vault
+            .as_ref()
+            .ok_or(MplHybridError::MissingVaultAccount)?;
+
+        //transfer the lamports to the recipe vault
+        let cpi_accounts_transfer = system_program::Transfer {
+            from: owner.to_account_info(),
+            to: vault.to_account_info(),
         };
 
-        let transfer_cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts_transfer);
+        let transfer_cpi_ctx = CpiContext::new(system_info.clone(), cpi_accounts_transfer);
 
-        token::transfer(transfer_cpi_ctx, recipe.amount)?;
-    }
+        system_program::transfer(transfer_cpi_ctx, amount)?;
 
-    //create transfer fee token instruction
-    let cpi_accounts_fee_transfer = Transfer {
-        from: user_token_account.to_account_info(),
-        to: fee_token_account.to_account_info(),
-        authority: owner.to_account_info(),
-    };
+        //transfer the lamport fee to the project
+        let cpi_accounts_fee_transfer = system_program::Transfer {
+            from: owner.to_account_info(),
+            to: fee_project_account.to_account_info(),
+        };
 
-    let transfer_fees_cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts_fee_transfer);
+        let transfer_fees_cpi_ctx = CpiContext::new(system_info.clone(), cpi_accounts_fee_transfer);
 
-    token::transfer(transfer_fees_cpi_ctx, recipe.fee_amount_capture)?;
+        system_program::transfer(transfer_fees_cpi_ctx, fee_amount)?;
+    } else {
+        // Basket recipes charge each entry of the basket, token fees don't apply to them.
+        let basket = ctx
+            .accounts
+            .basket
+            .as_ref()
+            .ok_or(MplHybridError::MissingBasketAccount)?;
+
+        capture_basket(
+            basket,
+            basket_accounts,
+            &owner.to_account_info(),
+            escrow_info,
+            &token_program.to_account_info(),
+            system_info,
+        )?;
+
+        basket_entries = basket.entries.clone();
+    }
 
     //create protocol transfer fee sol instruction
+    let protocol_fee = get_protocol_fee()?;
     let sol_fee_ix = anchor_lang::solana_program::system_instruction::transfer(
         &owner.key(),
         &fee_sol_account.key(),
-        get_protocol_fee()?,
+        protocol_fee,
     );
 
     //invoke protocol the transfer fee sol instruction
@@ -312,7 +616,7 @@ pub fn handler_capture_v2(ctx: Context<CaptureV2Ctx>) -> Result<()> {
     let sol_fee_project_ix = anchor_lang::solana_program::system_instruction::transfer(
         &owner.key(),
         &fee_project_account.key(),
-        recipe.sol_fee_amount_capture,
+        sol_fee_amount,
     );
 
     //invoke project the transfer fee sol instruction for project
@@ -327,5 +631,71 @@ pub fn handler_capture_v2(ctx: Context<CaptureV2Ctx>) -> Result<()> {
     //increment the swap count
     recipe.count += 1;
 
+    //the asset has left the escrow
+    recipe.inventory = recipe.inventory.saturating_sub(1);
+    recipe.net_swaps = recipe
+        .net_swaps
+        .checked_add(1)
+        .ok_or(MplHybridError::NumericalOverflow)?;
+
+    //captures paid in the recipe currency offset releases in the circuit breaker
+    if recipe.circuit_breaker.is_enabled() {
+        let circuit_breaker = recipe.circuit_breaker;
+        recipe
+            .outflow_window
+            .roll(&circuit_breaker, Clock::get()?.slot);
+        if alternative_payment.is_none() && recipe.currency != Currency::Basket {
+            recipe.outflow_window.record_capture(amount);
+        }
+    }
+
+    // Basket entries are carried on their own, the mint and amount only cover the recipe currency.
+    let (mint, amount) = match token_accounts {
+        Some((token, ..)) => (token.key(), amount),
+        None if recipe.currency == Currency::NativeSol => (recipe.token, amount),
+        None => (Pubkey::default(), 0),
+    };
+    let burned = if Path::BurnOnCapture.check(recipe.path) && alternative_payment.is_none() {
+        amount
+    } else {
+        0
+    };
+
+    if alternative_payment.is_some() {
+        recipe.stats.record_capture(0, 0, 0, sol_fee_amount);
+    } else {
+        recipe
+            .stats
+            .record_capture(amount - burned, burned, fee_amount, sol_fee_amount);
+    }
+
+    // Users opt into receipts by passing theirs.
+    if let Some(swap_receipt) = &ctx.accounts.swap_receipt {
+        record_swap_receipt(
+            swap_receipt,
+            &recipe.key(),
+            SwapDirection::Capture,
+            recipe.loyalty.points_per_capture,
+            owner,
+            system_program,
+        )?;
+    }
+
+    emit_cpi!(CaptureEvent {
+        recipe: recipe.key(),
+        escrow: escrow.key(),
+        asset: asset.key(),
+        user: owner.key(),
+        mint,
+        amount: amount - burned,
+        burned,
+        fee_amount,
+        sol_fee_amount,
+        protocol_fee,
+        index,
+        uri: new_uri,
+        basket: basket_entries,
+    });
+
     Ok(())
 }
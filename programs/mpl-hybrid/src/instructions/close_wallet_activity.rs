@@ -0,0 +1,45 @@
+use crate::error::MplHybridError;
+use crate::events::WalletActivityClosedEvent;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseWalletActivityV1Ctx<'info> {
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            "wallet".as_bytes(), 
+            recipe.key().as_ref(),
+            user.key().as_ref()
+            ],
+        bump=wallet_activity.bump,
+    )]
+    wallet_activity: Account<'info, WalletActivityV1>,
+
+    #[account(
+        address = wallet_activity.recipe @MplHybridError::InvalidWalletActivity
+    )]
+    recipe: Account<'info, RecipeV1>,
+
+    #[account(mut)]
+    user: Signer<'info>,
+}
+
+pub fn handler_close_wallet_activity_v1(ctx: Context<CloseWalletActivityV1Ctx>) -> Result<()> {
+    let wallet_activity = &ctx.accounts.wallet_activity;
+    let recipe = &ctx.accounts.recipe;
+
+    // Closing resets the wallet's counters, so it has to wait for them to no longer matter.
+    if !wallet_activity.is_expired(&recipe.wallet_limits, Clock::get()?.slot) {
+        return Err(MplHybridError::WalletActivityNotExpired.into());
+    }
+
+    emit_cpi!(WalletActivityClosedEvent {
+        recipe: recipe.key(),
+        user: wallet_activity.user,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,132 @@
+use crate::error::MplHybridError;
+use crate::events::{ConfigAction, RecipeEvent};
+use crate::instructions::apply_recipe_update;
+use crate::state::*;
+use crate::utils::{assert_recipe_admin, assert_recipe_authority, record_recipe_change};
+use anchor_lang::prelude::*;
+use mpl_utils::resize_or_reallocate_account_raw;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteRecipeUpdateV1Ctx<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "pending".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=pending_update.bump,
+        close = authority,
+    )]
+    pending_update: Account<'info, PendingRecipeUpdateV1>,
+
+    #[account(
+        mut,
+        seeds = [
+            "recipe".as_bytes(), 
+            recipe.collection.as_ref()
+            ],
+        bump=recipe.bump,
+    )]
+    recipe: Account<'info, RecipeV1>,
+
+    /// CHECK: We check against the pending update
+    #[account(mut,
+        address = pending_update.authority
+    )]
+    authority: AccountInfo<'info>,
+
+    // Anyone can execute the update once it is active.
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [
+            "operators".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=operators.bump,
+    )]
+    operators: Option<Account<'info, OperatorsV1>>,
+
+    /// CHECK: Checked by seeds, it is created on the first change.
+    #[account(
+        mut,
+        seeds = [
+            "history".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump,
+    )]
+    history: AccountInfo<'info>,
+}
+
+pub fn handler_execute_recipe_update_v1(ctx: Context<ExecuteRecipeUpdateV1Ctx>) -> Result<()> {
+    let pending_update = &ctx.accounts.pending_update;
+    let recipe = &mut ctx.accounts.recipe;
+
+    if Clock::get()?.slot < pending_update.activation_slot {
+        return Err(MplHybridError::TimelockNotElapsed.into());
+    }
+
+    // The signer that queued the update has to still hold the roles for it.
+    let update = &pending_update.update;
+    assert_recipe_admin(
+        recipe,
+        &pending_update.authority,
+        ctx.accounts.operators.as_deref(),
+        &update.roles(recipe, pending_update.token, pending_update.fee_location),
+    )?;
+
+    if update.needs_recipe_authority(recipe, pending_update.token) {
+        assert_recipe_authority(recipe, &pending_update.authority)?;
+    }
+
+    let old_recipe = (**recipe).clone();
+    let size_diff = apply_recipe_update(
+        recipe,
+        pending_update.update.clone(),
+        pending_update.token,
+        pending_update.fee_location,
+    )?;
+
+    let new_size = recipe
+        .to_account_info()
+        .data_len()
+        .checked_add(size_diff as usize)
+        .ok_or(MplHybridError::NumericalOverflow)?;
+    resize_or_reallocate_account_raw(
+        &recipe.to_account_info(),
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        new_size,
+    )?;
+
+    // The change is recorded against the signer that queued it.
+    let change = RecipeChange::new(
+        Clock::get()?.slot,
+        pending_update.authority,
+        &old_recipe,
+        recipe,
+    );
+    if change.changed != 0 {
+        record_recipe_change(
+            &ctx.accounts.history,
+            &recipe.key(),
+            ctx.bumps.history,
+            change,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+        )?;
+    }
+
+    emit_cpi!(RecipeEvent {
+        action: ConfigAction::Update,
+        recipe: recipe.key(),
+        data: (**recipe).clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use crate::events::{BasketEvent, ConfigAction};
+use crate::state::*;
+use crate::utils::{assert_fields_unlocked, assert_not_timelocked, assert_recipe_admin};
+use anchor_lang::{prelude::*, Discriminator};
+use mpl_utils::create_or_allocate_account_raw;
+use solana_program::program_memory::sol_memcpy;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitBasketV1Ix {
+    entries: Vec<BasketEntry>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitBasketV1Ctx<'info> {
+    /// CHECK: This account is checked and initialized in the handler.
+    #[account(
+        mut,
+        seeds = [
+            "basket".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump,
+    )]
+    basket: AccountInfo<'info>,
+
+    #[account(
+        seeds = [
+            "recipe".as_bytes(), 
+            collection.key().as_ref()
+            ],
+        bump=recipe.bump,
+    )]
+    recipe: Account<'info, RecipeV1>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// CHECK: We check against recipe
+    #[account(
+        address = recipe.collection
+    )]
+    collection: AccountInfo<'info>,
+
+    system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [
+            "operators".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=operators.bump,
+    )]
+    operators: Option<Account<'info, OperatorsV1>>,
+}
+
+pub fn handler_init_basket_v1(ctx: Context<InitBasketV1Ctx>, ix: InitBasketV1Ix) -> Result<()> {
+    let basket = &mut ctx.accounts.basket;
+    create_or_allocate_account_raw(
+        crate::ID,
+        basket,
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        BasketV1::BASE_BASKET_SIZE + ix.entries.len() * BasketEntry::SIZE,
+        &[
+            "basket".as_bytes(),
+            &ctx.accounts.recipe.key().to_bytes(),
+            &[ctx.bumps.basket],
+        ],
+    )?;
+
+    let recipe = &ctx.accounts.recipe;
+    let authority = &ctx.accounts.authority;
+
+    assert_recipe_admin(
+        recipe,
+        &authority.key(),
+        ctx.accounts.operators.as_deref(),
+        &[OperatorRole::UpdatePricing],
+    )?;
+    assert_not_timelocked(recipe)?;
+
+    //initialize with input data
+    let basket_state = BasketV1 {
+        recipe: recipe.key(),
+        entries: ix.entries,
+        bump: ctx.bumps.basket,
+    };
+    basket_state.validate()?;
+    assert_fields_unlocked(recipe, &[RecipeField::Pricing])?;
+
+    let mut basket_data = BasketV1::DISCRIMINATOR.to_vec();
+    basket_data.extend(basket_state.try_to_vec()?);
+
+    let mut basket_data_borrowed = basket.data.borrow_mut();
+    sol_memcpy(&mut basket_data_borrowed, &basket_data, basket_data.len());
+
+    emit_cpi!(BasketEvent {
+        action: ConfigAction::Init,
+        basket: basket.key(),
+        data: basket_state,
+    });
+
+    Ok(())
+}
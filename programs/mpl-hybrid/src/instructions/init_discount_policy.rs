@@ -0,0 +1,111 @@
+use crate::events::{ConfigAction, DiscountPolicyEvent};
+use crate::state::*;
+use crate::utils::{assert_fields_unlocked, assert_recipe_admin};
+use anchor_lang::{prelude::*, Discriminator};
+use mpl_utils::create_or_allocate_account_raw;
+use solana_program::program_memory::sol_memcpy;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitDiscountPolicyV1Ix {
+    entries: Vec<DiscountEntry>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitDiscountPolicyV1Ctx<'info> {
+    /// CHECK: This account is checked and initialized in the handler.
+    #[account(
+        mut,
+        seeds = [
+            "discount".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump,
+    )]
+    discount_policy: AccountInfo<'info>,
+
+    #[account(
+        seeds = [
+            "recipe".as_bytes(), 
+            collection.key().as_ref()
+            ],
+        bump=recipe.bump,
+    )]
+    recipe: Account<'info, RecipeV1>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// CHECK: We check against recipe
+    #[account(
+        address = recipe.collection
+    )]
+    collection: AccountInfo<'info>,
+
+    system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [
+            "operators".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=operators.bump,
+    )]
+    operators: Option<Account<'info, OperatorsV1>>,
+}
+
+pub fn handler_init_discount_policy_v1(
+    ctx: Context<InitDiscountPolicyV1Ctx>,
+    ix: InitDiscountPolicyV1Ix,
+) -> Result<()> {
+    let discount_policy = &mut ctx.accounts.discount_policy;
+    create_or_allocate_account_raw(
+        crate::ID,
+        discount_policy,
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        DiscountPolicyV1::BASE_DISCOUNT_POLICY_SIZE + ix.entries.len() * DiscountEntry::SIZE,
+        &[
+            "discount".as_bytes(),
+            &ctx.accounts.recipe.key().to_bytes(),
+            &[ctx.bumps.discount_policy],
+        ],
+    )?;
+
+    let recipe = &ctx.accounts.recipe;
+    let authority = &ctx.accounts.authority;
+
+    assert_recipe_admin(
+        recipe,
+        &authority.key(),
+        ctx.accounts.operators.as_deref(),
+        &[OperatorRole::ManageFees],
+    )?;
+    assert_fields_unlocked(recipe, &[RecipeField::Fees])?;
+
+    //initialize with input data
+    let discount_policy_state = DiscountPolicyV1 {
+        recipe: recipe.key(),
+        entries: ix.entries,
+        bump: ctx.bumps.discount_policy,
+    };
+    discount_policy_state.validate()?;
+
+    let mut discount_policy_data = DiscountPolicyV1::DISCRIMINATOR.to_vec();
+    discount_policy_data.extend(discount_policy_state.try_to_vec()?);
+
+    let mut discount_policy_data_borrowed = discount_policy.data.borrow_mut();
+    sol_memcpy(
+        &mut discount_policy_data_borrowed,
+        &discount_policy_data,
+        discount_policy_data.len(),
+    );
+
+    emit_cpi!(DiscountPolicyEvent {
+        action: ConfigAction::Init,
+        discount_policy: discount_policy.key(),
+        data: discount_policy_state,
+    });
+
+    Ok(())
+}
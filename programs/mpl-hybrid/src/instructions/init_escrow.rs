@@ -1,10 +1,11 @@
 use crate::constants::MPL_CORE;
 use crate::error::MplHybridError;
+use crate::events::{ConfigAction, EscrowEvent};
 use crate::state::*;
+use crate::utils::assert_collection_authority;
 use anchor_lang::{prelude::*, Discriminator};
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{Mint, Token, TokenAccount};
-use mpl_core::accounts::BaseCollectionV1;
 use mpl_core::load_key;
 use mpl_core::types::Key as MplCoreKey;
 use mpl_utils::create_or_allocate_account_raw;
@@ -22,6 +23,7 @@ pub struct InitEscrowV1Ix {
     path: u16,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct InitEscrowV1Ctx<'info> {
     /// CHECK: This account is checked and initialized in the handler.
@@ -92,39 +94,38 @@ pub fn handler_init_escrow_v1(ctx: Context<InitEscrowV1Ctx>, ix: InitEscrowV1Ix)
         return Err(MplHybridError::InvalidCollectionAccount.into());
     }
 
-    // We only fetch the Base collection to check authority.
-    let collection_data =
-        BaseCollectionV1::from_bytes(&collection.to_account_info().data.borrow())?;
-
-    // Check that the collection authority is the same as the escrow authority.
-    if collection_data.update_authority != authority.key() {
-        return Err(MplHybridError::InvalidCollectionAuthority.into());
-    }
+    // The signer has to be the collection update authority or one of its update delegates.
+    assert_collection_authority(collection, &authority.key())?;
 
     //initialize with input data
+    let escrow_state = EscrowV1 {
+        collection: collection.key(),
+        authority: authority.key(),
+        token: token.key(),
+        fee_location: fee_location.key(),
+        name: ix.name,
+        uri: ix.uri,
+        max: ix.max,
+        min: ix.min,
+        amount: ix.amount,
+        fee_amount: ix.fee_amount,
+        sol_fee_amount: ix.sol_fee_amount,
+        count: 1,
+        path: ix.path,
+        bump: ctx.bumps.escrow,
+    };
+
     let mut escrow_data = EscrowV1::DISCRIMINATOR.to_vec();
-    escrow_data.extend(
-        EscrowV1 {
-            collection: collection.key(),
-            authority: authority.key(),
-            token: token.key(),
-            fee_location: fee_location.key(),
-            name: ix.name,
-            uri: ix.uri,
-            max: ix.max,
-            min: ix.min,
-            amount: ix.amount,
-            fee_amount: ix.fee_amount,
-            sol_fee_amount: ix.sol_fee_amount,
-            count: 1,
-            path: ix.path,
-            bump: ctx.bumps.escrow,
-        }
-        .try_to_vec()?,
-    );
+    escrow_data.extend(escrow_state.try_to_vec()?);
 
     let mut escrow_data_borrowed = escrow.data.borrow_mut();
     sol_memcpy(&mut escrow_data_borrowed, &escrow_data, escrow_data.len());
 
+    emit_cpi!(EscrowEvent {
+        action: ConfigAction::Init,
+        escrow: escrow.key(),
+        data: escrow_state,
+    });
+
     Ok(())
 }
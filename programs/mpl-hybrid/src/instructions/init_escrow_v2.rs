@@ -1,8 +1,10 @@
+use crate::events::{ConfigAction, EscrowV2Event};
 use crate::state::*;
 use anchor_lang::{prelude::*, Discriminator};
 use mpl_utils::create_or_allocate_account_raw;
 use solana_program::program_memory::sol_memcpy;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct InitEscrowV2Ctx<'info> {
     /// CHECK: This account is checked and initialized in the handler.
@@ -40,17 +42,22 @@ pub fn handler_init_escrow_v2(ctx: Context<InitEscrowV2Ctx>) -> Result<()> {
     let authority = &mut ctx.accounts.authority;
 
     //initialize with input data
+    let escrow_state = EscrowV2 {
+        authority: authority.key(),
+        bump: ctx.bumps.escrow,
+    };
+
     let mut escrow_data = EscrowV2::DISCRIMINATOR.to_vec();
-    escrow_data.extend(
-        EscrowV2 {
-            authority: authority.key(),
-            bump: ctx.bumps.escrow,
-        }
-        .try_to_vec()?,
-    );
+    escrow_data.extend(escrow_state.try_to_vec()?);
 
     let mut escrow_data_borrowed = escrow.data.borrow_mut();
     sol_memcpy(&mut escrow_data_borrowed, &escrow_data, escrow_data.len());
 
+    emit_cpi!(EscrowV2Event {
+        action: ConfigAction::Init,
+        escrow: escrow.key(),
+        data: escrow_state,
+    });
+
     Ok(())
 }
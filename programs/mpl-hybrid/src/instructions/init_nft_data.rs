@@ -1,11 +1,13 @@
 use crate::constants::MPL_CORE;
 use crate::error::MplHybridError;
+use crate::events::{ConfigAction, NftDataEvent};
 use crate::state::*;
+use crate::utils::{assert_asset_authority, collection_kind};
 use anchor_lang::{prelude::*, Discriminator};
 use anchor_spl::token::Mint;
-use mpl_core::accounts::{BaseAssetV1, BaseCollectionV1};
+use mpl_core::accounts::BaseAssetV1;
 use mpl_core::load_key;
-use mpl_core::types::{Key as MplCoreKey, UpdateAuthority};
+use mpl_core::types::Key as MplCoreKey;
 use mpl_utils::create_or_allocate_account_raw;
 use solana_program::program_memory::sol_memcpy;
 
@@ -21,6 +23,7 @@ pub struct InitNftDataV1Ix {
     path: u16,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct InitNftDataV1Ctx<'info> {
     /// CHECK: This account is checked and initialized in the handler.
@@ -82,49 +85,38 @@ pub fn handler_init_nft_data_v1(ctx: Context<InitNftDataV1Ctx>, ix: InitNftDataV
         return Err(MplHybridError::InvalidAssetAccount.into());
     }
 
-    // We only fetch the Base assets because we only need to check the collection here.
+    // Creators of standalone assets are passed in place of the collection.
+    let kind = collection_kind(collection)?;
+
+    // We only fetch the Base assets because we only need to check the update authority here.
     let asset_data = BaseAssetV1::from_bytes(&asset.to_account_info().data.borrow())?;
-    // Check that the collection that the asset is a part of is the one this escrow is configured for.
-    if asset_data.update_authority != UpdateAuthority::Collection(collection.key()) {
+    // Check that the asset is part of the collection, or updated by the creator passed instead.
+    if asset_data.update_authority != kind.asset_update_authority(collection.key()) {
         return Err(MplHybridError::InvalidCollection.into());
     }
 
-    // Check that a valid collection has been passed in.
-    if *collection.owner != MPL_CORE
-        || load_key(&collection.to_account_info(), 0)? != MplCoreKey::CollectionV1
-    {
-        return Err(MplHybridError::InvalidCollectionAccount.into());
-    }
-
-    // We only fetch the Base collection to check authority.
-    let collection_data =
-        BaseCollectionV1::from_bytes(&collection.to_account_info().data.borrow())?;
-
-    // Check that the collection authority is the same as the escrow authority.
-    if collection_data.update_authority != authority.key() {
-        return Err(MplHybridError::InvalidCollectionAuthority.into());
-    }
+    // The signer has to administer the collection, or be the creator of standalone assets.
+    assert_asset_authority(kind, collection, &authority.key())?;
 
     //initialize with input data
+    let nft_data_state = NftDataV1 {
+        authority: authority.key(),
+        token: token.key(),
+        fee_location: fee_location.key(),
+        name: ix.name,
+        uri: ix.uri,
+        max: ix.max,
+        min: ix.min,
+        amount: ix.amount,
+        fee_amount: ix.fee_amount,
+        sol_fee_amount: ix.sol_fee_amount,
+        count: 0,
+        path: ix.path,
+        bump: ctx.bumps.nft_data,
+    };
+
     let mut nft_data_data = NftDataV1::DISCRIMINATOR.to_vec();
-    nft_data_data.extend(
-        NftDataV1 {
-            authority: authority.key(),
-            token: token.key(),
-            fee_location: fee_location.key(),
-            name: ix.name,
-            uri: ix.uri,
-            max: ix.max,
-            min: ix.min,
-            amount: ix.amount,
-            fee_amount: ix.fee_amount,
-            sol_fee_amount: ix.sol_fee_amount,
-            count: 0,
-            path: ix.path,
-            bump: ctx.bumps.nft_data,
-        }
-        .try_to_vec()?,
-    );
+    nft_data_data.extend(nft_data_state.try_to_vec()?);
 
     let mut escrow_data_borrowed = nft_data.data.borrow_mut();
     sol_memcpy(
@@ -133,5 +125,11 @@ pub fn handler_init_nft_data_v1(ctx: Context<InitNftDataV1Ctx>, ix: InitNftDataV
         nft_data_data.len(),
     );
 
+    emit_cpi!(NftDataEvent {
+        action: ConfigAction::Init,
+        nft_data: nft_data.key(),
+        data: nft_data_state,
+    });
+
     Ok(())
 }
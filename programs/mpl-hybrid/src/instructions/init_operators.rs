@@ -0,0 +1,99 @@
+use crate::events::{ConfigAction, OperatorsEvent};
+use crate::state::*;
+use crate::utils::{assert_recipe_authority, assert_unique_operators};
+use anchor_lang::{prelude::*, Discriminator};
+use mpl_utils::create_or_allocate_account_raw;
+use solana_program::program_memory::sol_memcpy;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitOperatorsV1Ix {
+    entries: Vec<OperatorEntry>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitOperatorsV1Ctx<'info> {
+    /// CHECK: This account is checked and initialized in the handler.
+    #[account(
+        mut,
+        seeds = [
+            "operators".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump,
+    )]
+    operators: AccountInfo<'info>,
+
+    #[account(
+        seeds = [
+            "recipe".as_bytes(), 
+            collection.key().as_ref()
+            ],
+        bump=recipe.bump,
+    )]
+    recipe: Account<'info, RecipeV1>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// CHECK: We check against recipe
+    #[account(
+        address = recipe.collection
+    )]
+    collection: AccountInfo<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn handler_init_operators_v1(
+    ctx: Context<InitOperatorsV1Ctx>,
+    ix: InitOperatorsV1Ix,
+) -> Result<()> {
+    let operators = &mut ctx.accounts.operators;
+    create_or_allocate_account_raw(
+        crate::ID,
+        operators,
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        OperatorsV1::BASE_OPERATORS_SIZE + ix.entries.len() * OperatorEntry::SIZE,
+        &[
+            "operators".as_bytes(),
+            &ctx.accounts.recipe.key().to_bytes(),
+            &[ctx.bumps.operators],
+        ],
+    )?;
+
+    let recipe = &ctx.accounts.recipe;
+    let authority = &ctx.accounts.authority;
+
+    // Operators can't grant roles, only the recipe authority manages them.
+    assert_recipe_authority(recipe, &authority.key())?;
+
+    assert_unique_operators(&ix.entries)?;
+
+    //initialize with input data
+    let operators_state = OperatorsV1 {
+        recipe: recipe.key(),
+        authority: authority.key(),
+        entries: ix.entries,
+        bump: ctx.bumps.operators,
+    };
+
+    let mut operators_data = OperatorsV1::DISCRIMINATOR.to_vec();
+    operators_data.extend(operators_state.try_to_vec()?);
+
+    let mut operators_data_borrowed = operators.data.borrow_mut();
+    sol_memcpy(
+        &mut operators_data_borrowed,
+        &operators_data,
+        operators_data.len(),
+    );
+
+    emit_cpi!(OperatorsEvent {
+        action: ConfigAction::Init,
+        operators: operators.key(),
+        data: operators_state,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use crate::error::MplHybridError;
+use crate::events::{ConfigAction, PaymentTableEvent};
+use crate::state::*;
+use crate::utils::{assert_fields_unlocked, assert_not_timelocked, assert_recipe_admin};
+use anchor_lang::{prelude::*, Discriminator};
+use mpl_utils::create_or_allocate_account_raw;
+use solana_program::program_memory::sol_memcpy;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitPaymentTableV1Ix {
+    entries: Vec<PaymentEntry>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitPaymentTableV1Ctx<'info> {
+    /// CHECK: This account is checked and initialized in the handler.
+    #[account(
+        mut,
+        seeds = [
+            "payments".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump,
+    )]
+    payment_table: AccountInfo<'info>,
+
+    #[account(
+        seeds = [
+            "recipe".as_bytes(), 
+            collection.key().as_ref()
+            ],
+        bump=recipe.bump,
+    )]
+    recipe: Account<'info, RecipeV1>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// CHECK: We check against recipe
+    #[account(
+        address = recipe.collection
+    )]
+    collection: AccountInfo<'info>,
+
+    system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [
+            "operators".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=operators.bump,
+    )]
+    operators: Option<Account<'info, OperatorsV1>>,
+}
+
+pub fn handler_init_payment_table_v1(
+    ctx: Context<InitPaymentTableV1Ctx>,
+    ix: InitPaymentTableV1Ix,
+) -> Result<()> {
+    let payment_table = &mut ctx.accounts.payment_table;
+    create_or_allocate_account_raw(
+        crate::ID,
+        payment_table,
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.authority.to_account_info(),
+        PaymentTableV1::BASE_PAYMENT_TABLE_SIZE + ix.entries.len() * PaymentEntry::SIZE,
+        &[
+            "payments".as_bytes(),
+            &ctx.accounts.recipe.key().to_bytes(),
+            &[ctx.bumps.payment_table],
+        ],
+    )?;
+
+    let recipe = &ctx.accounts.recipe;
+    let authority = &ctx.accounts.authority;
+
+    //initialize with input data
+    let payment_table_state = PaymentTableV1 {
+        recipe: recipe.key(),
+        entries: ix.entries,
+        bump: ctx.bumps.payment_table,
+    };
+
+    assert_recipe_admin(
+        recipe,
+        &authority.key(),
+        ctx.accounts.operators.as_deref(),
+        &payment_table_state.roles(&[]),
+    )?;
+    assert_not_timelocked(recipe)?;
+
+    payment_table_state.validate(&recipe.token)?;
+    if payment_table_state.fees_exceed(&recipe.fee_caps) {
+        return Err(MplHybridError::FeeCapExceeded.into());
+    }
+    assert_fields_unlocked(recipe, &[RecipeField::Token, RecipeField::Pricing])?;
+    if payment_table_state.changes_fees(&[]) {
+        assert_fields_unlocked(recipe, &[RecipeField::Fees])?;
+    }
+
+    let mut payment_table_data = PaymentTableV1::DISCRIMINATOR.to_vec();
+    payment_table_data.extend(payment_table_state.try_to_vec()?);
+
+    let mut payment_table_data_borrowed = payment_table.data.borrow_mut();
+    sol_memcpy(
+        &mut payment_table_data_borrowed,
+        &payment_table_data,
+        payment_table_data.len(),
+    );
+
+    emit_cpi!(PaymentTableEvent {
+        action: ConfigAction::Init,
+        payment_table: payment_table.key(),
+        data: payment_table_state,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use crate::error::MplHybridError;
+use crate::events::{ConfigAction, ProtocolConfigEvent};
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_program::bpf_loader_upgradeable;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitProtocolConfigV1Ix {
+    admin: Pubkey,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitProtocolConfigV1Ctx<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = ProtocolConfigV1::PROTOCOL_CONFIG_SIZE,
+        seeds = ["protocol".as_bytes()],
+        bump,
+    )]
+    protocol_config: Account<'info, ProtocolConfigV1>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        address = Pubkey::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::ID).0
+            @ MplHybridError::InvalidProtocolAdmin,
+        constraint = program_data.upgrade_authority_address == Some(authority.key())
+            @ MplHybridError::InvalidProtocolAdmin
+    )]
+    program_data: Account<'info, ProgramData>,
+
+    system_program: Program<'info, System>,
+}
+
+// The upgrade authority of the program hands the protocol settings to an admin.
+pub fn handler_init_protocol_config_v1(
+    ctx: Context<InitProtocolConfigV1Ctx>,
+    ix: InitProtocolConfigV1Ix,
+) -> Result<()> {
+    let protocol_config = &mut ctx.accounts.protocol_config;
+
+    protocol_config.admin = ix.admin;
+    protocol_config.paused = false;
+    protocol_config.pause_reason = 0;
+    protocol_config.bump = ctx.bumps.protocol_config;
+
+    emit_cpi!(ProtocolConfigEvent {
+        action: ConfigAction::Init,
+        protocol_config: protocol_config.key(),
+        data: (**protocol_config).clone(),
+    });
+
+    Ok(())
+}
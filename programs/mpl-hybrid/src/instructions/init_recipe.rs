@@ -1,14 +1,16 @@
 use crate::constants::MPL_CORE;
 use crate::error::MplHybridError;
+use crate::events::{ConfigAction, RecipeEvent};
 use crate::state::*;
+use crate::utils::assert_collection_authority;
 use anchor_lang::{prelude::*, Discriminator};
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{Mint, Token, TokenAccount};
-use mpl_core::accounts::BaseCollectionV1;
 use mpl_core::load_key;
 use mpl_core::types::Key as MplCoreKey;
 use mpl_utils::create_or_allocate_account_raw;
 use solana_program::program_memory::sol_memcpy;
+use spl_token::native_mint;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct InitRecipeV1Ix {
@@ -16,14 +18,19 @@ pub struct InitRecipeV1Ix {
     uri: String,
     max: u64,
     min: u64,
-    amount: u64,
+    capture_amount: u64,
+    release_amount: u64,
     fee_amount_capture: u64,
     fee_amount_release: u64,
     sol_fee_amount_capture: u64,
     sol_fee_amount_release: u64,
     path: u16,
+    currency: Currency,
+    kind: RecipeKind,
+    quote_signer: Option<Pubkey>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct InitRecipeV1Ctx<'info> {
     /// CHECK: This account is checked and initialized in the handler.
@@ -40,11 +47,11 @@ pub struct InitRecipeV1Ctx<'info> {
     #[account(mut)]
     authority: Signer<'info>,
 
-    /// CHECK: We check the collection bellow and with recipe seeds
+    /// CHECK: We check the collection bellow and with recipe seeds, it is the creator of creator recipes
     collection: UncheckedAccount<'info>,
 
     /// CHECK: This is a user defined account
-    token: Account<'info, Mint>,
+    token: Option<Account<'info, Mint>>,
 
     /// CHECK: This is a user defined account
     fee_location: UncheckedAccount<'info>,
@@ -56,11 +63,24 @@ pub struct InitRecipeV1Ctx<'info> {
         associated_token::mint = token,
         associated_token::authority = fee_location,
     )]
-    fee_ata: Account<'info, TokenAccount>,
+    fee_ata: Option<Account<'info, TokenAccount>>,
 
     system_program: Program<'info, System>,
     token_program: Program<'info, Token>,
     associated_token_program: Program<'info, AssociatedToken>,
+
+    /// The lamports of native SOL recipes are kept here
+    #[account(
+        init,
+        payer = authority,
+        space = RecipeVaultV1::BASE_VAULT_SIZE,
+        seeds = [
+            "vault".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump,
+    )]
+    vault: Option<Account<'info, RecipeVaultV1>>,
 }
 
 pub fn handler_init_recipe_v1(ctx: Context<InitRecipeV1Ctx>, ix: InitRecipeV1Ix) -> Result<()> {
@@ -88,47 +108,95 @@ pub fn handler_init_recipe_v1(ctx: Context<InitRecipeV1Ctx>, ix: InitRecipeV1Ix)
         return Err(MplHybridError::MaxMustBeGreaterThanMin.into());
     }
 
-    if *collection.owner != MPL_CORE
-        || load_key(&collection.to_account_info(), 0)? != MplCoreKey::CollectionV1
-    {
-        return Err(MplHybridError::InvalidCollectionAccount.into());
-    }
-
-    // We only fetch the Base collection to check authority.
-    let collection_data =
-        BaseCollectionV1::from_bytes(&collection.to_account_info().data.borrow())?;
-
-    // Check that the collection authority is the same as the recipe authority.
-    if collection_data.update_authority != authority.key() {
-        return Err(MplHybridError::InvalidCollectionAuthority.into());
+    // Token recipes need the mint and the fee ATA, the other currencies can't burn on the path.
+    let token = match ix.currency {
+        Currency::Token => {
+            if ctx.accounts.fee_ata.is_none() {
+                return Err(MplHybridError::MissingTokenAccount.into());
+            }
+            token
+                .as_ref()
+                .ok_or(MplHybridError::MissingTokenAccount)?
+                .key()
+        }
+        Currency::NativeSol => {
+            if Path::BurnOnCapture.check(ix.path) {
+                return Err(MplHybridError::InvalidPathForCurrency.into());
+            }
+            let vault = ctx
+                .accounts
+                .vault
+                .as_mut()
+                .ok_or(MplHybridError::MissingVaultAccount)?;
+            vault.recipe = recipe.key();
+            vault.bump = ctx.bumps.vault;
+            native_mint::ID
+        }
+        Currency::Basket => {
+            // Whether each basket token is burned is configured on the basket itself.
+            if Path::BurnOnCapture.check(ix.path) {
+                return Err(MplHybridError::InvalidPathForCurrency.into());
+            }
+            Pubkey::default()
+        }
+    };
+
+    match ix.kind {
+        RecipeKind::Collection => {
+            if *collection.owner != MPL_CORE
+                || load_key(&collection.to_account_info(), 0)? != MplCoreKey::CollectionV1
+            {
+                return Err(MplHybridError::InvalidCollectionAccount.into());
+            }
+
+            // The signer has to be the collection update authority or one of its update delegates.
+            assert_collection_authority(collection, &authority.key())?;
+        }
+        RecipeKind::Creator => {
+            // The creator is an address rather than a Core account.
+            if *collection.owner == MPL_CORE {
+                return Err(MplHybridError::InvalidCollectionAccount.into());
+            }
+
+            // Only the creator can set up a recipe for its assets.
+            if collection.key() != authority.key() {
+                return Err(MplHybridError::InvalidCollectionAuthority.into());
+            }
+        }
     }
 
     //initialize with input data
+    let recipe_state = RecipeV1 {
+        token,
+        fee_location: fee_location.key(),
+        name: ix.name,
+        uri: ix.uri,
+        max: ix.max,
+        min: ix.min,
+        capture_amount: ix.capture_amount,
+        fee_amount_capture: ix.fee_amount_capture,
+        sol_fee_amount_capture: ix.sol_fee_amount_capture,
+        fee_amount_release: ix.fee_amount_release,
+        sol_fee_amount_release: ix.sol_fee_amount_release,
+        count: 1,
+        path: ix.path,
+        release_amount: ix.release_amount,
+        currency: ix.currency,
+        quote_signer: ix.quote_signer.unwrap_or_default(),
+        ..RecipeV1::new(collection.key(), authority.key(), ix.kind, ctx.bumps.recipe)
+    };
+
     let mut recipe_data = RecipeV1::DISCRIMINATOR.to_vec();
-    recipe_data.extend(
-        RecipeV1 {
-            collection: collection.key(),
-            authority: authority.key(),
-            token: token.key(),
-            fee_location: fee_location.key(),
-            name: ix.name,
-            uri: ix.uri,
-            max: ix.max,
-            min: ix.min,
-            amount: ix.amount,
-            fee_amount_capture: ix.fee_amount_capture,
-            sol_fee_amount_capture: ix.sol_fee_amount_capture,
-            fee_amount_release: ix.fee_amount_release,
-            sol_fee_amount_release: ix.sol_fee_amount_release,
-            count: 1,
-            path: ix.path,
-            bump: ctx.bumps.recipe,
-        }
-        .try_to_vec()?,
-    );
+    recipe_data.extend(recipe_state.try_to_vec()?);
 
     let mut recipe_data_borrowed = recipe.data.borrow_mut();
     sol_memcpy(&mut recipe_data_borrowed, &recipe_data, recipe_data.len());
 
+    emit_cpi!(RecipeEvent {
+        action: ConfigAction::Init,
+        recipe: recipe.key(),
+        data: recipe_state,
+    });
+
     Ok(())
 }
@@ -0,0 +1,69 @@
+use crate::error::MplHybridError;
+use crate::events::{ConfigAction, RecipeEvent};
+use crate::state::*;
+use crate::utils::assert_recipe_authority;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LockRecipeV1Ix {
+    fields: u16,
+    fee_caps: Option<FeeCaps>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct LockRecipeV1Ctx<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "recipe".as_bytes(), 
+            collection.key().as_ref()
+            ],
+        bump=recipe.bump,
+    )]
+    recipe: Account<'info, RecipeV1>,
+
+    authority: Signer<'info>,
+
+    /// CHECK: We check against recipe
+    #[account(
+        address = recipe.collection
+    )]
+    collection: AccountInfo<'info>,
+}
+
+// Locks are permanent: fields are only ever added to the lock and caps only ever lowered.
+pub fn handler_lock_recipe_v1(ctx: Context<LockRecipeV1Ctx>, ix: LockRecipeV1Ix) -> Result<()> {
+    let recipe = &mut ctx.accounts.recipe;
+    let authority = &ctx.accounts.authority;
+
+    // Operators can't lock the recipe, only the recipe authority can.
+    assert_recipe_authority(recipe, &authority.key())?;
+
+    if ix.fields & !RecipeField::ALL != 0 {
+        return Err(MplHybridError::InvalidRecipeFields.into());
+    }
+
+    recipe.locked |= ix.fields;
+
+    if let Some(fee_caps) = ix.fee_caps {
+        if fee_caps.raises(&recipe.fee_caps) {
+            return Err(MplHybridError::InvalidFeeCap.into());
+        }
+
+        // The current fees have to fit under the new caps.
+        if recipe.fees_exceed(&fee_caps) {
+            return Err(MplHybridError::FeeCapExceeded.into());
+        }
+
+        recipe.fee_caps = fee_caps;
+    }
+
+    emit_cpi!(RecipeEvent {
+        action: ConfigAction::Update,
+        recipe: recipe.key(),
+        data: (**recipe).clone(),
+    });
+
+    Ok(())
+}
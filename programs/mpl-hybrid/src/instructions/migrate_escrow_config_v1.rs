@@ -0,0 +1,114 @@
+use crate::error::MplHybridError;
+use crate::events::{ConfigAction, RecipeEvent};
+use crate::state::*;
+use crate::utils::assert_collection_authority;
+use anchor_lang::{prelude::*, Discriminator};
+use mpl_utils::create_or_allocate_account_raw;
+use solana_program::program_memory::sol_memcpy;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateEscrowConfigV1Ctx<'info> {
+    /// CHECK: This account is checked and initialized in the handler.
+    #[account(
+        mut,
+        seeds = [
+            "recipe".as_bytes(), 
+            collection.key().as_ref()
+            ],
+        bump,
+    )]
+    recipe: AccountInfo<'info>,
+
+    // What the old escrow still holds is moved afterwards by migrate_nft_v1 and migrate_tokens_v1,
+    // which sign with its seeds rather than loading it.
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            "escrow".as_bytes(), 
+            collection.key().as_ref()
+            ],
+        bump=escrow_old.bump,
+    )]
+    escrow_old: Account<'info, EscrowV1>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// CHECK: We check against escrow
+    #[account(
+        address = escrow_old.collection
+    )]
+    collection: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn handler_migrate_escrow_config_v1(ctx: Context<MigrateEscrowConfigV1Ctx>) -> Result<()> {
+    let recipe = &mut ctx.accounts.recipe;
+    let escrow_old = &ctx.accounts.escrow_old;
+    let collection = &ctx.accounts.collection;
+    let authority = &ctx.accounts.authority;
+
+    if escrow_old.authority != authority.key() {
+        return Err(MplHybridError::InvalidAuthority.into());
+    }
+
+    // The signer has to be the collection update authority or one of its update delegates.
+    assert_collection_authority(collection, &authority.key())?;
+
+    create_or_allocate_account_raw(
+        crate::ID,
+        recipe,
+        &ctx.accounts.system_program.to_account_info(),
+        &authority.to_account_info(),
+        RecipeV1::BASE_RECIPE_SIZE + escrow_old.name.len() + escrow_old.uri.len(),
+        &[
+            "recipe".as_bytes(),
+            &collection.key.to_bytes(),
+            &[ctx.bumps.recipe],
+        ],
+    )?;
+
+    // Legacy escrows swap at a single amount and fee in both directions.
+    let recipe_state = RecipeV1 {
+        token: escrow_old.token,
+        fee_location: escrow_old.fee_location,
+        name: escrow_old.name.clone(),
+        uri: escrow_old.uri.clone(),
+        max: escrow_old.max,
+        min: escrow_old.min,
+        capture_amount: escrow_old.amount,
+        fee_amount_capture: escrow_old.fee_amount,
+        sol_fee_amount_capture: escrow_old.sol_fee_amount,
+        fee_amount_release: escrow_old.fee_amount,
+        sol_fee_amount_release: escrow_old.sol_fee_amount,
+        count: escrow_old.count,
+        path: escrow_old.path,
+        release_amount: escrow_old.amount,
+        // The holdings left in the old escrow can only be moved by its authority, in its token.
+        legacy_authority: escrow_old.authority,
+        legacy_token: escrow_old.token,
+        ..RecipeV1::new(
+            collection.key(),
+            authority.key(),
+            RecipeKind::Collection,
+            ctx.bumps.recipe,
+        )
+    };
+
+    let mut recipe_data = RecipeV1::DISCRIMINATOR.to_vec();
+    recipe_data.extend(recipe_state.try_to_vec()?);
+
+    let mut recipe_data_borrowed = recipe.data.borrow_mut();
+    sol_memcpy(&mut recipe_data_borrowed, &recipe_data, recipe_data.len());
+
+    emit_cpi!(RecipeEvent {
+        action: ConfigAction::Migrate,
+        recipe: recipe.key(),
+        data: recipe_state,
+    });
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use crate::constants::*;
 use crate::error::MplHybridError;
+use crate::events::MigrateNftEvent;
 use crate::state::*;
 use anchor_lang::prelude::*;
 use anchor_lang::{
@@ -8,6 +9,7 @@ use anchor_lang::{
 };
 use mpl_core::instructions::{TransferV1Cpi, TransferV1InstructionArgs};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct MigrateNftV1Ctx<'info> {
     #[account(mut)]
@@ -23,24 +25,23 @@ pub struct MigrateNftV1Ctx<'info> {
     )]
     escrow_new: Account<'info, EscrowV2>,
 
+    /// CHECK: Checked with the seeds only, the legacy escrow may already have been closed.
     #[account(
         mut,
         seeds = [
             "escrow".as_bytes(), 
             collection.key().as_ref()
             ],
-        bump=escrow_old.bump,
+        bump,
     )]
-    escrow_old: Account<'info, EscrowV1>,
+    escrow_old: UncheckedAccount<'info>,
 
     /// CHECK: We check the asset bellow
     #[account(mut)]
     asset: UncheckedAccount<'info>,
 
-    /// CHECK: We check against escrow
-    #[account(mut,
-        address = escrow_old.collection
-    )]
+    /// CHECK: We check against the escrow and recipe seeds
+    #[account(mut)]
     collection: AccountInfo<'info>,
     /// CHECK: We check against constant
     #[account(
@@ -48,6 +49,15 @@ pub struct MigrateNftV1Ctx<'info> {
     )]
     mpl_core: AccountInfo<'info>,
     system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [
+            "recipe".as_bytes(), 
+            collection.key().as_ref()
+            ],
+        bump=recipe.bump,
+    )]
+    recipe: Account<'info, RecipeV1>,
 }
 
 pub fn handler_migrate_nft_v1(ctx: Context<MigrateNftV1Ctx>) -> Result<()> {
@@ -55,15 +65,18 @@ pub fn handler_migrate_nft_v1(ctx: Context<MigrateNftV1Ctx>) -> Result<()> {
     let escrow_new = &mut ctx.accounts.escrow_new;
     let escrow_old = &mut ctx.accounts.escrow_old;
     let collection = &mut ctx.accounts.collection;
+    let recipe = &ctx.accounts.recipe;
     let asset = &mut ctx.accounts.asset;
     let mpl_core = &mut ctx.accounts.mpl_core;
     let system_program = &mut ctx.accounts.system_program;
 
-    if escrow_new.authority != escrow_old.authority {
-        return Err(MplHybridError::InvalidAuthority.into());
-    }
-
-    if escrow_new.authority != authority.key() {
+    // The escrow config is migrated first, then the recipe authority moves what the escrow holds.
+    // It has to be the authority of the old escrow too, recipes that weren't migrated from it have
+    // no legacy authority.
+    if authority.key() != recipe.authority
+        || authority.key() != recipe.legacy_authority
+        || escrow_new.authority != recipe.authority
+    {
         return Err(MplHybridError::InvalidAuthority.into());
     }
 
@@ -88,8 +101,17 @@ pub fn handler_migrate_nft_v1(ctx: Context<MigrateNftV1Ctx>) -> Result<()> {
     };
 
     //invoke the transfer instruction with seeds
-    let _transfer_nft_result =
-        transfer_nft_ix.invoke_signed(&[&[b"escrow", collection.key.as_ref(), &[escrow_old.bump]]]);
+    transfer_nft_ix.invoke_signed(&[&[
+        b"escrow",
+        collection.key.as_ref(),
+        &[ctx.bumps.escrow_old],
+    ]])?;
+
+    emit_cpi!(MigrateNftEvent {
+        escrow_old: escrow_old.key(),
+        escrow_new: escrow_new.key(),
+        asset: asset.key(),
+    });
 
     Ok(())
 }
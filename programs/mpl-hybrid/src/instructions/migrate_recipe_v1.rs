@@ -0,0 +1,73 @@
+use crate::error::MplHybridError;
+use crate::events::{ConfigAction, RecipeEvent};
+use crate::state::*;
+use crate::utils::assert_asset_authority;
+use anchor_lang::{prelude::*, Discriminator};
+use mpl_utils::resize_or_reallocate_account_raw;
+use solana_program::program_memory::sol_memcpy;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateRecipeV1Ctx<'info> {
+    /// CHECK: This account is checked and rewritten in the handler.
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [
+            "recipe".as_bytes(), 
+            collection.key().as_ref()
+            ],
+        bump,
+    )]
+    recipe: AccountInfo<'info>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// CHECK: We check the collection bellow and with recipe seeds
+    collection: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn handler_migrate_recipe_v1(ctx: Context<MigrateRecipeV1Ctx>) -> Result<()> {
+    let recipe = &mut ctx.accounts.recipe;
+    let collection = &mut ctx.accounts.collection;
+    let authority = &mut ctx.accounts.authority;
+
+    // Read the recipe with whatever layout it was written with.
+    let recipe_data = RecipeV1::try_deserialize_legacy(&recipe.data.borrow())?;
+
+    if recipe_data.collection != collection.key() {
+        return Err(MplHybridError::InvalidCollectionAccount.into());
+    }
+
+    // The signer has to administer the collection, or be the creator of creator recipes.
+    assert_asset_authority(recipe_data.kind, collection, &authority.key())?;
+
+    //rewrite with the current layout
+    let mut new_recipe_data = RecipeV1::DISCRIMINATOR.to_vec();
+    new_recipe_data.extend(recipe_data.try_to_vec()?);
+
+    resize_or_reallocate_account_raw(
+        recipe,
+        authority,
+        &ctx.accounts.system_program,
+        new_recipe_data.len(),
+    )?;
+
+    let mut recipe_data_borrowed = recipe.data.borrow_mut();
+    sol_memcpy(
+        &mut recipe_data_borrowed,
+        &new_recipe_data,
+        new_recipe_data.len(),
+    );
+
+    emit_cpi!(RecipeEvent {
+        action: ConfigAction::Migrate,
+        recipe: recipe.key(),
+        data: recipe_data,
+    });
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use crate::error::MplHybridError;
+use crate::events::MigrateTokensEvent;
 use crate::state::*;
 use crate::utils::{create_associated_token_account, validate_token_account};
 use anchor_lang::prelude::*;
@@ -17,6 +18,7 @@ pub struct MigrateTokensV1Ix {
     amount: u64,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct MigrateTokensV1Ctx<'info> {
     #[account(mut)]
@@ -32,20 +34,19 @@ pub struct MigrateTokensV1Ctx<'info> {
     )]
     escrow_new: Account<'info, EscrowV2>,
 
+    /// CHECK: Checked with the seeds only, the legacy escrow may already have been closed.
     #[account(
         mut,
         seeds = [
             "escrow".as_bytes(), 
             collection.key().as_ref()
             ],
-        bump=escrow_old.bump,
+        bump,
     )]
-    escrow_old: Account<'info, EscrowV1>,
+    escrow_old: UncheckedAccount<'info>,
 
-    /// CHECK: We check against escrow
-    #[account(mut,
-        address = escrow_old.collection
-    )]
+    /// CHECK: We check against the escrow and recipe seeds
+    #[account(mut)]
     collection: AccountInfo<'info>,
 
     /// CHECK: We check and initialize the token account below.
@@ -56,15 +57,24 @@ pub struct MigrateTokensV1Ctx<'info> {
     #[account(mut)]
     escrow_old_token_account: AccountInfo<'info>,
 
-    /// CHECK: This is a user defined account
+    /// The old escrow's token, the recipe token may have been repointed since.
     #[account(
-        address = escrow_old.token @MplHybridError::InvalidMintAccount
+        address = recipe.legacy_token @ MplHybridError::InvalidMintAccount
     )]
     token: Account<'info, Mint>,
 
     system_program: Program<'info, System>,
     token_program: Program<'info, Token>,
     associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(
+        seeds = [
+            "recipe".as_bytes(), 
+            collection.key().as_ref()
+            ],
+        bump=recipe.bump,
+    )]
+    recipe: Account<'info, RecipeV1>,
 }
 
 pub fn handler_migrate_tokens_v1(
@@ -76,17 +86,20 @@ pub fn handler_migrate_tokens_v1(
     let escrow_new = &mut ctx.accounts.escrow_new;
     let escrow_old = &mut ctx.accounts.escrow_old;
     let collection = &mut ctx.accounts.collection;
+    let recipe = &ctx.accounts.recipe;
     let escrow_new_token_account = &mut ctx.accounts.escrow_new_token_account;
     let escrow_old_token_account = &mut ctx.accounts.escrow_old_token_account;
     let token = &mut ctx.accounts.token;
     let system_program = &mut ctx.accounts.system_program;
     let token_program = &mut ctx.accounts.token_program;
 
-    if escrow_new.authority != escrow_old.authority {
-        return Err(MplHybridError::InvalidAuthority.into());
-    }
-
-    if escrow_new.authority != authority.key() {
+    // The escrow config is migrated first, then the recipe authority moves what the escrow holds.
+    // It has to be the authority of the old escrow too, recipes that weren't migrated from it have
+    // no legacy authority.
+    if authority.key() != recipe.authority
+        || authority.key() != recipe.legacy_authority
+        || escrow_new.authority != recipe.authority
+    {
         return Err(MplHybridError::InvalidAuthority.into());
     }
 
@@ -111,7 +124,7 @@ pub fn handler_migrate_tokens_v1(
     //create transfer token instruction
     let cpi_program = token_program.to_account_info();
 
-    let signer_seeds = &[b"escrow", collection.key.as_ref(), &[escrow_old.bump]];
+    let signer_seeds = &[b"escrow", collection.key.as_ref(), &[ctx.bumps.escrow_old]];
 
     let signer = &[&signer_seeds[..]];
 
@@ -126,5 +139,12 @@ pub fn handler_migrate_tokens_v1(
 
     token::transfer(transfer_cpi_ctx, ix.amount)?;
 
+    emit_cpi!(MigrateTokensEvent {
+        escrow_old: escrow_old.key(),
+        escrow_new: escrow_new.key(),
+        mint: token.key(),
+        amount: ix.amount,
+    });
+
     Ok(())
 }
@@ -1,27 +1,75 @@
+pub mod accept_recipe_authority;
+pub mod cancel_recipe_update;
 pub mod capture;
 pub mod capture_v2;
+pub mod close_wallet_activity;
+pub mod execute_recipe_update;
+pub mod init_basket;
+pub mod init_discount_policy;
 pub mod init_escrow;
 pub mod init_escrow_v2;
 pub mod init_nft_data;
+pub mod init_operators;
+pub mod init_payment_table;
+pub mod init_protocol_config;
 pub mod init_recipe;
+pub mod lock_recipe;
+pub mod migrate_escrow_config_v1;
 pub mod migrate_nft_v1;
+pub mod migrate_recipe_v1;
 pub mod migrate_tokens_v1;
+pub mod propose_recipe_authority;
+pub mod queue_recipe_update;
 pub mod release;
 pub mod release_v2;
+pub mod reset_circuit_breaker;
+pub mod set_pause;
+pub mod set_protocol_admin;
+pub mod transfer_escrow_asset;
+pub mod transfer_escrow_reserve;
+pub mod update_basket;
+pub mod update_discount_policy;
 pub mod update_escrow;
 pub mod update_new_data;
+pub mod update_operators;
+pub mod update_payment_table;
 pub mod update_recipe;
+pub mod withdraw_escrow;
 
+pub use accept_recipe_authority::*;
+pub use cancel_recipe_update::*;
 pub use capture::*;
 pub use capture_v2::*;
+pub use close_wallet_activity::*;
+pub use execute_recipe_update::*;
+pub use init_basket::*;
+pub use init_discount_policy::*;
 pub use init_escrow::*;
 pub use init_escrow_v2::*;
 pub use init_nft_data::*;
+pub use init_operators::*;
+pub use init_payment_table::*;
+pub use init_protocol_config::*;
 pub use init_recipe::*;
+pub use lock_recipe::*;
+pub use migrate_escrow_config_v1::*;
 pub use migrate_nft_v1::*;
+pub use migrate_recipe_v1::*;
 pub use migrate_tokens_v1::*;
+pub use propose_recipe_authority::*;
+pub use queue_recipe_update::*;
 pub use release::*;
 pub use release_v2::*;
+pub use reset_circuit_breaker::*;
+pub use set_pause::*;
+pub use set_protocol_admin::*;
+pub use transfer_escrow_asset::*;
+pub use transfer_escrow_reserve::*;
+pub use update_basket::*;
+pub use update_discount_policy::*;
 pub use update_escrow::*;
 pub use update_new_data::*;
+pub use update_operators::*;
+pub use update_payment_table::*;
 pub use update_recipe::*;
+pub use withdraw_escrow::*;
@@ -0,0 +1,47 @@
+use crate::error::MplHybridError;
+use crate::events::{ConfigAction, RecipeEvent};
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProposeRecipeAuthorityV1Ix {
+    new_authority: Pubkey,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeRecipeAuthorityV1Ctx<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "recipe".as_bytes(), 
+            recipe.collection.as_ref()
+            ],
+        bump=recipe.bump,
+    )]
+    recipe: Account<'info, RecipeV1>,
+
+    #[account(
+        address = recipe.authority @MplHybridError::InvalidAuthority
+    )]
+    authority: Signer<'info>,
+}
+
+// The current authority names its successor, who has to accept before anything changes. Proposing
+// the default key cancels a pending handover.
+pub fn handler_propose_recipe_authority_v1(
+    ctx: Context<ProposeRecipeAuthorityV1Ctx>,
+    ix: ProposeRecipeAuthorityV1Ix,
+) -> Result<()> {
+    let recipe = &mut ctx.accounts.recipe;
+
+    recipe.pending_authority = ix.new_authority;
+
+    emit_cpi!(RecipeEvent {
+        action: ConfigAction::Update,
+        recipe: recipe.key(),
+        data: (**recipe).clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use crate::error::MplHybridError;
+use crate::events::RecipeUpdateQueuedEvent;
+use crate::instructions::{apply_recipe_update, UpdateRecipeV1Ix};
+use crate::state::*;
+use crate::utils::{assert_recipe_admin, assert_recipe_authority};
+use anchor_lang::{prelude::*, Discriminator};
+use anchor_spl::token::Mint;
+use mpl_utils::create_or_allocate_account_raw;
+use solana_program::program_memory::sol_memcpy;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct QueueRecipeUpdateV1Ctx<'info> {
+    /// CHECK: This account is checked and initialized in the handler.
+    #[account(
+        mut,
+        seeds = [
+            "pending".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump,
+    )]
+    pending_update: AccountInfo<'info>,
+
+    #[account(
+        seeds = [
+            "recipe".as_bytes(), 
+            collection.key().as_ref()
+            ],
+        bump=recipe.bump,
+    )]
+    recipe: Account<'info, RecipeV1>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// CHECK: We check against recipe
+    #[account(
+        address = recipe.collection
+    )]
+    collection: AccountInfo<'info>,
+
+    /// CHECK: This is a user defined account
+    token: Option<Account<'info, Mint>>,
+
+    /// CHECK: This is a user defined account
+    fee_location: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            "operators".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=operators.bump,
+    )]
+    operators: Option<Account<'info, OperatorsV1>>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn handler_queue_recipe_update_v1(
+    ctx: Context<QueueRecipeUpdateV1Ctx>,
+    ix: UpdateRecipeV1Ix,
+) -> Result<()> {
+    let pending_update = &mut ctx.accounts.pending_update;
+    let recipe = &ctx.accounts.recipe;
+    let authority = &ctx.accounts.authority;
+    let token = ctx.accounts.token.as_ref().map(|token| token.key());
+    let fee_location = ctx.accounts.fee_location.key();
+
+    assert_recipe_admin(
+        recipe,
+        &authority.key(),
+        ctx.accounts.operators.as_deref(),
+        &ix.roles(recipe, token, fee_location),
+    )?;
+
+    if ix.needs_recipe_authority(recipe, token) {
+        assert_recipe_authority(recipe, &authority.key())?;
+    }
+
+    // Only one update can be queued at a time so frontends have a single change to show.
+    if pending_update.owner == &crate::ID {
+        return Err(MplHybridError::RecipeUpdateAlreadyQueued.into());
+    }
+
+    // Dry run the update so invalid changes fail now rather than once the timelock has passed.
+    apply_recipe_update(&mut (**recipe).clone(), ix.clone(), token, fee_location)?;
+
+    //initialize with input data
+    let pending_update_state = PendingRecipeUpdateV1 {
+        recipe: recipe.key(),
+        authority: authority.key(),
+        activation_slot: Clock::get()?
+            .slot
+            .checked_add(recipe.timelock_slots)
+            .ok_or(MplHybridError::NumericalOverflow)?,
+        token,
+        fee_location,
+        bump: ctx.bumps.pending_update,
+        update: ix,
+    };
+
+    let mut pending_update_data = PendingRecipeUpdateV1::DISCRIMINATOR.to_vec();
+    pending_update_data.extend(pending_update_state.try_to_vec()?);
+
+    create_or_allocate_account_raw(
+        crate::ID,
+        pending_update,
+        &ctx.accounts.system_program.to_account_info(),
+        &authority.to_account_info(),
+        pending_update_data.len(),
+        &[
+            "pending".as_bytes(),
+            &recipe.key().to_bytes(),
+            &[ctx.bumps.pending_update],
+        ],
+    )?;
+
+    let mut pending_update_data_borrowed = pending_update.data.borrow_mut();
+    sol_memcpy(
+        &mut pending_update_data_borrowed,
+        &pending_update_data,
+        pending_update_data.len(),
+    );
+
+    emit_cpi!(RecipeUpdateQueuedEvent {
+        recipe: recipe.key(),
+        pending_update: pending_update.key(),
+        data: pending_update_state,
+    });
+
+    Ok(())
+}
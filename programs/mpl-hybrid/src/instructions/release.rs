@@ -1,7 +1,10 @@
 use crate::constants::*;
 use crate::error::MplHybridError;
+use crate::events::ReleaseEvent;
 use crate::state::*;
-use crate::utils::{create_associated_token_account, validate_token_account};
+use crate::utils::{
+    assert_protocol_not_paused, create_associated_token_account, validate_token_account,
+};
 use anchor_lang::prelude::*;
 use anchor_lang::{
     accounts::{program::Program, signer::Signer},
@@ -20,6 +23,7 @@ use mpl_utils::assert_signer;
 use solana_program::program::invoke;
 use solana_program::system_program;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ReleaseV1Ctx<'info> {
     #[account(mut)]
@@ -93,6 +97,13 @@ pub struct ReleaseV1Ctx<'info> {
     system_program: Program<'info, System>,
     token_program: Program<'info, Token>,
     associated_token_program: Program<'info, AssociatedToken>,
+
+    /// CHECK: Checked by seeds, it is required so the protocol pause can't be skipped.
+    #[account(
+        seeds = ["protocol".as_bytes()],
+        bump,
+    )]
+    protocol_config: AccountInfo<'info>,
 }
 
 pub fn handler_release_v1(ctx: Context<ReleaseV1Ctx>) -> Result<()> {
@@ -117,6 +128,8 @@ pub fn handler_release_v1(ctx: Context<ReleaseV1Ctx>) -> Result<()> {
     let owner_info = &owner.to_account_info();
     let system_info = &system_program.to_account_info();
 
+    assert_protocol_not_paused(&ctx.accounts.protocol_config)?;
+
     // Create idempotent
     if user_token_account.owner == &system_program::ID {
         solana_program::msg!("Creating user token account");
@@ -167,6 +180,8 @@ pub fn handler_release_v1(ctx: Context<ReleaseV1Ctx>) -> Result<()> {
         assert_signer(&ctx.accounts.authority)?;
     }
 
+    let mut new_uri = None;
+
     //If the path has bit 0 unset, we need to update the metadata onchain
     if !Path::NoRerollMetadata.check(escrow.path) {
         //construct the captured uri
@@ -177,6 +192,8 @@ pub fn handler_release_v1(ctx: Context<ReleaseV1Ctx>) -> Result<()> {
         uri.push_str("captured");
         uri.push_str(json_extension);
 
+        new_uri = Some(uri.clone());
+
         //create update instruction
         let update_ix = UpdateV1Cpi {
             __program: &mpl_core.to_account_info(),
@@ -241,10 +258,11 @@ pub fn handler_release_v1(ctx: Context<ReleaseV1Ctx>) -> Result<()> {
     token::transfer(transfer_cpi_ctx, escrow.amount)?;
 
     //create protocol transfer fee sol instruction
+    let protocol_fee = get_protocol_fee()?;
     let sol_fee_ix = anchor_lang::solana_program::system_instruction::transfer(
         &owner.key(),
         &fee_sol_account.key(),
-        get_protocol_fee()?,
+        protocol_fee,
     );
 
     //invoke the protocol transfer fee sol instruction
@@ -272,5 +290,20 @@ pub fn handler_release_v1(ctx: Context<ReleaseV1Ctx>) -> Result<()> {
     //increment the swap count
     escrow.count += 1;
 
+    emit_cpi!(ReleaseEvent {
+        recipe: Pubkey::default(),
+        escrow: escrow.key(),
+        asset: asset.key(),
+        user: owner.key(),
+        mint: escrow.token,
+        amount: escrow.amount,
+        asset_burned: false,
+        fee_amount: 0,
+        sol_fee_amount: escrow.sol_fee_amount,
+        protocol_fee,
+        uri: new_uri,
+        basket: Vec::new(),
+    });
+
     Ok(())
 }
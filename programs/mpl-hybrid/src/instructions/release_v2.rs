@@ -1,7 +1,13 @@
 use crate::constants::*;
 use crate::error::MplHybridError;
+use crate::events::ReleaseEvent;
 use crate::state::*;
-use crate::utils::{create_associated_token_account, validate_token_account};
+use crate::utils::{
+    apply_discount, assert_protocol_not_paused, consume_quote_nonce,
+    create_associated_token_account, get_holder_discount_bps, record_asset_swap,
+    record_swap_receipt, record_wallet_swap, release_basket, validate_token_account,
+    verify_merkle_proof, verify_swap_quote, withdraw_lamports,
+};
 use anchor_lang::prelude::*;
 use anchor_lang::{
     accounts::{program::Program, signer::Signer},
@@ -16,11 +22,20 @@ use mpl_core::instructions::{
     BurnV1Cpi, BurnV1InstructionArgs, TransferV1Cpi, TransferV1InstructionArgs, UpdateV1Cpi,
     UpdateV1InstructionArgs,
 };
-use mpl_core::types::UpdateAuthority;
 use mpl_utils::assert_signer;
 use solana_program::program::invoke;
+use solana_program::program_pack::Pack;
 use solana_program::system_program;
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ReleaseV2Ix {
+    // The proof of the asset against the recipe asset root, empty when the root is unset.
+    asset_proof: Vec<[u8; 32]>,
+    // The proof of the owner against the recipe wallet root, empty when the root is unset.
+    wallet_proof: Vec<[u8; 32]>,
+}
+
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ReleaseV2Ctx<'info> {
     #[account(mut)]
@@ -62,21 +77,19 @@ pub struct ReleaseV2Ctx<'info> {
 
     /// CHECK: We check and initialize the token account below.
     #[account(mut)]
-    user_token_account: AccountInfo<'info>,
+    user_token_account: Option<AccountInfo<'info>>,
 
     /// CHECK: We check the token account below.
     #[account(mut)]
-    escrow_token_account: AccountInfo<'info>,
+    escrow_token_account: Option<AccountInfo<'info>>,
 
     /// CHECK: This is a user defined account
-    #[account(
-        address = recipe.token @MplHybridError::InvalidMintAccount
-    )]
-    token: Account<'info, Mint>,
+    /// Checked against the recipe token and its payment table in the handler.
+    token: Option<Box<Account<'info, Mint>>>,
 
     /// CHECK: We check and initialize the token account below.
     #[account(mut)]
-    fee_token_account: AccountInfo<'info>,
+    fee_token_account: Option<AccountInfo<'info>>,
 
     /// CHECK: We check against constant
     #[account(mut,
@@ -104,9 +117,81 @@ pub struct ReleaseV2Ctx<'info> {
     system_program: Program<'info, System>,
     token_program: Program<'info, Token>,
     associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(
+        seeds = [
+            "discount".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=discount_policy.bump,
+    )]
+    discount_policy: Option<Box<Account<'info, DiscountPolicyV1>>>,
+
+    /// CHECK: We check against the discount policy in the handler.
+    discount_proof: Option<AccountInfo<'info>>,
+
+    #[account(
+        seeds = [
+            "basket".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=basket.bump,
+    )]
+    basket: Option<Box<Account<'info, BasketV1>>>,
+
+    #[account(
+        seeds = [
+            "payments".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=payment_table.bump,
+    )]
+    payment_table: Option<Box<Account<'info, PaymentTableV1>>>,
+
+    /// CHECK: Checked by address, needed to read the quote of quoted recipes.
+    #[account(
+        address = solana_program::sysvar::instructions::ID @MplHybridError::InvalidQuote
+    )]
+    instructions_sysvar: Option<AccountInfo<'info>>,
+
+    /// CHECK: We check and initialize the quote nonce in the handler.
+    #[account(mut)]
+    quote_nonce: Option<AccountInfo<'info>>,
+
+    /// CHECK: We check and initialize the wallet activity in the handler.
+    #[account(mut)]
+    wallet_activity: Option<AccountInfo<'info>>,
+
+    /// CHECK: We check and initialize the asset cooldown in the handler.
+    #[account(mut)]
+    asset_cooldown: Option<AccountInfo<'info>>,
+
+    /// CHECK: We check and initialize the swap receipt in the handler.
+    #[account(mut)]
+    swap_receipt: Option<AccountInfo<'info>>,
+
+    #[account(
+        mut,
+        seeds = [
+            "vault".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=vault.bump,
+    )]
+    vault: Option<Box<Account<'info, RecipeVaultV1>>>,
+
+    /// CHECK: Checked by seeds, it is required so the protocol pause can't be skipped.
+    #[account(
+        seeds = ["protocol".as_bytes()],
+        bump,
+    )]
+    protocol_config: AccountInfo<'info>,
 }
 
-pub fn handler_release_v2(ctx: Context<ReleaseV2Ctx>) -> Result<()> {
+pub fn handler_release_v2<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReleaseV2Ctx<'info>>,
+    ix: ReleaseV2Ix,
+) -> Result<()> {
     //Need to add account checks for security
 
     let owner = &mut ctx.accounts.owner;
@@ -116,15 +201,17 @@ pub fn handler_release_v2(ctx: Context<ReleaseV2Ctx>) -> Result<()> {
     let authority = &mut ctx.accounts.authority;
     let collection = &mut ctx.accounts.collection;
     let mpl_core = &mut ctx.accounts.mpl_core;
-    let user_token_account = &mut ctx.accounts.user_token_account;
-    let escrow_token_account = &mut ctx.accounts.escrow_token_account;
-    let fee_token_account = &mut ctx.accounts.fee_token_account;
     let fee_sol_account = &mut ctx.accounts.fee_sol_account;
     let fee_project_account = &mut ctx.accounts.fee_project_account;
     let system_program = &mut ctx.accounts.system_program;
     let token_program = &mut ctx.accounts.token_program;
 
     let collection_info = &collection.to_account_info();
+    // Standalone assets aren't part of a collection.
+    let core_collection = match recipe.kind {
+        RecipeKind::Collection => Some(collection_info),
+        RecipeKind::Creator => None,
+    };
     let authority_info = &authority.to_account_info();
     let owner_info = &owner.to_account_info();
     let system_info = &system_program.to_account_info();
@@ -133,67 +220,227 @@ pub fn handler_release_v2(ctx: Context<ReleaseV2Ctx>) -> Result<()> {
         return Err(MplHybridError::InvalidAuthority.into());
     }
 
+    assert_protocol_not_paused(&ctx.accounts.protocol_config)?;
+
+    if recipe.paused {
+        msg!("Pause reason: {}", recipe.pause_reason);
+        return Err(MplHybridError::RecipePaused.into());
+    }
+
     if Path::BlockRelease.check(recipe.path) {
         return Err(MplHybridError::ReleaseBlocked.into());
     }
 
-    // Create idempotent
-    if user_token_account.owner == &system_program::ID {
-        solana_program::msg!("Creating user token account");
-        create_associated_token_account(
-            owner,
+    // Scheduled recipes only swap inside their window.
+    let now = Clock::get()?.unix_timestamp;
+    if !recipe.release_window.has_opened(now) {
+        return Err(MplHybridError::ReleaseNotOpen.into());
+    } else if recipe.release_window.has_closed(now) {
+        return Err(MplHybridError::ReleaseClosed.into());
+    }
+
+    // Allowlisted recipes only swap with the wallets in the list.
+    if recipe.wallet_root != [0; 32]
+        && !verify_merkle_proof(&recipe.wallet_root, &owner.key(), &ix.wallet_proof)
+    {
+        return Err(MplHybridError::WalletNotAllowlisted.into());
+    }
+
+    // Limited recipes track the swaps of every wallet.
+    if recipe.wallet_limits.is_enabled() {
+        record_wallet_swap(
+            ctx.accounts
+                .wallet_activity
+                .as_ref()
+                .ok_or(MplHybridError::InvalidWalletActivity)?,
+            &recipe.key(),
+            &recipe.wallet_limits,
+            SwapDirection::Release,
             owner,
-            &ctx.accounts.token.to_account_info(),
-            user_token_account,
-            token_program,
             system_program,
         )?;
-    } else {
-        validate_token_account(user_token_account, &owner.key(), &ctx.accounts.token.key())?;
     }
 
-    // The escrow token account should already exist.
-    validate_token_account(
-        escrow_token_account,
-        &escrow.key(),
-        &ctx.accounts.token.key(),
-    )?;
+    // Assets have to stay on each side of the escrow for the recipe's cooldown.
+    if recipe.asset_cooldown.is_enabled() {
+        record_asset_swap(
+            ctx.accounts
+                .asset_cooldown
+                .as_ref()
+                .ok_or(MplHybridError::InvalidAssetCooldown)?,
+            &asset.key(),
+            &recipe.asset_cooldown,
+            SwapDirection::Release,
+            owner,
+            system_program,
+        )?;
+    }
 
-    if fee_token_account.owner == &system_program::ID {
-        create_associated_token_account(
+    // Holders of a qualifying asset or token balance get a discount on the project fees.
+    let discount_bps = match (&ctx.accounts.discount_policy, &ctx.accounts.discount_proof) {
+        (Some(discount_policy), Some(discount_proof)) => {
+            get_holder_discount_bps(discount_policy, discount_proof, &owner.key())?
+        }
+        (None, None) => 0,
+        _ => return Err(MplHybridError::InvalidDiscountProof.into()),
+    };
+    let sol_fee_amount = apply_discount(recipe.sol_fee_amount_release, discount_bps)?;
+
+    // Quoted recipes take their price from a quote signed off-chain for this swap.
+    let quoted_amount = if recipe.quotes_enabled() {
+        let (instructions_sysvar, quote_nonce) =
+            match (&ctx.accounts.instructions_sysvar, &ctx.accounts.quote_nonce) {
+                (Some(instructions_sysvar), Some(quote_nonce)) => {
+                    (instructions_sysvar, quote_nonce)
+                }
+                _ => return Err(MplHybridError::InvalidQuote.into()),
+            };
+        let quote = verify_swap_quote(
+            instructions_sysvar,
+            &recipe.quote_signer,
+            &recipe.key(),
+            &owner.key(),
+            &asset.key(),
+            SwapDirection::Release,
+        )?;
+        consume_quote_nonce(
+            quote_nonce,
+            &recipe.key(),
+            quote.nonce,
             owner,
-            &fee_project_account.to_account_info(),
-            &ctx.accounts.token.to_account_info(),
-            fee_token_account,
-            token_program,
             system_program,
         )?;
+        // Quotes can't price the swap outside of the bounds of the recipe's curve.
+        Some(recipe.pricing.clamp(quote.amount))
     } else {
-        validate_token_account(
-            fee_token_account,
-            &fee_project_account.key(),
-            &ctx.accounts.token.key(),
-        )?;
+        None
+    };
+
+    // Native SOL recipes are paid in lamports and don't use any token accounts.
+    let token_accounts = match recipe.currency {
+        Currency::Token => Some((
+            ctx.accounts
+                .token
+                .as_ref()
+                .ok_or(MplHybridError::MissingTokenAccount)?,
+            ctx.accounts
+                .user_token_account
+                .as_ref()
+                .ok_or(MplHybridError::MissingTokenAccount)?,
+            ctx.accounts
+                .escrow_token_account
+                .as_ref()
+                .ok_or(MplHybridError::MissingTokenAccount)?,
+            ctx.accounts
+                .fee_token_account
+                .as_ref()
+                .ok_or(MplHybridError::MissingTokenAccount)?,
+        )),
+        Currency::NativeSol | Currency::Basket => None,
+    };
+
+    // Any mint other than the recipe token has to be priced by the recipe's payment table.
+    let alternative_payment = match token_accounts {
+        Some((token, ..)) if token.key() != recipe.token => Some(
+            ctx.accounts
+                .payment_table
+                .as_ref()
+                .and_then(|payment_table| {
+                    payment_table
+                        .entries
+                        .iter()
+                        .find(|entry| entry.mint == token.key())
+                })
+                .ok_or(MplHybridError::PaymentMintNotAccepted)?,
+        ),
+        _ => None,
+    };
+
+    // Quotes price the recipe token, alternative payments keep their fixed price.
+    if quoted_amount.is_some() && alternative_payment.is_some() {
+        return Err(MplHybridError::QuoteWithAlternativePayment.into());
     }
 
-    // We only fetch the Base assets because we only need to check the collection here.
+    let (amount, fee_amount) = match alternative_payment {
+        Some(payment) => (payment.release_amount, payment.fee_amount_release),
+        // Otherwise the curve moves the recipe amount with the escrow's inventory.
+        None => (
+            quoted_amount.unwrap_or_else(|| recipe.release_price()),
+            recipe.fee_amount_release,
+        ),
+    };
+    let fee_amount = apply_discount(fee_amount, discount_bps)?;
+
+    if let Some((token, user_token_account, escrow_token_account, fee_token_account)) =
+        token_accounts
+    {
+        // Create idempotent
+        if user_token_account.owner == &system_program::ID {
+            solana_program::msg!("Creating user token account");
+            create_associated_token_account(
+                owner,
+                owner,
+                &token.to_account_info(),
+                user_token_account,
+                token_program,
+                system_program,
+            )?;
+        } else {
+            validate_token_account(user_token_account, &owner.key(), &token.key())?;
+        }
+
+        // The escrow token account should already exist.
+        validate_token_account(escrow_token_account, &escrow.key(), &token.key())?;
+
+        // Alternative payments are only paid out of what the escrow has collected in that mint.
+        if alternative_payment.is_some()
+            && spl_token::state::Account::unpack(&escrow_token_account.data.borrow())?.amount
+                < amount
+        {
+            return Err(MplHybridError::InsufficientEscrowBalance.into());
+        }
+
+        if fee_token_account.owner == &system_program::ID {
+            create_associated_token_account(
+                owner,
+                &fee_project_account.to_account_info(),
+                &token.to_account_info(),
+                fee_token_account,
+                token_program,
+                system_program,
+            )?;
+        } else {
+            validate_token_account(fee_token_account, &fee_project_account.key(), &token.key())?;
+        }
+    }
+
+    // We only fetch the Base assets because we only need to check the update authority here.
     let asset_data = BaseAssetV1::from_bytes(&asset.to_account_info().data.borrow())?;
-    // Check that the collection that the asset is a part of is the one this recipe is configured for.
-    if asset_data.update_authority != UpdateAuthority::Collection(recipe.collection) {
+    // Check that the asset belongs to the collection or creator this recipe is configured for.
+    if asset_data.update_authority != recipe.asset_update_authority() {
         return Err(MplHybridError::InvalidCollection.into());
     }
 
+    // Only allowlisted assets can be released into the escrow.
+    if recipe.asset_root != [0; 32]
+        && !verify_merkle_proof(&recipe.asset_root, &asset.key(), &ix.asset_proof)
+    {
+        return Err(MplHybridError::AssetNotAllowlisted.into());
+    }
+
     if authority_info.key == &recipe.authority {
         assert_signer(authority)?;
     }
 
+    let mut new_uri = None;
+
     // If the path has burn on release, we burn the Asset
     if Path::BurnOnRelease.check(recipe.path) {
         //create burn instruction
         let burn_nft_ix = BurnV1Cpi {
             __program: &mpl_core.to_account_info(),
             asset: &asset.to_account_info(),
-            collection: Some(collection_info),
+            collection: core_collection,
             payer: &owner.to_account_info(),
             authority: Some(owner_info),
             system_program: Some(system_info),
@@ -218,11 +465,13 @@ pub fn handler_release_v2(ctx: Context<ReleaseV2Ctx>) -> Result<()> {
             uri.push_str("captured");
             uri.push_str(json_extension);
 
+            new_uri = Some(uri.clone());
+
             //create update instruction
             let update_ix = UpdateV1Cpi {
                 __program: &mpl_core.to_account_info(),
                 asset: &asset.to_account_info(),
-                collection: Some(collection_info),
+                collection: core_collection,
                 payer: &owner.to_account_info(),
                 authority: Some(authority_info),
                 system_program: &system_program.to_account_info(),
@@ -253,7 +502,7 @@ pub fn handler_release_v2(ctx: Context<ReleaseV2Ctx>) -> Result<()> {
         let transfer_nft_ix = TransferV1Cpi {
             __program: &mpl_core.to_account_info(),
             asset: &asset.to_account_info(),
-            collection: Some(collection_info),
+            collection: core_collection,
             payer: &owner.to_account_info(),
             authority: Some(owner_info),
             new_owner: &escrow.to_account_info(),
@@ -268,29 +517,94 @@ pub fn handler_release_v2(ctx: Context<ReleaseV2Ctx>) -> Result<()> {
         transfer_nft_ix.invoke()?;
     }
 
-    //create transfer token instruction
-    let cpi_program = token_program.to_account_info();
+    // The basket entries paid out by this release, empty for other currencies.
+    let mut basket_entries = Vec::new();
 
-    let signer_seeds = &[b"escrow", recipe.authority.as_ref(), &[escrow.bump]];
+    if let Some((_, user_token_account, escrow_token_account, fee_token_account)) = token_accounts {
+        //create transfer token instruction
+        let cpi_program = token_program.to_account_info();
 
-    let signer = &[&signer_seeds[..]];
+        let signer_seeds = &[b"escrow", recipe.authority.as_ref(), &[escrow.bump]];
 
-    let cpi_accounts_transfer = Transfer {
-        from: escrow_token_account.to_account_info(),
-        to: user_token_account.to_account_info(),
-        authority: escrow.to_account_info(),
-    };
+        let signer = &[&signer_seeds[..]];
+
+        let cpi_accounts_transfer = Transfer {
+            from: escrow_token_account.to_account_info(),
+            to: user_token_account.to_account_info(),
+            authority: escrow.to_account_info(),
+        };
+
+        let transfer_cpi_ctx =
+            CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts_transfer, signer);
+
+        token::transfer(transfer_cpi_ctx, amount)?;
+
+        //create transfer fee token instruction
+        let cpi_accounts_fee_transfer = Transfer {
+            from: user_token_account.to_account_info(),
+            to: fee_token_account.to_account_info(),
+            authority: owner.to_account_info(),
+        };
 
-    let transfer_cpi_ctx =
-        CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts_transfer, signer);
+        let transfer_fees_cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts_fee_transfer);
 
-    token::transfer(transfer_cpi_ctx, recipe.amount)?;
+        token::transfer(transfer_fees_cpi_ctx, fee_amount)?;
+    } else if recipe.currency == Currency::NativeSol {
+        let vault = ctx
+            .accounts
+            .vault
+            .as_ref()
+            .ok_or(MplHybridError::MissingVaultAccount)?;
+
+        // The vault holds data, so the system program can't move its lamports. As the owning
+        // program we debit it directly while keeping it rent exempt.
+        withdraw_lamports(&vault.to_account_info(), owner_info, amount)?;
+
+        //transfer the lamport fee to the project
+        let cpi_accounts_fee_transfer = anchor_lang::system_program::Transfer {
+            from: owner.to_account_info(),
+            to: fee_project_account.to_account_info(),
+        };
+
+        let transfer_fees_cpi_ctx = CpiContext::new(system_info.clone(), cpi_accounts_fee_transfer);
+
+        anchor_lang::system_program::transfer(transfer_fees_cpi_ctx, fee_amount)?;
+    } else {
+        // Basket recipes pay back each escrowed entry of the basket, token fees don't apply to them.
+        let basket = ctx
+            .accounts
+            .basket
+            .as_ref()
+            .ok_or(MplHybridError::MissingBasketAccount)?;
+
+        // Basket accounts come first in the remaining accounts, anything after them is ignored.
+        let (basket_accounts, _) = basket.split_accounts(ctx.remaining_accounts);
+
+        release_basket(
+            basket,
+            basket_accounts,
+            owner_info,
+            &escrow.to_account_info(),
+            &[&[b"escrow", recipe.authority.as_ref(), &[escrow.bump]]],
+            &token_program.to_account_info(),
+            system_info,
+        )?;
+
+        // Burned entries are only charged on capture.
+        basket_entries = basket
+            .entries
+            .iter()
+            .filter(|entry| !entry.burn)
+            .cloned()
+            .collect();
+    }
 
     //create protocol transfer fee sol instruction
+    let protocol_fee = get_protocol_fee()?;
     let sol_fee_ix = anchor_lang::solana_program::system_instruction::transfer(
         &owner.key(),
         &fee_sol_account.key(),
-        get_protocol_fee()?,
+        protocol_fee,
     );
 
     //invoke the protocol transfer fee sol instruction
@@ -299,22 +613,11 @@ pub fn handler_release_v2(ctx: Context<ReleaseV2Ctx>) -> Result<()> {
         &[owner.to_account_info(), fee_sol_account.to_account_info()],
     )?;
 
-    //create transfer fee token instruction
-    let cpi_accounts_fee_transfer = Transfer {
-        from: user_token_account.to_account_info(),
-        to: fee_token_account.to_account_info(),
-        authority: owner.to_account_info(),
-    };
-
-    let transfer_fees_cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_accounts_fee_transfer);
-
-    token::transfer(transfer_fees_cpi_ctx, recipe.fee_amount_release)?;
-
     //create project transfer fee sol instruction for project
     let sol_fee_project_ix = anchor_lang::solana_program::system_instruction::transfer(
         &owner.key(),
         &fee_project_account.key(),
-        recipe.sol_fee_amount_release,
+        sol_fee_amount,
     );
 
     //invoke project the transfer fee sol instruction for project
@@ -329,5 +632,79 @@ pub fn handler_release_v2(ctx: Context<ReleaseV2Ctx>) -> Result<()> {
     //increment the swap count
     recipe.count += 1;
 
+    //burned assets never reach the escrow
+    if !Path::BurnOnRelease.check(recipe.path) {
+        recipe.inventory = recipe
+            .inventory
+            .checked_add(1)
+            .ok_or(MplHybridError::NumericalOverflow)?;
+    }
+    recipe.net_swaps = recipe
+        .net_swaps
+        .checked_sub(1)
+        .ok_or(MplHybridError::NumericalOverflow)?;
+
+    //refuse the release once the window's outflow is used up
+    if recipe.circuit_breaker.is_enabled() {
+        let circuit_breaker = recipe.circuit_breaker;
+        recipe
+            .outflow_window
+            .roll(&circuit_breaker, Clock::get()?.slot);
+        // Alternative payments and baskets only count as a release, they aren't in the currency.
+        let outflow = if alternative_payment.is_none() && recipe.currency != Currency::Basket {
+            amount
+        } else {
+            0
+        };
+        if !recipe
+            .outflow_window
+            .record_release(&circuit_breaker, outflow)
+        {
+            return Err(MplHybridError::CircuitBreakerTripped.into());
+        }
+    }
+
+    // Basket entries are carried on their own, the mint and amount only cover the recipe currency.
+    let (mint, amount) = match token_accounts {
+        Some((token, ..)) => (token.key(), amount),
+        None if recipe.currency == Currency::NativeSol => (recipe.token, amount),
+        None => (Pubkey::default(), 0),
+    };
+
+    if alternative_payment.is_some() {
+        recipe.stats.record_release(0, 0, sol_fee_amount);
+    } else {
+        recipe
+            .stats
+            .record_release(amount, fee_amount, sol_fee_amount);
+    }
+
+    // Users opt into receipts by passing theirs.
+    if let Some(swap_receipt) = &ctx.accounts.swap_receipt {
+        record_swap_receipt(
+            swap_receipt,
+            &recipe.key(),
+            SwapDirection::Release,
+            recipe.loyalty.points_per_release,
+            owner,
+            system_program,
+        )?;
+    }
+
+    emit_cpi!(ReleaseEvent {
+        recipe: recipe.key(),
+        escrow: escrow.key(),
+        asset: asset.key(),
+        user: owner.key(),
+        mint,
+        amount,
+        asset_burned: Path::BurnOnRelease.check(recipe.path),
+        fee_amount,
+        sol_fee_amount,
+        protocol_fee,
+        uri: new_uri,
+        basket: basket_entries,
+    });
+
     Ok(())
 }
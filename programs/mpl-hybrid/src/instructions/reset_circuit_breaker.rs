@@ -0,0 +1,57 @@
+use crate::events::CircuitBreakerResetEvent;
+use crate::state::*;
+use crate::utils::assert_recipe_admin;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ResetCircuitBreakerV1Ctx<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "recipe".as_bytes(), 
+            collection.key().as_ref()
+            ],
+        bump=recipe.bump,
+    )]
+    recipe: Account<'info, RecipeV1>,
+
+    authority: Signer<'info>,
+
+    /// CHECK: We check against recipe
+    #[account(
+        address = recipe.collection
+    )]
+    collection: AccountInfo<'info>,
+
+    #[account(
+        seeds = [
+            "operators".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=operators.bump,
+    )]
+    operators: Option<Account<'info, OperatorsV1>>,
+}
+
+pub fn handler_reset_circuit_breaker_v1(ctx: Context<ResetCircuitBreakerV1Ctx>) -> Result<()> {
+    let recipe = &mut ctx.accounts.recipe;
+    let authority = &ctx.accounts.authority;
+
+    assert_recipe_admin(
+        recipe,
+        &authority.key(),
+        ctx.accounts.operators.as_deref(),
+        &[OperatorRole::Pause],
+    )?;
+
+    // Start a fresh window so releases are allowed again right away.
+    recipe.outflow_window.reset(Clock::get()?.slot);
+
+    emit_cpi!(CircuitBreakerResetEvent {
+        recipe: recipe.key(),
+        window_start: recipe.outflow_window.start,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use crate::error::MplHybridError;
+use crate::events::PauseEvent;
+use crate::state::*;
+use crate::utils::assert_recipe_admin;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PauseScope {
+    Recipe,
+    Protocol,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetPauseV1Ix {
+    scope: PauseScope,
+    paused: bool,
+    reason: u16,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPauseV1Ctx<'info> {
+    authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            "recipe".as_bytes(), 
+            recipe.collection.as_ref()
+            ],
+        bump=recipe.bump,
+    )]
+    recipe: Option<Account<'info, RecipeV1>>,
+
+    /// CHECK: We check against recipe
+    collection: Option<AccountInfo<'info>>,
+
+    #[account(
+        mut,
+        seeds = ["protocol".as_bytes()],
+        bump=protocol_config.bump,
+    )]
+    protocol_config: Option<Account<'info, ProtocolConfigV1>>,
+
+    /// Checked against the recipe in the handler.
+    operators: Option<Account<'info, OperatorsV1>>,
+}
+
+pub fn handler_set_pause_v1(ctx: Context<SetPauseV1Ctx>, ix: SetPauseV1Ix) -> Result<()> {
+    let authority = &ctx.accounts.authority;
+    let reason = if ix.paused { ix.reason } else { 0 };
+
+    let recipe = match ix.scope {
+        PauseScope::Recipe => {
+            let recipe = ctx
+                .accounts
+                .recipe
+                .as_mut()
+                .ok_or(MplHybridError::MissingPauseAccount)?;
+            let collection = ctx
+                .accounts
+                .collection
+                .as_ref()
+                .ok_or(MplHybridError::MissingPauseAccount)?;
+
+            if collection.key() != recipe.collection {
+                return Err(MplHybridError::InvalidCollectionAccount.into());
+            }
+
+            // Operators of other recipes are ignored.
+            let operators = ctx
+                .accounts
+                .operators
+                .as_deref()
+                .filter(|operators| operators.recipe == recipe.key());
+            assert_recipe_admin(recipe, &authority.key(), operators, &[OperatorRole::Pause])?;
+
+            recipe.paused = ix.paused;
+            recipe.pause_reason = reason;
+            Some(recipe.key())
+        }
+        PauseScope::Protocol => {
+            let protocol_config = ctx
+                .accounts
+                .protocol_config
+                .as_mut()
+                .ok_or(MplHybridError::MissingPauseAccount)?;
+
+            if protocol_config.admin != authority.key() {
+                return Err(MplHybridError::InvalidProtocolAdmin.into());
+            }
+
+            protocol_config.paused = ix.paused;
+            protocol_config.pause_reason = reason;
+            None
+        }
+    };
+
+    emit_cpi!(PauseEvent {
+        recipe,
+        paused: ix.paused,
+        reason,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use crate::error::MplHybridError;
+use crate::events::{ConfigAction, ProtocolConfigEvent};
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetProtocolAdminV1Ctx<'info> {
+    #[account(
+        mut,
+        seeds = ["protocol".as_bytes()],
+        bump=protocol_config.bump,
+        constraint = protocol_config.admin == admin.key() @ MplHybridError::InvalidProtocolAdmin,
+    )]
+    protocol_config: Account<'info, ProtocolConfigV1>,
+
+    admin: Signer<'info>,
+
+    /// The new admin co-signs so the settings can't be handed to a key nobody controls.
+    new_admin: Signer<'info>,
+}
+
+pub fn handler_set_protocol_admin_v1(ctx: Context<SetProtocolAdminV1Ctx>) -> Result<()> {
+    let protocol_config = &mut ctx.accounts.protocol_config;
+
+    protocol_config.admin = ctx.accounts.new_admin.key();
+
+    emit_cpi!(ProtocolConfigEvent {
+        action: ConfigAction::Update,
+        protocol_config: protocol_config.key(),
+        data: (**protocol_config).clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use crate::constants::*;
+use crate::error::MplHybridError;
+use crate::events::EscrowAssetTransferEvent;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use mpl_core::accounts::BaseAssetV1;
+use mpl_core::instructions::{TransferV1Cpi, TransferV1InstructionArgs};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TransferEscrowAssetV1Ctx<'info> {
+    // Only the authority this recipe was handed over from can move its escrow.
+    #[account(mut,
+        address = recipe.previous_authority @MplHybridError::InvalidAuthority
+    )]
+    authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            "escrow".as_bytes(), 
+            authority.key().as_ref()
+            ],
+        bump=escrow_old.bump,
+    )]
+    escrow_old: Account<'info, EscrowV2>,
+
+    // Assets can only move to the escrow of the authority the recipe was handed to.
+    #[account(
+        seeds = [
+            "escrow".as_bytes(), 
+            recipe.authority.as_ref()
+            ],
+        bump=escrow_new.bump,
+    )]
+    escrow_new: Account<'info, EscrowV2>,
+
+    #[account(
+        seeds = [
+            "recipe".as_bytes(), 
+            collection.key().as_ref()
+            ],
+        bump=recipe.bump,
+    )]
+    recipe: Account<'info, RecipeV1>,
+
+    /// CHECK: We check the asset bellow
+    #[account(mut)]
+    asset: UncheckedAccount<'info>,
+
+    /// CHECK: We check against recipe
+    #[account(mut,
+        address = recipe.collection
+    )]
+    collection: AccountInfo<'info>,
+
+    /// CHECK: We check against constant
+    #[account(
+        address = MPL_CORE @ MplHybridError::InvalidMplCore
+    )]
+    mpl_core: AccountInfo<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn handler_transfer_escrow_asset_v1(ctx: Context<TransferEscrowAssetV1Ctx>) -> Result<()> {
+    let authority = &ctx.accounts.authority;
+    let escrow_old = &ctx.accounts.escrow_old;
+    let escrow_new = &ctx.accounts.escrow_new;
+    let recipe = &ctx.accounts.recipe;
+    let asset = &ctx.accounts.asset;
+    let collection = &ctx.accounts.collection;
+
+    if escrow_old.key() == escrow_new.key() {
+        return Err(MplHybridError::InvalidAuthority.into());
+    }
+
+    // We only fetch the Base assets because we only need to check the update authority here.
+    let asset_data = BaseAssetV1::from_bytes(&asset.to_account_info().data.borrow())?;
+    // Check that the asset belongs to the collection or creator this recipe is configured for.
+    if asset_data.update_authority != recipe.asset_update_authority() {
+        return Err(MplHybridError::InvalidCollection.into());
+    }
+
+    let collection_info = &collection.to_account_info();
+    // Standalone assets aren't part of a collection.
+    let core_collection = match recipe.kind {
+        RecipeKind::Collection => Some(collection_info),
+        RecipeKind::Creator => None,
+    };
+
+    //create transfer instruction
+    let transfer_nft_ix = TransferV1Cpi {
+        __program: &ctx.accounts.mpl_core.to_account_info(),
+        asset: &asset.to_account_info(),
+        collection: core_collection,
+        payer: &authority.to_account_info(),
+        authority: Some(&escrow_old.to_account_info()),
+        new_owner: &escrow_new.to_account_info(),
+        system_program: Some(&ctx.accounts.system_program.to_account_info()),
+        log_wrapper: None,
+        __args: TransferV1InstructionArgs {
+            compression_proof: None,
+        },
+    };
+
+    //invoke the transfer instruction with seeds
+    transfer_nft_ix.invoke_signed(&[&[b"escrow", authority.key.as_ref(), &[escrow_old.bump]]])?;
+
+    emit_cpi!(EscrowAssetTransferEvent {
+        escrow_old: escrow_old.key(),
+        escrow_new: escrow_new.key(),
+        asset: asset.key(),
+    });
+
+    Ok(())
+}
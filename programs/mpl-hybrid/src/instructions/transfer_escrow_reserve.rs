@@ -0,0 +1,133 @@
+use crate::error::MplHybridError;
+use crate::events::EscrowReserveTransferEvent;
+use crate::state::*;
+use crate::utils::{create_associated_token_account, validate_token_account};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token;
+use anchor_spl::token::Mint;
+use anchor_spl::token::{Token, Transfer};
+use solana_program::system_program;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TransferEscrowReserveV1Ix {
+    amount: u64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TransferEscrowReserveV1Ctx<'info> {
+    // Only the authority this recipe was handed over from can move its escrow.
+    #[account(mut,
+        address = recipe.previous_authority @MplHybridError::InvalidAuthority
+    )]
+    authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            "escrow".as_bytes(), 
+            authority.key().as_ref()
+            ],
+        bump=escrow_old.bump,
+    )]
+    escrow_old: Account<'info, EscrowV2>,
+
+    // The reserve can only move to the escrow of the authority the recipe was handed to.
+    #[account(
+        mut,
+        seeds = [
+            "escrow".as_bytes(), 
+            recipe.authority.as_ref()
+            ],
+        bump=escrow_new.bump,
+    )]
+    escrow_new: Account<'info, EscrowV2>,
+
+    #[account(
+        seeds = [
+            "recipe".as_bytes(), 
+            recipe.collection.as_ref()
+            ],
+        bump=recipe.bump,
+    )]
+    recipe: Account<'info, RecipeV1>,
+
+    /// CHECK: We check and initialize the token account below.
+    #[account(mut)]
+    escrow_new_token_account: AccountInfo<'info>,
+
+    /// CHECK: We check the token account below.
+    #[account(mut)]
+    escrow_old_token_account: AccountInfo<'info>,
+
+    // Native SOL reserves sit in the recipe vault and follow the recipe on their own.
+    token: Account<'info, Mint>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handler_transfer_escrow_reserve_v1(
+    ctx: Context<TransferEscrowReserveV1Ctx>,
+    ix: TransferEscrowReserveV1Ix,
+) -> Result<()> {
+    let authority = &ctx.accounts.authority;
+    let escrow_old = &ctx.accounts.escrow_old;
+    let escrow_new = &ctx.accounts.escrow_new;
+    let system_program = &ctx.accounts.system_program;
+    let token_program = &ctx.accounts.token_program;
+
+    if escrow_old.key() == escrow_new.key() {
+        return Err(MplHybridError::InvalidAuthority.into());
+    }
+
+    let token = &ctx.accounts.token;
+    let escrow_old_token_account = &ctx.accounts.escrow_old_token_account;
+    let escrow_new_token_account = &ctx.accounts.escrow_new_token_account;
+
+    // Create idempotent
+    if escrow_new_token_account.owner == &system_program::ID {
+        create_associated_token_account(
+            authority,
+            &escrow_new.to_account_info(),
+            &token.to_account_info(),
+            escrow_new_token_account,
+            token_program,
+            system_program,
+        )?;
+    } else {
+        validate_token_account(escrow_new_token_account, &escrow_new.key(), &token.key())?;
+    }
+
+    // The escrow token account should already exist.
+    validate_token_account(escrow_old_token_account, &escrow_old.key(), &token.key())?;
+
+    let signer_seeds = &[b"escrow", authority.key.as_ref(), &[escrow_old.bump]];
+
+    let signer = &[&signer_seeds[..]];
+
+    let cpi_accounts_transfer = Transfer {
+        from: escrow_old_token_account.to_account_info(),
+        to: escrow_new_token_account.to_account_info(),
+        authority: escrow_old.to_account_info(),
+    };
+
+    let transfer_cpi_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        cpi_accounts_transfer,
+        signer,
+    );
+
+    token::transfer(transfer_cpi_ctx, ix.amount)?;
+
+    emit_cpi!(EscrowReserveTransferEvent {
+        escrow_old: escrow_old.key(),
+        escrow_new: escrow_new.key(),
+        mint: token.key(),
+        amount: ix.amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use crate::events::{BasketEvent, ConfigAction};
+use crate::state::*;
+use crate::utils::{assert_fields_unlocked, assert_not_timelocked, assert_recipe_admin};
+use anchor_lang::prelude::*;
+use mpl_utils::resize_or_reallocate_account_raw;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateBasketV1Ix {
+    entries: Vec<BasketEntry>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateBasketV1Ctx<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "basket".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=basket.bump,
+    )]
+    basket: Account<'info, BasketV1>,
+
+    #[account(
+        seeds = [
+            "recipe".as_bytes(), 
+            collection.key().as_ref()
+            ],
+        bump=recipe.bump,
+    )]
+    recipe: Account<'info, RecipeV1>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// CHECK: We check against recipe
+    #[account(
+        address = recipe.collection
+    )]
+    collection: AccountInfo<'info>,
+
+    system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [
+            "operators".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=operators.bump,
+    )]
+    operators: Option<Account<'info, OperatorsV1>>,
+}
+
+pub fn handler_update_basket_v1(
+    ctx: Context<UpdateBasketV1Ctx>,
+    ix: UpdateBasketV1Ix,
+) -> Result<()> {
+    let basket = &mut ctx.accounts.basket;
+    let authority = &mut ctx.accounts.authority;
+
+    assert_recipe_admin(
+        &ctx.accounts.recipe,
+        &authority.key(),
+        ctx.accounts.operators.as_deref(),
+        &[OperatorRole::UpdatePricing],
+    )?;
+    assert_not_timelocked(&ctx.accounts.recipe)?;
+
+    basket.entries = ix.entries;
+    basket.validate()?;
+
+    let new_size = BasketV1::BASE_BASKET_SIZE + basket.entries.len() * BasketEntry::SIZE;
+    resize_or_reallocate_account_raw(
+        &basket.to_account_info(),
+        authority,
+        &ctx.accounts.system_program,
+        new_size,
+    )?;
+    assert_fields_unlocked(&ctx.accounts.recipe, &[RecipeField::Pricing])?;
+
+    emit_cpi!(BasketEvent {
+        action: ConfigAction::Update,
+        basket: basket.key(),
+        data: (**basket).clone(),
+    });
+
+    Ok(())
+}
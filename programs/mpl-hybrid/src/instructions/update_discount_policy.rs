@@ -0,0 +1,90 @@
+use crate::events::{ConfigAction, DiscountPolicyEvent};
+use crate::state::*;
+use crate::utils::{assert_fields_unlocked, assert_not_timelocked, assert_recipe_admin};
+use anchor_lang::prelude::*;
+use mpl_utils::resize_or_reallocate_account_raw;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateDiscountPolicyV1Ix {
+    entries: Vec<DiscountEntry>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateDiscountPolicyV1Ctx<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "discount".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=discount_policy.bump,
+    )]
+    discount_policy: Account<'info, DiscountPolicyV1>,
+
+    #[account(
+        seeds = [
+            "recipe".as_bytes(), 
+            collection.key().as_ref()
+            ],
+        bump=recipe.bump,
+    )]
+    recipe: Account<'info, RecipeV1>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// CHECK: We check against recipe
+    #[account(
+        address = recipe.collection
+    )]
+    collection: AccountInfo<'info>,
+
+    system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [
+            "operators".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=operators.bump,
+    )]
+    operators: Option<Account<'info, OperatorsV1>>,
+}
+
+pub fn handler_update_discount_policy_v1(
+    ctx: Context<UpdateDiscountPolicyV1Ctx>,
+    ix: UpdateDiscountPolicyV1Ix,
+) -> Result<()> {
+    let discount_policy = &mut ctx.accounts.discount_policy;
+    let authority = &mut ctx.accounts.authority;
+
+    assert_recipe_admin(
+        &ctx.accounts.recipe,
+        &authority.key(),
+        ctx.accounts.operators.as_deref(),
+        &[OperatorRole::ManageFees],
+    )?;
+    assert_not_timelocked(&ctx.accounts.recipe)?;
+
+    discount_policy.entries = ix.entries;
+    discount_policy.validate()?;
+
+    let new_size = DiscountPolicyV1::BASE_DISCOUNT_POLICY_SIZE
+        + discount_policy.entries.len() * DiscountEntry::SIZE;
+    resize_or_reallocate_account_raw(
+        &discount_policy.to_account_info(),
+        authority,
+        &ctx.accounts.system_program,
+        new_size,
+    )?;
+    assert_fields_unlocked(&ctx.accounts.recipe, &[RecipeField::Fees])?;
+
+    emit_cpi!(DiscountPolicyEvent {
+        action: ConfigAction::Update,
+        discount_policy: discount_policy.key(),
+        data: (**discount_policy).clone(),
+    });
+
+    Ok(())
+}
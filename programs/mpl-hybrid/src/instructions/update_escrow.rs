@@ -1,8 +1,9 @@
 use crate::error::MplHybridError;
+use crate::events::{ConfigAction, EscrowEvent};
 use crate::state::*;
+use crate::utils::assert_collection_authority;
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
-use mpl_core::accounts::BaseCollectionV1;
 use mpl_utils::resize_or_reallocate_account_raw;
 
 //need to add options
@@ -20,6 +21,7 @@ pub struct UpdateEscrowV1Ix {
 
 //Need to define accounts better
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateEscrowV1Ctx<'info> {
     #[account(
@@ -61,14 +63,8 @@ pub fn handler_update_escrow_v1(
     let token = &mut ctx.accounts.token;
     let fee_location = &mut ctx.accounts.fee_location;
 
-    // We only fetch the Base collection to check authority.
-    let collection_data =
-        BaseCollectionV1::from_bytes(&collection.to_account_info().data.borrow())?;
-
-    // Check that the collection authority is the same as the escrow authority.
-    if collection_data.update_authority != authority.key() {
-        return Err(MplHybridError::InvalidCollectionAuthority.into());
-    }
+    // The signer has to be the collection update authority or one of its update delegates.
+    assert_collection_authority(collection, &authority.key())?;
 
     // We can't allow the max to be less than the min.
     if let (Some(max), Some(min)) = (ix.max, ix.min) {
@@ -140,5 +136,11 @@ pub fn handler_update_escrow_v1(
         new_size as usize,
     )?;
 
+    emit_cpi!(EscrowEvent {
+        action: ConfigAction::Update,
+        escrow: escrow.key(),
+        data: (**escrow).clone(),
+    });
+
     Ok(())
 }
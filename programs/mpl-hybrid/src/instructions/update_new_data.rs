@@ -1,9 +1,10 @@
 use crate::error::MplHybridError;
+use crate::events::{ConfigAction, NftDataEvent};
 use crate::state::*;
+use crate::utils::{assert_asset_authority, collection_kind};
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
-use mpl_core::accounts::{BaseAssetV1, BaseCollectionV1};
-use mpl_core::types::UpdateAuthority;
+use mpl_core::accounts::BaseAssetV1;
 use mpl_utils::resize_or_reallocate_account_raw;
 
 //need to add options
@@ -19,6 +20,7 @@ pub struct UpdateNftDataV1Ix {
     path: Option<u16>,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateNftDataV1Ctx<'info> {
     #[account(
@@ -59,21 +61,18 @@ pub fn handler_update_new_data_v1(
     let token = &mut ctx.accounts.token;
     let fee_location = &mut ctx.accounts.fee_location;
 
-    // We only fetch the Base assets because we only need to check the collection here.
+    // Creators of standalone assets are passed in place of the collection.
+    let kind = collection_kind(collection)?;
+
+    // We only fetch the Base assets because we only need to check the update authority here.
     let asset_data = BaseAssetV1::from_bytes(&asset.to_account_info().data.borrow())?;
-    // Check that the collection that the asset is a part of.
-    if asset_data.update_authority != UpdateAuthority::Collection(collection.key()) {
+    // Check that the asset is part of the collection, or updated by the creator passed instead.
+    if asset_data.update_authority != kind.asset_update_authority(collection.key()) {
         return Err(MplHybridError::InvalidCollection.into());
     }
 
-    // We only fetch the Base collection to check authority.
-    let collection_data =
-        BaseCollectionV1::from_bytes(&collection.to_account_info().data.borrow())?;
-
-    // Check that the collection authority is the same as the escrow authority.
-    if collection_data.update_authority != authority.key() {
-        return Err(MplHybridError::InvalidCollectionAuthority.into());
-    }
+    // The signer has to administer the collection, or be the creator of standalone assets.
+    assert_asset_authority(kind, collection, &authority.key())?;
 
     let mut size_diff: isize = 0;
     nft_data.authority = authority.key();
@@ -124,5 +123,11 @@ pub fn handler_update_new_data_v1(
         new_size,
     )?;
 
+    emit_cpi!(NftDataEvent {
+        action: ConfigAction::Update,
+        nft_data: nft_data.key(),
+        data: (**nft_data).clone(),
+    });
+
     Ok(())
 }
@@ -0,0 +1,76 @@
+use crate::events::{ConfigAction, OperatorsEvent};
+use crate::state::*;
+use crate::utils::{assert_recipe_authority, assert_unique_operators};
+use anchor_lang::prelude::*;
+use mpl_utils::resize_or_reallocate_account_raw;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateOperatorsV1Ix {
+    entries: Vec<OperatorEntry>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateOperatorsV1Ctx<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "operators".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=operators.bump,
+    )]
+    operators: Account<'info, OperatorsV1>,
+
+    #[account(
+        seeds = [
+            "recipe".as_bytes(), 
+            collection.key().as_ref()
+            ],
+        bump=recipe.bump,
+    )]
+    recipe: Account<'info, RecipeV1>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// CHECK: We check against recipe
+    #[account(
+        address = recipe.collection
+    )]
+    collection: AccountInfo<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn handler_update_operators_v1(
+    ctx: Context<UpdateOperatorsV1Ctx>,
+    ix: UpdateOperatorsV1Ix,
+) -> Result<()> {
+    let operators = &mut ctx.accounts.operators;
+    let authority = &mut ctx.accounts.authority;
+
+    // Operators can't grant roles, only the recipe authority manages them.
+    assert_recipe_authority(&ctx.accounts.recipe, &authority.key())?;
+
+    assert_unique_operators(&ix.entries)?;
+
+    operators.authority = authority.key();
+    operators.entries = ix.entries;
+
+    let new_size = OperatorsV1::BASE_OPERATORS_SIZE + operators.entries.len() * OperatorEntry::SIZE;
+    resize_or_reallocate_account_raw(
+        &operators.to_account_info(),
+        authority,
+        &ctx.accounts.system_program,
+        new_size,
+    )?;
+
+    emit_cpi!(OperatorsEvent {
+        action: ConfigAction::Update,
+        operators: operators.key(),
+        data: (**operators).clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use crate::error::MplHybridError;
+use crate::events::{ConfigAction, PaymentTableEvent};
+use crate::state::*;
+use crate::utils::{assert_fields_unlocked, assert_not_timelocked, assert_recipe_admin};
+use anchor_lang::prelude::*;
+use mpl_utils::resize_or_reallocate_account_raw;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdatePaymentTableV1Ix {
+    entries: Vec<PaymentEntry>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdatePaymentTableV1Ctx<'info> {
+    #[account(
+        mut,
+        seeds = [
+            "payments".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=payment_table.bump,
+    )]
+    payment_table: Account<'info, PaymentTableV1>,
+
+    #[account(
+        seeds = [
+            "recipe".as_bytes(), 
+            collection.key().as_ref()
+            ],
+        bump=recipe.bump,
+    )]
+    recipe: Account<'info, RecipeV1>,
+
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// CHECK: We check against recipe
+    #[account(
+        address = recipe.collection
+    )]
+    collection: AccountInfo<'info>,
+
+    system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [
+            "operators".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=operators.bump,
+    )]
+    operators: Option<Account<'info, OperatorsV1>>,
+}
+
+pub fn handler_update_payment_table_v1(
+    ctx: Context<UpdatePaymentTableV1Ctx>,
+    ix: UpdatePaymentTableV1Ix,
+) -> Result<()> {
+    let payment_table = &mut ctx.accounts.payment_table;
+    let recipe = &ctx.accounts.recipe;
+    let authority = &mut ctx.accounts.authority;
+
+    let previous = std::mem::replace(&mut payment_table.entries, ix.entries);
+    let changes_fees = payment_table.changes_fees(&previous);
+
+    assert_recipe_admin(
+        recipe,
+        &authority.key(),
+        ctx.accounts.operators.as_deref(),
+        &payment_table.roles(&previous),
+    )?;
+    assert_not_timelocked(recipe)?;
+
+    payment_table.validate(&recipe.token)?;
+    if payment_table.fees_exceed(&recipe.fee_caps) {
+        return Err(MplHybridError::FeeCapExceeded.into());
+    }
+
+    let new_size =
+        PaymentTableV1::BASE_PAYMENT_TABLE_SIZE + payment_table.entries.len() * PaymentEntry::SIZE;
+    resize_or_reallocate_account_raw(
+        &payment_table.to_account_info(),
+        authority,
+        &ctx.accounts.system_program,
+        new_size,
+    )?;
+    assert_fields_unlocked(recipe, &[RecipeField::Token, RecipeField::Pricing])?;
+    if changes_fees {
+        assert_fields_unlocked(recipe, &[RecipeField::Fees])?;
+    }
+
+    emit_cpi!(PaymentTableEvent {
+        action: ConfigAction::Update,
+        payment_table: payment_table.key(),
+        data: (**payment_table).clone(),
+    });
+
+    Ok(())
+}
@@ -1,27 +1,187 @@
 use crate::error::MplHybridError;
+use crate::events::{ConfigAction, RecipeEvent};
 use crate::state::*;
+use crate::utils::{
+    assert_recipe_admin, assert_recipe_authority, is_collection_recipe_authority,
+    record_recipe_change,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
-use mpl_core::accounts::BaseCollectionV1;
 use mpl_utils::resize_or_reallocate_account_raw;
 
 //need to add options
-#[derive(AnchorSerialize, AnchorDeserialize)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct UpdateRecipeV1Ix {
     name: Option<String>,
     uri: Option<String>,
     max: Option<u64>,
     min: Option<u64>,
-    amount: Option<u64>,
+    capture_amount: Option<u64>,
+    release_amount: Option<u64>,
     fee_amount_capture: Option<u64>,
     fee_amount_release: Option<u64>,
     sol_fee_amount_capture: Option<u64>,
     sol_fee_amount_release: Option<u64>,
     path: Option<u16>,
+    pricing: Option<PricingCurve>,
+    inventory: Option<u64>,
+    quote_signer: Option<Pubkey>,
+    capture_window: Option<SwapWindow>,
+    release_window: Option<SwapWindow>,
+    wallet_limits: Option<WalletLimits>,
+    circuit_breaker: Option<CircuitBreaker>,
+    asset_cooldown: Option<AssetCooldown>,
+    loyalty: Option<LoyaltyRate>,
+    timelock_slots: Option<u64>,
+    asset_root: Option<[u8; 32]>,
+    wallet_root: Option<[u8; 32]>,
+    gate: Option<RecipeGate>,
+}
+
+// Whether the token account passed repoints the recipe, passing its current token isn't a change.
+fn repoints_token(recipe: &RecipeV1, token: Option<Pubkey>) -> bool {
+    token.is_some_and(|token| token != recipe.token)
+}
+
+impl UpdateRecipeV1Ix {
+    // Repointing the token changes which escrow reserve withdrawals draw from, so operators can't.
+    pub fn needs_recipe_authority(&self, recipe: &RecipeV1, token: Option<Pubkey>) -> bool {
+        repoints_token(recipe, token)
+    }
+
+    // The operator roles covering every group of fields being changed.
+    pub fn roles(
+        &self,
+        recipe: &RecipeV1,
+        token: Option<Pubkey>,
+        fee_location: Pubkey,
+    ) -> Vec<OperatorRole> {
+        let mut roles = vec![];
+        if self.name.is_some()
+            || self.uri.is_some()
+            || self.max.is_some()
+            || self.min.is_some()
+            || self.path.is_some()
+        {
+            roles.push(OperatorRole::UpdateMetadata);
+        }
+        if repoints_token(recipe, token)
+            || self.capture_amount.is_some()
+            || self.release_amount.is_some()
+            || self.pricing.is_some()
+            || self.inventory.is_some()
+            || self.quote_signer.is_some()
+            || self.loyalty.is_some()
+        {
+            roles.push(OperatorRole::UpdatePricing);
+        }
+        if fee_location != recipe.fee_location
+            || self.fee_amount_capture.is_some()
+            || self.fee_amount_release.is_some()
+            || self.sol_fee_amount_capture.is_some()
+            || self.sol_fee_amount_release.is_some()
+        {
+            roles.push(OperatorRole::ManageFees);
+        }
+        if self.capture_window.is_some()
+            || self.release_window.is_some()
+            || self.wallet_limits.is_some()
+            || self.circuit_breaker.is_some()
+            || self.asset_cooldown.is_some()
+            || self.timelock_slots.is_some()
+            || self.asset_root.is_some()
+            || self.wallet_root.is_some()
+            || self.gate.is_some()
+        {
+            roles.push(OperatorRole::Pause);
+        }
+        roles
+    }
+
+    // Whether the change has to wait out the recipe timelock. The capture window, fee decreases
+    // and a longer timelock can't hurt holders so they stay immediate, anything that can hold up
+    // releases waits.
+    pub fn needs_timelock(
+        &self,
+        recipe: &RecipeV1,
+        token: Option<Pubkey>,
+        fee_location: Pubkey,
+    ) -> bool {
+        let raises = |new: Option<u64>, current: u64| new.is_some_and(|new| new > current);
+
+        repoints_token(recipe, token)
+            || fee_location != recipe.fee_location
+            || self.name.is_some()
+            || self.uri.is_some()
+            || self.max.is_some()
+            || self.min.is_some()
+            || self.path.is_some()
+            || self.capture_amount.is_some()
+            || self.release_amount.is_some()
+            || self.pricing.is_some()
+            || self.inventory.is_some()
+            || self.quote_signer.is_some()
+            || self.loyalty.is_some()
+            || self.asset_root.is_some()
+            || self.wallet_root.is_some()
+            || self.gate.is_some()
+            || self.release_window.is_some()
+            || self.wallet_limits.is_some()
+            || self.circuit_breaker.is_some()
+            || self.asset_cooldown.is_some()
+            || raises(self.fee_amount_capture, recipe.fee_amount_capture)
+            || raises(self.fee_amount_release, recipe.fee_amount_release)
+            || raises(self.sol_fee_amount_capture, recipe.sol_fee_amount_capture)
+            || raises(self.sol_fee_amount_release, recipe.sol_fee_amount_release)
+            || self
+                .timelock_slots
+                .is_some_and(|timelock_slots| timelock_slots < recipe.timelock_slots)
+    }
+
+    // Whether the change touches a field group locked by `lock_recipe_v1`.
+    pub fn touches_locked(
+        &self,
+        recipe: &RecipeV1,
+        token: Option<Pubkey>,
+        fee_location: Pubkey,
+    ) -> bool {
+        let locked = |field: RecipeField| field.check(recipe.locked);
+
+        (locked(RecipeField::Metadata)
+            && (self.name.is_some()
+                || self.uri.is_some()
+                || self.max.is_some()
+                || self.min.is_some()))
+            || (locked(RecipeField::Path) && self.path.is_some())
+            || (locked(RecipeField::Token) && repoints_token(recipe, token))
+            || (locked(RecipeField::Pricing)
+                && (self.capture_amount.is_some()
+                    || self.release_amount.is_some()
+                    || self.pricing.is_some()
+                    || self.inventory.is_some()
+                    || self.quote_signer.is_some()))
+            || (locked(RecipeField::Fees)
+                && (self.fee_amount_capture.is_some()
+                    || self.fee_amount_release.is_some()
+                    || self.sol_fee_amount_capture.is_some()
+                    || self.sol_fee_amount_release.is_some()))
+            || (locked(RecipeField::FeeLocation) && fee_location != recipe.fee_location)
+            || (locked(RecipeField::SwapLimits)
+                && (self.capture_window.is_some()
+                    || self.release_window.is_some()
+                    || self.wallet_limits.is_some()
+                    || self.circuit_breaker.is_some()
+                    || self.asset_cooldown.is_some()))
+            || (locked(RecipeField::Loyalty) && self.loyalty.is_some())
+            || (locked(RecipeField::Timelock) && self.timelock_slots.is_some())
+            || (locked(RecipeField::Allowlist)
+                && (self.asset_root.is_some() || self.wallet_root.is_some() || self.gate.is_some()))
+    }
 }
 
 //Need to define accounts better
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateRecipeV1Ctx<'info> {
     #[account(
@@ -44,11 +204,31 @@ pub struct UpdateRecipeV1Ctx<'info> {
     collection: AccountInfo<'info>,
 
     /// CHECK: This is a user defined account
-    token: Account<'info, Mint>,
+    token: Option<Account<'info, Mint>>,
 
     /// CHECK: This is a user defined account
     fee_location: UncheckedAccount<'info>,
     system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [
+            "operators".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=operators.bump,
+    )]
+    operators: Option<Account<'info, OperatorsV1>>,
+
+    /// CHECK: Checked by seeds, it is created on the first change.
+    #[account(
+        mut,
+        seeds = [
+            "history".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump,
+    )]
+    history: AccountInfo<'info>,
 }
 
 pub fn handler_update_recipe_v1(
@@ -58,18 +238,73 @@ pub fn handler_update_recipe_v1(
     //Need to add account checks for security
 
     let recipe = &mut ctx.accounts.recipe;
-    let collection = &mut ctx.accounts.collection;
     let authority = &mut ctx.accounts.authority;
-    let token = &mut ctx.accounts.token;
-    let fee_location = &mut ctx.accounts.fee_location;
+    let token = ctx.accounts.token.as_ref().map(|token| token.key());
+    let fee_location = ctx.accounts.fee_location.key();
+
+    if !is_collection_recipe_authority(recipe, &ctx.accounts.collection, &authority.key())? {
+        assert_recipe_admin(
+            recipe,
+            &authority.key(),
+            ctx.accounts.operators.as_deref(),
+            &ix.roles(recipe, token, fee_location),
+        )?;
+
+        if ix.needs_recipe_authority(recipe, token) {
+            assert_recipe_authority(recipe, &authority.key())?;
+        }
+    }
+
+    if recipe.timelock_slots > 0 && ix.needs_timelock(recipe, token, fee_location) {
+        return Err(MplHybridError::TimelockActive.into());
+    }
+
+    let old_recipe = (**recipe).clone();
+    let size_diff = apply_recipe_update(recipe, ix, token, fee_location)?;
+
+    let new_size = recipe
+        .to_account_info()
+        .data_len()
+        .checked_add(size_diff as usize)
+        .ok_or(MplHybridError::NumericalOverflow)?;
+    resize_or_reallocate_account_raw(
+        &recipe.to_account_info(),
+        authority,
+        &ctx.accounts.system_program,
+        new_size,
+    )?;
+
+    // Updates that leave the recipe as it was aren't recorded.
+    let change = RecipeChange::new(Clock::get()?.slot, authority.key(), &old_recipe, recipe);
+    if change.changed != 0 {
+        record_recipe_change(
+            &ctx.accounts.history,
+            &recipe.key(),
+            ctx.bumps.history,
+            change,
+            authority,
+            &ctx.accounts.system_program,
+        )?;
+    }
 
-    // We only fetch the Base collection to check authority.
-    let collection_data =
-        BaseCollectionV1::from_bytes(&collection.to_account_info().data.borrow())?;
+    emit_cpi!(RecipeEvent {
+        action: ConfigAction::Update,
+        recipe: recipe.key(),
+        data: (**recipe).clone(),
+    });
 
-    // Check that the collection authority is the same as the recipe authority.
-    if collection_data.update_authority != authority.key() {
-        return Err(MplHybridError::InvalidCollectionAuthority.into());
+    Ok(())
+}
+
+// Validates the change and applies it to the recipe, returning how much the recipe grows.
+pub fn apply_recipe_update(
+    recipe: &mut RecipeV1,
+    ix: UpdateRecipeV1Ix,
+    token: Option<Pubkey>,
+    fee_location: Pubkey,
+) -> Result<isize> {
+    if ix.touches_locked(recipe, token, fee_location) {
+        return Err(MplHybridError::RecipeFieldLocked.into());
     }
 
     // We can't allow the max to be less than the min.
@@ -87,10 +322,27 @@ pub fn handler_update_recipe_v1(
         }
     }
 
+    if let Some(pricing) = &ix.pricing {
+        if pricing.min_price > pricing.max_price {
+            return Err(MplHybridError::InvalidPricingCurve.into());
+        }
+    }
+
+    for window in [ix.capture_window, ix.release_window].iter().flatten() {
+        if !window.is_valid() {
+            return Err(MplHybridError::InvalidSwapWindow.into());
+        }
+    }
+
     let mut size_diff: isize = 0;
-    recipe.authority = authority.key();
-    recipe.token = token.key();
-    recipe.fee_location = fee_location.key();
+    if let Some(token) = token {
+        // Only token recipes have a mint to switch to.
+        if recipe.currency != Currency::Token {
+            return Err(MplHybridError::InvalidMintAccount.into());
+        }
+        recipe.token = token;
+    }
+    recipe.fee_location = fee_location;
     if let Some(name) = ix.name {
         // Reason: Use signed arithmetic so shorter/equal-length names are allowed.
         size_diff += (name.len() as isize)
@@ -111,8 +363,11 @@ pub fn handler_update_recipe_v1(
     if let Some(min) = ix.min {
         recipe.min = min;
     }
-    if let Some(amount) = ix.amount {
-        recipe.amount = amount;
+    if let Some(capture_amount) = ix.capture_amount {
+        recipe.capture_amount = capture_amount;
+    }
+    if let Some(release_amount) = ix.release_amount {
+        recipe.release_amount = release_amount;
     }
     if let Some(fee_amount_capture) = ix.fee_amount_capture {
         recipe.fee_amount_capture = fee_amount_capture;
@@ -134,20 +389,64 @@ pub fn handler_update_recipe_v1(
         {
             return Err(MplHybridError::PathCannotBeSet.into());
         }
+        if recipe.currency != Currency::Token && Path::BurnOnCapture.check(path) {
+            return Err(MplHybridError::InvalidPathForCurrency.into());
+        }
         recipe.path = path;
     }
+    if let Some(pricing) = ix.pricing {
+        recipe.pricing = pricing;
+    }
+    if let Some(inventory) = ix.inventory {
+        // Lets the authority account for NFTs already in the escrow before the counter existed,
+        // once and before any swap has moved the counter.
+        if recipe.inventory != 0 || recipe.stats.captures != 0 || recipe.stats.releases != 0 {
+            return Err(MplHybridError::InventoryCannotBeSet.into());
+        }
+        recipe.inventory = inventory;
+    }
+    if let Some(quote_signer) = ix.quote_signer {
+        // Setting the default key turns quotes back off.
+        recipe.quote_signer = quote_signer;
+    }
+    if let Some(capture_window) = ix.capture_window {
+        recipe.capture_window = capture_window;
+    }
+    if let Some(release_window) = ix.release_window {
+        recipe.release_window = release_window;
+    }
+    if let Some(wallet_limits) = ix.wallet_limits {
+        recipe.wallet_limits = wallet_limits;
+    }
+    if let Some(circuit_breaker) = ix.circuit_breaker {
+        recipe.circuit_breaker = circuit_breaker;
+        recipe.outflow_window.reset(Clock::get()?.slot);
+    }
+    if let Some(asset_cooldown) = ix.asset_cooldown {
+        recipe.asset_cooldown = asset_cooldown;
+    }
+    if let Some(loyalty) = ix.loyalty {
+        recipe.loyalty = loyalty;
+    }
+    if let Some(timelock_slots) = ix.timelock_slots {
+        recipe.timelock_slots = timelock_slots;
+    }
+    if let Some(asset_root) = ix.asset_root {
+        // Setting an empty root lets any asset of the recipe be released again.
+        recipe.asset_root = asset_root;
+    }
+    if let Some(wallet_root) = ix.wallet_root {
+        // Setting an empty root opens swaps to every wallet.
+        recipe.wallet_root = wallet_root;
+    }
+    if let Some(gate) = ix.gate {
+        // Setting the default collection removes the gate.
+        recipe.gate = gate;
+    }
 
-    let new_size = recipe
-        .to_account_info()
-        .data_len()
-        .checked_add(size_diff as usize)
-        .ok_or(MplHybridError::NumericalOverflow)?;
-    resize_or_reallocate_account_raw(
-        &recipe.to_account_info(),
-        authority,
-        &ctx.accounts.system_program,
-        new_size,
-    )?;
+    if recipe.fees_exceed(&recipe.fee_caps) {
+        return Err(MplHybridError::FeeCapExceeded.into());
+    }
 
-    Ok(())
+    Ok(size_diff)
 }
@@ -0,0 +1,193 @@
+use crate::error::MplHybridError;
+use crate::events::EscrowWithdrawEvent;
+use crate::state::*;
+use crate::utils::{
+    assert_recipe_admin, assert_recipe_authority, create_associated_token_account,
+    validate_token_account, withdraw_lamports,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token;
+use anchor_spl::token::Mint;
+use anchor_spl::token::{Token, Transfer};
+use solana_program::system_program;
+use spl_token::native_mint;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct WithdrawEscrowV1Ix {
+    amount: u64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawEscrowV1Ctx<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            "escrow".as_bytes(), 
+            recipe.authority.as_ref()
+            ],
+        bump=escrow.bump,
+    )]
+    escrow: Account<'info, EscrowV2>,
+
+    #[account(
+        seeds = [
+            "recipe".as_bytes(), 
+            collection.key().as_ref()
+            ],
+        bump=recipe.bump,
+    )]
+    recipe: Account<'info, RecipeV1>,
+
+    /// CHECK: We check against recipe
+    #[account(
+        address = recipe.collection
+    )]
+    collection: AccountInfo<'info>,
+
+    // Withdrawals can only be sent to the project fee wallet.
+    /// CHECK: We check against recipe
+    #[account(mut,
+        address = recipe.fee_location @ MplHybridError::InvalidProjectFeeWallet
+    )]
+    fee_project_account: AccountInfo<'info>,
+
+    /// CHECK: We check and initialize the token account below.
+    #[account(mut)]
+    fee_token_account: Option<AccountInfo<'info>>,
+
+    /// CHECK: We check the token account below.
+    #[account(mut)]
+    escrow_token_account: Option<AccountInfo<'info>>,
+
+    /// Lamports are withdrawn instead when no mint is passed.
+    #[account(
+        address = recipe.token @MplHybridError::InvalidMintAccount
+    )]
+    token: Option<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [
+            "operators".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=operators.bump,
+    )]
+    operators: Option<Account<'info, OperatorsV1>>,
+
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(
+        mut,
+        seeds = [
+            "vault".as_bytes(), 
+            recipe.key().as_ref()
+            ],
+        bump=vault.bump,
+    )]
+    vault: Option<Account<'info, RecipeVaultV1>>,
+}
+
+pub fn handler_withdraw_escrow_v1(
+    ctx: Context<WithdrawEscrowV1Ctx>,
+    ix: WithdrawEscrowV1Ix,
+) -> Result<()> {
+    let authority = &ctx.accounts.authority;
+    let escrow = &ctx.accounts.escrow;
+    let recipe = &ctx.accounts.recipe;
+    let fee_project_account = &ctx.accounts.fee_project_account;
+    let system_program = &ctx.accounts.system_program;
+    let token_program = &ctx.accounts.token_program;
+
+    assert_recipe_admin(
+        recipe,
+        &authority.key(),
+        ctx.accounts.operators.as_deref(),
+        &[OperatorRole::Withdraw],
+    )?;
+
+    let mint = match (
+        &ctx.accounts.token,
+        &ctx.accounts.escrow_token_account,
+        &ctx.accounts.fee_token_account,
+    ) {
+        (Some(token), Some(escrow_token_account), Some(fee_token_account)) => {
+            // The escrow backs every recipe of the authority, so operators of one recipe can only
+            // withdraw lamports from its own vault.
+            assert_recipe_authority(recipe, &authority.key())?;
+
+            // Create idempotent
+            if fee_token_account.owner == &system_program::ID {
+                create_associated_token_account(
+                    authority,
+                    fee_project_account,
+                    &token.to_account_info(),
+                    fee_token_account,
+                    token_program,
+                    system_program,
+                )?;
+            } else {
+                validate_token_account(
+                    fee_token_account,
+                    &fee_project_account.key(),
+                    &token.key(),
+                )?;
+            }
+
+            // The escrow token account should already exist.
+            validate_token_account(escrow_token_account, &escrow.key(), &token.key())?;
+
+            let signer_seeds = &[b"escrow", recipe.authority.as_ref(), &[escrow.bump]];
+
+            let signer = &[&signer_seeds[..]];
+
+            let cpi_accounts_transfer = Transfer {
+                from: escrow_token_account.to_account_info(),
+                to: fee_token_account.to_account_info(),
+                authority: escrow.to_account_info(),
+            };
+
+            let transfer_cpi_ctx = CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                cpi_accounts_transfer,
+                signer,
+            );
+
+            token::transfer(transfer_cpi_ctx, ix.amount)?;
+
+            token.key()
+        }
+        (None, None, None) => {
+            if recipe.currency != Currency::NativeSol {
+                return Err(MplHybridError::MissingTokenAccount.into());
+            }
+
+            // Lamports are only ever withdrawn from the recipe's own vault.
+            let vault = ctx
+                .accounts
+                .vault
+                .as_ref()
+                .ok_or(MplHybridError::MissingVaultAccount)?;
+            withdraw_lamports(&vault.to_account_info(), fee_project_account, ix.amount)?;
+
+            native_mint::ID
+        }
+        _ => return Err(MplHybridError::MissingTokenAccount.into()),
+    };
+
+    emit_cpi!(EscrowWithdrawEvent {
+        recipe: recipe.key(),
+        escrow: escrow.key(),
+        authority: authority.key(),
+        mint,
+        amount: ix.amount,
+    });
+
+    Ok(())
+}
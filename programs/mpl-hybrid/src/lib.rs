@@ -5,6 +5,7 @@ declare_id!("MPL4o4wMzndgh8T1NVDxELQCj5UQfYTYEkabX3wNKtb");
 
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod utils;
@@ -34,16 +35,22 @@ pub mod mpl_hybrid {
         capture::handler_capture_v1(ctx)
     }
 
-    pub fn capture_v2(ctx: Context<CaptureV2Ctx>) -> Result<()> {
-        capture_v2::handler_capture_v2(ctx)
+    pub fn capture_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, CaptureV2Ctx<'info>>,
+        ix: CaptureV2Ix,
+    ) -> Result<()> {
+        capture_v2::handler_capture_v2(ctx, ix)
     }
 
     pub fn release_v1(ctx: Context<ReleaseV1Ctx>) -> Result<()> {
         release::handler_release_v1(ctx)
     }
 
-    pub fn release_v2(ctx: Context<ReleaseV2Ctx>) -> Result<()> {
-        release_v2::handler_release_v2(ctx)
+    pub fn release_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReleaseV2Ctx<'info>>,
+        ix: ReleaseV2Ix,
+    ) -> Result<()> {
+        release_v2::handler_release_v2(ctx, ix)
     }
 
     pub fn update_recipe_v1(ctx: Context<UpdateRecipeV1Ctx>, ix: UpdateRecipeV1Ix) -> Result<()> {
@@ -71,4 +78,133 @@ pub mod mpl_hybrid {
     ) -> Result<()> {
         migrate_tokens_v1::handler_migrate_tokens_v1(ctx, ix)
     }
+
+    pub fn init_discount_policy_v1(
+        ctx: Context<InitDiscountPolicyV1Ctx>,
+        ix: InitDiscountPolicyV1Ix,
+    ) -> Result<()> {
+        init_discount_policy::handler_init_discount_policy_v1(ctx, ix)
+    }
+
+    pub fn update_discount_policy_v1(
+        ctx: Context<UpdateDiscountPolicyV1Ctx>,
+        ix: UpdateDiscountPolicyV1Ix,
+    ) -> Result<()> {
+        update_discount_policy::handler_update_discount_policy_v1(ctx, ix)
+    }
+
+    pub fn migrate_recipe_v1(ctx: Context<MigrateRecipeV1Ctx>) -> Result<()> {
+        migrate_recipe_v1::handler_migrate_recipe_v1(ctx)
+    }
+
+    pub fn init_basket_v1(ctx: Context<InitBasketV1Ctx>, ix: InitBasketV1Ix) -> Result<()> {
+        init_basket::handler_init_basket_v1(ctx, ix)
+    }
+
+    pub fn update_basket_v1(ctx: Context<UpdateBasketV1Ctx>, ix: UpdateBasketV1Ix) -> Result<()> {
+        update_basket::handler_update_basket_v1(ctx, ix)
+    }
+
+    pub fn init_payment_table_v1(
+        ctx: Context<InitPaymentTableV1Ctx>,
+        ix: InitPaymentTableV1Ix,
+    ) -> Result<()> {
+        init_payment_table::handler_init_payment_table_v1(ctx, ix)
+    }
+
+    pub fn update_payment_table_v1(
+        ctx: Context<UpdatePaymentTableV1Ctx>,
+        ix: UpdatePaymentTableV1Ix,
+    ) -> Result<()> {
+        update_payment_table::handler_update_payment_table_v1(ctx, ix)
+    }
+
+    pub fn close_wallet_activity_v1(ctx: Context<CloseWalletActivityV1Ctx>) -> Result<()> {
+        close_wallet_activity::handler_close_wallet_activity_v1(ctx)
+    }
+
+    pub fn reset_circuit_breaker_v1(ctx: Context<ResetCircuitBreakerV1Ctx>) -> Result<()> {
+        reset_circuit_breaker::handler_reset_circuit_breaker_v1(ctx)
+    }
+
+    pub fn init_protocol_config_v1(
+        ctx: Context<InitProtocolConfigV1Ctx>,
+        ix: InitProtocolConfigV1Ix,
+    ) -> Result<()> {
+        init_protocol_config::handler_init_protocol_config_v1(ctx, ix)
+    }
+
+    pub fn set_pause_v1(ctx: Context<SetPauseV1Ctx>, ix: SetPauseV1Ix) -> Result<()> {
+        set_pause::handler_set_pause_v1(ctx, ix)
+    }
+
+    pub fn set_protocol_admin_v1(ctx: Context<SetProtocolAdminV1Ctx>) -> Result<()> {
+        set_protocol_admin::handler_set_protocol_admin_v1(ctx)
+    }
+
+    pub fn propose_recipe_authority_v1(
+        ctx: Context<ProposeRecipeAuthorityV1Ctx>,
+        ix: ProposeRecipeAuthorityV1Ix,
+    ) -> Result<()> {
+        propose_recipe_authority::handler_propose_recipe_authority_v1(ctx, ix)
+    }
+
+    pub fn accept_recipe_authority_v1(ctx: Context<AcceptRecipeAuthorityV1Ctx>) -> Result<()> {
+        accept_recipe_authority::handler_accept_recipe_authority_v1(ctx)
+    }
+
+    pub fn transfer_escrow_asset_v1(ctx: Context<TransferEscrowAssetV1Ctx>) -> Result<()> {
+        transfer_escrow_asset::handler_transfer_escrow_asset_v1(ctx)
+    }
+
+    pub fn transfer_escrow_reserve_v1(
+        ctx: Context<TransferEscrowReserveV1Ctx>,
+        ix: TransferEscrowReserveV1Ix,
+    ) -> Result<()> {
+        transfer_escrow_reserve::handler_transfer_escrow_reserve_v1(ctx, ix)
+    }
+
+    pub fn init_operators_v1(
+        ctx: Context<InitOperatorsV1Ctx>,
+        ix: InitOperatorsV1Ix,
+    ) -> Result<()> {
+        init_operators::handler_init_operators_v1(ctx, ix)
+    }
+
+    pub fn update_operators_v1(
+        ctx: Context<UpdateOperatorsV1Ctx>,
+        ix: UpdateOperatorsV1Ix,
+    ) -> Result<()> {
+        update_operators::handler_update_operators_v1(ctx, ix)
+    }
+
+    pub fn withdraw_escrow_v1(
+        ctx: Context<WithdrawEscrowV1Ctx>,
+        ix: WithdrawEscrowV1Ix,
+    ) -> Result<()> {
+        withdraw_escrow::handler_withdraw_escrow_v1(ctx, ix)
+    }
+
+    pub fn queue_recipe_update_v1(
+        ctx: Context<QueueRecipeUpdateV1Ctx>,
+        ix: UpdateRecipeV1Ix,
+    ) -> Result<()> {
+        queue_recipe_update::handler_queue_recipe_update_v1(ctx, ix)
+    }
+
+    pub fn execute_recipe_update_v1(ctx: Context<ExecuteRecipeUpdateV1Ctx>) -> Result<()> {
+        execute_recipe_update::handler_execute_recipe_update_v1(ctx)
+    }
+
+    pub fn cancel_recipe_update_v1(ctx: Context<CancelRecipeUpdateV1Ctx>) -> Result<()> {
+        cancel_recipe_update::handler_cancel_recipe_update_v1(ctx)
+    }
+
+    pub fn lock_recipe_v1(ctx: Context<LockRecipeV1Ctx>, ix: LockRecipeV1Ix) -> Result<()> {
+        lock_recipe::handler_lock_recipe_v1(ctx, ix)
+    }
+
+    pub fn migrate_escrow_config_v1(ctx: Context<MigrateEscrowConfigV1Ctx>) -> Result<()> {
+        migrate_escrow_config_v1::handler_migrate_escrow_config_v1(ctx)
+    }
 }
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+// How long an asset has to stay on each side of the escrow, a zero value disables that side.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct AssetCooldown {
+    //8 the slots a captured asset stays out of escrow before it can be released
+    pub min_slots_out: u64,
+    //8 the slots a released asset stays in escrow before it can be captured
+    pub min_slots_in: u64,
+}
+
+impl AssetCooldown {
+    pub const SIZE: usize = 8 + 8;
+
+    pub fn is_enabled(&self) -> bool {
+        self.min_slots_out != 0 || self.min_slots_in != 0
+    }
+}
+
+// The last swaps of an asset, created by the first swap of the asset on a recipe with a cooldown.
+#[account]
+pub struct AssetCooldownV1 {
+    //32 the asset being swapped
+    pub asset: Pubkey,
+    //8 the slot the asset was last captured at
+    pub last_capture_slot: u64,
+    //8 the slot the asset was last released at
+    pub last_release_slot: u64,
+    //1 asset cooldown bump
+    pub bump: u8,
+}
+
+impl AssetCooldownV1 {
+    pub const ASSET_COOLDOWN_SIZE: usize = 8 + 32 + 8 + 8 + 1;
+}
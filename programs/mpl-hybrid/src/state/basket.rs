@@ -0,0 +1,59 @@
+use crate::error::MplHybridError;
+use anchor_lang::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct BasketEntry {
+    //32 the token mint
+    pub mint: Pubkey,
+    //8 the token amount per swap
+    pub amount: u64,
+    //1 whether the token is burned on capture instead of escrowed
+    pub burn: bool,
+}
+
+impl BasketEntry {
+    pub const SIZE: usize = 32 + 8 + 1;
+}
+
+// The tokens a basket recipe charges on capture and pays back on release. Burned entries are
+// not paid back. Each entry's mint, user token account and escrow token account are passed in
+// the remaining accounts in entry order.
+#[account]
+pub struct BasketV1 {
+    //32 the recipe the basket belongs to
+    pub recipe: Pubkey,
+    //4 the basket entries
+    pub entries: Vec<BasketEntry>,
+    //1 basket bump
+    pub bump: u8,
+}
+
+impl BasketV1 {
+    pub const BASE_BASKET_SIZE: usize = 8 + 32 + 4 + 1;
+    pub const ACCOUNTS_PER_ENTRY: usize = 3;
+
+    pub fn validate(&self) -> Result<()> {
+        // A basket needs at least one token and each mint may only appear once.
+        if self.entries.is_empty()
+            || self.entries.iter().enumerate().any(|(i, entry)| {
+                self.entries[..i]
+                    .iter()
+                    .any(|other| other.mint == entry.mint)
+            })
+        {
+            return Err(MplHybridError::InvalidBasket.into());
+        }
+
+        Ok(())
+    }
+
+    // Splits the entry accounts off the front of the remaining accounts, any accounts that
+    // follow them are left for the caller.
+    pub fn split_accounts<'a, 'info>(
+        &self,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> (&'a [AccountInfo<'info>], &'a [AccountInfo<'info>]) {
+        let len = self.entries.len() * Self::ACCOUNTS_PER_ENTRY;
+        accounts.split_at(len.min(accounts.len()))
+    }
+}
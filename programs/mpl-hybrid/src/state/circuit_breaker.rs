@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+// Caps what releases can take out of the escrow per window, a zero value disables that cap.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct CircuitBreaker {
+    //8 the length of a window in slots, zero disables the breaker
+    pub window_slots: u64,
+    //8 the max released amount minus captured amount per window
+    pub max_net_outflow: u64,
+    //4 the max releases per window
+    pub max_releases: u32,
+}
+
+impl CircuitBreaker {
+    pub const SIZE: usize = 8 + 8 + 4;
+
+    pub fn is_enabled(&self) -> bool {
+        self.window_slots != 0
+    }
+}
+
+// What has been released in the current circuit breaker window.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct OutflowWindow {
+    //8 the slot the window started at
+    pub start: u64,
+    //8 the released amount minus the captured amount
+    pub net_outflow: i64,
+    //4 the number of releases
+    pub releases: u32,
+}
+
+impl OutflowWindow {
+    pub const SIZE: usize = 8 + 8 + 4;
+
+    pub fn reset(&mut self, slot: u64) {
+        *self = Self {
+            start: slot,
+            ..Self::default()
+        };
+    }
+
+    // Starts a new window once the current one has passed.
+    pub fn roll(&mut self, breaker: &CircuitBreaker, slot: u64) {
+        if slot >= self.start.saturating_add(breaker.window_slots) {
+            self.reset(slot);
+        }
+    }
+
+    pub fn record_capture(&mut self, amount: u64) {
+        self.net_outflow = self.net_outflow.saturating_sub_unsigned(amount);
+    }
+
+    // Returns false if the release trips the breaker.
+    pub fn record_release(&mut self, breaker: &CircuitBreaker, amount: u64) -> bool {
+        self.net_outflow = self.net_outflow.saturating_add_unsigned(amount);
+        self.releases = self.releases.saturating_add(1);
+
+        // Limits above i64::MAX would wrap as an i64, so the outflow is compared in i128.
+        (breaker.max_net_outflow == 0
+            || self.net_outflow as i128 <= breaker.max_net_outflow as i128)
+            && (breaker.max_releases == 0 || self.releases <= breaker.max_releases)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker(max_net_outflow: u64) -> CircuitBreaker {
+        CircuitBreaker {
+            window_slots: 100,
+            max_net_outflow,
+            max_releases: 0,
+        }
+    }
+
+    #[test]
+    fn releases_within_the_outflow_cap_pass() {
+        let breaker = breaker(10);
+        let mut window = OutflowWindow::default();
+
+        assert!(window.record_release(&breaker, 10));
+        assert!(!window.record_release(&breaker, 1));
+    }
+
+    #[test]
+    fn caps_above_i64_max_do_not_wrap() {
+        let breaker = breaker(u64::MAX);
+        let mut window = OutflowWindow::default();
+
+        assert!(window.record_release(&breaker, 5));
+        assert!(window.record_release(&breaker, u64::MAX));
+    }
+}
@@ -0,0 +1,8 @@
+use anchor_lang::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum Currency {
+    Token,
+    NativeSol,
+    Basket,
+}
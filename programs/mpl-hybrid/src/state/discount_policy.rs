@@ -0,0 +1,52 @@
+use crate::constants::MAX_BASIS_POINTS;
+use crate::error::MplHybridError;
+use anchor_lang::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum DiscountKind {
+    Collection,
+    TokenMint,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct DiscountEntry {
+    //1 whether the proof is a Core asset or a token account
+    pub kind: DiscountKind,
+    //32 the qualifying collection or token mint
+    pub key: Pubkey,
+    //8 the minimum token balance to qualify (unused for collections)
+    pub min_amount: u64,
+    //2 the discount on project fees in basis points
+    pub discount_bps: u16,
+}
+
+impl DiscountEntry {
+    pub const SIZE: usize = 1 + 32 + 8 + 2;
+}
+
+#[account]
+pub struct DiscountPolicyV1 {
+    //32 the recipe the discounts apply to
+    pub recipe: Pubkey,
+    //4 the qualifying holdings
+    pub entries: Vec<DiscountEntry>,
+    //1 discount policy bump
+    pub bump: u8,
+}
+
+impl DiscountPolicyV1 {
+    pub const BASE_DISCOUNT_POLICY_SIZE: usize = 8 + 32 + 4 + 1;
+
+    pub fn validate(&self) -> Result<()> {
+        // A discount can at most waive the project fees entirely.
+        if self
+            .entries
+            .iter()
+            .any(|entry| entry.discount_bps > MAX_BASIS_POINTS)
+        {
+            return Err(MplHybridError::InvalidDiscount.into());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct RecipeGate {
+    //32 the collection whose assets unlock captures, captures aren't gated when unset
+    pub collection: Pubkey,
+    //4 the number of gate assets to hold, at least one
+    pub min_count: u32,
+}
+
+impl RecipeGate {
+    pub const SIZE: usize = 32 + 4;
+
+    pub fn is_enabled(&self) -> bool {
+        self.collection != Pubkey::default()
+    }
+
+    pub fn required(&self) -> u32 {
+        self.min_count.max(1)
+    }
+}
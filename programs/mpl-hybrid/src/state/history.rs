@@ -0,0 +1,294 @@
+use crate::state::RecipeV1;
+use anchor_lang::prelude::*;
+
+// The recipe fields tracked in the change bitmask.
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum HistoryField {
+    Token,
+    FeeLocation,
+    Name,
+    Uri,
+    Max,
+    Min,
+    CaptureAmount,
+    ReleaseAmount,
+    FeeAmountCapture,
+    FeeAmountRelease,
+    SolFeeAmountCapture,
+    SolFeeAmountRelease,
+    Path,
+    Pricing,
+    Inventory,
+    QuoteSigner,
+    CaptureWindow,
+    ReleaseWindow,
+    WalletLimits,
+    CircuitBreaker,
+    AssetCooldown,
+    Loyalty,
+    Timelock,
+    AssetRoot,
+    WalletRoot,
+    Gate,
+}
+
+impl HistoryField {
+    pub fn check(self, bits: u32) -> bool {
+        bits & (1 << (self as u32)) != 0
+    }
+
+    fn bit(self) -> u32 {
+        1 << (self as u32)
+    }
+}
+
+// The numeric recipe fields, recorded in full before and after each change.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct RecipeValues {
+    //8 the max index of NFTs that append to the uri
+    pub max: u64,
+    //8 the minimum index of NFTs that append to the uri
+    pub min: u64,
+    //8 the token cost to capture the NFT
+    pub capture_amount: u64,
+    //8 the token payout for releasing the NFT
+    pub release_amount: u64,
+    //8 the token fee for capturing the NFT
+    pub fee_amount_capture: u64,
+    //8 the token fee for releasing the NFT
+    pub fee_amount_release: u64,
+    //8 the sol fee for capturing the NFT
+    pub sol_fee_amount_capture: u64,
+    //8 the sol fee for releasing the NFT
+    pub sol_fee_amount_release: u64,
+    //8 the number of NFTs held in escrow
+    pub inventory: u64,
+    //8 the recipe timelock
+    pub timelock_slots: u64,
+    //2 onchain/offchain metadata update path
+    pub path: u16,
+}
+
+impl RecipeValues {
+    pub const SIZE: usize = 8 * 10 + 2;
+}
+
+impl From<&RecipeV1> for RecipeValues {
+    fn from(recipe: &RecipeV1) -> Self {
+        Self {
+            max: recipe.max,
+            min: recipe.min,
+            capture_amount: recipe.capture_amount,
+            release_amount: recipe.release_amount,
+            fee_amount_capture: recipe.fee_amount_capture,
+            fee_amount_release: recipe.fee_amount_release,
+            sol_fee_amount_capture: recipe.sol_fee_amount_capture,
+            sol_fee_amount_release: recipe.sol_fee_amount_release,
+            inventory: recipe.inventory,
+            timelock_slots: recipe.timelock_slots,
+            path: recipe.path,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct RecipeChange {
+    //8 the slot the change was applied at
+    pub slot: u64,
+    //32 the signer that applied the change
+    pub signer: Pubkey,
+    //4 the changed fields
+    pub changed: u32,
+    //82 the numeric fields before the change
+    pub old: RecipeValues,
+    //82 the numeric fields after the change
+    pub new: RecipeValues,
+}
+
+impl RecipeChange {
+    pub const SIZE: usize = 8 + 32 + 4 + RecipeValues::SIZE + RecipeValues::SIZE;
+
+    pub fn new(slot: u64, signer: Pubkey, old: &RecipeV1, new: &RecipeV1) -> Self {
+        let fields = [
+            (HistoryField::Token, old.token != new.token),
+            (
+                HistoryField::FeeLocation,
+                old.fee_location != new.fee_location,
+            ),
+            (HistoryField::Name, old.name != new.name),
+            (HistoryField::Uri, old.uri != new.uri),
+            (HistoryField::Max, old.max != new.max),
+            (HistoryField::Min, old.min != new.min),
+            (
+                HistoryField::CaptureAmount,
+                old.capture_amount != new.capture_amount,
+            ),
+            (
+                HistoryField::ReleaseAmount,
+                old.release_amount != new.release_amount,
+            ),
+            (
+                HistoryField::FeeAmountCapture,
+                old.fee_amount_capture != new.fee_amount_capture,
+            ),
+            (
+                HistoryField::FeeAmountRelease,
+                old.fee_amount_release != new.fee_amount_release,
+            ),
+            (
+                HistoryField::SolFeeAmountCapture,
+                old.sol_fee_amount_capture != new.sol_fee_amount_capture,
+            ),
+            (
+                HistoryField::SolFeeAmountRelease,
+                old.sol_fee_amount_release != new.sol_fee_amount_release,
+            ),
+            (HistoryField::Path, old.path != new.path),
+            (HistoryField::Pricing, old.pricing != new.pricing),
+            (HistoryField::Inventory, old.inventory != new.inventory),
+            (
+                HistoryField::QuoteSigner,
+                old.quote_signer != new.quote_signer,
+            ),
+            (
+                HistoryField::CaptureWindow,
+                old.capture_window != new.capture_window,
+            ),
+            (
+                HistoryField::ReleaseWindow,
+                old.release_window != new.release_window,
+            ),
+            (
+                HistoryField::WalletLimits,
+                old.wallet_limits != new.wallet_limits,
+            ),
+            (
+                HistoryField::CircuitBreaker,
+                old.circuit_breaker != new.circuit_breaker,
+            ),
+            (
+                HistoryField::AssetCooldown,
+                old.asset_cooldown != new.asset_cooldown,
+            ),
+            (HistoryField::Loyalty, old.loyalty != new.loyalty),
+            (
+                HistoryField::Timelock,
+                old.timelock_slots != new.timelock_slots,
+            ),
+            (HistoryField::AssetRoot, old.asset_root != new.asset_root),
+            (HistoryField::WalletRoot, old.wallet_root != new.wallet_root),
+            (HistoryField::Gate, old.gate != new.gate),
+        ];
+
+        Self {
+            slot,
+            signer,
+            changed: fields
+                .into_iter()
+                .filter(|(_, changed)| *changed)
+                .fold(0, |bits, (field, _)| bits | field.bit()),
+            old: RecipeValues::from(old),
+            new: RecipeValues::from(new),
+        }
+    }
+}
+
+#[account]
+pub struct RecipeHistoryV1 {
+    //32 the recipe the changes were made to
+    pub recipe: Pubkey,
+    //8 the number of changes ever recorded
+    pub count: u64,
+    //1 history bump
+    pub bump: u8,
+    //4 the latest changes, oldest ones are overwritten once the history is full
+    pub entries: Vec<RecipeChange>,
+}
+
+impl RecipeHistoryV1 {
+    pub const CAPACITY: usize = 16;
+    pub const RECIPE_HISTORY_SIZE: usize = 8 + 32 + 8 + 1 + 4 + Self::CAPACITY * RecipeChange::SIZE;
+
+    pub fn record(&mut self, change: RecipeChange) {
+        let index = (self.count % Self::CAPACITY as u64) as usize;
+        if index < self.entries.len() {
+            self.entries[index] = change;
+        } else {
+            self.entries.push(change);
+        }
+        self.count = self.count.saturating_add(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    // A recipe with every field zeroed, read through the legacy path so new fields default.
+    fn recipe() -> RecipeV1 {
+        let mut data = RecipeV1::DISCRIMINATOR.to_vec();
+        data.resize(RecipeV1::BASE_RECIPE_SIZE, 0);
+        RecipeV1::try_deserialize_legacy(&data).unwrap()
+    }
+
+    fn change(field: &HistoryField) -> fn(&mut RecipeV1) {
+        match field {
+            HistoryField::Token => |recipe| recipe.token = Pubkey::new_unique(),
+            HistoryField::FeeLocation => |recipe| recipe.fee_location = Pubkey::new_unique(),
+            HistoryField::Name => |recipe| recipe.name = "name".to_string(),
+            HistoryField::Uri => |recipe| recipe.uri = "uri".to_string(),
+            HistoryField::Max => |recipe| recipe.max = 1,
+            HistoryField::Min => |recipe| recipe.min = 1,
+            HistoryField::CaptureAmount => |recipe| recipe.capture_amount = 1,
+            HistoryField::ReleaseAmount => |recipe| recipe.release_amount = 1,
+            HistoryField::FeeAmountCapture => |recipe| recipe.fee_amount_capture = 1,
+            HistoryField::FeeAmountRelease => |recipe| recipe.fee_amount_release = 1,
+            HistoryField::SolFeeAmountCapture => |recipe| recipe.sol_fee_amount_capture = 1,
+            HistoryField::SolFeeAmountRelease => |recipe| recipe.sol_fee_amount_release = 1,
+            HistoryField::Path => |recipe| recipe.path = 1,
+            HistoryField::Pricing => |recipe| recipe.pricing.target = 1,
+            HistoryField::Inventory => |recipe| recipe.inventory = 1,
+            HistoryField::QuoteSigner => |recipe| recipe.quote_signer = Pubkey::new_unique(),
+            HistoryField::CaptureWindow => |recipe| recipe.capture_window.start = 1,
+            HistoryField::ReleaseWindow => |recipe| recipe.release_window.start = 1,
+            HistoryField::WalletLimits => |recipe| recipe.wallet_limits.window_slots = 1,
+            HistoryField::CircuitBreaker => |recipe| recipe.circuit_breaker.window_slots = 1,
+            HistoryField::AssetCooldown => |recipe| recipe.asset_cooldown.min_slots_out = 1,
+            HistoryField::Loyalty => |recipe| recipe.loyalty.points_per_capture = 1,
+            HistoryField::Timelock => |recipe| recipe.timelock_slots = 1,
+            HistoryField::AssetRoot => |recipe| recipe.asset_root = [1; 32],
+            HistoryField::WalletRoot => |recipe| recipe.wallet_root = [1; 32],
+            HistoryField::Gate => |recipe| recipe.gate.min_count = 1,
+        }
+    }
+
+    #[test]
+    fn each_field_sets_its_own_bit() {
+        // Every variant in order of its bit, the clients decode the bitmask with the same enum.
+        let fields: Vec<HistoryField> = (0..u32::BITS as u8)
+            .map_while(|bit| HistoryField::try_from_slice(&[bit]).ok())
+            .collect();
+        assert!(!fields.is_empty());
+
+        for field in fields {
+            let old = recipe();
+            let mut new = old.clone();
+            change(&field)(&mut new);
+
+            let change = RecipeChange::new(0, Pubkey::default(), &old, &new);
+            assert_eq!(change.changed, field.clone().bit(), "{:?}", field);
+            assert!(field.check(change.changed));
+        }
+    }
+
+    #[test]
+    fn unchanged_recipes_set_no_bits() {
+        let recipe = recipe();
+
+        assert_eq!(
+            RecipeChange::new(0, Pubkey::default(), &recipe, &recipe).changed,
+            0
+        );
+    }
+}
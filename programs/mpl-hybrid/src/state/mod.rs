@@ -1,11 +1,53 @@
+pub mod asset_cooldown;
+pub mod basket;
+pub mod circuit_breaker;
+pub mod currency;
+pub mod discount_policy;
 pub mod escrow;
 pub mod escrow_v2;
+pub mod gate;
+pub mod history;
 pub mod nft_data;
+pub mod operators;
 pub mod path;
+pub mod payment_table;
+pub mod pending_update;
+pub mod pricing;
+pub mod protocol_config;
+pub mod quote;
 pub mod recipe;
+pub mod recipe_kind;
+pub mod recipe_lock;
+pub mod stats;
+pub mod swap_direction;
+pub mod swap_receipt;
+pub mod swap_window;
+pub mod vault;
+pub mod wallet_activity;
 
+pub use asset_cooldown::*;
+pub use basket::*;
+pub use circuit_breaker::*;
+pub use currency::*;
+pub use discount_policy::*;
 pub use escrow::*;
 pub use escrow_v2::*;
+pub use gate::*;
+pub use history::*;
 pub use nft_data::*;
+pub use operators::*;
 pub use path::*;
+pub use payment_table::*;
+pub use pending_update::*;
+pub use pricing::*;
+pub use protocol_config::*;
+pub use quote::*;
 pub use recipe::*;
+pub use recipe_kind::*;
+pub use recipe_lock::*;
+pub use stats::*;
+pub use swap_direction::*;
+pub use swap_receipt::*;
+pub use swap_window::*;
+pub use vault::*;
+pub use wallet_activity::*;
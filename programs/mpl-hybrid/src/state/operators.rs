@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum OperatorRole {
+    UpdatePricing,
+    UpdateMetadata,
+    Pause,
+    Withdraw,
+    ManageFees,
+}
+
+impl OperatorRole {
+    pub fn check(self, bits: u16) -> bool {
+        bits & (1 << (self as u16)) != 0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct OperatorEntry {
+    //32 the operator key, can be a multisig PDA
+    pub operator: Pubkey,
+    //2 the roles granted to the operator
+    pub roles: u16,
+}
+
+impl OperatorEntry {
+    pub const SIZE: usize = 32 + 2;
+}
+
+#[account]
+pub struct OperatorsV1 {
+    //32 the recipe the operators administer
+    pub recipe: Pubkey,
+    //32 the recipe authority that granted the roles
+    pub authority: Pubkey,
+    //4 the operators and their roles
+    pub entries: Vec<OperatorEntry>,
+    //1 operators bump
+    pub bump: u8,
+}
+
+impl OperatorsV1 {
+    pub const BASE_OPERATORS_SIZE: usize = 8 + 32 + 32 + 4 + 1;
+
+    // Whether the key is an operator holding every one of the roles.
+    pub fn has_roles(&self, operator: &Pubkey, roles: &[OperatorRole]) -> bool {
+        self.entries.iter().any(|entry| {
+            entry.operator == *operator && roles.iter().all(|role| role.clone().check(entry.roles))
+        })
+    }
+}
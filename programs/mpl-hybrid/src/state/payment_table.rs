@@ -0,0 +1,81 @@
+use crate::error::MplHybridError;
+use crate::state::{FeeCaps, OperatorRole};
+use anchor_lang::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct PaymentEntry {
+    //32 the accepted token mint
+    pub mint: Pubkey,
+    //8 the token cost to capture the NFT
+    pub capture_amount: u64,
+    //8 the token payout for releasing the NFT
+    pub release_amount: u64,
+    //8 the token fee for capturing the NFT
+    pub fee_amount_capture: u64,
+    //8 the token fee for releasing the NFT
+    pub fee_amount_release: u64,
+}
+
+impl PaymentEntry {
+    pub const SIZE: usize = 32 + 8 + 8 + 8 + 8;
+}
+
+// Mints accepted by a token recipe in addition to its own token, each at a fixed price.
+// Alternative payments are always escrowed, even on burn paths.
+#[account]
+pub struct PaymentTableV1 {
+    //32 the recipe the payments are accepted for
+    pub recipe: Pubkey,
+    //4 the accepted payments
+    pub entries: Vec<PaymentEntry>,
+    //1 payment table bump
+    pub bump: u8,
+}
+
+impl PaymentTableV1 {
+    pub const BASE_PAYMENT_TABLE_SIZE: usize = 8 + 32 + 4 + 1;
+
+    pub fn validate(&self, recipe_token: &Pubkey) -> Result<()> {
+        // Each mint may only be priced once and the recipe token keeps the recipe's own price.
+        if self.entries.iter().enumerate().any(|(i, entry)| {
+            entry.mint == *recipe_token
+                || self.entries[..i]
+                    .iter()
+                    .any(|other| other.mint == entry.mint)
+        }) {
+            return Err(MplHybridError::InvalidPaymentTable.into());
+        }
+
+        Ok(())
+    }
+
+    // Operators need the fee role on top of the pricing role to change the fees charged.
+    pub fn roles(&self, previous: &[PaymentEntry]) -> Vec<OperatorRole> {
+        if self.changes_fees(previous) {
+            vec![OperatorRole::UpdatePricing, OperatorRole::ManageFees]
+        } else {
+            vec![OperatorRole::UpdatePricing]
+        }
+    }
+
+    pub fn fees_exceed(&self, caps: &FeeCaps) -> bool {
+        self.entries.iter().any(|entry| {
+            entry.fee_amount_capture > caps.fee_amount_capture
+                || entry.fee_amount_release > caps.fee_amount_release
+        })
+    }
+
+    // Whether any mint is charged other fees than in the previous entries, new mints count as a
+    // change when they charge a fee.
+    pub fn changes_fees(&self, previous: &[PaymentEntry]) -> bool {
+        self.entries.iter().any(|entry| {
+            let (capture, release) = previous
+                .iter()
+                .find(|other| other.mint == entry.mint)
+                .map_or((0, 0), |other| {
+                    (other.fee_amount_capture, other.fee_amount_release)
+                });
+            entry.fee_amount_capture != capture || entry.fee_amount_release != release
+        })
+    }
+}
@@ -0,0 +1,20 @@
+use crate::instructions::UpdateRecipeV1Ix;
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct PendingRecipeUpdateV1 {
+    //32 the recipe the update applies to
+    pub recipe: Pubkey,
+    //32 the signer that queued the update, refunded when it is executed or cancelled
+    pub authority: Pubkey,
+    //8 the first slot the update can be executed at
+    pub activation_slot: u64,
+    //33 the token to switch to
+    pub token: Option<Pubkey>,
+    //32 the account to send token fees to
+    pub fee_location: Pubkey,
+    //1 pending update bump
+    pub bump: u8,
+    //the queued update
+    pub update: UpdateRecipeV1Ix,
+}
@@ -0,0 +1,159 @@
+use crate::constants::MAX_BASIS_POINTS;
+use anchor_lang::prelude::*;
+
+// Fixed point scale used when compounding exponential curves.
+const CURVE_SCALE: u128 = 1_000_000_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum CurveKind {
+    Fixed,
+    Linear,
+    Exponential,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum CurveBasis {
+    EscrowInventory,
+    NetSwaps,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct PricingCurve {
+    //1 how the price responds to the curve input
+    pub kind: CurveKind,
+    //1 the curve input, either the escrow inventory or the net swap counter
+    pub basis: CurveBasis,
+    //8 the input level at which the recipe amounts apply unchanged
+    pub target: u64,
+    //8 the step per unit of input, in tokens for linear and basis points for exponential curves
+    pub factor: u64,
+    //8 the lowest price the curve can reach
+    pub min_price: u64,
+    //8 the highest price the curve can reach
+    pub max_price: u64,
+}
+
+impl Default for PricingCurve {
+    fn default() -> Self {
+        Self {
+            kind: CurveKind::Fixed,
+            basis: CurveBasis::EscrowInventory,
+            target: 0,
+            factor: 0,
+            min_price: 0,
+            max_price: u64::MAX,
+        }
+    }
+}
+
+impl PricingCurve {
+    pub const SIZE: usize = 1 + 1 + 8 + 8 + 8 + 8;
+
+    // Prices `base` for an input that is `deviation` units past the target. A positive deviation
+    // means the escrow is short on assets, so prices go up.
+    pub fn price(&self, base: u64, deviation: i128) -> u64 {
+        let price = match self.kind {
+            CurveKind::Fixed => return base,
+            CurveKind::Linear => (base as i128)
+                .saturating_add((self.factor as i128).saturating_mul(deviation))
+                .clamp(0, u64::MAX as i128) as u128,
+            CurveKind::Exponential => {
+                let multiplier = compound(self.factor, deviation.unsigned_abs());
+                if deviation >= 0 {
+                    (base as u128).saturating_mul(multiplier) / CURVE_SCALE
+                } else {
+                    (base as u128).saturating_mul(CURVE_SCALE) / multiplier
+                }
+            }
+        };
+
+        self.clamp(price.min(u64::MAX as u128) as u64)
+    }
+
+    // Keeps a price within the bounds of the curve.
+    pub fn clamp(&self, price: u64) -> u64 {
+        price.clamp(self.min_price, self.max_price)
+    }
+}
+
+// Returns (1 + factor_bps / 10000) ^ exponent scaled by CURVE_SCALE, saturating on overflow.
+fn compound(factor_bps: u64, exponent: u128) -> u128 {
+    let mut base = (MAX_BASIS_POINTS as u128)
+        .saturating_add(factor_bps as u128)
+        .saturating_mul(CURVE_SCALE)
+        / MAX_BASIS_POINTS as u128;
+    let mut result = CURVE_SCALE;
+    let mut exponent = exponent;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_scaled(result, base);
+        }
+        base = mul_scaled(base, base);
+        exponent >>= 1;
+    }
+
+    result
+}
+
+fn mul_scaled(a: u128, b: u128) -> u128 {
+    a.checked_mul(b)
+        .map_or(u128::MAX, |value| value / CURVE_SCALE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn curve(kind: CurveKind, factor: u64) -> PricingCurve {
+        PricingCurve {
+            kind,
+            factor,
+            ..PricingCurve::default()
+        }
+    }
+
+    #[test]
+    fn fixed_curves_ignore_the_deviation() {
+        let curve = curve(CurveKind::Fixed, 50);
+
+        assert_eq!(curve.price(100, 7), 100);
+        assert_eq!(curve.price(100, -7), 100);
+    }
+
+    #[test]
+    fn linear_curves_move_by_the_factor() {
+        let curve = curve(CurveKind::Linear, 5);
+
+        assert_eq!(curve.price(100, 6), 130);
+        assert_eq!(curve.price(100, -6), 70);
+        assert_eq!(curve.price(100, -30), 0);
+    }
+
+    #[test]
+    fn exponential_curves_compound_the_factor() {
+        let curve = curve(CurveKind::Exponential, 1_000);
+
+        assert_eq!(curve.price(1_000_000, 3), 1_331_000);
+        assert_eq!(curve.price(1_000_000, -3), 751_314);
+    }
+
+    #[test]
+    fn exponential_curves_saturate() {
+        let curve = curve(CurveKind::Exponential, 10_000);
+
+        assert_eq!(curve.price(u64::MAX, 200), u64::MAX);
+    }
+
+    #[test]
+    fn prices_are_clamped_to_the_curve_bounds() {
+        let curve = PricingCurve {
+            min_price: 20,
+            max_price: 2_000_000,
+            ..curve(CurveKind::Linear, 50)
+        };
+
+        assert_eq!(curve.price(100, -10), 20);
+        assert_eq!(curve.price(1_000_000, 100_000), 2_000_000);
+    }
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+// Program-wide settings controlled by the protocol admin.
+#[account]
+pub struct ProtocolConfigV1 {
+    //32 the protocol admin
+    pub admin: Pubkey,
+    //1 whether all swaps are paused
+    pub paused: bool,
+    //2 the reason code of the pause
+    pub pause_reason: u16,
+    //1 protocol config bump
+    pub bump: u8,
+}
+
+impl ProtocolConfigV1 {
+    pub const PROTOCOL_CONFIG_SIZE: usize = 8 + 32 + 1 + 2 + 1;
+}
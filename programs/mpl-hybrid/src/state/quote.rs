@@ -0,0 +1,36 @@
+use crate::state::SwapDirection;
+use anchor_lang::prelude::*;
+
+// The message signed by the recipe's quote signer and checked by the Ed25519 precompile.
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct SwapQuoteV1 {
+    //32 the recipe the quote is for
+    pub recipe: Pubkey,
+    //32 the wallet allowed to use the quote
+    pub user: Pubkey,
+    //32 the asset being swapped
+    pub asset: Pubkey,
+    //1 whether the quote prices a capture or a release
+    pub direction: SwapDirection,
+    //8 the token or lamport amount replacing the recipe price
+    pub amount: u64,
+    //8 the last slot the quote can be used in
+    pub expiry_slot: u64,
+    //8 the quote nonce, each can only be used once per recipe
+    pub nonce: u64,
+}
+
+// Marks a quote nonce as used.
+#[account]
+pub struct QuoteNonceV1 {
+    //32 the recipe the quote was used on
+    pub recipe: Pubkey,
+    //8 the used nonce
+    pub nonce: u64,
+    //1 quote nonce bump
+    pub bump: u8,
+}
+
+impl QuoteNonceV1 {
+    pub const QUOTE_NONCE_SIZE: usize = 8 + 32 + 8 + 1;
+}
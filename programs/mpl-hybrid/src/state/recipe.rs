@@ -1,10 +1,15 @@
-use anchor_lang::prelude::*;
+use crate::state::{
+    AssetCooldown, CircuitBreaker, Currency, CurveBasis, FeeCaps, LoyaltyRate, OutflowWindow,
+    PricingCurve, RecipeGate, RecipeKind, RecipeStats, SwapWindow, WalletLimits,
+};
+use anchor_lang::{prelude::*, Discriminator};
+use mpl_core::types::UpdateAuthority;
 
 #[account]
 pub struct RecipeV1 {
     //32 the collection account
     pub collection: Pubkey,
-    //32 the escrow authority (must match collection authority)
+    //32 the authority administering the recipe and owning its escrow
     pub authority: Pubkey,
     //32 the token to be dispensed
     pub token: Pubkey,
@@ -18,8 +23,8 @@ pub struct RecipeV1 {
     pub max: u64,
     //8 the minimum index of NFTs that append to the uri
     pub min: u64,
-    //8 the token cost to swap
-    pub amount: u64,
+    //8 the token cost to capture the NFT
+    pub capture_amount: u64,
     //8 the token fee for capturing the NFT
     pub fee_amount_capture: u64,
     //8 the sol fee for capturing the NFT
@@ -34,9 +39,285 @@ pub struct RecipeV1 {
     pub path: u16,
     //1 recipe bump
     pub bump: u8,
+    //8 the token payout for releasing the NFT
+    pub release_amount: u64,
+    //34 the curve applied to the capture and release amounts
+    pub pricing: PricingCurve,
+    //8 the number of NFTs currently held in escrow
+    pub inventory: u64,
+    //8 the number of captures minus the number of releases
+    pub net_swaps: i64,
+    //1 whether swaps are paid in the token or in native SOL
+    pub currency: Currency,
+    //32 the key signing price quotes, quotes are disabled when unset
+    pub quote_signer: Pubkey,
+    //16 when captures are allowed
+    pub capture_window: SwapWindow,
+    //16 when releases are allowed
+    pub release_window: SwapWindow,
+    //24 the swap limits of each wallet
+    pub wallet_limits: WalletLimits,
+    //20 the cap on releases per window
+    pub circuit_breaker: CircuitBreaker,
+    //20 the releases counted against the circuit breaker
+    pub outflow_window: OutflowWindow,
+    //1 whether swaps on the recipe are paused
+    pub paused: bool,
+    //2 the reason code of the pause
+    pub pause_reason: u16,
+    //16 how long assets stay on each side of the escrow
+    pub asset_cooldown: AssetCooldown,
+    //56 the lifetime swap totals
+    pub stats: RecipeStats,
+    //16 the loyalty points awarded to swap receipts
+    pub loyalty: LoyaltyRate,
+    //32 the authority the recipe is being handed to, unset when no handover is pending
+    pub pending_authority: Pubkey,
+    //8 the slots a queued change waits before it can be executed, changes are immediate when unset
+    pub timelock_slots: u64,
+    //2 the field groups that can no longer be updated
+    pub locked: u16,
+    //32 the highest fees the recipe can be updated to
+    pub fee_caps: FeeCaps,
+    //1 whether the recipe swaps collection assets or standalone assets of a creator
+    pub kind: RecipeKind,
+    //32 the Merkle root of the assets that can be released, any asset when unset
+    pub asset_root: [u8; 32],
+    //32 the Merkle root of the wallets that can swap, any wallet when unset
+    pub wallet_root: [u8; 32],
+    //36 the collection captures are gated on
+    pub gate: RecipeGate,
+    //32 the authority the recipe was last handed over from, unset before the first handover
+    pub previous_authority: Pubkey,
+    //32 the authority of the v1 escrow the recipe was migrated from, unset for other recipes
+    pub legacy_authority: Pubkey,
+    //32 the token of the v1 escrow the recipe was migrated from, unset for other recipes
+    pub legacy_token: Pubkey,
 }
 
 impl RecipeV1 {
-    pub const BASE_RECIPE_SIZE: usize =
+    // The size of the original layout, the fields appended since then are added on top.
+    const LEGACY_RECIPE_SIZE: usize =
         8 + 32 + 32 + 32 + 32 + 4 + 4 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 2 + 1;
+    pub const BASE_RECIPE_SIZE: usize = Self::LEGACY_RECIPE_SIZE
+        + 8
+        + PricingCurve::SIZE
+        + 8
+        + 8
+        + 1
+        + 32
+        + SwapWindow::SIZE
+        + SwapWindow::SIZE
+        + WalletLimits::SIZE
+        + CircuitBreaker::SIZE
+        + OutflowWindow::SIZE
+        + 1
+        + 2
+        + AssetCooldown::SIZE
+        + RecipeStats::SIZE
+        + LoyaltyRate::SIZE
+        + 32
+        + 8
+        + 2
+        + FeeCaps::SIZE
+        + 1
+        + 32
+        + 32
+        + RecipeGate::SIZE
+        + 32
+        + 32
+        + 32;
+
+    // A recipe with no swap terms and every swap control unset, callers fill in the terms.
+    pub fn new(collection: Pubkey, authority: Pubkey, kind: RecipeKind, bump: u8) -> Self {
+        Self {
+            collection,
+            authority,
+            token: Pubkey::default(),
+            fee_location: Pubkey::default(),
+            name: String::new(),
+            uri: String::new(),
+            max: 0,
+            min: 0,
+            capture_amount: 0,
+            fee_amount_capture: 0,
+            sol_fee_amount_capture: 0,
+            fee_amount_release: 0,
+            sol_fee_amount_release: 0,
+            count: 0,
+            path: 0,
+            bump,
+            release_amount: 0,
+            pricing: PricingCurve::default(),
+            inventory: 0,
+            net_swaps: 0,
+            currency: Currency::Token,
+            quote_signer: Pubkey::default(),
+            capture_window: SwapWindow::default(),
+            release_window: SwapWindow::default(),
+            wallet_limits: WalletLimits::default(),
+            circuit_breaker: CircuitBreaker::default(),
+            outflow_window: OutflowWindow::default(),
+            paused: false,
+            pause_reason: 0,
+            asset_cooldown: AssetCooldown::default(),
+            stats: RecipeStats::default(),
+            loyalty: LoyaltyRate::default(),
+            pending_authority: Pubkey::default(),
+            timelock_slots: 0,
+            locked: 0,
+            fee_caps: FeeCaps::default(),
+            kind,
+            asset_root: [0; 32],
+            wallet_root: [0; 32],
+            gate: RecipeGate::default(),
+            previous_authority: Pubkey::default(),
+            legacy_authority: Pubkey::default(),
+            legacy_token: Pubkey::default(),
+        }
+    }
+
+    // Deserializes a recipe written by an older version of the program. Fields appended to the
+    // layout since then are read when present and defaulted otherwise.
+    pub fn try_deserialize_legacy(buf: &[u8]) -> Result<Self> {
+        if buf.len() < 8 || buf[..8] != Self::DISCRIMINATOR {
+            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+        let buf = &mut &buf[8..];
+
+        let collection = Pubkey::deserialize(buf)?;
+        let authority = Pubkey::deserialize(buf)?;
+        let token = Pubkey::deserialize(buf)?;
+        let fee_location = Pubkey::deserialize(buf)?;
+        let name = String::deserialize(buf)?;
+        let uri = String::deserialize(buf)?;
+        let max = u64::deserialize(buf)?;
+        let min = u64::deserialize(buf)?;
+        let capture_amount = u64::deserialize(buf)?;
+        let fee_amount_capture = u64::deserialize(buf)?;
+        let sol_fee_amount_capture = u64::deserialize(buf)?;
+        let fee_amount_release = u64::deserialize(buf)?;
+        let sol_fee_amount_release = u64::deserialize(buf)?;
+        let count = u64::deserialize(buf)?;
+        let path = u16::deserialize(buf)?;
+        let bump = u8::deserialize(buf)?;
+
+        // The original layout used a single amount for both directions.
+        let release_amount = read_or(buf, capture_amount)?;
+        let pricing = read_or(buf, PricingCurve::default())?;
+        let inventory = read_or(buf, 0)?;
+        let net_swaps = read_or(buf, 0)?;
+        let currency = read_or(buf, Currency::Token)?;
+        let quote_signer = read_or(buf, Pubkey::default())?;
+        let capture_window = read_or(buf, SwapWindow::default())?;
+        let release_window = read_or(buf, SwapWindow::default())?;
+        let wallet_limits = read_or(buf, WalletLimits::default())?;
+        let circuit_breaker = read_or(buf, CircuitBreaker::default())?;
+        let outflow_window = read_or(buf, OutflowWindow::default())?;
+        let paused = read_or(buf, false)?;
+        let pause_reason = read_or(buf, 0)?;
+        let asset_cooldown = read_or(buf, AssetCooldown::default())?;
+        // Swaps made before the stats existed aren't counted.
+        let stats = read_or(buf, RecipeStats::default())?;
+        let loyalty = read_or(buf, LoyaltyRate::default())?;
+        let pending_authority = read_or(buf, Pubkey::default())?;
+        let timelock_slots = read_or(buf, 0)?;
+        let locked = read_or(buf, 0)?;
+        let fee_caps = read_or(buf, FeeCaps::default())?;
+        let kind = read_or(buf, RecipeKind::Collection)?;
+        let asset_root = read_or(buf, [0; 32])?;
+        let wallet_root = read_or(buf, [0; 32])?;
+        let gate = read_or(buf, RecipeGate::default())?;
+        let previous_authority = read_or(buf, Pubkey::default())?;
+        let legacy_authority = read_or(buf, Pubkey::default())?;
+        let legacy_token = read_or(buf, Pubkey::default())?;
+
+        Ok(Self {
+            collection,
+            authority,
+            token,
+            fee_location,
+            name,
+            uri,
+            max,
+            min,
+            capture_amount,
+            fee_amount_capture,
+            sol_fee_amount_capture,
+            fee_amount_release,
+            sol_fee_amount_release,
+            count,
+            path,
+            bump,
+            release_amount,
+            pricing,
+            inventory,
+            net_swaps,
+            currency,
+            quote_signer,
+            capture_window,
+            release_window,
+            wallet_limits,
+            circuit_breaker,
+            outflow_window,
+            paused,
+            pause_reason,
+            asset_cooldown,
+            stats,
+            loyalty,
+            pending_authority,
+            timelock_slots,
+            locked,
+            fee_caps,
+            kind,
+            asset_root,
+            wallet_root,
+            gate,
+            previous_authority,
+            legacy_authority,
+            legacy_token,
+        })
+    }
+
+    // How far the curve input is past the target, positive when the escrow is running short.
+    pub fn curve_deviation(&self) -> i128 {
+        match self.pricing.basis {
+            CurveBasis::EscrowInventory => self.pricing.target as i128 - self.inventory as i128,
+            CurveBasis::NetSwaps => self.net_swaps as i128 - self.pricing.target as i128,
+        }
+    }
+
+    pub fn capture_price(&self) -> u64 {
+        self.pricing
+            .price(self.capture_amount, self.curve_deviation())
+    }
+
+    pub fn release_price(&self) -> u64 {
+        self.pricing
+            .price(self.release_amount, self.curve_deviation())
+    }
+
+    pub fn quotes_enabled(&self) -> bool {
+        self.quote_signer != Pubkey::default()
+    }
+
+    // The update authority of the assets the recipe swaps.
+    pub fn asset_update_authority(&self) -> UpdateAuthority {
+        self.kind.asset_update_authority(self.collection)
+    }
+
+    pub fn fees_exceed(&self, caps: &FeeCaps) -> bool {
+        self.fee_amount_capture > caps.fee_amount_capture
+            || self.fee_amount_release > caps.fee_amount_release
+            || self.sol_fee_amount_capture > caps.sol_fee_amount_capture
+            || self.sol_fee_amount_release > caps.sol_fee_amount_release
+    }
+}
+
+fn read_or<T: AnchorDeserialize>(buf: &mut &[u8], default: T) -> Result<T> {
+    if buf.is_empty() {
+        Ok(default)
+    } else {
+        Ok(T::deserialize(buf)?)
+    }
 }
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+use mpl_core::types::UpdateAuthority;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum RecipeKind {
+    // The recipe is keyed by a Core collection and swaps the collection's assets.
+    Collection,
+    // The recipe is keyed by a creator and swaps standalone assets whose update authority is the
+    // creator's address.
+    Creator,
+}
+
+impl RecipeKind {
+    // The update authority of the assets keyed by the collection or creator.
+    pub fn asset_update_authority(&self, collection: Pubkey) -> UpdateAuthority {
+        match self {
+            RecipeKind::Collection => UpdateAuthority::Collection(collection),
+            RecipeKind::Creator => UpdateAuthority::Address(collection),
+        }
+    }
+}
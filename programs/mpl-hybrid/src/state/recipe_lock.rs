@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+// The groups of recipe fields that can be locked for good.
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum RecipeField {
+    Metadata,
+    Path,
+    Token,
+    Pricing,
+    Fees,
+    FeeLocation,
+    SwapLimits,
+    Loyalty,
+    Timelock,
+    Allowlist,
+}
+
+impl RecipeField {
+    // Every defined field group, lock bits outside of it are rejected.
+    pub const ALL: u16 = (1 << (RecipeField::Allowlist as u16 + 1)) - 1;
+
+    pub fn check(self, bits: u16) -> bool {
+        bits & (1 << (self as u16)) != 0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct FeeCaps {
+    //8 the highest token fee for capturing the NFT
+    pub fee_amount_capture: u64,
+    //8 the highest token fee for releasing the NFT
+    pub fee_amount_release: u64,
+    //8 the highest sol fee for capturing the NFT
+    pub sol_fee_amount_capture: u64,
+    //8 the highest sol fee for releasing the NFT
+    pub sol_fee_amount_release: u64,
+}
+
+impl FeeCaps {
+    pub const SIZE: usize = 8 + 8 + 8 + 8;
+
+    // Whether any cap is above the one it replaces, caps can only be lowered.
+    pub fn raises(&self, current: &FeeCaps) -> bool {
+        self.fee_amount_capture > current.fee_amount_capture
+            || self.fee_amount_release > current.fee_amount_release
+            || self.sol_fee_amount_capture > current.sol_fee_amount_capture
+            || self.sol_fee_amount_release > current.sol_fee_amount_release
+    }
+}
+
+// Fees are uncapped until caps are set.
+impl Default for FeeCaps {
+    fn default() -> Self {
+        Self {
+            fee_amount_capture: u64::MAX,
+            fee_amount_release: u64::MAX,
+            sol_fee_amount_capture: u64::MAX,
+            sol_fee_amount_release: u64::MAX,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+// Lifetime swap totals of a recipe. Token amounts are in the recipe currency, alternative payments
+// and baskets only count towards the swap totals.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct RecipeStats {
+    //8 the number of captures
+    pub captures: u64,
+    //8 the number of releases
+    pub releases: u64,
+    //8 the amount paid into the escrow by captures
+    pub tokens_in: u64,
+    //8 the amount paid out of the escrow by releases
+    pub tokens_out: u64,
+    //8 the amount burned by captures
+    pub tokens_burned: u64,
+    //8 the token fees paid to the project
+    pub token_fees: u64,
+    //8 the sol fees paid to the project
+    pub sol_fees: u64,
+}
+
+impl RecipeStats {
+    pub const SIZE: usize = 8 * 7;
+
+    pub fn record_capture(&mut self, amount: u64, burned: u64, token_fee: u64, sol_fee: u64) {
+        self.captures = self.captures.saturating_add(1);
+        self.tokens_in = self.tokens_in.saturating_add(amount);
+        self.tokens_burned = self.tokens_burned.saturating_add(burned);
+        self.token_fees = self.token_fees.saturating_add(token_fee);
+        self.sol_fees = self.sol_fees.saturating_add(sol_fee);
+    }
+
+    pub fn record_release(&mut self, amount: u64, token_fee: u64, sol_fee: u64) {
+        self.releases = self.releases.saturating_add(1);
+        self.tokens_out = self.tokens_out.saturating_add(amount);
+        self.token_fees = self.token_fees.saturating_add(token_fee);
+        self.sol_fees = self.sol_fees.saturating_add(sol_fee);
+    }
+}
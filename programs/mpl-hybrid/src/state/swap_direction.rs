@@ -0,0 +1,7 @@
+use anchor_lang::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum SwapDirection {
+    Capture,
+    Release,
+}
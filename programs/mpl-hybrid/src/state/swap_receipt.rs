@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+// The loyalty points a recipe awards per swap.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct LoyaltyRate {
+    //8 the points awarded per capture
+    pub points_per_capture: u64,
+    //8 the points awarded per release
+    pub points_per_release: u64,
+}
+
+impl LoyaltyRate {
+    pub const SIZE: usize = 8 + 8;
+}
+
+// A user's history with a recipe, updated by every swap the user passes it to. Other programs can
+// read it as an Anchor account to gate their own rewards.
+#[account]
+pub struct SwapReceiptV1 {
+    //32 the recipe the swaps are made on
+    pub recipe: Pubkey,
+    //32 the user making the swaps
+    pub user: Pubkey,
+    //8 the lifetime captures
+    pub captures: u64,
+    //8 the lifetime releases
+    pub releases: u64,
+    //8 the slot of the last swap
+    pub last_swap_slot: u64,
+    //8 the accumulated loyalty points
+    pub loyalty_points: u64,
+    //1 swap receipt bump
+    pub bump: u8,
+}
+
+impl SwapReceiptV1 {
+    pub const SWAP_RECEIPT_SIZE: usize = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 1;
+}
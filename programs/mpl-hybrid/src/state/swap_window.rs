@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+// A time window in unix seconds, a zero bound leaves that side of the window open.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct SwapWindow {
+    //8 the first timestamp swaps are allowed at
+    pub start: i64,
+    //8 the timestamp swaps are no longer allowed from
+    pub end: i64,
+}
+
+impl SwapWindow {
+    pub const SIZE: usize = 8 + 8;
+
+    pub fn is_valid(&self) -> bool {
+        self.start == 0 || self.end == 0 || self.start < self.end
+    }
+
+    pub fn has_opened(&self, now: i64) -> bool {
+        self.start == 0 || now >= self.start
+    }
+
+    pub fn has_closed(&self, now: i64) -> bool {
+        self.end != 0 && now >= self.end
+    }
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+// Holds the lamports of a native SOL recipe, so recipes sharing an escrow can't pay out of each
+// other's reserves.
+#[account]
+pub struct RecipeVaultV1 {
+    //32 the recipe the lamports belong to
+    pub recipe: Pubkey,
+    //1 vault bump
+    pub bump: u8,
+}
+
+impl RecipeVaultV1 {
+    pub const BASE_VAULT_SIZE: usize = 8 + 32 + 1;
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+// Per-wallet limits of a recipe, a zero value disables that limit.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct WalletLimits {
+    //8 the length of a limit window in slots, zero makes the limits lifetime limits
+    pub window_slots: u64,
+    //4 the max captures per wallet per window
+    pub max_captures: u32,
+    //4 the max releases per wallet per window
+    pub max_releases: u32,
+    //8 the minimum number of slots between two swaps of a wallet
+    pub cooldown_slots: u64,
+}
+
+impl WalletLimits {
+    pub const SIZE: usize = 8 + 4 + 4 + 8;
+
+    pub fn is_enabled(&self) -> bool {
+        self.max_captures != 0 || self.max_releases != 0 || self.cooldown_slots != 0
+    }
+}
+
+// Tracks the swaps of a wallet on a recipe, created by the first limited swap of the wallet.
+#[account]
+pub struct WalletActivityV1 {
+    //32 the recipe the swaps are made on
+    pub recipe: Pubkey,
+    //32 the wallet making the swaps
+    pub user: Pubkey,
+    //8 the slot the current window started at
+    pub window_start: u64,
+    //4 the captures in the current window
+    pub captures: u32,
+    //4 the releases in the current window
+    pub releases: u32,
+    //8 the slot of the last swap
+    pub last_swap_slot: u64,
+    //1 wallet activity bump
+    pub bump: u8,
+}
+
+impl WalletActivityV1 {
+    pub const WALLET_ACTIVITY_SIZE: usize = 8 + 32 + 32 + 8 + 4 + 4 + 8 + 1;
+
+    // Whether the window has passed and the cooldown is over, after which nothing is lost by
+    // closing the account.
+    pub fn is_expired(&self, limits: &WalletLimits, slot: u64) -> bool {
+        let window_passed = limits.window_slots != 0
+            && slot >= self.window_start.saturating_add(limits.window_slots);
+        let cooldown_passed = slot >= self.last_swap_slot.saturating_add(limits.cooldown_slots);
+        (window_passed || (limits.max_captures == 0 && limits.max_releases == 0)) && cooldown_passed
+    }
+}
@@ -1,8 +1,26 @@
-use anchor_lang::prelude::*;
-use solana_program::{program::invoke, program_pack::Pack};
+use anchor_lang::{prelude::*, system_program, Discriminator};
+use anchor_spl::token::{self, Burn, Transfer};
+use mpl_core::accounts::{BaseAssetV1, BaseCollectionV1};
+use mpl_core::types::{
+    Key as MplCoreKey, PluginAuthority, PluginType, UpdateAuthority, UpdateDelegate,
+};
+use mpl_core::{fetch_plugin, load_key};
+use mpl_utils::create_or_allocate_account_raw;
+use solana_program::program_memory::sol_memcpy;
+use solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use solana_program::{ed25519_program, keccak, program::invoke, program_pack::Pack};
 use spl_token::state::Account;
 
+use crate::constants::{MAX_BASIS_POINTS, MPL_CORE};
 use crate::error::MplHybridError;
+use crate::state::{
+    AssetCooldown, AssetCooldownV1, BasketV1, DiscountKind, DiscountPolicyV1, OperatorEntry,
+    OperatorRole, OperatorsV1, ProtocolConfigV1, QuoteNonceV1, RecipeChange, RecipeField,
+    RecipeGate, RecipeHistoryV1, RecipeKind, RecipeV1, SwapDirection, SwapQuoteV1, SwapReceiptV1,
+    WalletActivityV1, WalletLimits,
+};
 
 pub fn create_associated_token_account<'info>(
     payer: &AccountInfo<'info>,
@@ -47,3 +65,1000 @@ pub fn validate_token_account(
 
     Ok(())
 }
+
+// Returns the largest discount the proof account qualifies the owner for. The proof is either a
+// Core asset owned by the owner or a token account of the owner.
+pub fn get_holder_discount_bps(
+    policy: &DiscountPolicyV1,
+    proof: &AccountInfo<'_>,
+    owner: &Pubkey,
+) -> Result<u16> {
+    let mut discount_bps = 0;
+
+    if *proof.owner == MPL_CORE && load_key(proof, 0)? == MplCoreKey::AssetV1 {
+        let asset_data = BaseAssetV1::from_bytes(&proof.data.borrow())?;
+        if asset_data.owner != *owner {
+            return Err(MplHybridError::InvalidDiscountProof.into());
+        }
+
+        for entry in policy
+            .entries
+            .iter()
+            .filter(|entry| entry.kind == DiscountKind::Collection)
+        {
+            if asset_data.update_authority == UpdateAuthority::Collection(entry.key) {
+                discount_bps = discount_bps.max(entry.discount_bps);
+            }
+        }
+    } else {
+        for entry in policy
+            .entries
+            .iter()
+            .filter(|entry| entry.kind == DiscountKind::TokenMint)
+        {
+            if validate_token_account(proof, owner, &entry.key).is_ok()
+                && Account::unpack(&proof.data.borrow())?.amount >= entry.min_amount
+            {
+                discount_bps = discount_bps.max(entry.discount_bps);
+            }
+        }
+    }
+
+    if discount_bps == 0 {
+        return Err(MplHybridError::InvalidDiscountProof.into());
+    }
+
+    Ok(discount_bps)
+}
+
+pub fn apply_discount(amount: u64, discount_bps: u16) -> Result<u64> {
+    let discount = (amount as u128)
+        .checked_mul(discount_bps as u128)
+        .ok_or(MplHybridError::NumericalOverflow)?
+        / MAX_BASIS_POINTS as u128;
+
+    Ok(amount
+        .checked_sub(discount as u64)
+        .ok_or(MplHybridError::NumericalOverflow)?)
+}
+
+// Moves lamports out of an account owned by this program, keeping it rent exempt.
+pub fn withdraw_lamports(from: &AccountInfo<'_>, to: &AccountInfo<'_>, amount: u64) -> Result<()> {
+    let minimum_balance = Rent::get()?.minimum_balance(from.data_len());
+    if from.lamports().saturating_sub(minimum_balance) < amount {
+        return Err(MplHybridError::InsufficientEscrowBalance.into());
+    }
+
+    let to_balance = to
+        .lamports()
+        .checked_add(amount)
+        .ok_or(MplHybridError::NumericalOverflow)?;
+    **from.try_borrow_mut_lamports()? -= amount;
+    **to.try_borrow_mut_lamports()? = to_balance;
+
+    Ok(())
+}
+
+// Charges every basket entry from the owner, burning it or moving it into the escrow. The
+// accounts are the mint, user token account and escrow token account of each entry in order.
+pub fn capture_basket<'info>(
+    basket: &BasketV1,
+    accounts: &[AccountInfo<'info>],
+    owner: &AccountInfo<'info>,
+    escrow: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    if accounts.len() != basket.entries.len() * BasketV1::ACCOUNTS_PER_ENTRY {
+        return Err(MplHybridError::MissingTokenAccount.into());
+    }
+
+    for (entry, entry_accounts) in basket
+        .entries
+        .iter()
+        .zip(accounts.chunks_exact(BasketV1::ACCOUNTS_PER_ENTRY))
+    {
+        let (mint, user_token_account, escrow_token_account) =
+            (&entry_accounts[0], &entry_accounts[1], &entry_accounts[2]);
+
+        if mint.key() != entry.mint {
+            return Err(MplHybridError::InvalidMintAccount.into());
+        }
+
+        // The user token account should already exist.
+        validate_token_account(user_token_account, owner.key, &entry.mint)?;
+
+        if entry.burn {
+            let cpi_accounts_burn = Burn {
+                mint: mint.clone(),
+                from: user_token_account.clone(),
+                authority: owner.clone(),
+            };
+
+            token::burn(
+                CpiContext::new(token_program.clone(), cpi_accounts_burn),
+                entry.amount,
+            )?;
+        } else {
+            if escrow_token_account.owner == &system_program::ID {
+                create_associated_token_account(
+                    owner,
+                    escrow,
+                    mint,
+                    escrow_token_account,
+                    token_program,
+                    system_program,
+                )?;
+            } else {
+                validate_token_account(escrow_token_account, escrow.key, &entry.mint)?;
+            }
+
+            let cpi_accounts_transfer = Transfer {
+                from: user_token_account.clone(),
+                to: escrow_token_account.clone(),
+                authority: owner.clone(),
+            };
+
+            token::transfer(
+                CpiContext::new(token_program.clone(), cpi_accounts_transfer),
+                entry.amount,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+// Pays every escrowed basket entry back out to the owner. Burned entries are skipped but their
+// accounts still have to be passed to keep the order fixed.
+pub fn release_basket<'info>(
+    basket: &BasketV1,
+    accounts: &[AccountInfo<'info>],
+    owner: &AccountInfo<'info>,
+    escrow: &AccountInfo<'info>,
+    escrow_seeds: &[&[&[u8]]],
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    if accounts.len() != basket.entries.len() * BasketV1::ACCOUNTS_PER_ENTRY {
+        return Err(MplHybridError::MissingTokenAccount.into());
+    }
+
+    for (entry, entry_accounts) in basket
+        .entries
+        .iter()
+        .zip(accounts.chunks_exact(BasketV1::ACCOUNTS_PER_ENTRY))
+        .filter(|(entry, _)| !entry.burn)
+    {
+        let (mint, user_token_account, escrow_token_account) =
+            (&entry_accounts[0], &entry_accounts[1], &entry_accounts[2]);
+
+        if mint.key() != entry.mint {
+            return Err(MplHybridError::InvalidMintAccount.into());
+        }
+
+        // Create idempotent
+        if user_token_account.owner == &system_program::ID {
+            create_associated_token_account(
+                owner,
+                owner,
+                mint,
+                user_token_account,
+                token_program,
+                system_program,
+            )?;
+        } else {
+            validate_token_account(user_token_account, owner.key, &entry.mint)?;
+        }
+
+        // The escrow token account should already exist.
+        validate_token_account(escrow_token_account, escrow.key, &entry.mint)?;
+
+        let cpi_accounts_transfer = Transfer {
+            from: escrow_token_account.clone(),
+            to: user_token_account.clone(),
+            authority: escrow.clone(),
+        };
+
+        token::transfer(
+            CpiContext::new_with_signer(token_program.clone(), cpi_accounts_transfer, escrow_seeds),
+            entry.amount,
+        )?;
+    }
+
+    Ok(())
+}
+
+// Reads the quote verified by the Ed25519 instruction placed right before the current one. The
+// precompile has already checked the signature, so we only check who signed what.
+pub fn verify_swap_quote(
+    instructions_sysvar: &AccountInfo<'_>,
+    signer: &Pubkey,
+    recipe: &Pubkey,
+    user: &Pubkey,
+    asset: &Pubkey,
+    direction: SwapDirection,
+) -> Result<SwapQuoteV1> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    if current_index == 0 {
+        return Err(MplHybridError::InvalidQuote.into());
+    }
+    let ed25519_ix = load_instruction_at_checked(current_index as usize - 1, instructions_sysvar)?;
+
+    // A single signature whose offsets all point into the precompile instruction itself.
+    let data = &ed25519_ix.data;
+    if ed25519_ix.program_id != ed25519_program::ID || data.len() < 16 || data[0] != 1 {
+        return Err(MplHybridError::InvalidQuote.into());
+    }
+    let offset = |i: usize| u16::from_le_bytes([data[2 + i * 2], data[3 + i * 2]]);
+    if offset(1) != u16::MAX || offset(3) != u16::MAX || offset(6) != u16::MAX {
+        return Err(MplHybridError::InvalidQuote.into());
+    }
+
+    let public_key_offset = offset(2) as usize;
+    let message_offset = offset(4) as usize;
+    let message_size = offset(5) as usize;
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(MplHybridError::InvalidQuote)?;
+    let message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(MplHybridError::InvalidQuote)?;
+
+    if public_key != signer.as_ref() {
+        return Err(MplHybridError::InvalidQuote.into());
+    }
+
+    let quote = SwapQuoteV1::try_from_slice(message).map_err(|_| MplHybridError::InvalidQuote)?;
+    if quote.recipe != *recipe
+        || quote.user != *user
+        || quote.asset != *asset
+        || quote.direction != direction
+    {
+        return Err(MplHybridError::InvalidQuote.into());
+    }
+
+    if Clock::get()?.slot > quote.expiry_slot {
+        return Err(MplHybridError::QuoteExpired.into());
+    }
+
+    Ok(quote)
+}
+
+// Loads a program account that is created lazily at its PDA, allocating it and building its first
+// state with `init` when it doesn't exist yet. The seeds are given without the bump.
+pub fn load_or_create_account<'info, T: AccountDeserialize>(
+    account: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+    bump: u8,
+    size: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    init: impl FnOnce() -> T,
+) -> Result<T> {
+    if account.owner == &crate::ID {
+        return T::try_deserialize(&mut &account.data.borrow()[..]);
+    }
+
+    let bump = [bump];
+    let mut signer_seeds = seeds.to_vec();
+    signer_seeds.push(&bump);
+    create_or_allocate_account_raw(
+        crate::ID,
+        account,
+        system_program,
+        payer,
+        size,
+        &signer_seeds,
+    )?;
+
+    Ok(init())
+}
+
+// Writes the state of a program account created with `load_or_create_account`.
+pub fn write_account<T: AnchorSerialize + Discriminator>(
+    account: &AccountInfo<'_>,
+    state: &T,
+) -> Result<()> {
+    let mut data = T::DISCRIMINATOR.to_vec();
+    data.extend(state.try_to_vec()?);
+
+    let mut data_borrowed = account.data.borrow_mut();
+    sol_memcpy(&mut data_borrowed, &data, data.len());
+
+    Ok(())
+}
+
+// Creates the nonce account of a quote so it can't be replayed.
+pub fn consume_quote_nonce<'info>(
+    quote_nonce: &AccountInfo<'info>,
+    recipe: &Pubkey,
+    nonce: u64,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let (expected, bump) = Pubkey::find_program_address(
+        &["quote".as_bytes(), recipe.as_ref(), &nonce.to_le_bytes()],
+        &crate::ID,
+    );
+    if quote_nonce.key() != expected {
+        return Err(MplHybridError::InvalidQuote.into());
+    }
+    if quote_nonce.owner == &crate::ID {
+        return Err(MplHybridError::QuoteAlreadyUsed.into());
+    }
+
+    let nonce_bytes = nonce.to_le_bytes();
+    let quote_nonce_data = load_or_create_account(
+        quote_nonce,
+        &["quote".as_bytes(), recipe.as_ref(), &nonce_bytes],
+        bump,
+        QuoteNonceV1::QUOTE_NONCE_SIZE,
+        payer,
+        system_program,
+        || QuoteNonceV1 {
+            recipe: *recipe,
+            nonce,
+            bump,
+        },
+    )?;
+
+    write_account(quote_nonce, &quote_nonce_data)
+}
+
+// Counts a swap against the wallet's limits on the recipe, creating the wallet activity account
+// on the wallet's first limited swap.
+pub fn record_wallet_swap<'info>(
+    wallet_activity: &AccountInfo<'info>,
+    recipe: &Pubkey,
+    limits: &WalletLimits,
+    direction: SwapDirection,
+    user: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let (expected, bump) = Pubkey::find_program_address(
+        &["wallet".as_bytes(), recipe.as_ref(), user.key.as_ref()],
+        &crate::ID,
+    );
+    if wallet_activity.key() != expected {
+        return Err(MplHybridError::InvalidWalletActivity.into());
+    }
+
+    let slot = Clock::get()?.slot;
+    let mut activity = load_or_create_account(
+        wallet_activity,
+        &["wallet".as_bytes(), recipe.as_ref(), user.key.as_ref()],
+        bump,
+        WalletActivityV1::WALLET_ACTIVITY_SIZE,
+        user,
+        system_program,
+        || WalletActivityV1 {
+            recipe: *recipe,
+            user: user.key(),
+            window_start: slot,
+            captures: 0,
+            releases: 0,
+            last_swap_slot: 0,
+            bump,
+        },
+    )?;
+
+    if activity.last_swap_slot != 0
+        && slot
+            < activity
+                .last_swap_slot
+                .saturating_add(limits.cooldown_slots)
+    {
+        return Err(MplHybridError::WalletCooldownActive.into());
+    }
+
+    // Start a new window once the current one has passed.
+    if limits.window_slots != 0 && slot >= activity.window_start.saturating_add(limits.window_slots)
+    {
+        activity.window_start = slot;
+        activity.captures = 0;
+        activity.releases = 0;
+    }
+
+    match direction {
+        SwapDirection::Capture => {
+            activity.captures = activity.captures.saturating_add(1);
+            if limits.max_captures != 0 && activity.captures > limits.max_captures {
+                return Err(MplHybridError::WalletCaptureLimitReached.into());
+            }
+        }
+        SwapDirection::Release => {
+            activity.releases = activity.releases.saturating_add(1);
+            if limits.max_releases != 0 && activity.releases > limits.max_releases {
+                return Err(MplHybridError::WalletReleaseLimitReached.into());
+            }
+        }
+    }
+    activity.last_swap_slot = slot;
+
+    write_account(wallet_activity, &activity)
+}
+
+// Fails while the protocol admin has paused all swaps. The protocol config is only created once
+// an admin is set, until then the config PDA is uninitialized and swaps can't be paused.
+pub fn assert_protocol_not_paused(protocol_config: &AccountInfo<'_>) -> Result<()> {
+    if protocol_config.owner != &crate::ID {
+        return Ok(());
+    }
+
+    let protocol_config_data =
+        ProtocolConfigV1::try_deserialize(&mut &protocol_config.data.borrow()[..])?;
+    if protocol_config_data.paused {
+        msg!("Pause reason: {}", protocol_config_data.pause_reason);
+        return Err(MplHybridError::ProtocolPaused.into());
+    }
+
+    Ok(())
+}
+
+// Checks the asset has spent long enough on its side of the escrow and records the swap, creating
+// the asset cooldown account on the asset's first swap.
+pub fn record_asset_swap<'info>(
+    asset_cooldown: &AccountInfo<'info>,
+    asset: &Pubkey,
+    cooldown: &AssetCooldown,
+    direction: SwapDirection,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let (expected, bump) = Pubkey::find_program_address(
+        &["nft".as_bytes(), asset.as_ref(), "cooldown".as_bytes()],
+        &crate::ID,
+    );
+    if asset_cooldown.key() != expected {
+        return Err(MplHybridError::InvalidAssetCooldown.into());
+    }
+
+    let slot = Clock::get()?.slot;
+    let mut asset_cooldown_data = load_or_create_account(
+        asset_cooldown,
+        &["nft".as_bytes(), asset.as_ref(), "cooldown".as_bytes()],
+        bump,
+        AssetCooldownV1::ASSET_COOLDOWN_SIZE,
+        payer,
+        system_program,
+        || AssetCooldownV1 {
+            asset: *asset,
+            last_capture_slot: 0,
+            last_release_slot: 0,
+            bump,
+        },
+    )?;
+
+    match direction {
+        // Captures take the asset out of the escrow it was released into.
+        SwapDirection::Capture => {
+            if asset_cooldown_data.last_release_slot != 0
+                && slot
+                    < asset_cooldown_data
+                        .last_release_slot
+                        .saturating_add(cooldown.min_slots_in)
+            {
+                return Err(MplHybridError::AssetCooldownActive.into());
+            }
+            asset_cooldown_data.last_capture_slot = slot;
+        }
+        // Releases put back an asset that was captured out of the escrow.
+        SwapDirection::Release => {
+            if asset_cooldown_data.last_capture_slot != 0
+                && slot
+                    < asset_cooldown_data
+                        .last_capture_slot
+                        .saturating_add(cooldown.min_slots_out)
+            {
+                return Err(MplHybridError::AssetCooldownActive.into());
+            }
+            asset_cooldown_data.last_release_slot = slot;
+        }
+    }
+
+    write_account(asset_cooldown, &asset_cooldown_data)
+}
+
+// Adds a swap and its loyalty points to the user's receipt, creating the receipt on the user's
+// first swap with one.
+pub fn record_swap_receipt<'info>(
+    swap_receipt: &AccountInfo<'info>,
+    recipe: &Pubkey,
+    direction: SwapDirection,
+    points: u64,
+    user: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let (expected, bump) = Pubkey::find_program_address(
+        &["receipt".as_bytes(), recipe.as_ref(), user.key.as_ref()],
+        &crate::ID,
+    );
+    if swap_receipt.key() != expected {
+        return Err(MplHybridError::InvalidSwapReceipt.into());
+    }
+
+    let mut receipt = load_or_create_account(
+        swap_receipt,
+        &["receipt".as_bytes(), recipe.as_ref(), user.key.as_ref()],
+        bump,
+        SwapReceiptV1::SWAP_RECEIPT_SIZE,
+        user,
+        system_program,
+        || SwapReceiptV1 {
+            recipe: *recipe,
+            user: user.key(),
+            captures: 0,
+            releases: 0,
+            last_swap_slot: 0,
+            loyalty_points: 0,
+            bump,
+        },
+    )?;
+
+    match direction {
+        SwapDirection::Capture => receipt.captures = receipt.captures.saturating_add(1),
+        SwapDirection::Release => receipt.releases = receipt.releases.saturating_add(1),
+    }
+    receipt.last_swap_slot = Clock::get()?.slot;
+    receipt.loyalty_points = receipt.loyalty_points.saturating_add(points);
+
+    write_account(swap_receipt, &receipt)
+}
+
+// Whether the key administers the collection, either as its update authority or as a delegate of
+// its UpdateDelegate plugin.
+pub fn is_collection_authority(collection: &AccountInfo<'_>, authority: &Pubkey) -> Result<bool> {
+    // We only fetch the Base collection to check authority.
+    let collection_data = BaseCollectionV1::from_bytes(&collection.data.borrow())?;
+    if collection_data.update_authority == *authority {
+        return Ok(true);
+    }
+
+    // Collections without the plugin have no delegates.
+    Ok(
+        match fetch_plugin::<BaseCollectionV1, UpdateDelegate>(
+            collection,
+            PluginType::UpdateDelegate,
+        ) {
+            Ok((plugin_authority, update_delegate, _)) => {
+                plugin_authority
+                    == PluginAuthority::Address {
+                        address: *authority,
+                    }
+                    || update_delegate.additional_delegates.contains(authority)
+            }
+            Err(_) => false,
+        },
+    )
+}
+
+// The recipe is administered by its authority, which only changes through a handover.
+pub fn is_recipe_authority(recipe: &RecipeV1, authority: &Pubkey) -> bool {
+    recipe.authority == *authority
+}
+
+pub fn assert_recipe_authority(recipe: &RecipeV1, authority: &Pubkey) -> Result<()> {
+    if !is_recipe_authority(recipe, authority) {
+        return Err(MplHybridError::InvalidAuthority.into());
+    }
+
+    Ok(())
+}
+
+// Until a collection recipe is handed over outside of the collection, its update authority and
+// update delegates administer it interchangeably.
+pub fn is_collection_recipe_authority(
+    recipe: &RecipeV1,
+    collection: &AccountInfo<'_>,
+    authority: &Pubkey,
+) -> Result<bool> {
+    if is_recipe_authority(recipe, authority) {
+        return Ok(true);
+    }
+
+    Ok(recipe.kind == RecipeKind::Collection
+        && is_collection_authority(collection, &recipe.authority)?
+        && is_collection_authority(collection, authority)?)
+}
+
+// The kind of assets the key passed as the collection stands for where there's no recipe to tell,
+// Core collections stand for their assets and any other key for the standalone assets it updates.
+pub fn collection_kind(collection: &AccountInfo<'_>) -> Result<RecipeKind> {
+    if *collection.owner == MPL_CORE && load_key(collection, 0)? == MplCoreKey::CollectionV1 {
+        Ok(RecipeKind::Collection)
+    } else {
+        Ok(RecipeKind::Creator)
+    }
+}
+
+// Whether the key administers the assets of that kind, the collection authority of collection
+// assets and the creator itself of standalone assets.
+pub fn is_asset_authority(
+    kind: RecipeKind,
+    collection: &AccountInfo<'_>,
+    authority: &Pubkey,
+) -> Result<bool> {
+    match kind {
+        RecipeKind::Collection => is_collection_authority(collection, authority),
+        RecipeKind::Creator => Ok(collection.key == authority),
+    }
+}
+
+pub fn assert_asset_authority(
+    kind: RecipeKind,
+    collection: &AccountInfo<'_>,
+    authority: &Pubkey,
+) -> Result<()> {
+    if !is_asset_authority(kind, collection, authority)? {
+        return Err(MplHybridError::InvalidCollectionAuthority.into());
+    }
+
+    Ok(())
+}
+
+pub fn assert_collection_authority(collection: &AccountInfo<'_>, authority: &Pubkey) -> Result<()> {
+    if !is_collection_authority(collection, authority)? {
+        return Err(MplHybridError::InvalidCollectionAuthority.into());
+    }
+
+    Ok(())
+}
+
+// Checks the signer is the recipe authority, or is an operator holding every role needed for the
+// change. Roles granted by a previous authority lapse once the recipe is handed over.
+pub fn assert_recipe_admin(
+    recipe: &RecipeV1,
+    authority: &Pubkey,
+    operators: Option<&OperatorsV1>,
+    roles: &[OperatorRole],
+) -> Result<()> {
+    if is_recipe_authority(recipe, authority) {
+        return Ok(());
+    }
+
+    match operators {
+        Some(operators)
+            if operators.authority == recipe.authority && operators.has_roles(authority, roles) =>
+        {
+            Ok(())
+        }
+        _ => Err(MplHybridError::InvalidAuthority.into()),
+    }
+}
+
+// The basket, payment table and discount policy can't be queued, so they are frozen while a
+// timelock protects the recipe. Clearing the timelock is itself queued behind it.
+pub fn assert_not_timelocked(recipe: &RecipeV1) -> Result<()> {
+    if recipe.timelock_slots > 0 {
+        return Err(MplHybridError::RecipeTimelocked.into());
+    }
+
+    Ok(())
+}
+
+// The side configuration of a recipe follows the locks of the recipe fields it stands in for.
+pub fn assert_fields_unlocked(recipe: &RecipeV1, fields: &[RecipeField]) -> Result<()> {
+    if fields
+        .iter()
+        .any(|field| field.clone().check(recipe.locked))
+    {
+        return Err(MplHybridError::RecipeFieldLocked.into());
+    }
+
+    Ok(())
+}
+
+// Each operator is listed once so all of its roles are in a single entry.
+pub fn assert_unique_operators(entries: &[OperatorEntry]) -> Result<()> {
+    for (i, entry) in entries.iter().enumerate() {
+        if entries[..i]
+            .iter()
+            .any(|other| other.operator == entry.operator)
+        {
+            return Err(MplHybridError::DuplicateOperator.into());
+        }
+    }
+
+    Ok(())
+}
+
+// Appends the change to the recipe history, creating the history account on the first change.
+pub fn record_recipe_change<'info>(
+    history: &AccountInfo<'info>,
+    recipe: &Pubkey,
+    bump: u8,
+    change: RecipeChange,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let mut recipe_history = load_or_create_account(
+        history,
+        &["history".as_bytes(), recipe.as_ref()],
+        bump,
+        RecipeHistoryV1::RECIPE_HISTORY_SIZE,
+        payer,
+        system_program,
+        || RecipeHistoryV1 {
+            recipe: *recipe,
+            count: 0,
+            bump,
+            entries: vec![],
+        },
+    )?;
+
+    recipe_history.record(change);
+
+    write_account(history, &recipe_history)
+}
+
+// Verifies a keccak Merkle proof built with sorted pairs, the leaf being the hash of the key.
+pub fn verify_merkle_proof(root: &[u8; 32], key: &Pubkey, proof: &[[u8; 32]]) -> bool {
+    let mut node = keccak::hashv(&[key.as_ref()]).to_bytes();
+    for sibling in proof {
+        node = if node <= *sibling {
+            keccak::hashv(&[&node, sibling]).to_bytes()
+        } else {
+            keccak::hashv(&[sibling, &node]).to_bytes()
+        };
+    }
+
+    node == *root
+}
+
+// Checks the owner holds enough assets of the gate collection, each gate asset being passed once.
+pub fn assert_gate_holder(
+    gate: &RecipeGate,
+    assets: &[AccountInfo<'_>],
+    owner: &Pubkey,
+) -> Result<()> {
+    for (i, asset) in assets.iter().enumerate() {
+        if *asset.owner != MPL_CORE
+            || load_key(asset, 0)? != MplCoreKey::AssetV1
+            || assets[..i].iter().any(|other| other.key == asset.key)
+        {
+            return Err(MplHybridError::InvalidGateAsset.into());
+        }
+
+        let asset_data = BaseAssetV1::from_bytes(&asset.data.borrow())?;
+        if asset_data.owner != *owner
+            || asset_data.update_authority != UpdateAuthority::Collection(gate.collection)
+        {
+            return Err(MplHybridError::InvalidGateAsset.into());
+        }
+    }
+
+    if assets.len() < gate.required() as usize {
+        return Err(MplHybridError::GateNotMet.into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+        if a <= b {
+            keccak::hashv(&[a, b]).to_bytes()
+        } else {
+            keccak::hashv(&[b, a]).to_bytes()
+        }
+    }
+
+    // Builds a sorted-pair keccak tree bottom up, promoting the odd node of a level unhashed, and
+    // returns its root with the proof of every leaf.
+    fn tree(keys: &[Pubkey]) -> ([u8; 32], Vec<Vec<[u8; 32]>>) {
+        let mut level: Vec<[u8; 32]> = keys
+            .iter()
+            .map(|key| keccak::hashv(&[key.as_ref()]).to_bytes())
+            .collect();
+        let mut positions: Vec<usize> = (0..keys.len()).collect();
+        let mut proofs = vec![vec![]; keys.len()];
+
+        while level.len() > 1 {
+            for (proof, position) in proofs.iter_mut().zip(positions.iter_mut()) {
+                if let Some(sibling) = level.get(*position ^ 1) {
+                    proof.push(*sibling);
+                }
+                *position /= 2;
+            }
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => hash_pair(a, b),
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        (level[0], proofs)
+    }
+
+    fn keys(count: u8) -> Vec<Pubkey> {
+        (1..=count)
+            .map(|i| Pubkey::new_from_array([i; 32]))
+            .collect()
+    }
+
+    #[test]
+    fn every_key_of_the_tree_verifies() {
+        let keys = keys(5);
+        let (root, proofs) = tree(&keys);
+
+        for (key, proof) in keys.iter().zip(&proofs) {
+            assert!(verify_merkle_proof(&root, key, proof));
+        }
+    }
+
+    #[test]
+    fn a_single_key_tree_verifies_without_a_proof() {
+        let keys = keys(1);
+        let (root, _) = tree(&keys);
+
+        assert!(verify_merkle_proof(&root, &keys[0], &[]));
+    }
+
+    #[test]
+    fn keys_outside_the_tree_do_not_verify() {
+        let keys = keys(5);
+        let (root, proofs) = tree(&keys[..4]);
+
+        assert!(!verify_merkle_proof(&root, &keys[4], &proofs[0]));
+        assert!(!verify_merkle_proof(&root, &keys[1], &proofs[0]));
+    }
+
+    #[test]
+    fn tampered_proofs_do_not_verify() {
+        let keys = keys(4);
+        let (root, proofs) = tree(&keys);
+
+        let mut proof = proofs[2].clone();
+        proof[1][0] ^= 1;
+        assert!(!verify_merkle_proof(&root, &keys[2], &proof));
+        assert!(!verify_merkle_proof(&root, &keys[2], &proofs[2][..1]));
+    }
+
+    // A Core asset without plugins: the AssetV1 key, the owner, a collection update authority, an
+    // empty name and uri and no seq.
+    fn asset_data(owner: &Pubkey, collection: &Pubkey) -> Vec<u8> {
+        let mut data = vec![MplCoreKey::AssetV1 as u8];
+        data.extend_from_slice(owner.as_ref());
+        data.push(2);
+        data.extend_from_slice(collection.as_ref());
+        data.extend_from_slice(&[0; 9]);
+        data
+    }
+
+    // Runs the check against Core assets given as (address, owner, collection).
+    fn check_gate(
+        gate: &RecipeGate,
+        assets: &[(Pubkey, Pubkey, Pubkey)],
+        owner: &Pubkey,
+    ) -> Result<()> {
+        let mut lamports = vec![0; assets.len()];
+        let mut data: Vec<Vec<u8>> = assets
+            .iter()
+            .map(|(_, owner, collection)| asset_data(owner, collection))
+            .collect();
+        let infos: Vec<AccountInfo> = assets
+            .iter()
+            .zip(lamports.iter_mut())
+            .zip(data.iter_mut())
+            .map(|(((key, _, _), lamports), data)| {
+                AccountInfo::new(key, false, false, lamports, data, &MPL_CORE, false, 0)
+            })
+            .collect();
+
+        assert_gate_holder(gate, &infos, owner)
+    }
+
+    fn gate(min_count: u32) -> RecipeGate {
+        RecipeGate {
+            collection: Pubkey::new_from_array([9; 32]),
+            min_count,
+        }
+    }
+
+    #[test]
+    fn holders_of_enough_gate_assets_pass() {
+        let gate = gate(2);
+        let owner = Pubkey::new_unique();
+        let assets = [
+            (Pubkey::new_unique(), owner, gate.collection),
+            (Pubkey::new_unique(), owner, gate.collection),
+        ];
+
+        assert!(check_gate(&gate, &assets, &owner).is_ok());
+    }
+
+    #[test]
+    fn too_few_gate_assets_do_not_pass() {
+        let gate = gate(2);
+        let owner = Pubkey::new_unique();
+        let assets = [(Pubkey::new_unique(), owner, gate.collection)];
+
+        assert_eq!(
+            check_gate(&gate, &assets, &owner).unwrap_err(),
+            MplHybridError::GateNotMet.into()
+        );
+        // An unset minimum still requires one gate asset.
+        assert_eq!(
+            check_gate(&self::gate(0), &[], &owner).unwrap_err(),
+            MplHybridError::GateNotMet.into()
+        );
+    }
+
+    #[test]
+    fn gate_assets_of_other_owners_or_collections_are_rejected() {
+        let gate = gate(1);
+        let owner = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+
+        for asset in [
+            (Pubkey::new_unique(), other, gate.collection),
+            (Pubkey::new_unique(), owner, other),
+        ] {
+            assert_eq!(
+                check_gate(&gate, &[asset], &owner).unwrap_err(),
+                MplHybridError::InvalidGateAsset.into()
+            );
+        }
+    }
+
+    #[test]
+    fn a_gate_asset_counts_once() {
+        let gate = gate(2);
+        let owner = Pubkey::new_unique();
+        let asset = (Pubkey::new_unique(), owner, gate.collection);
+
+        assert_eq!(
+            check_gate(&gate, &[asset, asset], &owner).unwrap_err(),
+            MplHybridError::InvalidGateAsset.into()
+        );
+    }
+
+    // Runs the check against the protocol config PDA, owned by the program once initialized.
+    fn check_protocol(owner: &Pubkey, paused: Option<bool>) -> Result<()> {
+        let key = Pubkey::find_program_address(&[b"protocol"], &crate::ID).0;
+        let mut lamports = 0;
+        let mut data = vec![];
+        if let Some(paused) = paused {
+            let config = ProtocolConfigV1 {
+                admin: Pubkey::new_unique(),
+                paused,
+                pause_reason: 0,
+                bump: 0,
+            };
+            config.try_serialize(&mut data).unwrap();
+        }
+        let info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            owner,
+            false,
+            0,
+        );
+
+        assert_protocol_not_paused(&info)
+    }
+
+    #[test]
+    fn swaps_are_blocked_while_the_protocol_is_paused() {
+        assert_eq!(
+            check_protocol(&crate::ID, Some(true)).unwrap_err(),
+            MplHybridError::ProtocolPaused.into()
+        );
+        assert!(check_protocol(&crate::ID, Some(false)).is_ok());
+    }
+
+    #[test]
+    fn swaps_are_allowed_before_the_protocol_config_exists() {
+        assert!(check_protocol(&system_program::ID, None).is_ok());
+    }
+}